@@ -0,0 +1,1050 @@
+//! Convert the CSV files in `data/<year>/` into static Rust arrays.
+//!
+//! The generated `tax_data.rs` is included by `src/data.rs`. Every row is
+//! checked here, so malformed data fails the build instead of a later
+//! computation. Only years whose `year-<year>` feature is enabled are
+//! generated.
+//!
+//! It also generates the Consumer Price Index series from `data/cpi_u.csv`,
+//! each year's Social Security wage base from
+//! `data/social_security_wage_base.csv`, the cost-of-living adjustment of
+//! each year's brackets from `data/cost_of_living_adjustment.csv`, the
+//! poverty guidelines each year's premium tax credit uses from
+//! `data/poverty_guidelines.csv`, the life expectancy tables for required
+//! minimum distributions from `data/life_expectancy.csv`, and, with the
+//! `historical` feature, the earlier years' rate schedules from
+//! `data/historical/tax_rate_schedule.csv`.
+//!
+//! With the `capi` feature, it also generates the C header for `src/capi.rs`
+//! into `$OUT_DIR/us_tax_brackets.h`.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+/// The filing status keys used in the CSV files, in the order `src/data.rs`
+/// indexes them.
+const STATUS_KEYS: [&str; 4] = [
+    "single",
+    "married_filing_jointly",
+    "married_filing_separately",
+    "head_of_household",
+];
+
+/// The data files in each `data/<year>/` directory whose checksums are
+/// embedded.
+const DATA_FILES: [&str; 22] = [
+    "tax_table.csv",
+    "tax_computation_worksheet.csv",
+    "tax_rate_schedule.csv",
+    "filing_thresholds.csv",
+    "standard_deduction.csv",
+    "dependent_standard_deduction.csv",
+    "capital_gains_rate_schedule.csv",
+    "amt_exemptions.csv",
+    "trust_rate_schedule.csv",
+    "estate_gift_exclusions.csv",
+    "eitc.csv",
+    "eitc_investment_income_limit.csv",
+    "child_tax_credit.csv",
+    "education_credit_phaseouts.csv",
+    "savers_credit.csv",
+    "adoption_credit.csv",
+    "ptc_applicable_percentage.csv",
+    "qbi_thresholds.csv",
+    "ira_limits.csv",
+    "contribution_limits.csv",
+    "irmaa.csv",
+    "withholding.csv",
+];
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let data_dir = manifest_dir.join("data");
+    println!("cargo:rerun-if-changed=data");
+
+    let mut years: Vec<u16> = fs::read_dir(&data_dir)
+        .expect("data/ directory exists")
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .filter(|year| env::var_os(format!("CARGO_FEATURE_YEAR_{year}")).is_some())
+        .collect();
+    years.sort_unstable();
+
+    let mut tables = Vec::new();
+    let mut worksheets = Vec::new();
+    let mut schedules = Vec::new();
+    let mut thresholds = Vec::new();
+    let mut capital_gains = Vec::new();
+    let mut savers_credit = Vec::new();
+    let mut deductions = Vec::new();
+    let mut dependent_deductions = Vec::new();
+    let mut amt_exemptions = Vec::new();
+    let mut trust_schedules = Vec::new();
+    let mut estate_gift_exclusions = Vec::new();
+    let mut eitc_parameters = Vec::new();
+    let mut eitc_investment_income_limits = Vec::new();
+    let mut child_tax_credits = Vec::new();
+    let mut education_phaseouts = Vec::new();
+    let mut adoption_credits = Vec::new();
+    let mut applicable_percentages = Vec::new();
+    let mut qbi_thresholds = Vec::new();
+    let mut ira_limits = Vec::new();
+    let mut contribution_limits = Vec::new();
+    let mut irmaa_tiers = Vec::new();
+    let mut withholding_tables = Vec::new();
+    let mut provenance = Vec::new();
+    for &year in &years {
+        let dir = data_dir.join(year.to_string());
+        for file in DATA_FILES.iter().chain(&["provenance.csv"]) {
+            println!("cargo:rerun-if-changed=data/{year}/{file}");
+        }
+        provenance.push(data_provenance(&dir, year));
+        tables.push(tax_table(&dir, year));
+        worksheets.push(by_status(
+            &dir,
+            year,
+            "tax_computation_worksheet.csv",
+            5,
+            |c| {
+                let income_max = income_max(c[2])?;
+                Some(format!(
+                    "WorksheetBracket {{ income_min: {}, income_max: {income_max}, rate: {}_f64, \
+                 subtraction_amount: {}_f64, rate_basis_points: {}, subtraction_cents: {} }}",
+                    c[1].parse::<i64>().ok()?,
+                    float(c[3])?,
+                    float(c[4])?,
+                    scaled(c[3], 4)?,
+                    scaled(c[4], 2)?,
+                ))
+            },
+        ));
+        let bracket = |c: &[&str]| {
+            let income_max = income_max(c[2])?;
+            Some(format!(
+                "TaxBracket {{ income_min: {}, income_max: {income_max}, rate: {}_f64 }}",
+                c[1].parse::<i64>().ok()?,
+                float(c[3])?,
+            ))
+        };
+        schedules.push(by_status(&dir, year, "tax_rate_schedule.csv", 4, bracket));
+        capital_gains.push(by_status(
+            &dir,
+            year,
+            "capital_gains_rate_schedule.csv",
+            4,
+            bracket,
+        ));
+        savers_credit.push(by_status(&dir, year, "savers_credit.csv", 4, bracket));
+        thresholds.push(by_status(&dir, year, "filing_thresholds.csv", 3, |c| {
+            Some(format!(
+                "FilingThreshold {{ age_65_or_older: {}, gross_income: {} }}",
+                c[1].parse::<u8>().ok()?,
+                c[2].parse::<i64>().ok()?,
+            ))
+        }));
+        let trust_brackets = rows(&dir, year, "trust_rate_schedule.csv", 3, |c| {
+            let income_max = income_max(c[1])?;
+            Some(format!(
+                "TaxBracket {{ income_min: {}, income_max: {income_max}, rate: {}_f64 }}",
+                c[0].parse::<i64>().ok()?,
+                float(c[2])?,
+            ))
+        });
+        trust_schedules.push(format!("&[{}]", trust_brackets.join(", ")));
+        deductions.push(one_per_status(
+            &dir,
+            year,
+            "standard_deduction.csv",
+            3,
+            |c| {
+                Some(format!(
+                    "StandardDeductionAmounts {{ basic: {}, additional: {} }}",
+                    c[1].parse::<i64>().ok()?,
+                    c[2].parse::<i64>().ok()?,
+                ))
+            },
+        ));
+        amt_exemptions.push(one_per_status(&dir, year, "amt_exemptions.csv", 4, |c| {
+            Some(format!(
+                "AmtExemption {{ exemption: {}, phaseout_threshold: {}, rate_28_threshold: {} }}",
+                c[1].parse::<i64>().ok()?,
+                c[2].parse::<i64>().ok()?,
+                c[3].parse::<i64>().ok()?,
+            ))
+        }));
+        dependent_deductions.push(dependent_standard_deduction(&dir, year));
+        estate_gift_exclusions.push(estate_gift_exclusion(&dir, year));
+        eitc_parameters.push(eitc(&dir, year));
+        eitc_investment_income_limits.push(eitc_investment_income_limit(&dir, year));
+        child_tax_credits.push(child_tax_credit(&dir, year));
+        education_phaseouts.push(education_credit_phaseouts(&dir, year));
+        adoption_credits.push(adoption_credit(&dir, year));
+        applicable_percentages.push(ptc_applicable_percentages(&dir, year));
+        qbi_thresholds.push(one_per_status(&dir, year, "qbi_thresholds.csv", 3, |c| {
+            Some(format!(
+                "QbiThreshold {{ threshold: {}, phase_in_range: {} }}",
+                c[1].parse::<i64>().ok()?,
+                c[2].parse::<i64>().ok().filter(|&range| range > 0)?,
+            ))
+        }));
+        ira_limits.push(ira_limit(&dir, year));
+        contribution_limits.push(contribution_limit(&dir, year));
+        irmaa_tiers.push(by_status(&dir, year, "irmaa.csv", 4, |c| {
+            Some(format!(
+                "IrmaaTier {{ magi_over: {}, part_b_premium: UsdAmount::from_cents({}), \
+                 part_d_adjustment: UsdAmount::from_cents({}) }}",
+                c[1].parse::<i64>().ok()?,
+                scaled(c[2], 2)?,
+                scaled(c[3], 2)?,
+            ))
+        }));
+        // The percentage method tables start in 2020, with the redesigned
+        // Form W-4; earlier years have only a header.
+        let no_withholding_tables = rows(&dir, year, "withholding.csv", 5, |_| Some(())).is_empty();
+        withholding_tables.push(if no_withholding_tables {
+            vec!["&[]".to_string(); STATUS_KEYS.len()]
+        } else {
+            by_status(&dir, year, "withholding.csv", 5, |c| {
+                Some(format!(
+                    "WithholdingBracket {{ step_2_checkbox: {}, wage_over: {}, \
+                 tentative_withholding: UsdAmount::from_cents({}), rate: {}_f64 }}",
+                    c[1].parse::<bool>().ok()?,
+                    c[2].parse::<i64>().ok()?,
+                    scaled(c[3], 2)?,
+                    float(c[4])?,
+                ))
+            })
+        });
+    }
+
+    let n = years.len();
+    let mut out = String::from("// Generated by build.rs from data/<year>/*.csv. Do not edit.\n\n");
+    writeln!(out, "const DATA_YEARS: [u16; {n}] = {years:?};\n").unwrap();
+    writeln!(
+        out,
+        "static TAX_TABLES: [&[TaxTableRow]; {n}] = [{}];\n",
+        tables.join(", ")
+    )
+    .unwrap();
+    for (name, ty, per_year) in [
+        ("WORKSHEETS", "WorksheetBracket", &worksheets),
+        ("RATE_SCHEDULES", "TaxBracket", &schedules),
+        ("FILING_THRESHOLDS", "FilingThreshold", &thresholds),
+        ("CAPITAL_GAINS_SCHEDULES", "TaxBracket", &capital_gains),
+        ("SAVERS_CREDIT_TIERS", "TaxBracket", &savers_credit),
+        ("IRMAA_TIERS", "IrmaaTier", &irmaa_tiers),
+        (
+            "WITHHOLDING_TABLES",
+            "WithholdingBracket",
+            &withholding_tables,
+        ),
+    ] {
+        let years: Vec<String> = per_year
+            .iter()
+            .map(|statuses| format!("[{}]", statuses.join(", ")))
+            .collect();
+        writeln!(
+            out,
+            "static {name}: [[&[{ty}]; {}]; {n}] = [{}];\n",
+            STATUS_KEYS.len(),
+            years.join(", ")
+        )
+        .unwrap();
+    }
+
+    writeln!(
+        out,
+        "static STANDARD_DEDUCTIONS: [[StandardDeductionAmounts; {}]; {n}] = [{}];\n",
+        STATUS_KEYS.len(),
+        deductions.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static TRUST_SCHEDULES: [&[TaxBracket]; {n}] = [{}];\n",
+        trust_schedules.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static AMT_EXEMPTIONS: [[AmtExemption; {}]; {n}] = [{}];\n",
+        STATUS_KEYS.len(),
+        amt_exemptions.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static DEPENDENT_STANDARD_DEDUCTIONS: [DependentStandardDeduction; {n}] = [{}];\n",
+        dependent_deductions.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static ESTATE_GIFT_EXCLUSIONS: [EstateGiftExclusions; {n}] = [{}];\n",
+        estate_gift_exclusions.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static EITC_PARAMETERS: [[EitcParameters; 4]; {n}] = [{}];\n",
+        eitc_parameters.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static EITC_INVESTMENT_INCOME_LIMITS: [i64; {n}] = [{}];\n",
+        eitc_investment_income_limits.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static CHILD_TAX_CREDITS: [ChildTaxCreditAmounts; {n}] = [{}];\n",
+        child_tax_credits.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static EDUCATION_CREDIT_PHASEOUTS: [[EducationCreditPhaseout; 2]; {n}] = [{}];\n",
+        education_phaseouts.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static ADOPTION_CREDITS: [AdoptionCreditAmounts; {n}] = [{}];\n",
+        adoption_credits.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static PTC_APPLICABLE_PERCENTAGES: [&[ApplicablePercentage]; {n}] = [{}];\n",
+        applicable_percentages.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static QBI_THRESHOLDS: [[QbiThreshold; {}]; {n}] = [{}];\n",
+        STATUS_KEYS.len(),
+        qbi_thresholds.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static IRA_LIMITS: [IraLimits; {n}] = [{}];\n",
+        ira_limits.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static CONTRIBUTION_LIMITS: [ContributionLimitAmounts; {n}] = [{}];\n",
+        contribution_limits.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "static PROVENANCE: [DataProvenance; {n}] = [{}];",
+        provenance.join(", ")
+    )
+    .unwrap();
+
+    println!("cargo:rerun-if-changed=data/cpi_u.csv");
+    out.push('\n');
+    out.push_str(&consumer_price_index(&data_dir));
+
+    println!("cargo:rerun-if-changed=data/social_security_wage_base.csv");
+    out.push('\n');
+    out.push_str(&social_security_wage_bases(&data_dir, &years));
+
+    println!("cargo:rerun-if-changed=data/cost_of_living_adjustment.csv");
+    out.push('\n');
+    out.push_str(&cost_of_living_adjustments(&data_dir, &years));
+
+    println!("cargo:rerun-if-changed=data/poverty_guidelines.csv");
+    out.push('\n');
+    out.push_str(&poverty_guidelines(&data_dir, &years));
+
+    println!("cargo:rerun-if-changed=data/life_expectancy.csv");
+    out.push('\n');
+    out.push_str(&life_expectancy(&data_dir));
+
+    if env::var_os("CARGO_FEATURE_HISTORICAL").is_some() {
+        println!("cargo:rerun-if-changed=data/historical/tax_rate_schedule.csv");
+        out.push('\n');
+        out.push_str(&historical_schedules(&data_dir));
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("tax_data.rs"), out).unwrap();
+
+    #[cfg(feature = "capi")]
+    c_header(&manifest_dir, &out_dir);
+}
+
+/// Generate the C header with cbindgen.
+#[cfg(feature = "capi")]
+fn c_header(manifest_dir: &Path, out_dir: &Path) {
+    println!("cargo:rerun-if-changed=src/capi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    let config = cbindgen::Config::from_file(manifest_dir.join("cbindgen.toml"))
+        .expect("cbindgen.toml is valid");
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(manifest_dir.join("src/capi.rs"))
+        .generate()
+        .expect("src/capi.rs generates a C header")
+        .write_to_file(out_dir.join("us_tax_brackets.h"));
+}
+
+/// Generate the slice literal for a year's Tax Table.
+fn tax_table(dir: &Path, year: u16) -> String {
+    let rows = rows(dir, year, "tax_table.csv", 6, |c| {
+        let n: Vec<i64> = c
+            .iter()
+            .map(|col| col.parse().ok())
+            .collect::<Option<_>>()?;
+        Some(format!(
+            "TaxTableRow {{ income_min: {}, income_max: {}, single: {}, \
+             married_filing_jointly: {}, married_filing_separately: {}, head_of_household: {} }}",
+            n[0], n[1], n[2], n[3], n[4], n[5]
+        ))
+    });
+    format!("&[{}]", rows.join(", "))
+}
+
+/// Generate an array literal from a CSV with exactly one row per filing
+/// status, in [`STATUS_KEYS`] order.
+fn one_per_status(
+    dir: &Path,
+    year: u16,
+    file: &str,
+    columns: usize,
+    row: impl Fn(&[&str]) -> Option<String>,
+) -> String {
+    let mut literals: Vec<Option<String>> = vec![None; STATUS_KEYS.len()];
+    let keyed = rows(dir, year, file, columns, |c| {
+        let status = STATUS_KEYS.iter().position(|key| *key == c[0])?;
+        Some((status, row(c)?))
+    });
+    for (status, literal) in keyed {
+        if literals[status].replace(literal).is_some() {
+            panic!(
+                "data/{year}/{file}: more than one row for {}",
+                STATUS_KEYS[status]
+            );
+        }
+    }
+    let literals: Vec<String> = literals
+        .into_iter()
+        .enumerate()
+        .map(|(status, literal)| {
+            literal
+                .unwrap_or_else(|| panic!("data/{year}/{file}: no row for {}", STATUS_KEYS[status]))
+        })
+        .collect();
+    format!("[{}]", literals.join(", "))
+}
+
+/// Generate the `DependentStandardDeduction` literal for a year from its
+/// one-row `dependent_standard_deduction.csv`.
+fn dependent_standard_deduction(dir: &Path, year: u16) -> String {
+    let file = "dependent_standard_deduction.csv";
+    let mut rows = rows(dir, year, file, 2, |c| {
+        Some(format!(
+            "DependentStandardDeduction {{ minimum: {}, earned_income_addition: {} }}",
+            c[0].parse::<i64>().ok()?,
+            c[1].parse::<i64>().ok()?,
+        ))
+    });
+    let (Some(literal), None) = (rows.pop(), rows.pop()) else {
+        panic!("data/{year}/{file}: expected exactly one row");
+    };
+    literal
+}
+
+/// Generate the `EstateGiftExclusions` literal for a year from its one-row
+/// `estate_gift_exclusions.csv`.
+fn estate_gift_exclusion(dir: &Path, year: u16) -> String {
+    let file = "estate_gift_exclusions.csv";
+    let mut rows = rows(dir, year, file, 2, |c| {
+        Some(format!(
+            "EstateGiftExclusions {{ basic_exclusion: {}, annual_exclusion: {} }}",
+            c[0].parse::<i64>().ok()?,
+            c[1].parse::<i64>().ok()?,
+        ))
+    });
+    let (Some(literal), None) = (rows.pop(), rows.pop()) else {
+        panic!("data/{year}/{file}: expected exactly one row");
+    };
+    literal
+}
+
+/// Generate the array of `EitcParameters` literals for a year from its
+/// `eitc.csv`, which has one row for each number of qualifying children
+/// from 0 to 3, in order.
+fn eitc(dir: &Path, year: u16) -> String {
+    let file = "eitc.csv";
+    let rows = rows(dir, year, file, 7, |c| {
+        Some((
+            c[0].parse::<usize>().ok()?,
+            format!(
+                "EitcParameters {{ credit_rate_basis_points: {}, phaseout_rate_basis_points: {}, \
+                 earned_income_amount: {}, maximum_credit: {}, phaseout_threshold: {}, \
+                 phaseout_threshold_joint: {} }}",
+                scaled(c[1], 4)?,
+                scaled(c[2], 4)?,
+                c[3].parse::<i64>().ok()?,
+                c[4].parse::<i64>().ok()?,
+                c[5].parse::<i64>().ok()?,
+                c[6].parse::<i64>().ok()?,
+            ),
+        ))
+    });
+    if !rows.iter().map(|(children, _)| *children).eq(0..4) {
+        panic!("data/{year}/{file}: expected rows for 0, 1, 2, and 3 children in order");
+    }
+    let literals: Vec<String> = rows.into_iter().map(|(_, literal)| literal).collect();
+    format!("[{}]", literals.join(", "))
+}
+
+/// Read the investment income limit for a year from its one-row
+/// `eitc_investment_income_limit.csv`.
+fn eitc_investment_income_limit(dir: &Path, year: u16) -> String {
+    let file = "eitc_investment_income_limit.csv";
+    let mut rows = rows(dir, year, file, 1, |c| c[0].parse::<i64>().ok());
+    let (Some(limit), None) = (rows.pop(), rows.pop()) else {
+        panic!("data/{year}/{file}: expected exactly one row");
+    };
+    limit.to_string()
+}
+
+/// Generate the `ChildTaxCreditAmounts` literal for a year from its one-row
+/// `child_tax_credit.csv`.
+fn child_tax_credit(dir: &Path, year: u16) -> String {
+    let file = "child_tax_credit.csv";
+    let mut rows = rows(dir, year, file, 5, |c| {
+        Some(format!(
+            "ChildTaxCreditAmounts {{ per_child: {}, per_other_dependent: {}, \
+             refundable_per_child: {}, phaseout_threshold: {}, phaseout_threshold_joint: {} }}",
+            c[0].parse::<i64>().ok()?,
+            c[1].parse::<i64>().ok()?,
+            c[2].parse::<i64>().ok()?,
+            c[3].parse::<i64>().ok()?,
+            c[4].parse::<i64>().ok()?,
+        ))
+    });
+    let (Some(literal), None) = (rows.pop(), rows.pop()) else {
+        panic!("data/{year}/{file}: expected exactly one row");
+    };
+    literal
+}
+
+/// Generate the array of `EducationCreditPhaseout` literals for a year from
+/// its `education_credit_phaseouts.csv`, which has a row for the American
+/// opportunity credit and then one for the lifetime learning credit.
+fn education_credit_phaseouts(dir: &Path, year: u16) -> String {
+    let file = "education_credit_phaseouts.csv";
+    let rows = rows(dir, year, file, 5, |c| {
+        let amounts: Vec<i64> = c[1..]
+            .iter()
+            .map(|col| col.parse().ok())
+            .collect::<Option<_>>()?;
+        Some((
+            c[0].to_string(),
+            format!(
+                "EducationCreditPhaseout {{ start: {}, start_joint: {}, end: {}, end_joint: {} }}",
+                amounts[0], amounts[1], amounts[2], amounts[3],
+            ),
+        ))
+    });
+    if !rows
+        .iter()
+        .map(|(credit, _)| credit.as_str())
+        .eq(["american_opportunity", "lifetime_learning"])
+    {
+        panic!("data/{year}/{file}: expected american_opportunity and lifetime_learning rows");
+    }
+    let literals: Vec<String> = rows.into_iter().map(|(_, literal)| literal).collect();
+    format!("[{}]", literals.join(", "))
+}
+
+/// Generate the `AdoptionCreditAmounts` literal for a year from its one-row
+/// `adoption_credit.csv`.
+fn adoption_credit(dir: &Path, year: u16) -> String {
+    let file = "adoption_credit.csv";
+    let mut rows = rows(dir, year, file, 4, |c| {
+        Some(format!(
+            "AdoptionCreditAmounts {{ maximum_credit: {}, phaseout_threshold: {}, \
+             phaseout_range: {}, refundable_maximum: {} }}",
+            c[0].parse::<i64>().ok()?,
+            c[1].parse::<i64>().ok()?,
+            c[2].parse::<i64>().ok().filter(|&range| range > 0)?,
+            c[3].parse::<i64>().ok()?,
+        ))
+    });
+    let (Some(literal), None) = (rows.pop(), rows.pop()) else {
+        panic!("data/{year}/{file}: expected exactly one row");
+    };
+    literal
+}
+
+/// Generate the `ApplicablePercentage` slice literal for a year from its
+/// `ptc_applicable_percentage.csv`, whose rows must cover household incomes
+/// from 0% of the poverty line upward without gaps.
+fn ptc_applicable_percentages(dir: &Path, year: u16) -> String {
+    let file = "ptc_applicable_percentage.csv";
+    let rows = rows(dir, year, file, 4, |c| {
+        let fpl_max = match c[1] {
+            "" => None,
+            max => Some(max.parse::<i64>().ok()?),
+        };
+        let initial = scaled(c[2], 4)?;
+        let last = scaled(c[3], 4)?;
+        Some((c[0].parse::<i64>().ok()?, fpl_max, initial, last))
+    });
+    let mut next = Some(0);
+    let literals: Vec<String> = rows
+        .iter()
+        .map(|&(fpl_min, fpl_max, initial, last)| {
+            if next != Some(fpl_min) || fpl_max.is_some_and(|max| max <= fpl_min) {
+                panic!("data/{year}/{file}: rows must be contiguous from 0");
+            }
+            next = fpl_max;
+            format!(
+                "ApplicablePercentage {{ fpl_min: {fpl_min}, fpl_max: {fpl_max:?}, \
+                 initial_basis_points: {initial}, final_basis_points: {last} }}"
+            )
+        })
+        .collect();
+    format!("&[{}]", literals.join(", "))
+}
+
+/// Generate the `IraLimits` literal for a year from its one-row
+/// `ira_limits.csv`.
+fn ira_limit(dir: &Path, year: u16) -> String {
+    let file = "ira_limits.csv";
+    let mut rows = rows(dir, year, file, 6, |c| {
+        let n: Vec<i64> = c
+            .iter()
+            .map(|col| col.parse().ok())
+            .collect::<Option<_>>()?;
+        (n[2] < n[3] && n[4] < n[5]).then(|| {
+            format!(
+                "IraLimits {{ contribution_limit: {}, catch_up: {}, roth_phaseout_start: {}, \
+                 roth_phaseout_end: {}, roth_phaseout_start_joint: {}, \
+                 roth_phaseout_end_joint: {} }}",
+                n[0], n[1], n[2], n[3], n[4], n[5]
+            )
+        })
+    });
+    let (Some(literal), None) = (rows.pop(), rows.pop()) else {
+        panic!("data/{year}/{file}: expected exactly one row");
+    };
+    literal
+}
+
+/// Generate the `ContributionLimitAmounts` literal for a year from its
+/// one-row `contribution_limits.csv`.
+fn contribution_limit(dir: &Path, year: u16) -> String {
+    let file = "contribution_limits.csv";
+    let mut rows = rows(dir, year, file, 7, |c| {
+        let n: Vec<i64> = c
+            .iter()
+            .map(|col| col.parse().ok())
+            .collect::<Option<_>>()?;
+        Some(format!(
+            "ContributionLimitAmounts {{ elective_deferral: {}, elective_deferral_catch_up: {}, \
+             elective_deferral_catch_up_60_to_63: {}, total_additions: {}, hsa_self_only: {}, \
+             hsa_family: {}, hsa_catch_up: {} }}",
+            n[0], n[1], n[2], n[3], n[4], n[5], n[6]
+        ))
+    });
+    let (Some(literal), None) = (rows.pop(), rows.pop()) else {
+        panic!("data/{year}/{file}: expected exactly one row");
+    };
+    literal
+}
+
+/// Generate the `DataProvenance` literal for a year from its
+/// `provenance.csv`, which lists the sources of each data file, and the
+/// SHA-256 of each of its CSV files, including `provenance.csv` itself.
+fn data_provenance(dir: &Path, year: u16) -> String {
+    let file = "provenance.csv";
+    let sources = rows(dir, year, file, 4, |c| {
+        if !DATA_FILES.contains(&c[0]) || c[1].is_empty() || !c[2].starts_with("https://") {
+            return None;
+        }
+        let revision = match c[3] {
+            "" => "None".to_string(),
+            revision => format!("Some({revision:?})"),
+        };
+        Some((
+            c[0].to_string(),
+            format!(
+                "DataSource {{ publication: {:?}, url: {:?}, revision: {revision} }}",
+                c[1], c[2]
+            ),
+        ))
+    });
+
+    let files: Vec<String> = DATA_FILES
+        .iter()
+        .chain(&[file])
+        .map(|name| {
+            let bytes =
+                fs::read(dir.join(name)).unwrap_or_else(|e| panic!("data/{year}/{name}: {e}"));
+            let sha256: String = Sha256::digest(&bytes)
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect();
+            let sources: Vec<&str> = sources
+                .iter()
+                .filter(|(source_file, _)| source_file == name)
+                .map(|(_, source)| source.as_str())
+                .collect();
+            // Every file with data rows must say where they came from.
+            let has_rows = String::from_utf8_lossy(&bytes)
+                .lines()
+                .skip(1) // header
+                .any(|line| !line.trim().is_empty());
+            if *name != file && has_rows && sources.is_empty() {
+                panic!("data/{year}/{file}: no source for {name}");
+            }
+            format!(
+                "DataFileDigest {{ name: {name:?}, sha256: {sha256:?}, sources: &[{}] }}",
+                sources.join(", ")
+            )
+        })
+        .collect();
+    format!("DataProvenance {{ files: &[{}] }}", files.join(", "))
+}
+
+/// Generate `CPI_U` from `data/cpi_u.csv`, the annual average Consumer Price
+/// Index for each year.
+fn consumer_price_index(data_dir: &Path) -> String {
+    let file = "data/cpi_u.csv";
+    let contents =
+        fs::read_to_string(data_dir.join("cpi_u.csv")).unwrap_or_else(|e| panic!("{file}: {e}"));
+    let mut previous = None;
+    let entries: Vec<String> = contents
+        .lines()
+        .enumerate()
+        .skip(1) // header
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let parsed = line.trim_end().split_once(',').and_then(|(year, index)| {
+                let year = year.parse::<u16>().ok()?;
+                // One row per year, in order, so lookups can binary search.
+                (previous.is_none_or(|previous| previous < year) && scaled(index, 3)? > 0)
+                    .then_some((year, index))
+            });
+            let Some((year, index_value)) = parsed else {
+                panic!("{file}:{}: malformed row", index + 1);
+            };
+            previous = Some(year);
+            format!("({year}, {index_value}_f64)")
+        })
+        .collect();
+    format!(
+        "static CPI_U: [(u16, f64); {}] = [{}];\n",
+        entries.len(),
+        entries.join(", ")
+    )
+}
+
+/// Generate `SOCIAL_SECURITY_WAGE_BASES`, the wage base for each of `years`,
+/// from the series in `data/social_security_wage_base.csv`.
+fn social_security_wage_bases(data_dir: &Path, years: &[u16]) -> String {
+    let file = "data/social_security_wage_base.csv";
+    let contents = fs::read_to_string(data_dir.join("social_security_wage_base.csv"))
+        .unwrap_or_else(|e| panic!("{file}: {e}"));
+    let series: BTreeMap<u16, i64> = contents
+        .lines()
+        .enumerate()
+        .skip(1) // header
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            line.trim_end()
+                .split_once(',')
+                .and_then(|(year, wage_base)| Some((year.parse().ok()?, wage_base.parse().ok()?)))
+                .filter(|&(_, wage_base)| wage_base > 0)
+                .unwrap_or_else(|| panic!("{file}:{}: malformed row", index + 1))
+        })
+        .collect();
+    let wage_bases: Vec<String> = years
+        .iter()
+        .map(|year| match series.get(year) {
+            Some(wage_base) => wage_base.to_string(),
+            None => panic!("{file}: no wage base for {year}"),
+        })
+        .collect();
+    format!(
+        "static SOCIAL_SECURITY_WAGE_BASES: [i64; {}] = [{}];\n",
+        years.len(),
+        wage_bases.join(", ")
+    )
+}
+
+/// Generate `COST_OF_LIVING_ADJUSTMENTS`, the factor each of `years`'
+/// Revenue Procedure applies to the statutory brackets, from the series in
+/// `data/cost_of_living_adjustment.csv`.
+fn cost_of_living_adjustments(data_dir: &Path, years: &[u16]) -> String {
+    let file = "data/cost_of_living_adjustment.csv";
+    let contents = fs::read_to_string(data_dir.join("cost_of_living_adjustment.csv"))
+        .unwrap_or_else(|e| panic!("{file}: {e}"));
+    let series: BTreeMap<u16, &str> = contents
+        .lines()
+        .enumerate()
+        .skip(1) // header
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            line.trim_end()
+                .split_once(',')
+                .and_then(|(year, factor)| Some((year.parse().ok()?, factor)))
+                // The statutory brackets are the 2018 thresholds, so no
+                // year adjusts them downwards.
+                .filter(|&(_, factor)| scaled(factor, 5).is_some_and(|f| f >= 100_000))
+                .unwrap_or_else(|| panic!("{file}:{}: malformed row", index + 1))
+        })
+        .collect();
+    let factors: Vec<String> = years
+        .iter()
+        .map(|year| match series.get(year) {
+            Some(factor) => format!("{factor}_f64"),
+            None => panic!("{file}: no cost-of-living adjustment for {year}"),
+        })
+        .collect();
+    format!(
+        "static COST_OF_LIVING_ADJUSTMENTS: [f64; {}] = [{}];\n",
+        years.len(),
+        factors.join(", ")
+    )
+}
+
+/// Generate `POVERTY_GUIDELINES`, the first-person and additional-person
+/// amounts of the poverty guidelines for the 48 contiguous states that the
+/// premium tax credit uses in each of `years`: those published the year
+/// before, from the series in `data/poverty_guidelines.csv`.
+fn poverty_guidelines(data_dir: &Path, years: &[u16]) -> String {
+    let file = "data/poverty_guidelines.csv";
+    let contents = fs::read_to_string(data_dir.join("poverty_guidelines.csv"))
+        .unwrap_or_else(|e| panic!("{file}: {e}"));
+    let series: BTreeMap<u16, (i64, i64)> = contents
+        .lines()
+        .enumerate()
+        .skip(1) // header
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let c: Vec<&str> = line.trim_end().split(',').collect();
+            (c.len() == 3)
+                .then(|| Some((c[0].parse().ok()?, (c[1].parse().ok()?, c[2].parse().ok()?))))
+                .flatten()
+                .filter(|&(_, (first, additional))| first > 0 && additional > 0)
+                .unwrap_or_else(|| panic!("{file}:{}: malformed row", index + 1))
+        })
+        .collect();
+    let guidelines: Vec<String> = years
+        .iter()
+        .map(|year| match series.get(&(year - 1)) {
+            Some((first, additional)) => format!("({first}, {additional})"),
+            None => panic!("{file}: no guidelines for {}", year - 1),
+        })
+        .collect();
+    format!(
+        "static POVERTY_GUIDELINES: [(i64, i64); {}] = [{}];\n",
+        years.len(),
+        guidelines.join(", ")
+    )
+}
+
+/// Generate `LIFE_EXPECTANCY` from `data/life_expectancy.csv`, the Single
+/// Life Table and Uniform Lifetime Table distribution periods in tenths of a
+/// year, indexed by age. Every age from 0 is listed, and the Uniform
+/// Lifetime Table, which starts at 72, is empty before it.
+fn life_expectancy(data_dir: &Path) -> String {
+    let file = "data/life_expectancy.csv";
+    let contents = fs::read_to_string(data_dir.join("life_expectancy.csv"))
+        .unwrap_or_else(|e| panic!("{file}: {e}"));
+    let entries: Vec<String> = contents
+        .lines()
+        .enumerate()
+        .skip(1) // header
+        .filter(|(_, line)| !line.trim().is_empty())
+        .enumerate()
+        .map(|(age, (index, line))| {
+            let c: Vec<&str> = line.trim_end().split(',').collect();
+            let parsed = (c.len() == 3 && c[0].parse() == Ok(age))
+                .then(|| {
+                    let single_life = scaled(c[1], 1).filter(|&tenths| tenths > 0)?;
+                    let uniform_lifetime = match c[2] {
+                        "" => None,
+                        period => Some(scaled(period, 1).filter(|&tenths| tenths > 0)?),
+                    };
+                    Some((single_life, uniform_lifetime))
+                })
+                .flatten();
+            let Some((single_life, uniform_lifetime)) = parsed else {
+                panic!("{file}:{}: malformed row", index + 1);
+            };
+            format!("({single_life}, {uniform_lifetime:?})")
+        })
+        .collect();
+    format!(
+        "static LIFE_EXPECTANCY: [(i64, Option<i64>); {}] = [{}];\n",
+        entries.len(),
+        entries.join(", ")
+    )
+}
+
+/// Generate `HISTORICAL_SCHEDULES` from `data/historical/tax_rate_schedule.csv`,
+/// which lists the Tax Rate Schedule brackets of earlier years with the year
+/// in the first column.
+fn historical_schedules(data_dir: &Path) -> String {
+    let file = "data/historical/tax_rate_schedule.csv";
+    let contents = fs::read_to_string(data_dir.join("historical/tax_rate_schedule.csv"))
+        .unwrap_or_else(|e| panic!("{file}: {e}"));
+    let mut years: BTreeMap<u16, BTreeMap<usize, Vec<String>>> = BTreeMap::new();
+    for (index, line) in contents.lines().enumerate().skip(1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let c: Vec<&str> = line.trim_end().split(',').collect();
+        let parsed = (c.len() == 5)
+            .then(|| {
+                let year = c[0].parse::<u16>().ok()?;
+                let status = STATUS_KEYS.iter().position(|key| *key == c[1])?;
+                let literal = format!(
+                    "TaxBracket {{ income_min: {}, income_max: {}, rate: {}_f64 }}",
+                    c[2].parse::<i64>().ok()?,
+                    income_max(c[3])?,
+                    float(c[4])?,
+                );
+                Some((year, status, literal))
+            })
+            .flatten();
+        let Some((year, status, literal)) = parsed else {
+            panic!("{file}:{}: malformed row", index + 1);
+        };
+        years
+            .entry(year)
+            .or_default()
+            .entry(status)
+            .or_default()
+            .push(literal);
+    }
+
+    let entries: Vec<String> = years
+        .iter()
+        .map(|(year, statuses)| {
+            let statuses: Vec<String> = (0..STATUS_KEYS.len())
+                .map(|status| match statuses.get(&status) {
+                    Some(rows) => format!("&[{}]", rows.join(", ")),
+                    None => panic!("{file}: no rows for {year} {}", STATUS_KEYS[status]),
+                })
+                .collect();
+            format!("({year}, [{}])", statuses.join(", "))
+        })
+        .collect();
+    format!(
+        "static HISTORICAL_SCHEDULES: [(u16, [&[TaxBracket]; {}]); {}] = [{}];\n",
+        STATUS_KEYS.len(),
+        entries.len(),
+        entries.join(", ")
+    )
+}
+
+/// Generate one slice literal per filing status from a CSV whose first column
+/// is the status key, in [`STATUS_KEYS`] order.
+fn by_status(
+    dir: &Path,
+    year: u16,
+    file: &str,
+    columns: usize,
+    row: impl Fn(&[&str]) -> Option<String>,
+) -> Vec<String> {
+    let mut grouped: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+    let keyed = rows(dir, year, file, columns, |c| {
+        let status = STATUS_KEYS.iter().position(|key| *key == c[0])?;
+        Some((status, row(c)?))
+    });
+    for (status, literal) in keyed {
+        grouped.entry(status).or_default().push(literal);
+    }
+    (0..STATUS_KEYS.len())
+        .map(|status| match grouped.get(&status) {
+            Some(rows) => format!("&[{}]", rows.join(", ")),
+            None => panic!("data/{year}/{file}: no rows for {}", STATUS_KEYS[status]),
+        })
+        .collect()
+}
+
+/// Parse every data row of a CSV file, panicking with the file and line of the
+/// first malformed row.
+fn rows<T>(
+    dir: &Path,
+    year: u16,
+    file: &str,
+    columns: usize,
+    row: impl Fn(&[&str]) -> Option<T>,
+) -> Vec<T> {
+    let contents =
+        fs::read_to_string(dir.join(file)).unwrap_or_else(|e| panic!("data/{year}/{file}: {e}"));
+    contents
+        .lines()
+        .enumerate()
+        .skip(1) // header
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let cols: Vec<&str> = line.trim_end().split(',').collect();
+            (cols.len() == columns)
+                .then(|| row(&cols))
+                .flatten()
+                .unwrap_or_else(|| panic!("data/{year}/{file}:{}: malformed row", index + 1))
+        })
+        .collect()
+}
+
+/// An optional upper bound as a Rust literal, where an empty column means
+/// "no limit".
+fn income_max(col: &str) -> Option<String> {
+    if col.is_empty() {
+        Some("None".to_string())
+    } else {
+        col.parse::<i64>().ok().map(|max| format!("Some({max})"))
+    }
+}
+
+/// A non-negative decimal column, checked and returned as written so the
+/// generated `f64` literal matches the CSV exactly.
+fn float(col: &str) -> Option<&str> {
+    scaled(col, 6)?;
+    Some(col)
+}
+
+/// Parse a non-negative decimal string into an integer scaled by
+/// `10^decimals`, without going through floating point.
+fn scaled(s: &str, decimals: u32) -> Option<i64> {
+    let (whole, frac) = s.split_once('.').unwrap_or((s, ""));
+    if whole.is_empty() || frac.len() > decimals as usize {
+        return None;
+    }
+    if !whole
+        .bytes()
+        .chain(frac.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let frac_value: i64 = if frac.is_empty() {
+        0
+    } else {
+        frac.parse().ok()?
+    };
+    whole
+        .parse::<i64>()
+        .ok()?
+        .checked_mul(10_i64.pow(decimals))?
+        .checked_add(frac_value * 10_i64.pow(decimals - frac.len() as u32))
+}
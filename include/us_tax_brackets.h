@@ -0,0 +1,145 @@
+#ifndef US_TAX_BRACKETS_H
+#define US_TAX_BRACKETS_H
+
+/* Generated by cbindgen from src/capi.rs. Do not edit. */
+
+#include <stdint.h>
+
+/**
+ * Single.
+ */
+#define USTAX_SINGLE 0
+
+/**
+ * Married filing jointly.
+ */
+#define USTAX_MARRIED_FILING_JOINTLY 1
+
+/**
+ * Married filing separately.
+ */
+#define USTAX_MARRIED_FILING_SEPARATELY 2
+
+/**
+ * Head of household.
+ */
+#define USTAX_HEAD_OF_HOUSEHOLD 3
+
+/**
+ * Qualifying surviving spouse.
+ */
+#define USTAX_QUALIFYING_SURVIVING_SPOUSE 4
+
+/**
+ * Result code returned by every C function.
+ *
+ * The values are stable: existing codes never change, and new error kinds
+ * get new codes. Codes below 100 mirror the crate's `TaxError` variants.
+ */
+enum UstaxStatus
+#if __STDC_VERSION__ >= 202311L
+  : int32_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * Success; the out-pointer was written.
+   */
+  USTAX_STATUS_OK = 0,
+  /**
+   * Taxable income is negative.
+   */
+  USTAX_STATUS_NEGATIVE_INCOME = 1,
+  /**
+   * Taxable income does not fit in an `int64_t`.
+   */
+  USTAX_STATUS_INCOME_OUT_OF_RANGE = 2,
+  /**
+   * No bracket matches the income (should not occur).
+   */
+  USTAX_STATUS_NO_BRACKET_FOUND = 3,
+  /**
+   * The embedded data is malformed (should not occur).
+   */
+  USTAX_STATUS_MALFORMED_DATA = 4,
+  /**
+   * The crate has no data for the year.
+   */
+  USTAX_STATUS_UNSUPPORTED_YEAR = 5,
+  /**
+   * The year is not a valid calendar year.
+   */
+  USTAX_STATUS_INVALID_YEAR = 6,
+  /**
+   * The filing status is not one of the `USTAX_*` constants.
+   */
+  USTAX_STATUS_INVALID_FILING_STATUS = 7,
+  /**
+   * A dollar amount could not be parsed.
+   */
+  USTAX_STATUS_INVALID_AMOUNT = 8,
+  /**
+   * The embedded data failed validation (should not occur).
+   */
+  USTAX_STATUS_INVALID_DATA = 9,
+  /**
+   * A tax data file could not be read.
+   */
+  USTAX_STATUS_UNREADABLE_DATA = 10,
+  /**
+   * A custom tax schedule is invalid (not used by the C functions).
+   */
+  USTAX_STATUS_INVALID_SCHEDULE = 11,
+  /**
+   * An out-pointer was null.
+   */
+  USTAX_STATUS_NULL_POINTER = 100,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum UstaxStatus UstaxStatus;
+#else
+typedef int32_t UstaxStatus;
+#endif // __STDC_VERSION__ >= 202311L
+
+/**
+ * Compute federal income tax in whole dollars, as `compute_tax` does.
+ *
+ * # Safety
+ *
+ * `tax_out` must be null or point to writable memory for an `int64_t`.
+ */
+UstaxStatus ustax_compute(uint16_t year, int32_t status, int64_t taxable_income, int64_t *tax_out);
+
+/**
+ * The marginal tax rate at a taxable income, as `marginal_rate` does.
+ *
+ * # Safety
+ *
+ * `rate_out` must be null or point to writable memory for a `double`.
+ */
+UstaxStatus ustax_marginal_rate(uint16_t year,
+                                int32_t status,
+                                int64_t taxable_income,
+                                double *rate_out);
+
+/**
+ * The effective (average) tax rate at a taxable income, as
+ * `effective_rate` does.
+ *
+ * # Safety
+ *
+ * `rate_out` must be null or point to writable memory for a `double`.
+ */
+UstaxStatus ustax_effective_rate(uint16_t year,
+                                 int32_t status,
+                                 int64_t taxable_income,
+                                 double *rate_out);
+
+/**
+ * A static, NUL-terminated English description of a status code, or
+ * `"unknown status"` for a value that is not a code.
+ *
+ * The returned string must not be freed.
+ */
+const char *ustax_status_message(int32_t status);
+
+#endif  /* US_TAX_BRACKETS_H */
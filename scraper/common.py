@@ -10,7 +10,7 @@ HTML_URL = "https://www.irs.gov/instructions/i1040gi"
 PDF_URL_TEMPLATE = "https://www.irs.gov/pub/irs-prior/i1040gi--{year}.pdf"
 
 # Tax years to scrape. Add new years here.
-TAX_YEARS = [2023, 2024, 2025]
+TAX_YEARS = [2018, 2019, 2020, 2021, 2022, 2023, 2024, 2025]
 
 HEADERS = {"User-Agent": "Mozilla/5.0 (compatible; us-tax-brackets-scraper/1.0)"}
 
@@ -69,3 +69,42 @@ def write_computation_worksheet_csv(data, path):
                     row["income_max"] = ""
                 writer.writerow(row)
     print(f"  Wrote {path}")
+
+
+def write_provenance_csv(url, year, path, files):
+    """Record that `files` were scraped from the instructions at `url`.
+
+    Rows for other files, whose sources are entered by hand, are kept, as is
+    the revision already recorded for a scraped file; a new one defaults to
+    the year printed on the instructions.
+    """
+
+    def scraped(file, revision=None):
+        return {
+            "file": file,
+            "publication": f"{year} Instructions for Form 1040",
+            "source_url": url,
+            "revision": revision or str(year),
+        }
+
+    rows = []
+    if os.path.exists(path):
+        with open(path, newline="") as f:
+            for row in csv.DictReader(f):
+                if row["file"] not in files:
+                    rows.append(row)
+                elif all(other["file"] != row["file"] for other in rows):
+                    rows.append(scraped(row["file"], row["revision"]))
+    for file in files:
+        if all(row["file"] != file for row in rows):
+            rows.append(scraped(file))
+    os.makedirs(os.path.dirname(path), exist_ok=True)
+    with open(path, "w", newline="") as f:
+        writer = csv.DictWriter(
+            f,
+            fieldnames=["file", "publication", "source_url", "revision"],
+            lineterminator="\n",
+        )
+        writer.writeheader()
+        writer.writerows(rows)
+    print(f"  Wrote {path}")
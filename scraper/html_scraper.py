@@ -10,8 +10,10 @@ from common import (
     FILING_STATUSES,
     HEADERS,
     HTML_URL,
+    PDF_URL_TEMPLATE,
     WORKSHEET_SECTION_LABELS,
     write_computation_worksheet_csv,
+    write_provenance_csv,
     write_tax_table_csv,
 )
 
@@ -162,4 +164,12 @@ def scrape_html(year, base_dir):
     write_computation_worksheet_csv(
         worksheet, os.path.join(year_dir, "tax_computation_worksheet.csv")
     )
+    # The HTML page moves on to the next year, so record the archived PDF of
+    # the same revision.
+    write_provenance_csv(
+        PDF_URL_TEMPLATE.format(year=year),
+        year,
+        os.path.join(year_dir, "provenance.csv"),
+        ["tax_table.csv", "tax_computation_worksheet.csv"],
+    )
     return True
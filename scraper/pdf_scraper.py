@@ -12,6 +12,7 @@ from common import (
     HEADERS,
     PDF_URL_TEMPLATE,
     write_computation_worksheet_csv,
+    write_provenance_csv,
     write_tax_table_csv,
 )
 
@@ -191,5 +192,11 @@ def scrape_pdf(year, base_dir):
                 worksheet,
                 os.path.join(year_dir, "tax_computation_worksheet.csv"),
             )
+            write_provenance_csv(
+                url,
+                year,
+                os.path.join(year_dir, "provenance.csv"),
+                ["tax_table.csv", "tax_computation_worksheet.csv"],
+            )
     finally:
         os.unlink(tmp.name)
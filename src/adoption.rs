@@ -0,0 +1,189 @@
+//! The adoption credit and exclusion (Form 8839).
+
+use crate::compute::income_to_i64;
+use crate::data;
+use crate::types::{TaxError, TaxYear};
+
+/// The most adoption expenses for each child that can be credited in
+/// `year`, which is also the most employer-provided adoption benefits that
+/// can be excluded from income.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "year-2024", feature = "year-2025"))] {
+/// use us_tax_brackets::{adoption_credit_limit, TaxYear};
+///
+/// assert_eq!(adoption_credit_limit(TaxYear::Y2024), 16_810);
+/// assert_eq!(adoption_credit_limit(TaxYear::Y2025), 17_280);
+/// # }
+/// ```
+pub const fn adoption_credit_limit(year: TaxYear) -> i64 {
+    data::adoption_credit(year).maximum_credit
+}
+
+/// The modified adjusted gross income range over which the adoption credit
+/// and exclusion phase out in `year`, as the first income with a reduced
+/// amount and the income at which nothing is left.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{adoption_credit_phaseout, TaxYear};
+///
+/// assert_eq!(adoption_credit_phaseout(TaxYear::Y2025), (259_190, 299_190));
+/// # }
+/// ```
+pub const fn adoption_credit_phaseout(year: TaxYear) -> (i64, i64) {
+    let amounts = data::adoption_credit(year);
+    (
+        amounts.phaseout_threshold,
+        amounts.phaseout_threshold + amounts.phaseout_range,
+    )
+}
+
+/// The adoption credit for one child, returned by
+/// [`compute_adoption_credit`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AdoptionCredit {
+    /// The credit after the phaseout (Form 8839, line 11).
+    pub credit: i64,
+    /// The part of [`credit`](Self::credit) that is refundable, from 2025.
+    /// The rest can only reduce the tax, and what it does not use carries
+    /// forward for up to five years.
+    pub refundable: i64,
+}
+
+/// Compute the adoption credit on Form 8839 for one child, with
+/// `qualified_expenses` and modified adjusted gross income `magi`.
+///
+/// The expenses count up to the [`adoption_credit_limit`], and the credit
+/// is reduced in proportion as `magi` moves through the
+/// [`adoption_credit_phaseout`]; the fraction is rounded to three decimal
+/// places and the credit to whole dollars. The adoption of a child with
+/// special needs, once final, gets the full limit whatever the expenses.
+/// Credits already claimed for the same child in earlier years share the
+/// limit and should be left out of `qualified_expenses`. Married people
+/// filing separately generally cannot claim the credit.
+///
+/// # Errors
+///
+/// Returns [`TaxError::IncomeOutOfRange`] if `magi` does not fit in an
+/// `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{compute_adoption_credit, TaxYear};
+///
+/// let adoption = compute_adoption_credit(TaxYear::Y2024, 150_000, 12_000, false).unwrap();
+/// assert_eq!(adoption.credit, 12_000);
+///
+/// // Halfway through the phaseout
+/// let adoption = compute_adoption_credit(TaxYear::Y2024, 272_150, 30_000, false).unwrap();
+/// assert_eq!(adoption.credit, 8_405);
+/// # }
+/// ```
+pub fn compute_adoption_credit(
+    year: TaxYear,
+    magi: impl TryInto<i64>,
+    qualified_expenses: i64,
+    special_needs: bool,
+) -> Result<AdoptionCredit, TaxError> {
+    let magi = income_to_i64(magi)?;
+    let amounts = data::adoption_credit(year);
+    let expenses = if special_needs {
+        amounts.maximum_credit
+    } else {
+        qualified_expenses.clamp(0, amounts.maximum_credit)
+    };
+    let excess = magi
+        .saturating_sub(amounts.phaseout_threshold)
+        .clamp(0, amounts.phaseout_range);
+    // The fraction phased out in thousandths, rounded half up
+    let thousandths = (excess * 2_000 / amounts.phaseout_range + 1) / 2;
+    let credit = expenses - (expenses * thousandths + 500) / 1_000;
+    Ok(AdoptionCredit {
+        credit,
+        refundable: credit.min(amounts.refundable_maximum),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits() {
+        assert_eq!(
+            TaxYear::ALL.map(adoption_credit_limit),
+            TaxYear::select([
+                13_810, 14_080, 14_300, 14_440, 14_890, 15_950, 16_810, 17_280
+            ])
+        );
+        for year in TaxYear::ALL {
+            let (start, end) = adoption_credit_phaseout(year);
+            assert_eq!(end - start, 40_000);
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn phaseout() {
+        let year = TaxYear::Y2024;
+        let credit = |magi| {
+            compute_adoption_credit(year, magi, 20_000, false)
+                .unwrap()
+                .credit
+        };
+        assert_eq!(credit(-10_000), 16_810);
+        assert_eq!(credit(252_150), 16_810);
+        assert_eq!(credit(262_150), 16_810 - 4_203);
+        // 0.2223 rounds to 0.222 of the credit phased out.
+        assert_eq!(credit(261_042), 16_810 - 3_732);
+        assert_eq!(credit(292_150), 0);
+        assert_eq!(credit(i64::MAX), 0);
+    }
+
+    #[cfg(feature = "year-2023")]
+    #[test]
+    fn expenses_and_special_needs() {
+        let year = TaxYear::Y2023;
+        let adoption = |expenses, special_needs| {
+            compute_adoption_credit(year, 100_000, expenses, special_needs).unwrap()
+        };
+        assert_eq!(adoption(5_000, false).credit, 5_000);
+        assert_eq!(adoption(-5_000, false).credit, 0);
+        assert_eq!(adoption(0, true).credit, 15_950);
+        // Nothing was refundable before 2025.
+        assert_eq!(adoption(5_000, false).refundable, 0);
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn refundable_from_2025() {
+        let year = TaxYear::Y2025;
+        let adoption = compute_adoption_credit(year, 100_000, 12_000, false).unwrap();
+        assert_eq!(
+            adoption,
+            AdoptionCredit {
+                credit: 12_000,
+                refundable: 5_000
+            }
+        );
+        let adoption = compute_adoption_credit(year, 100_000, 3_000, false).unwrap();
+        assert_eq!(adoption.refundable, 3_000);
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn errors() {
+        assert_eq!(
+            compute_adoption_credit(TaxYear::Y2025, u64::MAX, 10_000, false),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
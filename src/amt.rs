@@ -0,0 +1,373 @@
+//! Alternative minimum tax (Form 6251).
+
+use crate::capital_gains::{CapitalGains, ltcg_brackets, schedule_d_tax_worksheet};
+use crate::compute::income_to_i64;
+use crate::data::{self, AmtExemption};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The result of Form 6251, returned by [`compute_amt`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AmtComputation {
+    /// Alternative minimum taxable income (line 4), including the additional
+    /// amount for high-income married people filing separately.
+    pub amti: i64,
+    /// The exemption after the phaseout (line 5).
+    pub exemption: i64,
+    /// The tentative minimum tax (line 9).
+    pub tentative_minimum_tax: i64,
+    /// The regular tax the tentative minimum tax is compared with (line 10).
+    pub regular_tax: i64,
+    /// The alternative minimum tax owed on top of the regular tax (line 11).
+    pub amt: i64,
+}
+
+impl AmtComputation {
+    /// The regular tax plus the alternative minimum tax: the larger of the
+    /// regular tax and the tentative minimum tax.
+    pub fn total_tax(&self) -> i64 {
+        self.regular_tax + self.amt
+    }
+}
+
+/// Compute the alternative minimum tax with Form 6251.
+///
+/// `taxable_income` and `gains` are as for
+/// [`compute_tax_with_gains`](crate::compute_tax_with_gains), which gives the
+/// regular tax. `adjustments` is the total of the adjustments and
+/// preferences on lines 2a through 3, added to taxable income to get
+/// alternative minimum taxable income (AMTI). These include the standard
+/// deduction, or the deduction for state and local taxes when itemizing,
+/// and the bargain element of incentive stock options exercised and held.
+/// Negative adjustments reduce AMTI.
+///
+/// The exemption is reduced by 25% of AMTI above the phaseout threshold.
+/// AMTI after the exemption is taxed at 26% up to the 28% rate threshold and
+/// 28% above it, except that qualified dividends and net capital gain keep
+/// their lower rates (Part III). The alternative minimum tax is the amount by
+/// which that tentative minimum tax exceeds the regular tax. The AMT foreign
+/// tax credit is not supported.
+///
+/// # Errors
+///
+/// Returns the same errors as
+/// [`schedule_d_tax_worksheet`](crate::schedule_d_tax_worksheet), and
+/// [`TaxError::IncomeOutOfRange`] if `adjustments` does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{compute_amt, CapitalGains, FilingStatus, TaxYear};
+///
+/// // $150,000 taxable income, a $14,600 standard deduction added back, and
+/// // $200,000 of incentive stock option spread
+/// let amt = compute_amt(
+///     TaxYear::Y2024,
+///     FilingStatus::Single,
+///     150_000,
+///     14_600 + 200_000,
+///     &CapitalGains::default(),
+/// )
+/// .unwrap();
+/// assert_eq!(amt.amti, 364_600);
+/// assert_eq!(amt.exemption, 85_700);
+/// assert_eq!(amt.tentative_minimum_tax, 73_440);
+/// assert_eq!(amt.amt, amt.tentative_minimum_tax - amt.regular_tax);
+/// # }
+/// ```
+pub fn compute_amt(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+    adjustments: impl TryInto<i64>,
+    gains: &CapitalGains,
+) -> Result<AmtComputation, TaxError> {
+    let taxable_income = income_to_i64(taxable_income)?;
+    let adjustments = income_to_i64(adjustments)?;
+    let worksheet = schedule_d_tax_worksheet(year, status, taxable_income, gains)?;
+    let amounts = data::amt_exemption(year, status);
+
+    let mut amti = taxable_income.saturating_add(adjustments).max(0);
+    if status == FilingStatus::MarriedFilingSeparately {
+        // Above the end of the phaseout, a married person filing separately
+        // adds back the exemption their spouse could have lost.
+        let phaseout_end = amounts.phaseout_threshold + 4 * amounts.exemption;
+        let excess = amti.saturating_sub(phaseout_end).max(0);
+        amti = amti.saturating_add(quarter(excess).min(amounts.exemption));
+    }
+    let reduction = quarter(amti.saturating_sub(amounts.phaseout_threshold).max(0));
+    let exemption = (amounts.exemption - reduction).max(0);
+    let line6 = (amti - exemption).max(0);
+
+    let has_gains = gains.qualified_dividends > 0 || gains.net_capital_gain > 0;
+    let tentative_minimum_tax = if line6 == 0 {
+        0
+    } else if has_gains {
+        capital_gains_part(
+            &amounts,
+            line6,
+            taxable_income,
+            gains,
+            worksheet.ordinary_income,
+            year,
+            status,
+        )
+    } else {
+        dollars(amt_rates_cents(&amounts, line6))
+    };
+    let regular_tax = worksheet.tax;
+    Ok(AmtComputation {
+        amti,
+        exemption,
+        tentative_minimum_tax,
+        regular_tax,
+        amt: (tentative_minimum_tax - regular_tax).max(0),
+    })
+}
+
+/// Part III of Form 6251: the tax on `line12` with qualified dividends and
+/// net capital gain at their lower rates.
+fn capital_gains_part(
+    amounts: &AmtExemption,
+    line12: i64,
+    taxable_income: i64,
+    gains: &CapitalGains,
+    regular_ordinary_income: i64,
+    year: TaxYear,
+    status: FilingStatus,
+) -> i64 {
+    let brackets = ltcg_brackets(year, status);
+    let zero_rate_max = brackets[0].income_max.unwrap_or(i64::MAX);
+    let fifteen_rate_max = brackets[1].income_max.unwrap_or(i64::MAX);
+
+    // Lines 10 and 13 of the Schedule D Tax Worksheet.
+    let worksheet_line10 = gains
+        .qualified_dividends
+        .saturating_add(gains.net_capital_gain);
+    let special_gains = gains
+        .collectibles_gain
+        .saturating_add(gains.unrecaptured_section_1250_gain);
+    let worksheet_line13 = worksheet_line10 - gains.net_capital_gain.min(special_gains);
+
+    let line13 = worksheet_line13;
+    let line14 = gains.unrecaptured_section_1250_gain;
+    let line15 = if special_gains > 0 {
+        line13.saturating_add(line14).min(worksheet_line10)
+    } else {
+        line13
+    };
+    let line16 = line12.min(line15);
+    let line17 = line12 - line16;
+    let line18 = amt_rates_cents(amounts, line17);
+    let line20 = (taxable_income - worksheet_line13).max(0);
+    let line21 = (zero_rate_max - line20).max(0);
+    let line22 = line12.min(line13);
+    let line23 = line21.min(line22);
+    let line24 = line22 - line23;
+    let line28 = line21.saturating_add(regular_ordinary_income);
+    let line29 = (fifteen_rate_max - line28).max(0);
+    let line30 = line24.min(line29);
+    let line32 = line23 + line30;
+    let (mut line33, mut line36) = (0, 0);
+    if line32 != line12 {
+        line33 = line22 - line32;
+        if line14 > 0 {
+            let line35 = line17 + line32 + line33;
+            line36 = (line12 - line35).max(0);
+        }
+    }
+    // Whole dollars times whole percentages are whole cents.
+    let line38 =
+        line18 + i128::from(line30) * 15 + i128::from(line33) * 20 + i128::from(line36) * 25;
+    let line39 = amt_rates_cents(amounts, line12);
+    dollars(line38.min(line39))
+}
+
+/// The 26% and 28% tax on `amount`, in cents.
+fn amt_rates_cents(amounts: &AmtExemption, amount: i64) -> i128 {
+    let amount = i128::from(amount);
+    let threshold = i128::from(amounts.rate_28_threshold);
+    if amount <= threshold {
+        amount * 26
+    } else {
+        amount * 28 - threshold * 2
+    }
+}
+
+/// 25% of a whole-dollar amount, rounded to the nearest dollar.
+fn quarter(amount: i64) -> i64 {
+    ((i128::from(amount) * 25 + 50) / 100) as i64
+}
+
+/// Cents rounded to the nearest dollar, with 50 cents rounding up.
+fn dollars(cents: i128) -> i64 {
+    i64::try_from((cents + 50) / 100).unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::compute_tax;
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn exemption_phaseout() {
+        let year = TaxYear::Y2024;
+        let status = FilingStatus::MarriedFilingJointly;
+        let none = CapitalGains::default();
+        let exemption = |amti: i64| compute_amt(year, status, amti, 0, &none).unwrap().exemption;
+        assert_eq!(exemption(1_000_000), 133_300);
+        assert_eq!(exemption(1_218_700), 133_300);
+        assert_eq!(exemption(1_318_700), 108_300);
+        assert_eq!(exemption(1_751_900), 0);
+        assert_eq!(exemption(5_000_000), 0);
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn rate_break() {
+        let year = TaxYear::Y2025;
+        let status = FilingStatus::Single;
+        let none = CapitalGains::default();
+        // AMTI of $327,200 leaves exactly $239,100 after the exemption.
+        let amt = compute_amt(year, status, 327_200, 0, &none).unwrap();
+        assert_eq!(amt.tentative_minimum_tax, 62_166);
+        let amt = compute_amt(year, status, 337_200, 0, &none).unwrap();
+        assert_eq!(amt.tentative_minimum_tax, 62_166 + 2_800);
+        // Married filing separately splits the break in half.
+        let amt = compute_amt(
+            year,
+            FilingStatus::MarriedFilingSeparately,
+            188_050,
+            0,
+            &none,
+        )
+        .unwrap();
+        assert_eq!(amt.tentative_minimum_tax, 31_083);
+    }
+
+    #[cfg(feature = "year-2018")]
+    #[test]
+    fn rate_break_2018() {
+        // Rev. Proc. 2018-18 lowered the break to $191,100 ($95,550 married
+        // filing separately), so the 2% adjustment is $3,822 ($1,911).
+        let year = TaxYear::Y2018;
+        let none = CapitalGains::default();
+        let tentative = |status, amti| {
+            compute_amt(year, status, amti, 0, &none)
+                .unwrap()
+                .tentative_minimum_tax
+        };
+        let single = FilingStatus::Single;
+        assert_eq!(tentative(single, 70_300 + 191_100), 49_686);
+        assert_eq!(tentative(single, 70_300 + 201_100), 56_308 - 3_822);
+        let separately = FilingStatus::MarriedFilingSeparately;
+        assert_eq!(tentative(separately, 54_700 + 95_550), 24_843);
+        assert_eq!(tentative(separately, 54_700 + 105_550), 29_554 - 1_911);
+    }
+
+    #[test]
+    fn no_amt_without_adjustments() {
+        let none = CapitalGains::default();
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for income in (0..3_000_000).step_by(73_471) {
+                    let amt = compute_amt(year, status, income, 0, &none).unwrap();
+                    assert_eq!(amt.regular_tax, compute_tax(year, status, income).unwrap());
+                    assert_eq!(amt.amt, 0, "{year} {status} {income}");
+                    assert_eq!(
+                        amt.total_tax(),
+                        amt.regular_tax.max(amt.tentative_minimum_tax)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn no_tentative_minimum_tax_within_exemption() {
+        let gains = CapitalGains {
+            net_capital_gain: 10_000,
+            ..CapitalGains::default()
+        };
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for (income, gains) in [(50_000, CapitalGains::default()), (20_000, gains)] {
+                    let amt = compute_amt(year, status, income, 0, &gains).unwrap();
+                    assert!(amt.amti <= amt.exemption, "{year} {status} {income}");
+                    assert_eq!(amt.tentative_minimum_tax, 0, "{year} {status} {income}");
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn married_filing_separately_addition() {
+        let year = TaxYear::Y2024;
+        let status = FilingStatus::MarriedFilingSeparately;
+        let none = CapitalGains::default();
+        // The phaseout ends at $609,350 + 4 × $66,650 = $875,950.
+        let amt = compute_amt(year, status, 875_950, 0, &none).unwrap();
+        assert_eq!((amt.amti, amt.exemption), (875_950, 0));
+        let amt = compute_amt(year, status, 975_950, 0, &none).unwrap();
+        assert_eq!(amt.amti, 1_000_950);
+        let amt = compute_amt(year, status, 2_000_000, 0, &none).unwrap();
+        assert_eq!(amt.amti, 2_066_650);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn capital_gains_keep_their_rates() {
+        let year = TaxYear::Y2024;
+        let status = FilingStatus::Single;
+        let gains = CapitalGains {
+            net_capital_gain: 50_000,
+            ..Default::default()
+        };
+        let amt = compute_amt(year, status, 200_000, 100_000, &gains).unwrap();
+        assert_eq!(amt.amti, 300_000);
+        assert_eq!(amt.exemption, 85_700);
+        // 26% of the $164,300 ordinary part and 15% of the gain
+        assert_eq!(amt.tentative_minimum_tax, 42_718 + 7_500);
+        let regular = compute_tax(year, status, 150_000).unwrap() + 7_500;
+        assert_eq!(amt.regular_tax, regular);
+        assert_eq!(amt.amt, 42_718 + 7_500 - regular);
+
+        // Gains that fit in the 0% bracket for the regular tax are also
+        // untaxed for the AMT.
+        let gains = CapitalGains {
+            qualified_dividends: 10_000,
+            ..Default::default()
+        };
+        let amt = compute_amt(year, status, 30_000, 60_000, &gains).unwrap();
+        assert_eq!(amt.exemption, 85_700);
+        assert_eq!(amt.tentative_minimum_tax, 0);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn errors() {
+        assert_eq!(
+            compute_amt(
+                TaxYear::Y2024,
+                FilingStatus::Single,
+                -1,
+                0,
+                &CapitalGains::default()
+            ),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            compute_amt(
+                TaxYear::Y2024,
+                FilingStatus::Single,
+                0,
+                u64::MAX,
+                &CapitalGains::default()
+            ),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
@@ -0,0 +1,322 @@
+//! Converting hourly and partial-year income to annual amounts and back.
+
+use crate::compute::compute_tax;
+use crate::money::UsdAmount;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// Days before the first of each month in a common year.
+const DAYS_BEFORE_MONTH: [u16; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+/// Compute the income from working `hours_per_week` at `hourly_rate` for
+/// `weeks`, in whole dollars.
+///
+/// A full year is 52 weeks; for part of one, pass [`PartialYear::weeks`]
+/// rather than 52, which is the usual mistake when a job starts mid-year.
+/// The result is rounded to the nearest dollar.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if any input is negative,
+/// [`TaxError::InvalidAmount`] if `hours_per_week` or `weeks` is not finite,
+/// and [`TaxError::IncomeOutOfRange`] if the income does not fit in an
+/// `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{hourly_income, PartialYear, TaxYear, UsdAmount};
+///
+/// let rate = UsdAmount::from_cents(2_250);
+/// assert_eq!(hourly_income(rate, 40.0, 52.0), Ok(46_800));
+/// // Starting on July 1 leaves 26 weeks and 2 days.
+/// let weeks = PartialYear::starting(TaxYear::Y2025, 7, 1).unwrap().weeks();
+/// assert_eq!(hourly_income(rate, 40.0, weeks), Ok(23_657));
+/// # }
+/// ```
+pub fn hourly_income(
+    hourly_rate: UsdAmount,
+    hours_per_week: f64,
+    weeks: f64,
+) -> Result<i64, TaxError> {
+    if !hours_per_week.is_finite() || !weeks.is_finite() {
+        return Err(TaxError::InvalidAmount(format!(
+            "{hours_per_week} hours a week for {weeks} weeks"
+        )));
+    }
+    if hourly_rate.cents() < 0 || hours_per_week < 0.0 || weeks < 0.0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let dollars = (hourly_rate.cents() as f64 * hours_per_week * weeks / 100.0).round();
+    if dollars >= i64::MAX as f64 {
+        return Err(TaxError::IncomeOutOfRange);
+    }
+    Ok(dollars as i64)
+}
+
+/// A run of days within one tax year, such as the part of the year after
+/// a job started.
+///
+/// The span includes its first and last days and counts February 29 in a
+/// leap year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartialYear {
+    year: TaxYear,
+    first_day: u16,
+    last_day: u16,
+}
+
+impl PartialYear {
+    /// The days from `start` through `end`, each a `(month, day)` pair.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::InvalidAmount`] if either is not a date in
+    /// `year` or `end` is before `start`.
+    pub fn between(year: TaxYear, start: (u32, u32), end: (u32, u32)) -> Result<Self, TaxError> {
+        let first_day = day_of_year(year, start)?;
+        let last_day = day_of_year(year, end)?;
+        if last_day < first_day {
+            return Err(TaxError::InvalidAmount(format!(
+                "{}/{} is before {}/{}",
+                end.0, end.1, start.0, start.1
+            )));
+        }
+        Ok(PartialYear {
+            year,
+            first_day,
+            last_day,
+        })
+    }
+
+    /// The days from `month` and `day` through December 31.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::InvalidAmount`] if the date is not in `year`.
+    pub fn starting(year: TaxYear, month: u32, day: u32) -> Result<Self, TaxError> {
+        PartialYear::between(year, (month, day), (12, 31))
+    }
+
+    /// The days from January 1 through `month` and `day`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::InvalidAmount`] if the date is not in `year`.
+    pub fn ending(year: TaxYear, month: u32, day: u32) -> Result<Self, TaxError> {
+        PartialYear::between(year, (1, 1), (month, day))
+    }
+
+    /// The tax year.
+    pub fn year(&self) -> TaxYear {
+        self.year
+    }
+
+    /// The number of days in the span.
+    pub fn days(&self) -> u16 {
+        self.last_day - self.first_day + 1
+    }
+
+    /// The number of days in the whole year: 365, or 366 in a leap year.
+    pub fn days_in_year(&self) -> u16 {
+        days_in_year(self.year)
+    }
+
+    /// The number of weeks in the span, for [`hourly_income`].
+    pub fn weeks(&self) -> f64 {
+        f64::from(self.days()) / 7.0
+    }
+
+    /// The share of the year in the span, from 0 to 1.
+    pub fn fraction(&self) -> f64 {
+        f64::from(self.days()) / f64::from(self.days_in_year())
+    }
+
+    /// Scale `income` earned during the span up to a full year at the same
+    /// pace, rounded to the nearest dollar.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::IncomeOutOfRange`] if the result does not fit in
+    /// an `i64`.
+    pub fn annualize(&self, income: i64) -> Result<i64, TaxError> {
+        i64::try_from(scale(income, self.days_in_year(), self.days()))
+            .map_err(|_| TaxError::IncomeOutOfRange)
+    }
+
+    /// Scale a full year's `amount` down to the span, rounded to the
+    /// nearest dollar. This undoes [`annualize`](Self::annualize), up to
+    /// rounding.
+    pub fn prorate(&self, amount: i64) -> i64 {
+        scale(amount, self.days(), self.days_in_year()) as i64
+    }
+
+    /// The tax on `taxable_income` earned during the span at a full year's
+    /// pace: the tax on the annualized income, prorated back to the span.
+    ///
+    /// This is the share of a full year's tax that the span's income
+    /// accounts for, which is what withholding and estimated payments aim at
+    /// while the income keeps coming. It is not the tax owed if the income
+    /// stops: a return taxes the year's actual income, so someone who works
+    /// only part of the year owes [`compute_tax`] on what they earned, which
+    /// is less with progressive brackets.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::NegativeIncome`] if `taxable_income` is negative,
+    /// and the same errors as [`annualize`](Self::annualize) and
+    /// [`compute_tax`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "year-2025")] {
+    /// use us_tax_brackets::{compute_tax, FilingStatus, PartialYear, TaxYear};
+    ///
+    /// let (year, status) = (TaxYear::Y2025, FilingStatus::Single);
+    /// let second_half = PartialYear::starting(year, 7, 1).unwrap();
+    /// assert_eq!(second_half.annualize(40_000), Ok(79_348));
+    /// // The pace of a $79,348 year, against the tax on $40,000 alone
+    /// assert_eq!(second_half.prorated_tax(status, 40_000), Ok(6_234));
+    /// assert_eq!(compute_tax(year, status, 40_000), Ok(4_565));
+    /// # }
+    /// ```
+    pub fn prorated_tax(&self, status: FilingStatus, taxable_income: i64) -> Result<i64, TaxError> {
+        if taxable_income < 0 {
+            return Err(TaxError::NegativeIncome);
+        }
+        let annual_tax = compute_tax(self.year, status, self.annualize(taxable_income)?)?;
+        Ok(self.prorate(annual_tax))
+    }
+}
+
+/// 365, or 366 if `year` is a leap year.
+fn days_in_year(year: TaxYear) -> u16 {
+    let year = u16::from(year);
+    if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
+        366
+    } else {
+        365
+    }
+}
+
+/// The 1-based day of `year` for a `(month, day)` pair.
+fn day_of_year(year: TaxYear, (month, day): (u32, u32)) -> Result<u16, TaxError> {
+    let leap = days_in_year(year) == 366;
+    let length = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => 0,
+    };
+    if day == 0 || day > length {
+        return Err(TaxError::InvalidAmount(format!(
+            "{month}/{day} is not a date in {year}"
+        )));
+    }
+    let leap_day = u16::from(leap && month > 2);
+    Ok(DAYS_BEFORE_MONTH[month as usize - 1] + leap_day + day as u16)
+}
+
+/// `amount * numerator / denominator`, rounded half away from zero.
+fn scale(amount: i64, numerator: u16, denominator: u16) -> i128 {
+    let product = i128::from(amount) * i128::from(numerator);
+    let half = i128::from(denominator) / 2;
+    if product < 0 {
+        (product - half) / i128::from(denominator)
+    } else {
+        (product + half) / i128::from(denominator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hourly() {
+        let rate = UsdAmount::from_dollars(30);
+        assert_eq!(hourly_income(rate, 37.5, 52.0), Ok(58_500));
+        assert_eq!(hourly_income(rate, 0.0, 52.0), Ok(0));
+        assert_eq!(
+            hourly_income(UsdAmount::from_dollars(-1), 40.0, 52.0),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            hourly_income(rate, 40.0, -1.0),
+            Err(TaxError::NegativeIncome)
+        );
+        assert!(matches!(
+            hourly_income(rate, f64::NAN, 52.0),
+            Err(TaxError::InvalidAmount(_))
+        ));
+        assert_eq!(
+            hourly_income(UsdAmount::from_cents(i64::MAX), 1e6, 52.0),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn spans() {
+        let whole = PartialYear::between(TaxYear::Y2024, (1, 1), (12, 31)).unwrap();
+        assert_eq!((whole.days(), whole.days_in_year()), (366, 366));
+        assert_eq!(whole.fraction(), 1.0);
+        assert_eq!(whole.annualize(50_000), Ok(50_000));
+
+        // 2024 is a leap year, so March 1 is day 61 rather than 60.
+        let spring = PartialYear::starting(TaxYear::Y2024, 3, 1).unwrap();
+        assert_eq!(spring.days(), 306);
+        let spring = PartialYear::starting(TaxYear::Y2025, 3, 1).unwrap();
+        assert_eq!(spring.days(), 306);
+        assert_eq!(spring.days_in_year(), 365);
+
+        let january = PartialYear::ending(TaxYear::Y2025, 1, 31).unwrap();
+        assert_eq!(january.days(), 31);
+        assert_eq!(january.annualize(3_100), Ok(36_500));
+        assert_eq!(january.prorate(36_500), 3_100);
+        let one_day = PartialYear::between(TaxYear::Y2025, (6, 15), (6, 15)).unwrap();
+        assert_eq!(one_day.days(), 1);
+        assert_eq!(one_day.weeks(), 1.0 / 7.0);
+    }
+
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn invalid_dates() {
+        let year = TaxYear::Y2025;
+        for (month, day) in [(2, 29), (4, 31), (0, 1), (13, 1), (1, 0)] {
+            assert!(matches!(
+                PartialYear::starting(year, month, day),
+                Err(TaxError::InvalidAmount(_))
+            ));
+        }
+        assert!(PartialYear::starting(TaxYear::Y2024, 2, 29).is_ok());
+        assert!(matches!(
+            PartialYear::between(year, (7, 1), (6, 30)),
+            Err(TaxError::InvalidAmount(_))
+        ));
+        let one_day = PartialYear::ending(year, 1, 1).unwrap();
+        assert_eq!(one_day.annualize(i64::MAX), Err(TaxError::IncomeOutOfRange));
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn prorated_tax() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::MarriedFilingJointly);
+        let span = PartialYear::ending(year, 4, 30).unwrap();
+        let annual = span.annualize(30_000).unwrap();
+        assert_eq!(
+            span.prorated_tax(status, 30_000),
+            Ok(span.prorate(compute_tax(year, status, annual).unwrap()))
+        );
+        // The full-year pace is taxed at higher brackets than the income
+        // alone.
+        assert!(
+            span.prorated_tax(status, 30_000).unwrap() > compute_tax(year, status, 30_000).unwrap()
+        );
+        assert_eq!(span.prorated_tax(status, 0), Ok(0));
+        assert_eq!(span.prorated_tax(status, -1), Err(TaxError::NegativeIncome));
+    }
+}
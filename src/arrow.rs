@@ -0,0 +1,206 @@
+//! Apache Arrow column integration (requires the `arrow` feature).
+
+use arrow_array::{Array, Int32Array, Int64Array, StringArray};
+
+use crate::batch::BatchData;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// Compute federal income tax over Arrow columns (requires the `arrow`
+/// feature).
+///
+/// Row `i` is taxed with tax year `years[i]`, filing status `statuses[i]`,
+/// and taxable income `incomes[i]`, and the taxes are returned as a new
+/// column in the same order. Years are calendar years (`Int32`, as produced
+/// by most date-part functions); statuses accept anything
+/// [`FilingStatus`]'s `FromStr` does, such as `"single"` or `"MFJ"`. A null
+/// in any input column gives a null tax for that row.
+///
+/// Rows are grouped by year and status and each group goes through the same
+/// single-pass walk as [`compute_tax_column`](crate::compute_tax_column), so
+/// no per-row [`Vec`] round trip is needed.
+///
+/// # Errors
+///
+/// Returns [`TaxError::InvalidAmount`] if the three columns differ in
+/// length. Otherwise, the years and statuses of every row are checked before
+/// any tax is computed: the first row with a year without data gives
+/// [`TaxError::UnsupportedYear`] or [`TaxError::InvalidYear`], and with an
+/// unrecognized status [`TaxError::InvalidFilingStatus`]. Only then does a
+/// negative income give [`TaxError::NegativeIncome`], which need not be for
+/// the first such row.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "year-2024", feature = "year-2025"))] {
+/// use arrow_array::{Int32Array, Int64Array, StringArray};
+/// use us_tax_brackets::compute_tax_arrow;
+///
+/// let years = Int32Array::from(vec![2025, 2025, 2024]);
+/// let statuses = StringArray::from(vec![Some("single"), None, Some("mfj")]);
+/// let incomes = Int64Array::from(vec![75_000, 50_000, 150_000]);
+///
+/// let taxes = compute_tax_arrow(&years, &statuses, &incomes).unwrap();
+/// assert_eq!(taxes, Int64Array::from(vec![Some(11_420), None, Some(23_106)]));
+/// # }
+/// ```
+pub fn compute_tax_arrow(
+    years: &Int32Array,
+    statuses: &StringArray,
+    incomes: &Int64Array,
+) -> Result<Int64Array, TaxError> {
+    if years.len() != incomes.len() || statuses.len() != incomes.len() {
+        return Err(TaxError::InvalidAmount(format!(
+            "columns of {}, {}, and {} rows",
+            years.len(),
+            statuses.len(),
+            incomes.len()
+        )));
+    }
+
+    // Few distinct years and statuses appear in practice, so small vectors
+    // beat hashing here.
+    let mut parsed_statuses: Vec<(&str, FilingStatus)> = Vec::new();
+    let mut group_keys: Vec<(TaxYear, FilingStatus)> = Vec::new();
+    let mut group_rows: Vec<Vec<(i64, usize)>> = Vec::new();
+    let mut valid = vec![false; incomes.len()];
+
+    for (index, is_valid) in valid.iter_mut().enumerate() {
+        if years.is_null(index) || statuses.is_null(index) || incomes.is_null(index) {
+            continue;
+        }
+        *is_valid = true;
+
+        let year = years.value(index);
+        let year = u16::try_from(year)
+            .map_err(|_| TaxError::InvalidYear(year.to_string()))
+            .and_then(TaxYear::try_from)?;
+
+        let name = statuses.value(index);
+        let status = match parsed_statuses.iter().find(|(known, _)| *known == name) {
+            Some(&(_, status)) => status,
+            None => {
+                let status = name.parse()?;
+                parsed_statuses.push((name, status));
+                status
+            }
+        };
+
+        let row = (incomes.value(index), index);
+        match group_keys.iter().position(|&key| key == (year, status)) {
+            Some(group) => group_rows[group].push(row),
+            None => {
+                group_keys.push((year, status));
+                group_rows.push(vec![row]);
+            }
+        }
+    }
+
+    let mut taxes = vec![0; incomes.len()];
+    for ((year, status), mut rows) in group_keys.into_iter().zip(group_rows) {
+        rows.sort_unstable();
+        BatchData::load(year, status).walk(
+            rows.into_iter().map(|(income, index)| (index, income)),
+            &mut taxes,
+        )?;
+    }
+
+    Ok(taxes
+        .into_iter()
+        .zip(valid)
+        .map(|(tax, valid)| valid.then_some(tax))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::compute_tax;
+
+    #[test]
+    fn matches_compute_tax() {
+        let mut years = Vec::new();
+        let mut statuses = Vec::new();
+        let mut incomes = Vec::new();
+        for (i, year) in TaxYear::ALL.into_iter().enumerate() {
+            for status in FilingStatus::ALL {
+                for step in 0..200_i64 {
+                    years.push(i32::from(u16::from(year)));
+                    statuses.push(status.to_string());
+                    incomes.push((step * 6_151 + i as i64 * 977) % 800_000);
+                }
+            }
+        }
+        let taxes = compute_tax_arrow(
+            &Int32Array::from(years.clone()),
+            &StringArray::from(statuses.clone()),
+            &Int64Array::from(incomes.clone()),
+        )
+        .unwrap();
+
+        assert_eq!(taxes.null_count(), 0);
+        for row in 0..incomes.len() {
+            let year = TaxYear::try_from(years[row] as u16).unwrap();
+            let status: FilingStatus = statuses[row].parse().unwrap();
+            assert_eq!(
+                taxes.value(row),
+                compute_tax(year, status, incomes[row]).unwrap()
+            );
+        }
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn nulls_propagate() {
+        let taxes = compute_tax_arrow(
+            &Int32Array::from(vec![None, Some(2025), Some(2025)]),
+            &StringArray::from(vec!["single", "single", "hoh"]),
+            &Int64Array::from(vec![Some(75_000), None, Some(0)]),
+        )
+        .unwrap();
+        assert_eq!(taxes, Int64Array::from(vec![None, None, Some(0)]));
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn invalid_rows() {
+        let incomes = Int64Array::from(vec![75_000]);
+        let single = StringArray::from(vec!["single"]);
+        assert_eq!(
+            compute_tax_arrow(&Int32Array::from(vec![1999]), &single, &incomes),
+            Err(TaxError::UnsupportedYear(1999))
+        );
+        assert_eq!(
+            compute_tax_arrow(&Int32Array::from(vec![-1]), &single, &incomes),
+            Err(TaxError::InvalidYear("-1".to_string()))
+        );
+        assert_eq!(
+            compute_tax_arrow(
+                &Int32Array::from(vec![2025]),
+                &StringArray::from(vec!["married"]),
+                &incomes
+            ),
+            Err(TaxError::InvalidFilingStatus("married".to_string()))
+        );
+        assert_eq!(
+            compute_tax_arrow(
+                &Int32Array::from(vec![2025]),
+                &single,
+                &Int64Array::from(vec![-1])
+            ),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[test]
+    fn length_mismatch() {
+        assert!(matches!(
+            compute_tax_arrow(
+                &Int32Array::from(vec![2025]),
+                &StringArray::from(vec!["single", "single"]),
+                &Int64Array::from(vec![1]),
+            ),
+            Err(TaxError::InvalidAmount(_))
+        ));
+    }
+}
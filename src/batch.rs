@@ -0,0 +1,290 @@
+//! Batch computation over many incomes.
+
+use crate::compute::{WORKSHEET_THRESHOLD, search_tax_table, search_worksheet, worksheet_tax};
+use crate::data::{self, TaxTableRow, WorksheetBracket};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// Compute federal income tax for many incomes at once.
+///
+/// Returns one result per income, in order, each identical to what
+/// [`compute_tax`](crate::compute_tax) returns for that income. The data for
+/// the year and filing status is looked up once for the whole batch rather
+/// than once per income.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_tax_batch, FilingStatus, TaxError, TaxYear};
+///
+/// let taxes = compute_tax_batch(TaxYear::Y2025, FilingStatus::Single, &[0, 75_000, 150_000, -1]);
+/// assert_eq!(taxes, [Ok(0), Ok(11_420), Ok(28_847), Err(TaxError::NegativeIncome)]);
+/// # }
+/// ```
+pub fn compute_tax_batch(
+    year: TaxYear,
+    status: FilingStatus,
+    incomes: &[i64],
+) -> Vec<Result<i64, TaxError>> {
+    let batch = BatchData::load(year, status);
+    incomes.iter().map(|&income| batch.tax(income)).collect()
+}
+
+/// Compute federal income tax for many incomes in parallel (requires the
+/// `rayon` feature).
+///
+/// Identical to [`compute_tax_batch`], but the incomes are split across the
+/// [`rayon`] thread pool, which share the same static data. Worthwhile for
+/// large batches; for a few thousand incomes the sequential version is
+/// usually as fast.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_tax_batch_par, FilingStatus, TaxYear};
+///
+/// let incomes: Vec<i64> = (0..1_000_000).map(|i| i % 500_000).collect();
+/// let taxes = compute_tax_batch_par(TaxYear::Y2025, FilingStatus::Single, &incomes);
+/// assert_eq!(taxes[75_000], Ok(11_420));
+/// # }
+/// ```
+#[cfg(feature = "rayon")]
+pub fn compute_tax_batch_par(
+    year: TaxYear,
+    status: FilingStatus,
+    incomes: &[i64],
+) -> Vec<Result<i64, TaxError>> {
+    use rayon::prelude::*;
+
+    let batch = BatchData::load(year, status);
+    incomes
+        .par_iter()
+        .map(|&income| batch.tax(income))
+        .collect()
+}
+
+/// Compute federal income tax for a large column of incomes, walking the
+/// data once.
+///
+/// Gives the same taxes as [`compute_tax_batch`], but is built for analytics
+/// workloads with millions of rows. Instead of a binary search per income,
+/// the incomes are sorted (unless they already are) and matched against the
+/// Tax Table and worksheet in a single forward pass, so each row of data is
+/// visited at most once. The taxes are returned in the original order.
+///
+/// The result is all-or-nothing: a plain vector of taxes, or the first error.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if any income is negative.
+/// Returns [`TaxError::NoBracketFound`] if an income has no matching row
+/// (should not occur with valid embedded data).
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_tax_column, FilingStatus, TaxError, TaxYear};
+///
+/// let taxes = compute_tax_column(TaxYear::Y2025, FilingStatus::Single, &[150_000, 0, 75_000]);
+/// assert_eq!(taxes, Ok(vec![28_847, 0, 11_420]));
+///
+/// let taxes = compute_tax_column(TaxYear::Y2025, FilingStatus::Single, &[75_000, -1]);
+/// assert_eq!(taxes, Err(TaxError::NegativeIncome));
+/// # }
+/// ```
+pub fn compute_tax_column(
+    year: TaxYear,
+    status: FilingStatus,
+    incomes: &[i64],
+) -> Result<Vec<i64>, TaxError> {
+    let batch = BatchData::load(year, status);
+    let mut taxes = vec![0; incomes.len()];
+    if incomes.is_sorted() {
+        batch.walk(incomes.iter().copied().enumerate(), &mut taxes)?;
+    } else {
+        let mut order: Vec<(i64, usize)> = incomes
+            .iter()
+            .enumerate()
+            .map(|(index, &income)| (income, index))
+            .collect();
+        order.sort_unstable();
+        batch.walk(
+            order.into_iter().map(|(income, index)| (index, income)),
+            &mut taxes,
+        )?;
+    }
+    Ok(taxes)
+}
+
+/// The data for one year and filing status, shared across a batch.
+pub(crate) struct BatchData {
+    year: TaxYear,
+    status: FilingStatus,
+    table: &'static [TaxTableRow],
+    brackets: &'static [WorksheetBracket],
+}
+
+impl BatchData {
+    /// Look up the Tax Table and worksheet.
+    pub(crate) fn load(year: TaxYear, status: FilingStatus) -> Self {
+        BatchData {
+            year,
+            status,
+            table: data::tax_table(year),
+            brackets: data::worksheet(year, status),
+        }
+    }
+
+    /// Compute the tax on one income, as [`compute_tax`](crate::compute_tax)
+    /// does.
+    pub(crate) fn tax(&self, taxable_income: i64) -> Result<i64, TaxError> {
+        if taxable_income < 0 {
+            return Err(TaxError::NegativeIncome);
+        }
+        if taxable_income == 0 {
+            return Ok(0);
+        }
+        if taxable_income < WORKSHEET_THRESHOLD {
+            let row = search_tax_table(self.table, self.year, self.status, taxable_income)?;
+            Ok(row.tax_for(self.status))
+        } else {
+            let bracket = search_worksheet(self.brackets, self.year, self.status, taxable_income)?;
+            worksheet_tax(&bracket, taxable_income)
+        }
+    }
+
+    /// Compute the tax on `(index, income)` pairs sorted by income, writing
+    /// each tax to `taxes[index]`.
+    ///
+    /// Cursors into the Tax Table and worksheet only move forward, so the
+    /// whole walk is linear in the number of incomes plus rows.
+    pub(crate) fn walk(
+        &self,
+        sorted: impl Iterator<Item = (usize, i64)>,
+        taxes: &mut [i64],
+    ) -> Result<(), TaxError> {
+        let no_bracket = |taxable_income| TaxError::NoBracketFound {
+            year: self.year,
+            status: self.status,
+            taxable_income,
+        };
+        let (mut row, mut bracket) = (0, 0);
+        for (index, taxable_income) in sorted {
+            taxes[index] = if taxable_income < 0 {
+                return Err(TaxError::NegativeIncome);
+            } else if taxable_income == 0 {
+                0
+            } else if taxable_income < WORKSHEET_THRESHOLD {
+                while self
+                    .table
+                    .get(row)
+                    .is_some_and(|r| taxable_income >= r.income_max)
+                {
+                    row += 1;
+                }
+                match self.table.get(row) {
+                    Some(r) if taxable_income >= r.income_min => r.tax_for(self.status),
+                    _ => return Err(no_bracket(taxable_income)),
+                }
+            } else {
+                while self
+                    .brackets
+                    .get(bracket)
+                    .is_some_and(|b| b.income_max.is_some_and(|max| taxable_income > max))
+                {
+                    bracket += 1;
+                }
+                match self.brackets.get(bracket) {
+                    Some(b) if b.contains(taxable_income) => worksheet_tax(b, taxable_income)?,
+                    _ => return Err(no_bracket(taxable_income)),
+                }
+            };
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::compute_tax;
+
+    #[test]
+    fn matches_compute_tax() {
+        let incomes: Vec<i64> = (0..400).map(|i| i * 1_237).collect();
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                let batch = compute_tax_batch(year, status, &incomes);
+                assert_eq!(batch.len(), incomes.len());
+                for (&income, tax) in incomes.iter().zip(batch) {
+                    assert_eq!(
+                        tax,
+                        compute_tax(year, status, income),
+                        "{year} {status} {income}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(all(feature = "year-2023", feature = "rayon"))]
+    #[test]
+    fn parallel_matches_sequential() {
+        let incomes: Vec<i64> = (-10..200_000).map(|i| i * 7).collect();
+        assert_eq!(
+            compute_tax_batch_par(TaxYear::Y2023, FilingStatus::HeadOfHousehold, &incomes),
+            compute_tax_batch(TaxYear::Y2023, FilingStatus::HeadOfHousehold, &incomes)
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn empty_batch() {
+        assert!(compute_tax_batch(TaxYear::Y2024, FilingStatus::Single, &[]).is_empty());
+        assert_eq!(
+            compute_tax_column(TaxYear::Y2024, FilingStatus::Single, &[]),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn column_matches_compute_tax() {
+        // Unsorted, with duplicates, band edges, and both sides of $100,000
+        let mut incomes: Vec<i64> = (0..5_000).map(|i| (i * 7_919) % 1_200_000).collect();
+        incomes.extend([
+            0, 1, 4, 5, 50, 99_999, 100_000, 731_200, 731_201, 5, 100_000,
+        ]);
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                let column = compute_tax_column(year, status, &incomes).unwrap();
+                for (&income, tax) in incomes.iter().zip(column) {
+                    assert_eq!(
+                        Ok(tax),
+                        compute_tax(year, status, income),
+                        "{year} {status} {income}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn column_accepts_sorted_input() {
+        let incomes: Vec<i64> = (0..3_000).map(|i| i * 97).collect();
+        let column = compute_tax_column(TaxYear::Y2025, FilingStatus::HeadOfHousehold, &incomes);
+        let batch = compute_tax_batch(TaxYear::Y2025, FilingStatus::HeadOfHousehold, &incomes);
+        assert_eq!(column, batch.into_iter().collect());
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn column_negative_income() {
+        assert_eq!(
+            compute_tax_column(TaxYear::Y2025, FilingStatus::Single, &[10, 200_000, -5]),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! The `batch` subcommand: compute tax for every row of a CSV file.
+
+use std::error::Error;
+use std::io::Read;
+
+use serde_json::{Map, Value};
+use us_tax_brackets::{
+    FilingStatus, TaxError, TaxYear, compute_tax, effective_rate, marginal_rate,
+};
+
+use crate::parse_income;
+
+/// The columns every input file must have, matched case-insensitively.
+const REQUIRED_COLUMNS: [&str; 3] = ["year", "status", "income"];
+
+/// The computed values for one row.
+struct RowResult {
+    tax: i64,
+    marginal_rate: f64,
+    effective_rate: f64,
+}
+
+fn compute_row(year: &str, status: &str, income: &str) -> Result<RowResult, TaxError> {
+    let year: TaxYear = year.parse()?;
+    let status: FilingStatus = status.parse()?;
+    let income = parse_income(income)?;
+    Ok(RowResult {
+        tax: compute_tax(year, status, income)?,
+        marginal_rate: marginal_rate(year, status, income)?,
+        effective_rate: effective_rate(year, status, income)?,
+    })
+}
+
+/// Read a CSV with `year`, `status`, and `income` columns and return it with
+/// `tax` (and, if `rates` is set, `marginal_rate` and `effective_rate`)
+/// columns and an `error` column appended.
+///
+/// Other input columns, such as a client name, are copied through unchanged.
+/// A row that cannot be computed gets empty result columns and a message in
+/// `error` rather than stopping the batch. With `json`, the rows are written
+/// as an array of objects instead.
+pub(crate) fn batch(input: impl Read, rates: bool, json: bool) -> Result<String, Box<dyn Error>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(input);
+    let headers = reader.headers()?.clone();
+    let mut columns = [0; REQUIRED_COLUMNS.len()];
+    for (column, name) in columns.iter_mut().zip(REQUIRED_COLUMNS) {
+        *column = headers
+            .iter()
+            .position(|header| header.eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("input has no {name:?} column"))?;
+    }
+
+    let added_columns: &[&str] = if rates {
+        &["tax", "marginal_rate", "effective_rate", "error"]
+    } else {
+        &["tax", "error"]
+    };
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    if !json {
+        writer.write_record(headers.iter().chain(added_columns.iter().copied()))?;
+    }
+    let mut objects = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        let [year, status, income] = columns.map(|column| &record[column]);
+        let result = compute_row(year, status, income);
+
+        // Values for `added_columns`, in order.
+        let mut added = vec![Value::Null; added_columns.len()];
+        match result {
+            Ok(result) => {
+                added[0] = result.tax.into();
+                if rates {
+                    added[1] = result.marginal_rate.into();
+                    added[2] = result.effective_rate.into();
+                }
+            }
+            Err(error) => *added.last_mut().unwrap() = error.to_string().into(),
+        }
+
+        if json {
+            let object: Map<String, Value> = headers
+                .iter()
+                .zip(&record)
+                .map(|(header, field)| (header.to_string(), field.into()))
+                .chain(added_columns.iter().map(|name| name.to_string()).zip(added))
+                .collect();
+            objects.push(Value::Object(object));
+            continue;
+        }
+
+        let values = added.into_iter().map(|value| match value {
+            Value::Null => String::new(),
+            Value::String(text) => text,
+            other => other.to_string(),
+        });
+        writer.write_record(record.iter().map(str::to_string).chain(values))?;
+    }
+
+    if json {
+        return Ok(serde_json::to_string_pretty(&objects)? + "\n");
+    }
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "year-2025")]
+    const INPUT: &str = "\
+client,Year,Status,Income
+Alice,2025,single,75000
+Bob,2024,mfj,\"$150,000\"
+Carol,2017,hoh,50000
+";
+
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn appends_tax_columns() {
+        let out = batch(INPUT.as_bytes(), false, false).unwrap();
+        assert_eq!(
+            out,
+            "\
+client,Year,Status,Income,tax,error
+Alice,2025,single,75000,11420,
+Bob,2024,mfj,\"$150,000\",23106,
+Carol,2017,hoh,50000,,tax year 2017 is not supported
+"
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn rates() {
+        let out = batch(INPUT.as_bytes(), true, false).unwrap();
+        let mut lines = out.lines();
+        assert_eq!(
+            lines.next(),
+            Some("client,Year,Status,Income,tax,marginal_rate,effective_rate,error")
+        );
+        assert!(
+            lines
+                .next()
+                .unwrap()
+                .starts_with("Alice,2025,single,75000,11420,0.22,0.152")
+        );
+        assert_eq!(
+            lines.nth(1),
+            Some("Carol,2017,hoh,50000,,,,tax year 2017 is not supported")
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn json() {
+        let out = batch(INPUT.as_bytes(), true, true).unwrap();
+        let rows: Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(rows[0]["client"], "Alice");
+        assert_eq!(rows[0]["tax"], 11_420);
+        assert_eq!(rows[0]["marginal_rate"], 0.22);
+        assert_eq!(rows[0]["error"], Value::Null);
+        assert_eq!(rows[2]["tax"], Value::Null);
+        assert_eq!(rows[2]["error"], "tax year 2017 is not supported");
+    }
+
+    #[test]
+    fn empty_input_keeps_header() {
+        let out = batch("year,status,income\n".as_bytes(), false, false).unwrap();
+        assert_eq!(out, "year,status,income,tax,error\n");
+    }
+
+    #[test]
+    fn missing_column() {
+        let error = batch("year,status\n2025,single\n".as_bytes(), false, false).unwrap_err();
+        assert_eq!(error.to_string(), "input has no \"income\" column");
+    }
+}
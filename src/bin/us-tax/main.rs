@@ -0,0 +1,354 @@
+//! `us-tax`: compute U.S. federal income tax from the terminal (requires the
+//! `cli` feature).
+//!
+//! ```sh
+//! us-tax compute --year 2025 --status single --income '$75,000'
+//! us-tax brackets --year 2025 --status mfj
+//! us-tax compare --year 2025 --income 150000 --json
+//! us-tax batch clients.csv --rates > clients-tax.csv
+//! ```
+
+mod batch;
+
+use std::error::Error;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+use us_tax_brackets::{
+    FilingStatus, Method, TaxBracket, TaxError, TaxSchedule, TaxYear, UsdAmount,
+    compute_tax_with_method, effective_rate, marginal_rate,
+};
+
+/// Compute U.S. federal income tax from the IRS Tax Table and Tax
+/// Computation Worksheet.
+#[derive(Debug, Parser)]
+#[command(name = "us-tax", version)]
+struct Cli {
+    /// Print JSON instead of text.
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Compute the tax on a taxable income.
+    Compute {
+        /// Tax year, e.g. 2025.
+        #[arg(long)]
+        year: TaxYear,
+        /// Filing status, e.g. single, mfj, mfs, hoh, or qss.
+        #[arg(long)]
+        status: FilingStatus,
+        /// Taxable income in whole dollars, e.g. 75000 or '$75,000'.
+        #[arg(long, value_parser = parse_income)]
+        income: i64,
+    },
+    /// List the Tax Rate Schedule brackets.
+    Brackets {
+        /// Tax year, e.g. 2025.
+        #[arg(long)]
+        year: TaxYear,
+        /// Filing status, e.g. single, mfj, mfs, hoh, or qss.
+        #[arg(long)]
+        status: FilingStatus,
+    },
+    /// Compute the tax on one income under every filing status.
+    Compare {
+        /// Tax year, e.g. 2025.
+        #[arg(long)]
+        year: TaxYear,
+        /// Taxable income in whole dollars, e.g. 75000 or '$75,000'.
+        #[arg(long, value_parser = parse_income)]
+        income: i64,
+    },
+    /// Compute the tax for each row of a CSV file with year, status, and
+    /// income columns.
+    Batch {
+        /// CSV file to read; standard input if omitted or `-`.
+        input: Option<PathBuf>,
+        /// Also write marginal_rate and effective_rate columns.
+        #[arg(long)]
+        rates: bool,
+    },
+}
+
+/// Parse a whole-dollar income such as `75000` or `$75,000`.
+fn parse_income(input: &str) -> Result<i64, TaxError> {
+    let amount: UsdAmount = input.parse()?;
+    if !amount.is_whole_dollars() {
+        return Err(TaxError::InvalidAmount(input.to_string()));
+    }
+    Ok(amount.dollars())
+}
+
+/// The result of `compute`, and one row of `compare`.
+#[derive(Debug, Serialize)]
+struct Computation {
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: i64,
+    tax: i64,
+    method: Method,
+    marginal_rate: f64,
+    effective_rate: f64,
+}
+
+impl Computation {
+    fn new(year: TaxYear, status: FilingStatus, taxable_income: i64) -> Result<Self, TaxError> {
+        let (tax, method) = compute_tax_with_method(year, status, taxable_income)?;
+        Ok(Computation {
+            year,
+            status,
+            taxable_income,
+            tax,
+            method,
+            marginal_rate: marginal_rate(year, status, taxable_income)?,
+            effective_rate: effective_rate(year, status, taxable_income)?,
+        })
+    }
+}
+
+/// The result of `brackets`.
+#[derive(Debug, Serialize)]
+struct Brackets<'a> {
+    year: TaxYear,
+    status: FilingStatus,
+    brackets: &'a [TaxBracket],
+}
+
+/// Format a rate as a percentage with two decimal places.
+fn percent(rate: f64) -> String {
+    format!("{:.2}%", rate * 100.0)
+}
+
+fn to_json(value: &impl Serialize) -> String {
+    serde_json::to_string_pretty(value).expect("CLI output always serializes") + "\n"
+}
+
+/// Run a parsed command line and return what it prints.
+fn run(cli: Cli) -> Result<String, Box<dyn Error>> {
+    let mut out = String::new();
+    match cli.command {
+        Command::Compute {
+            year,
+            status,
+            income,
+        } => {
+            let computation = Computation::new(year, status, income)?;
+            if cli.json {
+                return Ok(to_json(&computation));
+            }
+            let Computation {
+                tax,
+                method,
+                marginal_rate,
+                effective_rate,
+                ..
+            } = computation;
+            writeln!(out, "Tax year:        {year}").unwrap();
+            writeln!(out, "Filing status:   {status}").unwrap();
+            writeln!(out, "Taxable income:  {}", UsdAmount::from_dollars(income)).unwrap();
+            writeln!(
+                out,
+                "Tax:             {} ({method})",
+                UsdAmount::from_dollars(tax)
+            )
+            .unwrap();
+            writeln!(out, "Marginal rate:   {}", percent(marginal_rate)).unwrap();
+            writeln!(out, "Effective rate:  {}", percent(effective_rate)).unwrap();
+        }
+        Command::Brackets { year, status } => {
+            let schedule = TaxSchedule::new(year, status)?;
+            if cli.json {
+                return Ok(to_json(&Brackets {
+                    year,
+                    status,
+                    brackets: schedule.brackets(),
+                }));
+            }
+            writeln!(out, "{year} {status}").unwrap();
+            writeln!(out, "{:>6}  {:>12}  {:>12}", "Rate", "Over", "Not over").unwrap();
+            for bracket in &schedule {
+                let max = bracket.income_max.map_or_else(
+                    || "-".to_string(),
+                    |max| UsdAmount::from_dollars(max).to_string(),
+                );
+                writeln!(
+                    out,
+                    "{:>6}  {:>12}  {:>12}",
+                    format!("{:.0}%", bracket.rate * 100.0),
+                    UsdAmount::from_dollars(bracket.income_min).to_string(),
+                    max
+                )
+                .unwrap();
+            }
+        }
+        Command::Compare { year, income } => {
+            let rows = FilingStatus::ALL
+                .into_iter()
+                .map(|status| Computation::new(year, status, income))
+                .collect::<Result<Vec<_>, _>>()?;
+            if cli.json {
+                return Ok(to_json(&rows));
+            }
+            writeln!(
+                out,
+                "{year}, taxable income {}",
+                UsdAmount::from_dollars(income)
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "{:<28}  {:>10}  {:>8}  {:>9}",
+                "Filing status", "Tax", "Marginal", "Effective"
+            )
+            .unwrap();
+            for row in rows {
+                writeln!(
+                    out,
+                    "{:<28}  {:>10}  {:>8}  {:>9}",
+                    row.status.to_string(),
+                    UsdAmount::from_dollars(row.tax).to_string(),
+                    percent(row.marginal_rate),
+                    percent(row.effective_rate)
+                )
+                .unwrap();
+            }
+        }
+        Command::Batch { input, rates } => {
+            return match input {
+                Some(path) if path.as_os_str() != "-" => {
+                    let file = File::open(&path)
+                        .map_err(|error| format!("{}: {error}", path.display()))?;
+                    batch::batch(file, rates, cli.json)
+                }
+                _ => batch::batch(io::stdin().lock(), rates, cli.json),
+            };
+        }
+    }
+    Ok(out)
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(out) => {
+            print!("{out}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Every test runs the commands on 2024 or 2025 data.
+#[cfg(all(test, any(feature = "year-2024", feature = "year-2025")))]
+mod tests {
+    use super::*;
+
+    fn run_args(args: &[&str]) -> Result<String, Box<dyn Error>> {
+        run(Cli::try_parse_from([&["us-tax"], args].concat()).unwrap())
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn compute() {
+        let out = run_args(&[
+            "compute", "--year", "2025", "--status", "single", "--income", "$75,000",
+        ])
+        .unwrap();
+        assert!(
+            out.contains("Tax:             $11,420 (Tax Table)"),
+            "{out}"
+        );
+        assert!(out.contains("Marginal rate:   22.00%"), "{out}");
+        assert!(out.contains("Effective rate:  15.23%"), "{out}");
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn compute_json() {
+        let out = run_args(&[
+            "compute", "--year", "2024", "--status", "mfj", "--income", "150000", "--json",
+        ])
+        .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value["year"], 2024);
+        assert_eq!(value["status"], "married_filing_jointly");
+        assert_eq!(value["tax"], 23_106);
+        assert_eq!(value["method"], "worksheet");
+        assert_eq!(value["marginal_rate"], 0.22);
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn brackets() {
+        let out = run_args(&["brackets", "--year", "2025", "--status", "single"]).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], "2025 Single");
+        assert_eq!(lines.len(), 2 + 7);
+        assert!(lines[2].starts_with("   10%"), "{out}");
+        assert!(lines[2].ends_with("$11,925"), "{out}");
+        assert!(lines[8].ends_with("-"), "{out}");
+
+        let out = run_args(&["--json", "brackets", "--year", "2025", "--status", "s"]).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value["brackets"][0]["income_max"], 11_925);
+        assert_eq!(value["brackets"][6]["income_max"], serde_json::Value::Null);
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn compare() {
+        let out = run_args(&["compare", "--year", "2025", "--income", "75000"]).unwrap();
+        assert_eq!(out.lines().count(), 2 + FilingStatus::ALL.len());
+        assert!(out.contains("Single"), "{out}");
+        assert!(out.contains("$11,420"), "{out}");
+
+        let out = run_args(&["compare", "--year", "2025", "--income", "75000", "--json"]).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value.as_array().unwrap().len(), FilingStatus::ALL.len());
+        assert_eq!(value[0]["tax"], 11_420);
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn invalid_arguments() {
+        let parse = |args: &[&str]| Cli::try_parse_from([&["us-tax"], args].concat());
+        assert!(
+            parse(&[
+                "compute", "--year", "1999", "--status", "s", "--income", "1"
+            ])
+            .is_err()
+        );
+        assert!(
+            parse(&[
+                "compute", "--year", "2025", "--status", "x", "--income", "1"
+            ])
+            .is_err()
+        );
+        assert!(
+            parse(&[
+                "compute", "--year", "2025", "--status", "s", "--income", "1.50"
+            ])
+            .is_err()
+        );
+        assert!(parse(&["compare", "--year", "2025"]).is_err());
+
+        let error =
+            run_args(&["compute", "--year", "2025", "--status", "s", "--income=-1"]).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<TaxError>(),
+            Some(&TaxError::NegativeIncome)
+        );
+    }
+}
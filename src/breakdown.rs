@@ -0,0 +1,133 @@
+//! Per-bracket breakdown of federal income tax.
+
+use crate::schedule::TaxSchedule;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The slice of taxable income that falls within a single Tax Rate Schedule
+/// bracket, and the tax attributable to it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(get_all, frozen, skip_from_py_object, module = "us_tax_brackets")
+)]
+pub struct BracketPortion {
+    /// Lower bound of the bracket ("Over $X").
+    pub income_min: i64,
+    /// Upper bound of the bracket ("But not over $Y"), or [`None`] for the
+    /// highest (unbounded) bracket.
+    pub income_max: Option<i64>,
+    /// Marginal rate applied within the bracket (e.g., 0.22 for 22%).
+    pub rate: f64,
+    /// Amount of taxable income taxed in this bracket, in whole dollars.
+    pub income: i64,
+    /// Tax attributable to this slice (`income × rate`), unrounded.
+    pub tax: f64,
+}
+
+/// Split taxable income across the Tax Rate Schedule brackets for a given tax
+/// year and filing status.
+///
+/// Only brackets that contain part of `taxable_income` are returned, in
+/// ascending order. Zero income yields an empty breakdown.
+///
+/// The portions sum to the exact rate-schedule tax. Below $100,000 this can
+/// differ by a few dollars from [`compute_tax`](crate::compute_tax), because
+/// the Tax Table evaluates the schedule at the midpoint of each $50 band; at
+/// $100,000 and above the sum matches the worksheet before rounding.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `taxable_income` is negative.
+/// Returns [`TaxError::IncomeOutOfRange`] if `taxable_income` does not fit in
+/// an `i64`.
+/// Returns [`TaxError::NoBracketFound`] if the brackets do not cover
+/// `taxable_income` (should not occur with valid embedded data).
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_tax_breakdown, FilingStatus, TaxYear};
+///
+/// let portions = compute_tax_breakdown(TaxYear::Y2025, FilingStatus::Single, 50_000).unwrap();
+/// assert_eq!(portions.len(), 3);
+///
+/// // First $11,925 taxed at 10%
+/// assert_eq!(portions[0].income, 11_925);
+/// assert_eq!(portions[0].rate, 0.10);
+///
+/// // Remaining $1,525 above $48,475 taxed at 22%
+/// assert_eq!(portions[2].income, 1_525);
+/// assert_eq!(portions[2].rate, 0.22);
+/// # }
+/// ```
+pub fn compute_tax_breakdown(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+) -> Result<Vec<BracketPortion>, TaxError> {
+    TaxSchedule::new(year, status)?.breakdown(taxable_income)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::compute_tax;
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn zero_income_is_empty() {
+        assert!(
+            compute_tax_breakdown(TaxYear::Y2024, FilingStatus::Single, 0)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn negative_income() {
+        assert_eq!(
+            compute_tax_breakdown(TaxYear::Y2024, FilingStatus::Single, -1),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[cfg(feature = "year-2023")]
+    #[test]
+    fn portions_sum_to_income() {
+        let portions =
+            compute_tax_breakdown(TaxYear::Y2023, FilingStatus::HeadOfHousehold, 300_000).unwrap();
+        assert_eq!(portions.len(), 6);
+        assert_eq!(portions.iter().map(|p| p.income).sum::<i64>(), 300_000);
+        // Top slice: $300,000 − $231,250 at 35%
+        let top = portions.last().unwrap();
+        assert_eq!(top.income, 68_750);
+        assert_eq!(top.rate, 0.35);
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn matches_worksheet_above_100k() {
+        for income in [100_000, 150_000, 400_000, 1_000_000] {
+            let portions =
+                compute_tax_breakdown(TaxYear::Y2025, FilingStatus::MarriedFilingJointly, income)
+                    .unwrap();
+            let total: f64 = portions.iter().map(|p| p.tax).sum();
+            let tax =
+                compute_tax(TaxYear::Y2025, FilingStatus::MarriedFilingJointly, income).unwrap();
+            assert_eq!(total.round() as i64, tax);
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn income_at_threshold_stays_in_lower_bracket() {
+        // 2024 single: $11,600 fills the 10% bracket exactly
+        let portions = compute_tax_breakdown(TaxYear::Y2024, FilingStatus::Single, 11_600).unwrap();
+        assert_eq!(portions.len(), 1);
+        assert_eq!(portions[0].income, 11_600);
+        assert_eq!(portions[0].income_max, Some(11_600));
+    }
+}
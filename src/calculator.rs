@@ -0,0 +1,175 @@
+//! A tax calculator bound to one tax year.
+
+use crate::batch::compute_tax_batch;
+use crate::breakdown::{BracketPortion, compute_tax_breakdown};
+use crate::compute::{compute_tax, compute_tax_cents, compute_tax_with_method};
+use crate::rates::{effective_rate, marginal_rate};
+use crate::schedule::TaxSchedule;
+use crate::trace::{TaxComputation, compute_tax_detailed};
+use crate::types::{FilingStatus, Method, TaxError, TaxYear};
+use crate::validate::validate_data;
+
+/// Computes tax for one tax year, with its data loaded and validated up
+/// front.
+///
+/// [`TaxCalculator::new`] runs [`validate_data`] on the year's data, so data
+/// problems surface when the calculator is built instead of on some later
+/// request. The methods then mirror the crate's free functions with the year
+/// filled in.
+///
+/// The data itself is static, so a calculator is cheap to copy and to build
+/// more than once.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{FilingStatus, TaxCalculator, TaxYear};
+///
+/// let calculator = TaxCalculator::new(TaxYear::Y2025).unwrap();
+/// assert_eq!(calculator.compute(FilingStatus::Single, 75_000).unwrap(), 11_420);
+/// assert_eq!(calculator.marginal_rate(FilingStatus::Single, 75_000).unwrap(), 0.22);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaxCalculator {
+    year: TaxYear,
+}
+
+impl TaxCalculator {
+    /// Validate the data for `year`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::InvalidData`] if the data fails [`validate_data`].
+    pub fn new(year: TaxYear) -> Result<Self, TaxError> {
+        validate_data(year).map_err(TaxError::InvalidData)?;
+        Ok(TaxCalculator { year })
+    }
+
+    /// The tax year this calculator computes.
+    pub fn year(&self) -> TaxYear {
+        self.year
+    }
+
+    /// See [`compute_tax`].
+    pub fn compute(
+        &self,
+        status: FilingStatus,
+        taxable_income: impl TryInto<i64>,
+    ) -> Result<i64, TaxError> {
+        compute_tax(self.year, status, taxable_income)
+    }
+
+    /// See [`compute_tax_with_method`].
+    pub fn compute_with_method(
+        &self,
+        status: FilingStatus,
+        taxable_income: impl TryInto<i64>,
+    ) -> Result<(i64, Method), TaxError> {
+        compute_tax_with_method(self.year, status, taxable_income)
+    }
+
+    /// See [`compute_tax_cents`].
+    pub fn compute_cents(
+        &self,
+        status: FilingStatus,
+        taxable_income_cents: impl TryInto<i64>,
+    ) -> Result<i64, TaxError> {
+        compute_tax_cents(self.year, status, taxable_income_cents)
+    }
+
+    /// See [`compute_tax_detailed`].
+    pub fn compute_detailed(
+        &self,
+        status: FilingStatus,
+        taxable_income: impl TryInto<i64>,
+    ) -> Result<TaxComputation, TaxError> {
+        compute_tax_detailed(self.year, status, taxable_income)
+    }
+
+    /// See [`compute_tax_batch`].
+    pub fn compute_batch(
+        &self,
+        status: FilingStatus,
+        incomes: &[i64],
+    ) -> Vec<Result<i64, TaxError>> {
+        compute_tax_batch(self.year, status, incomes)
+    }
+
+    /// See [`marginal_rate`].
+    pub fn marginal_rate(
+        &self,
+        status: FilingStatus,
+        taxable_income: impl TryInto<i64>,
+    ) -> Result<f64, TaxError> {
+        marginal_rate(self.year, status, taxable_income)
+    }
+
+    /// See [`effective_rate`].
+    pub fn effective_rate(
+        &self,
+        status: FilingStatus,
+        taxable_income: impl TryInto<i64>,
+    ) -> Result<f64, TaxError> {
+        effective_rate(self.year, status, taxable_income)
+    }
+
+    /// See [`compute_tax_breakdown`].
+    pub fn breakdown(
+        &self,
+        status: FilingStatus,
+        taxable_income: impl TryInto<i64>,
+    ) -> Result<Vec<BracketPortion>, TaxError> {
+        compute_tax_breakdown(self.year, status, taxable_income)
+    }
+
+    /// The Tax Rate Schedule for a filing status. See [`TaxSchedule::new`].
+    pub fn schedule(&self, status: FilingStatus) -> Result<TaxSchedule, TaxError> {
+        TaxSchedule::new(self.year, status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_year_loads() {
+        for year in TaxYear::ALL {
+            let calculator = TaxCalculator::new(year).unwrap();
+            assert_eq!(calculator.year(), year);
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn delegates_to_free_functions() {
+        let calculator = TaxCalculator::new(TaxYear::Y2024).unwrap();
+        for status in FilingStatus::ALL {
+            for income in [0, 42_000, 100_000, 600_000] {
+                let year = TaxYear::Y2024;
+                assert_eq!(
+                    calculator.compute_with_method(status, income),
+                    compute_tax_with_method(year, status, income)
+                );
+                assert_eq!(
+                    calculator.compute_cents(status, income * 100),
+                    compute_tax_cents(year, status, income * 100)
+                );
+                assert_eq!(
+                    calculator.marginal_rate(status, income),
+                    marginal_rate(year, status, income)
+                );
+                assert_eq!(
+                    calculator.breakdown(status, income),
+                    compute_tax_breakdown(year, status, income)
+                );
+            }
+        }
+        assert_eq!(
+            calculator.compute(FilingStatus::Single, -1),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+}
@@ -0,0 +1,302 @@
+//! C ABI (requires the `capi` feature).
+//!
+//! Every function returns a [`UstaxStatus`] and writes its result through an
+//! out-pointer only on success. Filing statuses are passed as the `USTAX_*`
+//! integer constants below, and years as calendar years. The header
+//! `include/us_tax_brackets.h` is generated from this module by cbindgen.
+//!
+//! Build a static or shared library with:
+//!
+//! ```sh
+//! cargo rustc --release --features capi --crate-type staticlib
+//! cargo rustc --release --features capi --crate-type cdylib
+//! ```
+
+use std::ffi::{CStr, c_char};
+
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// Single.
+pub const USTAX_SINGLE: i32 = 0;
+/// Married filing jointly.
+pub const USTAX_MARRIED_FILING_JOINTLY: i32 = 1;
+/// Married filing separately.
+pub const USTAX_MARRIED_FILING_SEPARATELY: i32 = 2;
+/// Head of household.
+pub const USTAX_HEAD_OF_HOUSEHOLD: i32 = 3;
+/// Qualifying surviving spouse.
+pub const USTAX_QUALIFYING_SURVIVING_SPOUSE: i32 = 4;
+
+/// Result code returned by every C function.
+///
+/// The values are stable: existing codes never change, and new error kinds
+/// get new codes. Codes below 100 mirror the crate's `TaxError` variants.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UstaxStatus {
+    /// Success; the out-pointer was written.
+    Ok = 0,
+    /// Taxable income is negative.
+    NegativeIncome = 1,
+    /// Taxable income does not fit in an `int64_t`.
+    IncomeOutOfRange = 2,
+    /// No bracket matches the income (should not occur).
+    NoBracketFound = 3,
+    /// The embedded data is malformed (should not occur).
+    MalformedData = 4,
+    /// The crate has no data for the year.
+    UnsupportedYear = 5,
+    /// The year is not a valid calendar year.
+    InvalidYear = 6,
+    /// The filing status is not one of the `USTAX_*` constants.
+    InvalidFilingStatus = 7,
+    /// A dollar amount could not be parsed.
+    InvalidAmount = 8,
+    /// The embedded data failed validation (should not occur).
+    InvalidData = 9,
+    /// A tax data file could not be read.
+    UnreadableData = 10,
+    /// A custom tax schedule is invalid (not used by the C functions).
+    InvalidSchedule = 11,
+    /// An out-pointer was null.
+    NullPointer = 100,
+}
+
+/// The message for each status code, in declaration order.
+const MESSAGES: [(UstaxStatus, &CStr); 13] = [
+    (UstaxStatus::Ok, c"success"),
+    (
+        UstaxStatus::NegativeIncome,
+        c"taxable income cannot be negative",
+    ),
+    (
+        UstaxStatus::IncomeOutOfRange,
+        c"taxable income is out of range",
+    ),
+    (
+        UstaxStatus::NoBracketFound,
+        c"no tax bracket found for the income",
+    ),
+    (
+        UstaxStatus::MalformedData,
+        c"embedded tax data is malformed",
+    ),
+    (UstaxStatus::UnsupportedYear, c"unsupported tax year"),
+    (UstaxStatus::InvalidYear, c"invalid tax year"),
+    (UstaxStatus::InvalidFilingStatus, c"invalid filing status"),
+    (UstaxStatus::InvalidAmount, c"invalid dollar amount"),
+    (UstaxStatus::InvalidData, c"tax data failed validation"),
+    (
+        UstaxStatus::UnreadableData,
+        c"tax data file could not be read",
+    ),
+    (UstaxStatus::InvalidSchedule, c"invalid tax schedule"),
+    (UstaxStatus::NullPointer, c"output pointer is null"),
+];
+
+impl From<&TaxError> for UstaxStatus {
+    fn from(error: &TaxError) -> Self {
+        match error {
+            TaxError::NegativeIncome => UstaxStatus::NegativeIncome,
+            TaxError::IncomeOutOfRange => UstaxStatus::IncomeOutOfRange,
+            TaxError::NoBracketFound { .. } => UstaxStatus::NoBracketFound,
+            TaxError::MalformedData { .. } => UstaxStatus::MalformedData,
+            TaxError::UnsupportedYear(_) => UstaxStatus::UnsupportedYear,
+            TaxError::InvalidYear(_) => UstaxStatus::InvalidYear,
+            TaxError::InvalidFilingStatus(_) => UstaxStatus::InvalidFilingStatus,
+            TaxError::InvalidAmount(_) => UstaxStatus::InvalidAmount,
+            TaxError::InvalidData(_) => UstaxStatus::InvalidData,
+            TaxError::UnreadableData { .. } => UstaxStatus::UnreadableData,
+            TaxError::InvalidSchedule(_) => UstaxStatus::InvalidSchedule,
+        }
+    }
+}
+
+/// Convert the C arguments, run `compute`, and write its result to `out`.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+unsafe fn call<T>(
+    year: u16,
+    status: i32,
+    out: *mut T,
+    compute: impl FnOnce(TaxYear, FilingStatus) -> Result<T, TaxError>,
+) -> UstaxStatus {
+    if out.is_null() {
+        return UstaxStatus::NullPointer;
+    }
+    let status = match status {
+        USTAX_SINGLE => FilingStatus::Single,
+        USTAX_MARRIED_FILING_JOINTLY => FilingStatus::MarriedFilingJointly,
+        USTAX_MARRIED_FILING_SEPARATELY => FilingStatus::MarriedFilingSeparately,
+        USTAX_HEAD_OF_HOUSEHOLD => FilingStatus::HeadOfHousehold,
+        USTAX_QUALIFYING_SURVIVING_SPOUSE => FilingStatus::QualifyingSurvivingSpouse,
+        _ => return UstaxStatus::InvalidFilingStatus,
+    };
+    match TaxYear::try_from(year).and_then(|year| compute(year, status)) {
+        Ok(value) => {
+            // SAFETY: `out` is non-null, and the caller guarantees it is
+            // valid for writes.
+            unsafe { out.write(value) };
+            UstaxStatus::Ok
+        }
+        Err(error) => UstaxStatus::from(&error),
+    }
+}
+
+/// Compute federal income tax in whole dollars, as `compute_tax` does.
+///
+/// # Safety
+///
+/// `tax_out` must be null or point to writable memory for an `int64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ustax_compute(
+    year: u16,
+    status: i32,
+    taxable_income: i64,
+    tax_out: *mut i64,
+) -> UstaxStatus {
+    // SAFETY: forwarded from this function's contract.
+    unsafe {
+        call(year, status, tax_out, |year, status| {
+            crate::compute_tax(year, status, taxable_income)
+        })
+    }
+}
+
+/// The marginal tax rate at a taxable income, as `marginal_rate` does.
+///
+/// # Safety
+///
+/// `rate_out` must be null or point to writable memory for a `double`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ustax_marginal_rate(
+    year: u16,
+    status: i32,
+    taxable_income: i64,
+    rate_out: *mut f64,
+) -> UstaxStatus {
+    // SAFETY: forwarded from this function's contract.
+    unsafe {
+        call(year, status, rate_out, |year, status| {
+            crate::marginal_rate(year, status, taxable_income)
+        })
+    }
+}
+
+/// The effective (average) tax rate at a taxable income, as
+/// `effective_rate` does.
+///
+/// # Safety
+///
+/// `rate_out` must be null or point to writable memory for a `double`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ustax_effective_rate(
+    year: u16,
+    status: i32,
+    taxable_income: i64,
+    rate_out: *mut f64,
+) -> UstaxStatus {
+    // SAFETY: forwarded from this function's contract.
+    unsafe {
+        call(year, status, rate_out, |year, status| {
+            crate::effective_rate(year, status, taxable_income)
+        })
+    }
+}
+
+/// A static, NUL-terminated English description of a status code, or
+/// `"unknown status"` for a value that is not a code.
+///
+/// The returned string must not be freed.
+#[unsafe(no_mangle)]
+pub extern "C" fn ustax_status_message(status: i32) -> *const c_char {
+    MESSAGES
+        .iter()
+        .find(|(code, _)| *code as i32 == status)
+        .map_or(c"unknown status", |(_, message)| message)
+        .as_ptr()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn compute() {
+        let mut tax = 0;
+        let status = unsafe { ustax_compute(2025, USTAX_SINGLE, 75_000, &mut tax) };
+        assert_eq!((status, tax), (UstaxStatus::Ok, 11_420));
+    }
+
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn rates() {
+        let mut rate = 0.0;
+        let status =
+            unsafe { ustax_marginal_rate(2024, USTAX_HEAD_OF_HOUSEHOLD, 80_000, &mut rate) };
+        assert_eq!((status, rate), (UstaxStatus::Ok, 0.22));
+
+        let status = unsafe { ustax_effective_rate(2025, USTAX_SINGLE, 0, &mut rate) };
+        assert_eq!((status, rate), (UstaxStatus::Ok, 0.0));
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn errors_leave_output_untouched() {
+        let mut tax = -7;
+        let cases = [
+            (2025, USTAX_SINGLE, -1, UstaxStatus::NegativeIncome),
+            (1999, USTAX_SINGLE, 1, UstaxStatus::UnsupportedYear),
+            (2025, 5, 1, UstaxStatus::InvalidFilingStatus),
+            (2025, -1, 1, UstaxStatus::InvalidFilingStatus),
+        ];
+        for (year, filing_status, income, expected) in cases {
+            let status = unsafe { ustax_compute(year, filing_status, income, &mut tax) };
+            assert_eq!(status, expected);
+        }
+        assert_eq!(tax, -7);
+        assert_eq!(
+            unsafe { ustax_compute(2025, USTAX_SINGLE, 1, ptr::null_mut()) },
+            UstaxStatus::NullPointer
+        );
+    }
+
+    #[test]
+    fn status_codes_are_stable() {
+        assert_eq!(UstaxStatus::Ok as i32, 0);
+        assert_eq!(UstaxStatus::InvalidData as i32, 9);
+        assert_eq!(UstaxStatus::NullPointer as i32, 100);
+        assert_eq!(
+            UstaxStatus::from(&TaxError::UnsupportedYear(1999)),
+            UstaxStatus::UnsupportedYear
+        );
+    }
+
+    #[test]
+    fn status_messages() {
+        let message = |status| unsafe { CStr::from_ptr(ustax_status_message(status)) }.to_str();
+        assert_eq!(
+            message(UstaxStatus::NegativeIncome as i32),
+            Ok("taxable income cannot be negative")
+        );
+        assert_eq!(message(-3), Ok("unknown status"));
+        for (index, (code, _)) in MESSAGES.iter().enumerate() {
+            assert!(MESSAGES[..index].iter().all(|(other, _)| other != code));
+        }
+    }
+
+    #[test]
+    fn header_is_up_to_date() {
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/us_tax_brackets.h"));
+        let committed = include_str!("../include/us_tax_brackets.h");
+        assert!(
+            generated == committed,
+            "include/us_tax_brackets.h is stale; copy it from {}",
+            concat!(env!("OUT_DIR"), "/us_tax_brackets.h")
+        );
+    }
+}
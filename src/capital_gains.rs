@@ -0,0 +1,557 @@
+//! Tax on income that includes qualified dividends and capital gains.
+
+use crate::compute::{compute_tax, income_to_i64};
+use crate::data;
+use crate::schedule::TaxBracket;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The brackets of the 0%, 15%, and 20% long-term capital gain rates for
+/// `year` and `status`, in ascending order.
+///
+/// Qualified dividends and net capital gain are stacked on top of ordinary
+/// income, so the bracket bounds apply to taxable income including the
+/// gains. The thresholds are inflation-adjusted each year along with the
+/// regular brackets; qualifying surviving spouses use the married filing
+/// jointly ones. Long-term gains up to the top of the 0% bracket can be
+/// realized tax-free.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{ltcg_brackets, FilingStatus, TaxYear};
+///
+/// let brackets = ltcg_brackets(TaxYear::Y2025, FilingStatus::Single);
+/// assert_eq!(brackets.len(), 3);
+/// assert_eq!(brackets[0].income_max, Some(48_350));
+/// assert_eq!(brackets[2].income_min, 533_400);
+/// assert_eq!(brackets[2].rate, 0.20);
+/// # }
+/// ```
+pub fn ltcg_brackets(year: TaxYear, status: FilingStatus) -> &'static [TaxBracket] {
+    data::capital_gains_schedule(year, status)
+}
+
+/// The long-term capital gain rate on the next dollar of qualified dividends
+/// or net capital gain, at `taxable_income` including the gains.
+///
+/// Like [`marginal_rate`](crate::marginal_rate), an income exactly at a
+/// threshold gets the lower bracket's rate. The 3.8% net investment income
+/// tax is not included.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `taxable_income` is negative and
+/// [`TaxError::IncomeOutOfRange`] if it does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{ltcg_rate_for, FilingStatus, TaxYear};
+///
+/// let status = FilingStatus::MarriedFilingJointly;
+/// assert_eq!(ltcg_rate_for(TaxYear::Y2024, status, 94_050), Ok(0.0));
+/// assert_eq!(ltcg_rate_for(TaxYear::Y2024, status, 94_051), Ok(0.15));
+/// assert_eq!(ltcg_rate_for(TaxYear::Y2024, status, 1_000_000), Ok(0.20));
+/// # }
+/// ```
+pub fn ltcg_rate_for(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+) -> Result<f64, TaxError> {
+    let taxable_income = income_to_i64(taxable_income)?;
+    if taxable_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    ltcg_brackets(year, status)
+        .iter()
+        .find(|bracket| bracket.contains(taxable_income))
+        .map(|bracket| bracket.rate)
+        .ok_or(TaxError::NoBracketFound {
+            year,
+            status,
+            taxable_income,
+        })
+}
+
+/// The capital gain and dividend amounts from Schedule D and Form 1099-DIV
+/// that get the lower capital gain rates, in whole dollars.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CapitalGains {
+    /// Qualified dividends (Form 1040, line 3a).
+    pub qualified_dividends: i64,
+    /// Net capital gain: the smaller of the net long-term gain and the total
+    /// net gain (Schedule D, lines 15 and 16). Zero if either is a loss.
+    pub net_capital_gain: i64,
+    /// The part of the net capital gain from collectibles and section 1202
+    /// stock, taxed at up to 28% (Schedule D, line 18).
+    pub collectibles_gain: i64,
+    /// The part of the net capital gain from depreciation on real property,
+    /// taxed at up to 25% (Schedule D, line 19).
+    pub unrecaptured_section_1250_gain: i64,
+}
+
+/// The result of the Schedule D Tax Worksheet, returned by
+/// [`schedule_d_tax_worksheet`].
+///
+/// The amounts taxed at each rate add up to taxable income, and the tax is
+/// the smaller of the tax at those rates and the [`compute_tax`] on all of
+/// taxable income.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ScheduleDWorksheet {
+    /// Taxable income (line 1).
+    pub taxable_income: i64,
+    /// Income taxed at the regular rates, with the Tax Table or the Tax
+    /// Computation Worksheet (line 21).
+    pub ordinary_income: i64,
+    /// Qualified dividends and capital gain taxed at 0% (line 22).
+    pub taxed_at_0: i64,
+    /// Qualified dividends and capital gain taxed at 15% (line 30).
+    pub taxed_at_15: i64,
+    /// Qualified dividends and capital gain taxed at 20% (line 33).
+    pub taxed_at_20: i64,
+    /// Unrecaptured section 1250 gain taxed at 25% (line 39).
+    pub taxed_at_25: i64,
+    /// Collectibles and section 1202 gain taxed at 28% (line 42).
+    pub taxed_at_28: i64,
+    /// The tax on all of taxable income at the regular rates (line 46).
+    pub regular_tax: i64,
+    /// The tax, rounded to whole dollars (line 47).
+    pub tax: i64,
+}
+
+/// Fill in the Schedule D Tax Worksheet from the Form 1040 instructions.
+///
+/// Qualified dividends and net capital gain are stacked on top of ordinary
+/// income and taxed at 0%, 15%, or 20% depending on where they fall. The
+/// collectibles gain is taxed at no more than 28% and the unrecaptured
+/// section 1250 gain at no more than 25%, but either is taxed at the
+/// regular rates when those are lower. With neither, the result is the same
+/// as the Qualified Dividends and Capital Gain Tax Worksheet.
+///
+/// Investment interest expense elected on Form 4952 to be paid from
+/// qualified dividends or capital gain (lines 3 and 4 of the worksheet) is
+/// not supported; subtract it from the gains first.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if taxable income or any amount in
+/// `gains` is negative, and [`TaxError::IncomeOutOfRange`] if taxable
+/// income does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{compute_tax, schedule_d_tax_worksheet, CapitalGains, FilingStatus, TaxYear};
+///
+/// let gains = CapitalGains {
+///     qualified_dividends: 5_000,
+///     net_capital_gain: 20_000,
+///     ..Default::default()
+/// };
+/// let worksheet =
+///     schedule_d_tax_worksheet(TaxYear::Y2024, FilingStatus::Single, 100_000, &gains).unwrap();
+/// assert_eq!(worksheet.ordinary_income, 75_000);
+/// assert_eq!(worksheet.taxed_at_15, 25_000);
+/// let ordinary = compute_tax(TaxYear::Y2024, FilingStatus::Single, 75_000).unwrap();
+/// assert_eq!(worksheet.tax, ordinary + 3_750);
+/// # }
+/// ```
+pub fn schedule_d_tax_worksheet(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+    gains: &CapitalGains,
+) -> Result<ScheduleDWorksheet, TaxError> {
+    let line1 = income_to_i64(taxable_income)?;
+    let CapitalGains {
+        qualified_dividends,
+        net_capital_gain,
+        collectibles_gain,
+        unrecaptured_section_1250_gain,
+    } = *gains;
+    if line1 < 0
+        || qualified_dividends < 0
+        || net_capital_gain < 0
+        || collectibles_gain < 0
+        || unrecaptured_section_1250_gain < 0
+    {
+        return Err(TaxError::NegativeIncome);
+    }
+
+    let brackets = ltcg_brackets(year, status);
+    let zero_rate_max = brackets[0].income_max.unwrap_or(i64::MAX);
+    let fifteen_rate_max = brackets[1].income_max.unwrap_or(i64::MAX);
+    // The regular rates are below 25% up to the top of the 24% bracket.
+    let below_25_max = data::rate_schedule(year, status)
+        .iter()
+        .find(|bracket| bracket.rate == 0.24)
+        .and_then(|bracket| bracket.income_max)
+        .unwrap_or(0);
+
+    let line6 = qualified_dividends;
+    let line9 = net_capital_gain;
+    let line10 = line6.saturating_add(line9);
+    let line11 = collectibles_gain.saturating_add(unrecaptured_section_1250_gain);
+    let line12 = line9.min(line11);
+    let line13 = line10 - line12;
+    let line14 = (line1 - line13).max(0);
+    let line16 = line1.min(zero_rate_max);
+    let line17 = line14.min(line16);
+    let line18 = (line1 - line10).max(0);
+    let line20 = line14.min(line1.min(below_25_max));
+    let line21 = line18.max(line20);
+    let line22 = line16 - line17;
+
+    let (mut line30, mut line33, mut line39, mut line42) = (0, 0, 0, 0);
+    if line1 != line16 {
+        let line23 = line1.min(line13);
+        let line25 = (line23 - line22).max(0);
+        let line27 = line1.min(fifteen_rate_max);
+        let line29 = (line27 - (line21 + line22)).max(0);
+        line30 = line25.min(line29);
+        let line32 = line22 + line30;
+        if line1 != line32 {
+            line33 = (line23 - line32).max(0);
+            if unrecaptured_section_1250_gain > 0 {
+                let line35 = line9.min(unrecaptured_section_1250_gain);
+                let line38 = (line10.saturating_add(line21) - line1).max(0);
+                line39 = (line35 - line38).max(0);
+            }
+            if collectibles_gain > 0 {
+                let line41 = line21 + line22 + line30 + line33 + line39;
+                line42 = (line1 - line41).max(0);
+            }
+        }
+    }
+
+    // Whole dollars times whole percentages are whole cents.
+    let preferential_cents = i128::from(line30) * 15
+        + i128::from(line33) * 20
+        + i128::from(line39) * 25
+        + i128::from(line42) * 28;
+    let line45_cents = i128::from(compute_tax(year, status, line21)?) * 100 + preferential_cents;
+    let line45 =
+        i64::try_from((line45_cents + 50) / 100).map_err(|_| TaxError::IncomeOutOfRange)?;
+    let line46 = compute_tax(year, status, line1)?;
+    Ok(ScheduleDWorksheet {
+        taxable_income: line1,
+        ordinary_income: line21,
+        taxed_at_0: line22,
+        taxed_at_15: line30,
+        taxed_at_20: line33,
+        taxed_at_25: line39,
+        taxed_at_28: line42,
+        regular_tax: line46,
+        tax: line45.min(line46),
+    })
+}
+
+/// Compute federal income tax on taxable income that includes qualified
+/// dividends or capital gains.
+///
+/// This is the [`tax`](ScheduleDWorksheet::tax) from
+/// [`schedule_d_tax_worksheet`], the amount for Form 1040, line 16.
+///
+/// # Errors
+///
+/// Returns the same errors as [`schedule_d_tax_worksheet`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_tax_with_gains, CapitalGains, FilingStatus, TaxYear};
+///
+/// // Long-term gains that fit under the 0% threshold are not taxed
+/// let gains = CapitalGains { net_capital_gain: 30_000, ..Default::default() };
+/// let tax = compute_tax_with_gains(TaxYear::Y2025, FilingStatus::MarriedFilingJointly, 90_000, &gains);
+/// let ordinary = us_tax_brackets::compute_tax(TaxYear::Y2025, FilingStatus::MarriedFilingJointly, 60_000);
+/// assert_eq!(tax, ordinary);
+/// # }
+/// ```
+pub fn compute_tax_with_gains(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+    gains: &CapitalGains,
+) -> Result<i64, TaxError> {
+    Ok(schedule_d_tax_worksheet(year, status, taxable_income, gains)?.tax)
+}
+
+/// How much long-term gain fits in each capital gain rate, returned by
+/// [`gain_harvesting_headroom`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GainHarvestingHeadroom {
+    /// Gain that can be realized at 0%.
+    pub taxed_at_0: i64,
+    /// Gain that can be realized at 15% after the 0% room is used, before
+    /// any is taxed at 20%.
+    pub taxed_at_15: i64,
+}
+
+/// Compute how much long-term capital gain can be realized at the 0% rate,
+/// and then at 15%, on top of `ordinary_income`.
+///
+/// `ordinary_income` is taxable income without qualified dividends and net
+/// capital gain: adjusted gross income from everything else, less the
+/// deductions. It may be negative, when the deductions exceed that income,
+/// and the unused deduction then shelters gains too. `preferential_income`
+/// is the qualified dividends and net capital gain already expected. Gains
+/// are stacked on top of ordinary income, so the room at each rate is what
+/// is left of its bracket above both.
+///
+/// Realized gains also raise adjusted gross income, which can reduce
+/// credits and add the 3.8% net investment income tax; neither is
+/// considered.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `preferential_income` is negative
+/// and [`TaxError::IncomeOutOfRange`] if either amount does not fit in an
+/// `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{gain_harvesting_headroom, FilingStatus, TaxYear};
+///
+/// let status = FilingStatus::MarriedFilingJointly;
+/// // The 0% rate ends at $96,700 of taxable income in 2025.
+/// let headroom = gain_harvesting_headroom(TaxYear::Y2025, status, 60_000, 5_000).unwrap();
+/// assert_eq!(headroom.taxed_at_0, 31_700);
+/// assert_eq!(headroom.taxed_at_15, 600_050 - 96_700);
+/// # }
+/// ```
+pub fn gain_harvesting_headroom(
+    year: TaxYear,
+    status: FilingStatus,
+    ordinary_income: impl TryInto<i64>,
+    preferential_income: impl TryInto<i64>,
+) -> Result<GainHarvestingHeadroom, TaxError> {
+    let ordinary_income = income_to_i64(ordinary_income)?;
+    let preferential_income = income_to_i64(preferential_income)?;
+    if preferential_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let brackets = ltcg_brackets(year, status);
+    let zero_rate_max = brackets[0].income_max.unwrap_or(i64::MAX);
+    let fifteen_rate_max = brackets[1].income_max.unwrap_or(i64::MAX);
+    let stacked = ordinary_income.saturating_add(preferential_income);
+    let taxed_at_0 = zero_rate_max.saturating_sub(stacked).max(0);
+    let taxed_at_15 = fifteen_rate_max
+        .saturating_sub(stacked.max(zero_rate_max))
+        .max(0);
+    Ok(GainHarvestingHeadroom {
+        taxed_at_0,
+        taxed_at_15,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stack the preferential income on ordinary income and tax each part
+    /// at its rate, without the worksheet's lines.
+    fn stacked(year: TaxYear, status: FilingStatus, income: i64, preferential: i64) -> i64 {
+        let preferential = preferential.min(income);
+        let ordinary = income - preferential;
+        let brackets = ltcg_brackets(year, status);
+        let mut cents = i128::from(compute_tax(year, status, ordinary).unwrap()) * 100;
+        for bracket in brackets {
+            let max = bracket.income_max.unwrap_or(i64::MAX);
+            let portion = (income.min(max) - ordinary.max(bracket.income_min)).max(0);
+            cents += i128::from(portion) * (bracket.rate * 100.0).round() as i128;
+        }
+        let tax = ((cents + 50) / 100) as i64;
+        tax.min(compute_tax(year, status, income).unwrap())
+    }
+
+    #[test]
+    fn matches_stacked_rates() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for income in (0..1_200_000).step_by(37_813) {
+                    for gain in [0, 1_000, 25_000, 150_000, 2_000_000] {
+                        let gains = CapitalGains {
+                            qualified_dividends: gain / 4,
+                            net_capital_gain: gain - gain / 4,
+                            ..Default::default()
+                        };
+                        let worksheet =
+                            schedule_d_tax_worksheet(year, status, income, &gains).unwrap();
+                        assert_eq!(
+                            worksheet.tax,
+                            stacked(year, status, income, gain),
+                            "{year} {status} {income} {gain}"
+                        );
+                        assert_eq!(
+                            worksheet.ordinary_income
+                                + worksheet.taxed_at_0
+                                + worksheet.taxed_at_15
+                                + worksheet.taxed_at_20,
+                            income,
+                            "{year} {status} {income} {gain}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(all(
+        feature = "year-2018",
+        feature = "year-2022",
+        feature = "year-2023",
+        feature = "year-2025"
+    ))]
+    #[test]
+    fn ltcg_thresholds() {
+        let zero_rate_max = |year| ltcg_brackets(year, FilingStatus::Single)[0].income_max;
+        assert_eq!(zero_rate_max(TaxYear::Y2018), Some(38_600));
+        assert_eq!(zero_rate_max(TaxYear::Y2022), Some(41_675));
+        assert_eq!(zero_rate_max(TaxYear::Y2023), Some(44_625));
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                let brackets = ltcg_brackets(year, status);
+                let rates: Vec<f64> = brackets.iter().map(|bracket| bracket.rate).collect();
+                assert_eq!(rates, [0.0, 0.15, 0.20], "{year} {status}");
+                assert_eq!(brackets[0].income_min, 0);
+                assert_eq!(brackets[0].income_max, Some(brackets[1].income_min));
+                assert_eq!(brackets[1].income_max, Some(brackets[2].income_min));
+                assert_eq!(brackets[2].income_max, None);
+            }
+            // The 0% bracket for joint filers is twice the single one.
+            assert_eq!(
+                ltcg_brackets(year, FilingStatus::MarriedFilingJointly)[0].income_max,
+                zero_rate_max(year).map(|max| 2 * max)
+            );
+        }
+        assert_eq!(
+            ltcg_rate_for(TaxYear::Y2025, FilingStatus::HeadOfHousehold, 0),
+            Ok(0.0)
+        );
+        assert_eq!(
+            ltcg_rate_for(TaxYear::Y2025, FilingStatus::HeadOfHousehold, 566_701),
+            Ok(0.20)
+        );
+        assert_eq!(
+            ltcg_rate_for(TaxYear::Y2025, FilingStatus::Single, -1),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn collectibles_at_28_percent() {
+        let year = TaxYear::Y2024;
+        let status = FilingStatus::Single;
+        let gains = CapitalGains {
+            net_capital_gain: 50_000,
+            collectibles_gain: 50_000,
+            ..Default::default()
+        };
+        let worksheet = schedule_d_tax_worksheet(year, status, 300_000, &gains).unwrap();
+        assert_eq!(worksheet.ordinary_income, 250_000);
+        assert_eq!(worksheet.taxed_at_28, 50_000);
+        assert_eq!(
+            worksheet.tax,
+            compute_tax(year, status, 250_000).unwrap() + 14_000
+        );
+
+        // Below the 24% bracket's top the regular rates are lower than 28%.
+        let worksheet = schedule_d_tax_worksheet(year, status, 120_000, &gains).unwrap();
+        assert_eq!(worksheet.taxed_at_28, 0);
+        assert_eq!(worksheet.tax, worksheet.regular_tax);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn unrecaptured_gain_at_25_percent() {
+        let year = TaxYear::Y2024;
+        let status = FilingStatus::MarriedFilingJointly;
+        let gains = CapitalGains {
+            qualified_dividends: 10_000,
+            net_capital_gain: 60_000,
+            unrecaptured_section_1250_gain: 40_000,
+            ..Default::default()
+        };
+        let worksheet = schedule_d_tax_worksheet(year, status, 500_000, &gains).unwrap();
+        assert_eq!(worksheet.ordinary_income, 430_000);
+        assert_eq!(worksheet.taxed_at_15, 30_000);
+        assert_eq!(worksheet.taxed_at_25, 40_000);
+        assert_eq!(
+            worksheet.tax,
+            compute_tax(year, status, 430_000).unwrap() + 4_500 + 10_000
+        );
+
+        // Inside the 24% bracket the regular rates are lower than 25%.
+        let worksheet = schedule_d_tax_worksheet(year, status, 400_000, &gains).unwrap();
+        assert_eq!(worksheet.ordinary_income, 370_000);
+        assert_eq!(worksheet.taxed_at_15, 30_000);
+        assert_eq!(worksheet.taxed_at_25, 0);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn harvesting_headroom() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+        let headroom = |ordinary, preferential| {
+            gain_harvesting_headroom(year, status, ordinary, preferential).unwrap()
+        };
+        let room = headroom(40_000, 0);
+        assert_eq!(room.taxed_at_0, 7_025);
+        assert_eq!(room.taxed_at_15, 518_900 - 47_025);
+        // Realizing exactly that much adds no tax.
+        let gains = CapitalGains {
+            net_capital_gain: room.taxed_at_0,
+            ..Default::default()
+        };
+        assert_eq!(
+            compute_tax_with_gains(year, status, 47_025, &gains),
+            compute_tax(year, status, 40_000)
+        );
+        // An unused deduction shelters gains as well.
+        assert_eq!(headroom(-10_000, 0).taxed_at_0, 57_025);
+        // Gains already expected use up the room first.
+        assert_eq!(headroom(40_000, 10_000).taxed_at_0, 0);
+        assert_eq!(headroom(40_000, 10_000).taxed_at_15, 518_900 - 50_000);
+        let room = headroom(600_000, 0);
+        assert_eq!((room.taxed_at_0, room.taxed_at_15), (0, 0));
+        assert_eq!(
+            gain_harvesting_headroom(year, status, 0, -1),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn errors() {
+        let year = TaxYear::Y2025;
+        let status = FilingStatus::HeadOfHousehold;
+        let negative = CapitalGains {
+            collectibles_gain: -1,
+            ..Default::default()
+        };
+        assert_eq!(
+            compute_tax_with_gains(year, status, 50_000, &negative),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            compute_tax_with_gains(year, status, -1, &CapitalGains::default()),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            compute_tax_with_gains(year, status, 80_000, &CapitalGains::default()),
+            compute_tax(year, status, 80_000)
+        );
+    }
+}
@@ -0,0 +1,429 @@
+//! Finding the incomes where one more dollar costs far more than the rate.
+
+use std::ops::RangeInclusive;
+
+use crate::capital_gains::ltcg_brackets;
+use crate::conversion::irmaa_tiers;
+use crate::deduction::DeductionChoice;
+use crate::ptc::{compute_ptc, federal_poverty_line, ptc_applicable_figure};
+use crate::savers::{compute_savers_credit, savers_credit_tiers};
+use crate::schedule::{TaxBracket, TaxSchedule};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// What changes at a [`Cliff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum CliffKind {
+    /// Ordinary income reaches the next bracket of the Tax Rate Schedule.
+    Bracket,
+    /// Qualified dividends and capital gains start or stop being pushed into
+    /// a higher long-term capital gain rate by each added dollar.
+    CapitalGainRate,
+    /// Modified adjusted gross income passes a Medicare premium tier from
+    /// [`irmaa_tiers`].
+    IrmaaTier,
+    /// Household income passes the limit for the premium tax credit, where
+    /// the whole credit is lost.
+    PremiumTaxCredit,
+    /// Adjusted gross income passes a tier of the saver's credit, where its
+    /// rate drops.
+    SaversCredit,
+}
+
+/// The household that [`find_cliffs`] scans incomes for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CliffProfile {
+    /// The tax year.
+    pub year: TaxYear,
+    /// The filing status.
+    pub status: FilingStatus,
+    /// The deduction subtracted from adjusted gross income.
+    pub deduction: DeductionChoice,
+    /// Qualified dividends and net capital gain included in the income, in
+    /// whole dollars.
+    pub preferential_income: i64,
+    /// The number of people on the return enrolled in Medicare, whose
+    /// premiums follow the income.
+    pub medicare_enrollees: u8,
+    /// The household size for the premium tax credit.
+    pub household_size: u8,
+    /// The yearly premium of the benchmark marketplace plan, or 0 without
+    /// marketplace coverage.
+    pub benchmark_premium: i64,
+    /// Retirement contributions for the saver's credit, one per person, as
+    /// for [`compute_savers_credit`]. Empty to leave the credit out.
+    pub savers_contributions: Vec<i64>,
+}
+
+impl CliffProfile {
+    /// A profile with the standard deduction, ordinary income only, and no
+    /// Medicare, marketplace coverage, or retirement contributions, so only
+    /// the tax brackets are found.
+    pub fn new(year: TaxYear, status: FilingStatus) -> Self {
+        CliffProfile {
+            year,
+            status,
+            deduction: DeductionChoice::Standard,
+            preferential_income: 0,
+            medicare_enrollees: 0,
+            household_size: 1,
+            benchmark_premium: 0,
+            savers_contributions: Vec::new(),
+        }
+    }
+}
+
+/// An income where the cost of the next dollar jumps, found by
+/// [`find_cliffs`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cliff {
+    /// What changes.
+    pub kind: CliffKind,
+    /// The highest adjusted gross income before the change, in whole
+    /// dollars. One dollar more crosses it.
+    pub income: i64,
+    /// The amount lost all at once by crossing, such as a year of higher
+    /// Medicare premiums or a credit, in whole dollars. Zero where only the
+    /// rate changes.
+    pub cost: i64,
+    /// The income tax rate on the last dollar before [`income`](Self::income).
+    pub rate_before: f64,
+    /// The income tax rate on the dollar after it.
+    pub rate_after: f64,
+}
+
+/// Find the incomes in `incomes` where the cost of earning one more dollar
+/// jumps for `profile`: the tax bracket thresholds, where the marginal rate
+/// changes, and the cliffs, where crossing by a dollar costs a lump sum.
+///
+/// The incomes are adjusted gross income, which also stands in for the
+/// modified adjusted gross income of the Medicare premiums and the premium
+/// tax credit. Each added dollar is ordinary income, with the
+/// [`preferential_income`](CliffProfile::preferential_income) stacked on top
+/// of it, so as ordinary income rises it pushes gains out of the 0% and 15%
+/// rates and the rate on the dollar is the ordinary rate plus the
+/// difference. The rates are those of the Tax Rate Schedules, without the
+/// $50 steps of the Tax Table.
+///
+/// Cliffs are found for the Medicare premium tiers when
+/// [`medicare_enrollees`](CliffProfile::medicare_enrollees) is set, the
+/// premium tax credit when there is a
+/// [`benchmark_premium`](CliffProfile::benchmark_premium) and the year has
+/// an income limit, and the saver's credit tiers when there are
+/// [`savers_contributions`](CliffProfile::savers_contributions). The
+/// premium tiers are the year's own, although the premiums they set are
+/// paid two years later, and the saver's credit is taken in full although
+/// it cannot exceed the tax. Phaseouts that reduce a credit gradually, such
+/// as the earned income credit's, raise the marginal rate without a jump and
+/// are not included.
+///
+/// The cliffs are returned in order of income, with only those where
+/// crossing happens within `incomes`.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if the preferential income is
+/// negative, and the same errors as [`DeductionChoice::amount`],
+/// [`TaxSchedule::new`], [`compute_ptc`], and [`compute_savers_credit`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{find_cliffs, CliffKind, CliffProfile, FilingStatus, TaxYear};
+///
+/// let profile = CliffProfile {
+///     medicare_enrollees: 2,
+///     ..CliffProfile::new(TaxYear::Y2025, FilingStatus::MarriedFilingJointly)
+/// };
+/// let cliffs = find_cliffs(&profile, 150_000..=250_000).unwrap();
+/// let irmaa = cliffs.iter().find(|cliff| cliff.kind == CliffKind::IrmaaTier).unwrap();
+/// // One dollar over $212,000 costs $2,104.80 a year in Medicare premiums.
+/// assert_eq!(irmaa.income, 212_000);
+/// assert_eq!(irmaa.cost, 2_105);
+///
+/// // The 24% bracket starts above $206,700 of taxable income.
+/// let bracket = cliffs.iter().find(|cliff| cliff.kind == CliffKind::Bracket).unwrap();
+/// assert_eq!(bracket.income, 206_700 + 31_500);
+/// assert_eq!((bracket.rate_before, bracket.rate_after), (0.22, 0.24));
+/// # }
+/// ```
+pub fn find_cliffs(
+    profile: &CliffProfile,
+    incomes: RangeInclusive<i64>,
+) -> Result<Vec<Cliff>, TaxError> {
+    let CliffProfile {
+        year,
+        status,
+        preferential_income,
+        ..
+    } = *profile;
+    if preferential_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let rates = IncomeTaxRates {
+        deduction: profile.deduction.amount(year, status)?,
+        preferential_income,
+        schedule: TaxSchedule::new(year, status)?,
+        gains: ltcg_brackets(year, status),
+    };
+    let (low, high) = (*incomes.start(), *incomes.end());
+    let crossed = |income: i64| low <= income && income < high;
+
+    // Rate changes, at the incomes where the ordinary income or the top of
+    // the gains reaches a bracket threshold
+    let offset = rates.deduction.saturating_add(preferential_income);
+    let mut candidates: Vec<(CliffKind, i64)> = std::iter::once(0)
+        .chain(rates.schedule.thresholds())
+        .map(|threshold| (CliffKind::Bracket, threshold.saturating_add(offset)))
+        .collect();
+    for bracket in rates.gains.iter().filter(|bracket| bracket.income_min > 0) {
+        for start in [rates.deduction, offset] {
+            let income = bracket.income_min.saturating_add(start);
+            candidates.push((CliffKind::CapitalGainRate, income));
+        }
+    }
+    let mut cliffs: Vec<Cliff> = Vec::new();
+    for (kind, income) in candidates {
+        if !crossed(income) || cliffs.iter().any(|cliff| cliff.income == income) {
+            continue;
+        }
+        let cliff = rates.cliff(kind, income, 0);
+        if cliff.rate_before != cliff.rate_after {
+            cliffs.push(cliff);
+        }
+    }
+
+    // Lump sums
+    if profile.medicare_enrollees > 0 {
+        let enrollees = i64::from(profile.medicare_enrollees);
+        for tiers in irmaa_tiers(year, status).windows(2) {
+            let increase = tiers[1].annual_cost().cents() - tiers[0].annual_cost().cents();
+            let cost = (increase * enrollees + 50) / 100;
+            if crossed(tiers[1].magi_over) {
+                cliffs.push(rates.cliff(CliffKind::IrmaaTier, tiers[1].magi_over, cost));
+            }
+        }
+    }
+    if profile.benchmark_premium > 0 {
+        let poverty_line = federal_poverty_line(year, profile.household_size);
+        // The first percentage of the poverty line with no credit
+        let limit = (100..=1_000).find(|&percent| ptc_applicable_figure(year, percent).is_none());
+        if let Some(percent) = limit {
+            let income = (percent * poverty_line + 99) / 100 - 1;
+            let premium = profile.benchmark_premium;
+            let credit =
+                |income| compute_ptc(year, status, income, profile.household_size, premium);
+            let cost = credit(income)? - credit(income + 1)?;
+            if cost > 0 && crossed(income) {
+                cliffs.push(rates.cliff(CliffKind::PremiumTaxCredit, income, cost));
+            }
+        }
+    }
+    if !profile.savers_contributions.is_empty() {
+        let contributions = &profile.savers_contributions;
+        let credit = |income| compute_savers_credit(year, status, income, contributions);
+        for income in savers_credit_tiers(year, status)
+            .iter()
+            .filter_map(|tier| tier.income_max)
+        {
+            let cost = credit(income)? - credit(income + 1)?;
+            if cost > 0 && crossed(income) {
+                cliffs.push(rates.cliff(CliffKind::SaversCredit, income, cost));
+            }
+        }
+    }
+    cliffs.sort_by_key(|cliff| (cliff.income, cliff.kind));
+    Ok(cliffs)
+}
+
+/// The marginal income tax rate on each dollar of adjusted gross income.
+struct IncomeTaxRates {
+    deduction: i64,
+    preferential_income: i64,
+    schedule: TaxSchedule,
+    gains: &'static [TaxBracket],
+}
+
+impl IncomeTaxRates {
+    /// The cliff of `kind` just after `income`, with the rates around it.
+    fn cliff(&self, kind: CliffKind, income: i64, cost: i64) -> Cliff {
+        Cliff {
+            kind,
+            income,
+            cost,
+            rate_before: self.rate(income - 1),
+            rate_after: self.rate(income),
+        }
+    }
+
+    /// The tax rate on the dollar of ordinary income that raises adjusted
+    /// gross income from `agi` to `agi + 1`.
+    fn rate(&self, agi: i64) -> f64 {
+        let taxable_income = agi.saturating_sub(self.deduction);
+        if taxable_income < 0 {
+            return 0.0;
+        }
+        let gain_rate = |income: i64| rate_of_next_dollar(self.gains, income);
+        if taxable_income < self.preferential_income {
+            // The deduction still shelters ordinary income, so the dollar
+            // makes room for a dollar of gain at the top.
+            return gain_rate(taxable_income);
+        }
+        let ordinary_income = taxable_income - self.preferential_income;
+        let rate = rate_of_next_dollar(self.schedule.brackets(), ordinary_income)
+            + (gain_rate(taxable_income) - gain_rate(ordinary_income));
+        // In basis points, so that sums like 0.12 + 0.15 compare equal
+        (rate * 10_000.0).round() / 10_000.0
+    }
+}
+
+/// The rate of the bracket taxing the dollar above `income`.
+fn rate_of_next_dollar(brackets: &[TaxBracket], income: i64) -> f64 {
+    brackets
+        .iter()
+        .find(|bracket| bracket.contains(income.saturating_add(1)))
+        .map_or(0.0, |bracket| bracket.rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn brackets_only() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+        let cliffs = find_cliffs(&CliffProfile::new(year, status), 0..=1_000_000).unwrap();
+        let incomes: Vec<i64> = cliffs.iter().map(|cliff| cliff.income).collect();
+        let schedule = TaxSchedule::new(year, status).unwrap();
+        let expected: Vec<i64> = std::iter::once(0)
+            .chain(schedule.thresholds())
+            .map(|threshold| threshold + 14_600)
+            .collect();
+        assert_eq!(incomes, expected);
+        // The first dollar over the standard deduction is taxed at 10%.
+        assert_eq!((cliffs[0].rate_before, cliffs[0].rate_after), (0.0, 0.10));
+        for cliff in &cliffs {
+            assert_eq!(cliff.kind, CliffKind::Bracket);
+            assert_eq!(cliff.cost, 0);
+            assert!(cliff.rate_after > cliff.rate_before);
+        }
+
+        // Only cliffs crossed within the range
+        let cliffs = find_cliffs(&CliffProfile::new(year, status), 26_200..=61_750).unwrap();
+        assert_eq!(cliffs.len(), 1);
+        assert_eq!(cliffs[0].income, 11_600 + 14_600);
+        assert!(
+            find_cliffs(&CliffProfile::new(year, status), 14_600..=14_600)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn capital_gains_stacking() {
+        // $40,000 of gains on top of ordinary income, 2025 joint: the 0%
+        // rate ends at $96,700 of taxable income.
+        let profile = CliffProfile {
+            preferential_income: 40_000,
+            ..CliffProfile::new(TaxYear::Y2025, FilingStatus::MarriedFilingJointly)
+        };
+        let cliffs = find_cliffs(&profile, 0..=200_000).unwrap();
+        let at = |income: i64| cliffs.iter().find(|cliff| cliff.income == income).unwrap();
+        // Ordinary income is taxed once the deduction is used up.
+        assert_eq!(at(31_500 + 40_000).rate_after, 0.10);
+        // Each dollar then pushes a dollar of gain from 0% to 15%, until the
+        // ordinary income itself reaches $96,700.
+        let start = at(31_500 + 96_700);
+        assert_eq!(start.kind, CliffKind::CapitalGainRate);
+        assert_eq!((start.rate_before, start.rate_after), (0.12, 0.27));
+        assert_eq!(at(31_500 + 40_000 + 96_700).rate_after, 0.12);
+        // The 12% bracket ends at $96,950.
+        assert_eq!(at(31_500 + 40_000 + 96_950).rate_after, 0.22);
+    }
+
+    #[cfg(all(feature = "year-2020", feature = "year-2024"))]
+    #[test]
+    fn lump_sums() {
+        let profile = CliffProfile {
+            medicare_enrollees: 1,
+            household_size: 2,
+            benchmark_premium: 15_000,
+            savers_contributions: vec![2_000],
+            ..CliffProfile::new(TaxYear::Y2020, FilingStatus::HeadOfHousehold)
+        };
+        let cliffs = find_cliffs(&profile, 0..=300_000).unwrap();
+        let kinds = |kind| cliffs.iter().filter(move |cliff| cliff.kind == kind);
+        // The 2020 credit ends at 400% of the poverty line.
+        let ptc: Vec<&Cliff> = kinds(CliffKind::PremiumTaxCredit).collect();
+        assert_eq!(ptc.len(), 1);
+        let income = ptc[0].income;
+        let household_size = 2;
+        assert!(
+            compute_ptc(
+                TaxYear::Y2020,
+                profile.status,
+                income,
+                household_size,
+                15_000
+            )
+            .unwrap()
+                > 0
+        );
+        assert_eq!(
+            compute_ptc(
+                TaxYear::Y2020,
+                profile.status,
+                income + 1,
+                household_size,
+                15_000
+            ),
+            Ok(0)
+        );
+        assert_eq!(kinds(CliffKind::SaversCredit).count(), 3);
+        let tiers = irmaa_tiers(TaxYear::Y2020, profile.status);
+        let in_range = tiers[1..].iter().filter(|tier| tier.magi_over < 300_000);
+        assert_eq!(kinds(CliffKind::IrmaaTier).count(), in_range.count());
+        for cliff in cliffs
+            .iter()
+            .filter(|cliff| cliff.kind != CliffKind::Bracket)
+        {
+            assert!(cliff.cost > 0);
+        }
+        assert!(
+            cliffs
+                .windows(2)
+                .all(|pair| pair[0].income <= pair[1].income)
+        );
+
+        // No income limit on the credit since 2021
+        let profile = CliffProfile {
+            year: TaxYear::Y2024,
+            ..profile
+        };
+        let cliffs = find_cliffs(&profile, 0..=300_000).unwrap();
+        assert!(
+            cliffs
+                .iter()
+                .all(|cliff| cliff.kind != CliffKind::PremiumTaxCredit)
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn errors() {
+        let profile = CliffProfile {
+            preferential_income: -1,
+            ..CliffProfile::new(TaxYear::Y2024, FilingStatus::Single)
+        };
+        assert_eq!(find_cliffs(&profile, 0..=1), Err(TaxError::NegativeIncome));
+    }
+}
@@ -0,0 +1,268 @@
+//! Side-by-side comparison of tax scenarios.
+
+use crate::compute::compute_tax;
+use crate::deduction::DeductionChoice;
+use crate::rates::marginal_rate;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// One set of inputs for [`compare_scenarios`], in whole dollars.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scenario {
+    /// A name for the scenario, copied to its row.
+    pub label: String,
+    /// The tax year.
+    pub year: TaxYear,
+    /// The filing status.
+    pub status: FilingStatus,
+    /// Total income before adjustments (Form 1040, line 9).
+    pub income: i64,
+    /// Adjustments to income from Schedule 1, such as deductible retirement
+    /// contributions.
+    pub adjustments: i64,
+    /// The deduction subtracted from adjusted gross income.
+    pub deduction: DeductionChoice,
+}
+
+impl Scenario {
+    /// A scenario with no adjustments and the standard deduction.
+    pub fn new(label: impl Into<String>, year: TaxYear, status: FilingStatus, income: i64) -> Self {
+        Scenario {
+            label: label.into(),
+            year,
+            status,
+            income,
+            adjustments: 0,
+            deduction: DeductionChoice::Standard,
+        }
+    }
+}
+
+/// The results for one [`Scenario`], in a [`ComparisonTable`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComparisonRow {
+    /// The scenario's label.
+    pub label: String,
+    /// The tax year.
+    pub year: TaxYear,
+    /// The filing status.
+    pub status: FilingStatus,
+    /// Adjusted gross income: income less adjustments.
+    pub agi: i64,
+    /// The amount of the deduction.
+    pub deduction: i64,
+    /// Adjusted gross income less the deduction, and at least 0.
+    pub taxable_income: i64,
+    /// The tax from [`compute_tax`].
+    pub tax: i64,
+    /// The rate from [`marginal_rate`].
+    pub marginal_rate: f64,
+    /// [`tax`](Self::tax) divided by [`taxable_income`](Self::taxable_income),
+    /// or 0 with no taxable income.
+    pub effective_rate: f64,
+    /// Income less adjustments and [`tax`](Self::tax).
+    pub after_tax_income: i64,
+    /// [`tax`](Self::tax) less the baseline's tax.
+    pub tax_change: i64,
+    /// [`after_tax_income`](Self::after_tax_income) less the baseline's.
+    pub after_tax_income_change: i64,
+    /// [`effective_rate`](Self::effective_rate) less the baseline's.
+    pub effective_rate_change: f64,
+}
+
+/// The rows of [`compare_scenarios`], in the order of the scenarios given.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComparisonTable {
+    /// One row per scenario. The first is the baseline that the changes are
+    /// measured against.
+    pub rows: Vec<ComparisonRow>,
+}
+
+impl ComparisonTable {
+    /// The first row, which the others are compared with.
+    pub fn baseline(&self) -> Option<&ComparisonRow> {
+        self.rows.first()
+    }
+
+    /// The row with the least tax, the earliest on ties.
+    pub fn lowest_tax(&self) -> Option<&ComparisonRow> {
+        self.rows
+            .iter()
+            .reduce(|best, row| if row.tax < best.tax { row } else { best })
+    }
+}
+
+/// Compute the tax for each of `scenarios` and compare it with the first, the
+/// baseline.
+///
+/// Each scenario's adjusted gross income is its income less adjustments, and
+/// its taxable income that less the deduction. The rows keep the scenarios'
+/// order and repeat their inputs, so they can be shown as a table without
+/// looking the scenarios up again. Changes are the row's value less the
+/// baseline's; the baseline's own are 0. No scenarios give an empty table.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if a scenario's income or adjustments
+/// are negative, and the same errors as [`DeductionChoice::amount`] and
+/// [`compute_tax`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compare_scenarios, FilingStatus, Scenario, TaxYear};
+///
+/// let (year, status) = (TaxYear::Y2025, FilingStatus::Single);
+/// let table = compare_scenarios(&[
+///     Scenario::new("Current", year, status, 100_000),
+///     Scenario {
+///         adjustments: 23_500,
+///         ..Scenario::new("Maximum 401(k)", year, status, 100_000)
+///     },
+/// ])
+/// .unwrap();
+/// assert_eq!(table.rows[0].taxable_income, 84_250);
+/// assert_eq!(table.rows[1].taxable_income, 60_750);
+/// // The contribution is deducted at 22%.
+/// assert_eq!(table.rows[1].tax_change, -5_170);
+/// assert_eq!(table.lowest_tax().unwrap().label, "Maximum 401(k)");
+/// # }
+/// ```
+pub fn compare_scenarios(scenarios: &[Scenario]) -> Result<ComparisonTable, TaxError> {
+    let mut rows: Vec<ComparisonRow> = Vec::with_capacity(scenarios.len());
+    for scenario in scenarios {
+        let row = evaluate(scenario)?;
+        let row = match rows.first() {
+            Some(baseline) => ComparisonRow {
+                tax_change: row.tax - baseline.tax,
+                after_tax_income_change: row.after_tax_income - baseline.after_tax_income,
+                effective_rate_change: row.effective_rate - baseline.effective_rate,
+                ..row
+            },
+            None => row,
+        };
+        rows.push(row);
+    }
+    Ok(ComparisonTable { rows })
+}
+
+/// The row for `scenario`, with no changes.
+fn evaluate(scenario: &Scenario) -> Result<ComparisonRow, TaxError> {
+    let Scenario {
+        year,
+        status,
+        income,
+        adjustments,
+        ..
+    } = *scenario;
+    if income < 0 || adjustments < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let agi = income - adjustments;
+    let deduction = scenario.deduction.amount(year, status)?;
+    let taxable_income = agi.saturating_sub(deduction).max(0);
+    let tax = compute_tax(year, status, taxable_income)?;
+    Ok(ComparisonRow {
+        label: scenario.label.clone(),
+        year,
+        status,
+        agi,
+        deduction,
+        taxable_income,
+        tax,
+        marginal_rate: marginal_rate(year, status, taxable_income)?,
+        effective_rate: if taxable_income == 0 {
+            0.0
+        } else {
+            tax as f64 / taxable_income as f64
+        },
+        after_tax_income: agi - tax,
+        tax_change: 0,
+        after_tax_income_change: 0,
+        effective_rate_change: 0.0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn rows_follow_scenarios() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::MarriedFilingJointly);
+        let table = compare_scenarios(&[
+            Scenario::new("Joint", year, status, 150_000),
+            Scenario::new(
+                "Separate",
+                year,
+                FilingStatus::MarriedFilingSeparately,
+                150_000,
+            ),
+            Scenario {
+                deduction: DeductionChoice::Itemized(40_000),
+                ..Scenario::new("Itemized", year, status, 150_000)
+            },
+            Scenario::new("Next year", TaxYear::Y2025, status, 150_000),
+        ])
+        .unwrap();
+        let labels: Vec<&str> = table.rows.iter().map(|row| row.label.as_str()).collect();
+        assert_eq!(labels, ["Joint", "Separate", "Itemized", "Next year"]);
+
+        let baseline = table.baseline().unwrap();
+        assert_eq!(baseline.deduction, 29_200);
+        assert_eq!(baseline.taxable_income, 120_800);
+        assert_eq!(
+            (baseline.tax_change, baseline.effective_rate_change),
+            (0, 0.0)
+        );
+        for row in &table.rows {
+            assert_eq!(
+                row.tax,
+                compute_tax(row.year, row.status, row.taxable_income).unwrap()
+            );
+            assert_eq!(row.tax_change, row.tax - baseline.tax);
+            assert_eq!(row.after_tax_income_change, -row.tax_change);
+        }
+        // Filing separately taxes all the income at single-like brackets.
+        assert!(table.rows[1].tax_change > 0);
+        assert_eq!(table.rows[2].deduction, 40_000);
+        assert_eq!(table.lowest_tax().unwrap().label, "Itemized");
+        // The 2025 brackets and deduction are higher.
+        assert!(table.rows[3].tax_change < 0);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn edges() {
+        let table = compare_scenarios(&[]).unwrap();
+        assert_eq!(table, ComparisonTable::default());
+        assert_eq!(table.baseline(), None);
+        assert_eq!(table.lowest_tax(), None);
+
+        let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+        let table = compare_scenarios(&[Scenario {
+            adjustments: 20_000,
+            ..Scenario::new("Loss", year, status, 10_000)
+        }])
+        .unwrap();
+        let row = &table.rows[0];
+        assert_eq!((row.agi, row.taxable_income, row.tax), (-10_000, 0, 0));
+        assert_eq!(row.effective_rate, 0.0);
+
+        assert_eq!(
+            compare_scenarios(&[Scenario::new("", year, status, -1)]),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            compare_scenarios(&[Scenario {
+                deduction: DeductionChoice::Itemized(-1),
+                ..Scenario::new("", year, status, 1_000)
+            }]),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+}
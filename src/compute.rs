@@ -1,7 +1,20 @@
 //! Core tax computation logic.
 
 use crate::data;
-use crate::types::{FilingStatus, TaxError, TaxYear};
+use crate::source::{EmbeddedData, compute_tax_with_method_from};
+use crate::types::{FilingStatus, Method, TaxError, TaxYear};
+
+/// Taxable income at which the IRS switches from the Tax Table to the Tax
+/// Computation Worksheet.
+pub(crate) const WORKSHEET_THRESHOLD: i64 = 100_000;
+
+/// Convert a caller-supplied income of any integer type to `i64`.
+///
+/// Returns [`TaxError::IncomeOutOfRange`] instead of silently truncating
+/// values that do not fit.
+pub(crate) fn income_to_i64(income: impl TryInto<i64>) -> Result<i64, TaxError> {
+    income.try_into().map_err(|_| TaxError::IncomeOutOfRange)
+}
 
 /// Compute federal income tax for a given tax year, filing status, and taxable income.
 ///
@@ -11,7 +24,8 @@ use crate::types::{FilingStatus, TaxError, TaxYear};
 /// * `status` — The taxpayer's filing status.
 /// * `taxable_income` — Taxable income in whole dollars (typically Form 1040,
 ///   line 15). This is usually derived from adjusted gross income (AGI) or
-///   modified adjusted gross income (MAGI) minus deductions.
+///   modified adjusted gross income (MAGI) minus deductions. Any integer type
+///   convertible to `i64` is accepted (`u32`, `u64`, `i128`, ...).
 ///
 /// # Returns
 ///
@@ -25,17 +39,22 @@ use crate::types::{FilingStatus, TaxError, TaxYear};
 ///   to find the matching row.
 ///
 /// - **Income >= $100,000** — Uses the Tax Computation Worksheet formula:
-///   `tax = taxable_income × rate − subtraction_amount`.
+///   `tax = taxable_income × rate − subtraction_amount`, evaluated exactly in
+///   integer arithmetic and rounded half-up, so the result is the same on
+///   every platform and optimization level.
 ///
 /// # Errors
 ///
 /// Returns [`TaxError::NegativeIncome`] if `taxable_income` is negative.
+/// Returns [`TaxError::IncomeOutOfRange`] if `taxable_income` does not fit in
+/// an `i64`.
 /// Returns [`TaxError::NoBracketFound`] if no matching bracket exists (should
 /// not occur with valid embedded data).
 ///
 /// # Examples
 ///
 /// ```
+/// # #[cfg(feature = "year-2025")] {
 /// use us_tax_brackets::{compute_tax, FilingStatus, TaxYear};
 ///
 /// // Tax Table lookup (income under $100k)
@@ -45,85 +64,316 @@ use crate::types::{FilingStatus, TaxError, TaxYear};
 /// // Worksheet formula (income $100k+)
 /// let tax = compute_tax(TaxYear::Y2025, FilingStatus::Single, 150_000).unwrap();
 /// assert_eq!(tax, 28_847);
+///
+/// // Unsigned and wide integers are converted without truncation
+/// let tax = compute_tax(TaxYear::Y2025, FilingStatus::Single, 150_000_u64).unwrap();
+/// assert_eq!(tax, 28_847);
+/// assert!(compute_tax(TaxYear::Y2025, FilingStatus::Single, u64::MAX).is_err());
+/// # }
 /// ```
 pub fn compute_tax(
     year: TaxYear,
     status: FilingStatus,
-    taxable_income: i64,
+    taxable_income: impl TryInto<i64>,
 ) -> Result<i64, TaxError> {
-    if taxable_income < 0 {
+    compute_tax_with_method(year, status, taxable_income).map(|(tax, _)| tax)
+}
+
+/// Compute federal income tax and report which IRS method produced it.
+///
+/// Identical to [`compute_tax`], but also returns the [`Method`] used: the
+/// Tax Table below $100,000 (including zero income) and the Tax Computation
+/// Worksheet at $100,000 and above.
+///
+/// # Errors
+///
+/// Returns the same errors as [`compute_tax`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_tax_with_method, FilingStatus, Method, TaxYear};
+///
+/// let (tax, method) = compute_tax_with_method(TaxYear::Y2025, FilingStatus::Single, 99_999).unwrap();
+/// assert_eq!((tax, method), (16_909, Method::TaxTable));
+///
+/// let (tax, method) = compute_tax_with_method(TaxYear::Y2025, FilingStatus::Single, 100_000).unwrap();
+/// assert_eq!((tax, method), (16_914, Method::Worksheet));
+/// # }
+/// ```
+pub fn compute_tax_with_method(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+) -> Result<(i64, Method), TaxError> {
+    compute_tax_with_method_from(&EmbeddedData, year, status, taxable_income)
+}
+
+/// Compute federal income tax in integer cents, without floating point.
+///
+/// `taxable_income_cents` is taxable income in cents (e.g., `10_000_050` for
+/// $100,000.50), and the result is tax in cents.
+///
+/// - **Income < $100,000** — The Tax Table only has whole-dollar amounts, so
+///   the result is the table amount for the row containing the income,
+///   multiplied by 100.
+///
+/// - **Income >= $100,000** — The worksheet formula is evaluated exactly with
+///   the rate in basis points and the subtraction amount in cents, then
+///   rounded half-up to the nearest cent.
+///
+/// # Errors
+///
+/// Returns the same errors as [`compute_tax`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "year-2024", feature = "year-2025"))] {
+/// use us_tax_brackets::{compute_tax_cents, FilingStatus, TaxYear};
+///
+/// // Table amounts are whole dollars
+/// let tax = compute_tax_cents(TaxYear::Y2025, FilingStatus::Single, 5_000_000).unwrap();
+/// assert_eq!(tax, 592_000);
+///
+/// // 2024: $150,000.01 × 0.24 − $6,957.50 = $29,042.5024 → $29,042.50
+/// let tax = compute_tax_cents(TaxYear::Y2024, FilingStatus::Single, 15_000_001).unwrap();
+/// assert_eq!(tax, 2_904_250);
+/// # }
+/// ```
+pub fn compute_tax_cents(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income_cents: impl TryInto<i64>,
+) -> Result<i64, TaxError> {
+    let taxable_income_cents = income_to_i64(taxable_income_cents)?;
+    if taxable_income_cents < 0 {
         return Err(TaxError::NegativeIncome);
     }
-    if taxable_income == 0 {
+    if taxable_income_cents == 0 {
         return Ok(0);
     }
 
-    let (table_csv, worksheet_csv) = data::csv_for_year(year);
+    if taxable_income_cents < WORKSHEET_THRESHOLD * 100 {
+        // Table rows are whole-dollar ranges [min, max), so dropping the
+        // cents selects the same row.
+        let tax = compute_from_tax_table(year, status, taxable_income_cents / 100)?;
+        Ok(tax * 100)
+    } else {
+        compute_from_worksheet_cents(year, status, taxable_income_cents)
+    }
+}
+
+/// Compute federal income tax in a `const` context.
+///
+/// Gives the same result as [`compute_tax`], so golden values can be checked
+/// at compile time or baked into constants. Because `const fn`s cannot take
+/// generic conversions or drop a [`TaxError`], the income must be an `i64`
+/// and failures are reported as [`None`]: a negative income, or a missing
+/// bracket (which should not occur with valid embedded data).
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_tax_const, FilingStatus, TaxYear};
+///
+/// const TAX: i64 = match compute_tax_const(TaxYear::Y2025, FilingStatus::Single, 150_000) {
+///     Some(tax) => tax,
+///     None => panic!("no bracket"),
+/// };
+/// const _: () = assert!(TAX == 28_847);
+///
+/// assert_eq!(compute_tax_const(TaxYear::Y2025, FilingStatus::Single, -1), None);
+/// # }
+/// ```
+pub const fn compute_tax_const(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: i64,
+) -> Option<i64> {
+    if taxable_income < 0 {
+        return None;
+    }
+    if taxable_income == 0 {
+        return Some(0);
+    }
 
-    if taxable_income < 100_000 {
-        compute_from_tax_table(table_csv, status, taxable_income)
+    if taxable_income < WORKSHEET_THRESHOLD {
+        match lookup_tax_table(data::tax_table(year), taxable_income) {
+            Some(row) => Some(row.tax_for(status)),
+            None => None,
+        }
     } else {
-        compute_from_worksheet(worksheet_csv, status, taxable_income)
+        match lookup_worksheet(data::worksheet(year, status), taxable_income) {
+            Some(bracket) => worksheet_tax_const(&bracket, taxable_income),
+            None => None,
+        }
     }
 }
 
 /// Look up the tax in the IRS Tax Table (income < $100,000).
+pub(crate) fn compute_from_tax_table(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: i64,
+) -> Result<i64, TaxError> {
+    find_tax_table_row(year, status, taxable_income).map(|row| row.tax_for(status))
+}
+
+/// Find the Tax Table row containing `taxable_income`.
 ///
 /// The table rows are sorted by `income_min` in $50 increments, so binary
 /// search finds the matching row in O(log n).
-fn compute_from_tax_table(
-    csv: &str,
+pub(crate) fn find_tax_table_row(
+    year: TaxYear,
     status: FilingStatus,
     taxable_income: i64,
-) -> Result<i64, TaxError> {
-    let table = data::parse_tax_table(csv);
-
-    let idx = table
-        .binary_search_by(|row| {
-            if taxable_income < row.income_min {
-                std::cmp::Ordering::Greater
-            } else if taxable_income >= row.income_max {
-                std::cmp::Ordering::Less
-            } else {
-                std::cmp::Ordering::Equal
-            }
-        })
-        .map_err(|_| TaxError::NoBracketFound)?;
-
-    let row = &table[idx];
-    Ok(match status {
-        FilingStatus::Single => row.single,
-        FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse => {
-            row.married_filing_jointly
-        }
-        FilingStatus::MarriedFilingSeparately => row.married_filing_separately,
-        FilingStatus::HeadOfHousehold => row.head_of_household,
-    })
+) -> Result<data::TaxTableRow, TaxError> {
+    search_tax_table(data::tax_table(year), year, status, taxable_income)
 }
 
-/// Compute tax using the Tax Computation Worksheet (income >= $100,000).
+/// Binary search Tax Table rows for `taxable_income`.
 ///
-/// Iterates through the brackets for the given filing status and applies
-/// `tax = income × rate − subtraction_amount` for the matching bracket.
-fn compute_from_worksheet(
-    csv: &str,
+/// `year` and `status` are only used to describe a missing row in the error.
+pub(crate) fn search_tax_table(
+    table: &[data::TaxTableRow],
+    year: TaxYear,
     status: FilingStatus,
     taxable_income: i64,
+) -> Result<data::TaxTableRow, TaxError> {
+    lookup_tax_table(table, taxable_income).ok_or(TaxError::NoBracketFound {
+        year,
+        status,
+        taxable_income,
+    })
+}
+
+/// Binary search Tax Table rows for `taxable_income`, in a `const` context.
+pub(crate) const fn lookup_tax_table(
+    table: &[data::TaxTableRow],
+    taxable_income: i64,
+) -> Option<data::TaxTableRow> {
+    let (mut low, mut high) = (0, table.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let row = table[mid];
+        if taxable_income < row.income_min {
+            high = mid;
+        } else if taxable_income >= row.income_max {
+            low = mid + 1;
+        } else {
+            return Some(row);
+        }
+    }
+    None
+}
+
+/// Apply a worksheet bracket's formula and round half-up to whole dollars.
+///
+/// # Errors
+///
+/// Returns [`TaxError::IncomeOutOfRange`] if the tax does not fit in an
+/// `i64`, which only a rate over 100% can cause.
+pub(crate) fn worksheet_tax(
+    bracket: &data::WorksheetBracket,
+    taxable_income: i64,
 ) -> Result<i64, TaxError> {
-    let brackets = data::parse_worksheet(csv, status);
+    worksheet_tax_const(bracket, taxable_income).ok_or(TaxError::IncomeOutOfRange)
+}
+
+/// [`worksheet_tax`] in a `const` context, or [`None`] if the tax does not
+/// fit in an `i64`.
+///
+/// With the rate in basis points, `income × rate_bp − subtraction_cents × 100`
+/// is the tax in ten-thousandths of a dollar, so the formula is exact.
+pub(crate) const fn worksheet_tax_const(
+    bracket: &data::WorksheetBracket,
+    taxable_income: i64,
+) -> Option<i64> {
+    let scaled = taxable_income as i128 * bracket.rate_basis_points as i128
+        - bracket.subtraction_cents as i128 * 100;
+    let tax = (scaled + 5_000).div_euclid(10_000);
+    if tax < i64::MIN as i128 || tax > i64::MAX as i128 {
+        return None;
+    }
+    Some(tax as i64)
+}
+
+/// Find the Tax Computation Worksheet bracket containing `taxable_income`.
+pub(crate) fn find_worksheet_bracket(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: i64,
+) -> Result<data::WorksheetBracket, TaxError> {
+    search_worksheet(data::worksheet(year, status), year, status, taxable_income)
+}
 
-    for bracket in &brackets {
+/// Find the bracket containing `taxable_income` among the given
+/// worksheet brackets.
+///
+/// `year` and `status` are only used to describe a missing bracket in the
+/// error.
+pub(crate) fn search_worksheet(
+    brackets: &[data::WorksheetBracket],
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: i64,
+) -> Result<data::WorksheetBracket, TaxError> {
+    lookup_worksheet(brackets, taxable_income).ok_or(TaxError::NoBracketFound {
+        year,
+        status,
+        taxable_income,
+    })
+}
+
+/// Find the worksheet bracket containing `taxable_income`, in a `const`
+/// context.
+pub(crate) const fn lookup_worksheet(
+    brackets: &[data::WorksheetBracket],
+    taxable_income: i64,
+) -> Option<data::WorksheetBracket> {
+    let mut index = 0;
+    while index < brackets.len() {
+        if brackets[index].contains(taxable_income) {
+            return Some(brackets[index]);
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Compute tax in cents using the Tax Computation Worksheet with integer
+/// arithmetic (income >= $100,000).
+///
+/// The rate is in basis points, so `income_cents × rate_bp − subtraction_cents
+/// × 10,000` is the tax in ten-thousandths of a cent. That product is rounded
+/// half-up to whole cents.
+fn compute_from_worksheet_cents(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income_cents: i64,
+) -> Result<i64, TaxError> {
+    for bracket in data::worksheet(year, status) {
+        let min_cents = bracket.income_min * 100;
         let in_range = match bracket.income_max {
-            Some(max) => taxable_income >= bracket.income_min && taxable_income <= max,
-            None => taxable_income > bracket.income_min,
+            Some(max) => taxable_income_cents >= min_cents && taxable_income_cents <= max * 100,
+            None => taxable_income_cents > min_cents,
         };
         if in_range {
-            let tax = (taxable_income as f64) * bracket.rate - bracket.subtraction_amount;
-            return Ok(tax.round() as i64);
+            let scaled = i128::from(taxable_income_cents) * i128::from(bracket.rate_basis_points)
+                - i128::from(bracket.subtraction_cents) * 10_000;
+            return i64::try_from((scaled + 5_000).div_euclid(10_000))
+                .map_err(|_| TaxError::IncomeOutOfRange);
         }
     }
 
-    Err(TaxError::NoBracketFound)
+    Err(TaxError::NoBracketFound {
+        year,
+        status,
+        taxable_income: taxable_income_cents / 100,
+    })
 }
 
 #[cfg(test)]
@@ -132,6 +382,7 @@ mod tests {
 
     // ----- Edge cases -----
 
+    #[cfg(feature = "year-2024")]
     #[test]
     fn zero_income() {
         assert_eq!(
@@ -140,6 +391,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "year-2023")]
     #[test]
     fn negative_income() {
         assert_eq!(
@@ -150,6 +402,7 @@ mod tests {
 
     // ----- Tax Table lookups (income < $100,000) -----
 
+    #[cfg(feature = "year-2023")]
     #[test]
     fn low_income_single() {
         // $10 falls in the $5–$15 row -> $1 tax
@@ -159,6 +412,7 @@ mod tests {
         );
     }
 
+    #[cfg(all(feature = "year-2023", feature = "year-2024", feature = "year-2025"))]
     #[test]
     fn table_single_50k() {
         // Inflation-adjusted brackets cause tax to decrease year over year
@@ -176,6 +430,38 @@ mod tests {
         );
     }
 
+    #[cfg(all(
+        feature = "year-2018",
+        feature = "year-2019",
+        feature = "year-2020",
+        feature = "year-2021",
+        feature = "year-2022"
+    ))]
+    #[test]
+    fn table_single_50k_2018_to_2022() {
+        assert_eq!(
+            compute_tax(TaxYear::Y2018, FilingStatus::Single, 50_000).unwrap(),
+            6_945
+        );
+        assert_eq!(
+            compute_tax(TaxYear::Y2019, FilingStatus::Single, 50_000).unwrap(),
+            6_864
+        );
+        assert_eq!(
+            compute_tax(TaxYear::Y2020, FilingStatus::Single, 50_000).unwrap(),
+            6_796
+        );
+        assert_eq!(
+            compute_tax(TaxYear::Y2021, FilingStatus::Single, 50_000).unwrap(),
+            6_754
+        );
+        assert_eq!(
+            compute_tax(TaxYear::Y2022, FilingStatus::Single, 50_000).unwrap(),
+            6_623
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
     #[test]
     fn table_married_jointly_75k() {
         assert_eq!(
@@ -184,6 +470,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "year-2023")]
     #[test]
     fn table_head_of_household_75k() {
         assert_eq!(
@@ -194,6 +481,7 @@ mod tests {
 
     // ----- Tax Table / Worksheet boundary -----
 
+    #[cfg(all(feature = "year-2023", feature = "year-2025"))]
     #[test]
     fn boundary_99999_uses_table() {
         assert_eq!(
@@ -206,6 +494,7 @@ mod tests {
         );
     }
 
+    #[cfg(all(feature = "year-2023", feature = "year-2025"))]
     #[test]
     fn boundary_100k_uses_worksheet() {
         // 2025: 100000 × 0.22 − 5086 = 16914
@@ -222,6 +511,7 @@ mod tests {
 
     // ----- Worksheet computations (income >= $100,000) -----
 
+    #[cfg(feature = "year-2024")]
     #[test]
     fn worksheet_single_150k() {
         // 2024: 150000 × 0.24 − 6957.5 = 29042.5 → 29043
@@ -231,6 +521,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "year-2023")]
     #[test]
     fn worksheet_married_jointly_200k() {
         // 2023: 200000 × 0.24 − 13200 = 34800
@@ -240,6 +531,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "year-2024")]
     #[test]
     fn worksheet_head_of_household_300k() {
         // 2024: 300000 × 0.35 − 31318 = 73682
@@ -249,6 +541,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "year-2025")]
     #[test]
     fn worksheet_single_1m() {
         // 2025: 1000000 × 0.37 − 42979.75 = 327020.25 → 327020
@@ -258,8 +551,119 @@ mod tests {
         );
     }
 
+    // ----- Generic income types -----
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn generic_income_types() {
+        let expected = compute_tax(TaxYear::Y2024, FilingStatus::Single, 75_000_i64).unwrap();
+        assert_eq!(
+            compute_tax(TaxYear::Y2024, FilingStatus::Single, 75_000_u32),
+            Ok(expected)
+        );
+        assert_eq!(
+            compute_tax(TaxYear::Y2024, FilingStatus::Single, 75_000_i128),
+            Ok(expected)
+        );
+        assert_eq!(
+            compute_tax(TaxYear::Y2024, FilingStatus::Single, u64::MAX),
+            Err(TaxError::IncomeOutOfRange)
+        );
+        assert_eq!(
+            compute_tax_cents(TaxYear::Y2024, FilingStatus::Single, i128::MIN),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+
+    #[test]
+    fn worksheet_tax_out_of_range() {
+        // A 200% rate doubles the income past i64::MAX.
+        let bracket = data::WorksheetBracket::new(0, None, 20_000, 0);
+        assert_eq!(worksheet_tax(&bracket, 1_000), Ok(2_000));
+        assert_eq!(
+            worksheet_tax(&bracket, i64::MAX),
+            Err(TaxError::IncomeOutOfRange)
+        );
+        assert_eq!(worksheet_tax_const(&bracket, i64::MAX), None);
+    }
+
+    // ----- Method reporting -----
+
+    #[cfg(all(feature = "year-2023", feature = "year-2024"))]
+    #[test]
+    fn method_switches_at_100k() {
+        assert_eq!(
+            compute_tax_with_method(TaxYear::Y2024, FilingStatus::HeadOfHousehold, 0).unwrap(),
+            (0, Method::TaxTable)
+        );
+        assert_eq!(
+            compute_tax_with_method(TaxYear::Y2023, FilingStatus::Single, 99_999)
+                .unwrap()
+                .1,
+            Method::TaxTable
+        );
+        assert_eq!(
+            compute_tax_with_method(TaxYear::Y2023, FilingStatus::Single, 100_000).unwrap(),
+            (17_400, Method::Worksheet)
+        );
+    }
+
+    // ----- Cents precision -----
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn cents_zero_and_negative() {
+        assert_eq!(
+            compute_tax_cents(TaxYear::Y2025, FilingStatus::Single, 0),
+            Ok(0)
+        );
+        assert_eq!(
+            compute_tax_cents(TaxYear::Y2025, FilingStatus::Single, -1),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn cents_table_uses_dollar_row() {
+        // $99,999.99 is still in the $99,950–$100,000 row
+        assert_eq!(
+            compute_tax_cents(TaxYear::Y2025, FilingStatus::Single, 9_999_999).unwrap(),
+            1_690_900
+        );
+    }
+
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn cents_worksheet_keeps_sub_dollar_precision() {
+        // 2024: 150000 × 0.24 − 6957.5 = 29042.50 (compute_tax rounds to 29043)
+        assert_eq!(
+            compute_tax_cents(TaxYear::Y2024, FilingStatus::Single, 15_000_000).unwrap(),
+            2_904_250
+        );
+        // 2025: 1000000 × 0.37 − 42979.75 = 327020.25
+        assert_eq!(
+            compute_tax_cents(TaxYear::Y2025, FilingStatus::Single, 100_000_000).unwrap(),
+            32_702_025
+        );
+    }
+
+    #[test]
+    fn cents_agrees_with_dollars_on_whole_dollar_worksheet_incomes() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for income in [100_000, 123_457, 250_000, 777_777] {
+                    let dollars = compute_tax(year, status, income).unwrap();
+                    let cents = compute_tax_cents(year, status, income * 100).unwrap();
+                    assert!((cents - dollars * 100).abs() <= 50);
+                }
+            }
+        }
+    }
+
     // ----- Qualifying surviving spouse -----
 
+    #[cfg(all(feature = "year-2023", feature = "year-2024"))]
     #[test]
     fn qualifying_surviving_spouse_matches_mfj() {
         // Table lookup (2024)
@@ -285,6 +689,7 @@ mod tests {
 
     // ----- Cross-status comparison -----
 
+    #[cfg(feature = "year-2025")]
     #[test]
     fn all_statuses_at_200k() {
         let single = compute_tax(TaxYear::Y2025, FilingStatus::Single, 200_000).unwrap();
@@ -307,4 +712,41 @@ mod tests {
         assert_eq!(mfs, 41_063); //   same brackets as single at this level
         assert_eq!(hoh, 39_324); //   200000 × 0.32 − 24676
     }
+
+    // ----- Const evaluation -----
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn const_matches_compute_tax() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for income in [0, 1, 49, 50, 75_000, 99_999, 100_000, 731_200, 10_000_000] {
+                    assert_eq!(
+                        compute_tax_const(year, status, income),
+                        compute_tax(year, status, income).ok()
+                    );
+                }
+            }
+        }
+        assert_eq!(
+            compute_tax_const(TaxYear::Y2025, FilingStatus::Single, -1),
+            None
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn worksheet_half_dollar_rounds_up() {
+        // 2024 MFJ: 731,200 × 0.35 − 59,250.50 = 196,669.50
+        assert_eq!(
+            compute_tax(TaxYear::Y2024, FilingStatus::MarriedFilingJointly, 731_200).unwrap(),
+            196_670
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    const GOLDEN_2025_SINGLE_75K: Option<i64> =
+        compute_tax_const(TaxYear::Y2025, FilingStatus::Single, 75_000);
+    #[cfg(feature = "year-2025")]
+    const _: () = assert!(matches!(GOLDEN_2025_SINGLE_75K, Some(11_420)));
 }
@@ -0,0 +1,293 @@
+//! Sizing Roth conversions to fill a bracket or stay under a threshold.
+
+use crate::capital_gains::{ltcg_brackets, ltcg_rate_for};
+use crate::compute::{compute_tax, income_to_i64};
+use crate::data;
+use crate::money::UsdAmount;
+use crate::rates::marginal_rate;
+use crate::schedule::TaxBracket;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// A Medicare premium tier from the income-related monthly adjustment
+/// amounts (IRMAA), returned by [`irmaa_tiers`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct IrmaaTier {
+    /// The modified adjusted gross income above which the tier applies. The
+    /// first tier, with the standard premium, starts at 0.
+    pub magi_over: i64,
+    /// The monthly Part B premium, including the adjustment.
+    pub part_b_premium: UsdAmount,
+    /// The monthly adjustment added to the Part D plan premium.
+    pub part_d_adjustment: UsdAmount,
+}
+
+impl IrmaaTier {
+    /// The Part B premium and Part D adjustment for a year, per person.
+    pub fn annual_cost(&self) -> UsdAmount {
+        UsdAmount::from_cents((self.part_b_premium.cents() + self.part_d_adjustment.cents()) * 12)
+    }
+}
+
+/// The Medicare premium tiers for `year`, in ascending order.
+///
+/// Premiums for a year are set by modified adjusted gross income (adjusted
+/// gross income plus tax-exempt interest) on the return from two years
+/// before, so income in 2025 sets the premiums for 2027. Heads of household
+/// and qualifying surviving spouses use the single tiers. Married people
+/// filing separately who lived with their spouse go straight from the
+/// standard premium to the two highest tiers; those who did not should use
+/// [`FilingStatus::Single`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{irmaa_tiers, FilingStatus, TaxYear};
+///
+/// let tiers = irmaa_tiers(TaxYear::Y2025, FilingStatus::MarriedFilingJointly);
+/// assert_eq!(tiers.len(), 6);
+/// assert_eq!(tiers[1].magi_over, 212_000);
+/// assert_eq!(tiers[0].part_b_premium.to_string(), "$185");
+/// assert_eq!(tiers[1].part_b_premium.to_string(), "$259");
+/// # }
+/// ```
+pub fn irmaa_tiers(year: TaxYear, status: FilingStatus) -> &'static [IrmaaTier] {
+    data::irmaa_tiers(year, status)
+}
+
+/// The threshold a Roth conversion should stop at, for
+/// [`optimal_conversion`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum FillTo {
+    /// The top of the ordinary income bracket taxed at this rate, such as
+    /// 0.24.
+    Bracket(f64),
+    /// The top of the long-term capital gain bracket taxed at this rate,
+    /// 0.0 or 0.15, so that gains already in it keep the rate.
+    CapitalGainRate(f64),
+    /// The top of a Medicare premium tier from [`irmaa_tiers`], with 0 the
+    /// standard premium.
+    IrmaaTier {
+        /// The index of the tier to stay in.
+        tier: usize,
+        /// Modified adjusted gross income before the conversion.
+        magi: i64,
+    },
+}
+
+/// How much to convert to reach a [`FillTo`] target, returned by
+/// [`optimal_conversion`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RothConversion {
+    /// The additional ordinary income that fits under the target, or 0 if
+    /// income is already past it.
+    pub amount: i64,
+    /// The additional income tax on [`amount`](Self::amount), from
+    /// [`compute_tax`].
+    pub tax: i64,
+    /// The ordinary income tax rate on the next dollar past the target.
+    pub rate_beyond: f64,
+    /// The long-term capital gain rate on the next dollar past the target,
+    /// which applies to any gains that dollar pushes up.
+    pub capital_gain_rate_beyond: f64,
+    /// For a [`FillTo::IrmaaTier`] target, the yearly increase in Medicare
+    /// premiums per person from the next dollar past it. Zero otherwise.
+    pub premium_increase_beyond: UsdAmount,
+}
+
+/// Compute how much ordinary income, such as a Roth conversion, can be added
+/// to `current_taxable_income` before crossing `target`, and what converting
+/// past it would cost.
+///
+/// Bracket and capital gain targets are measured in taxable income,
+/// including any qualified dividends and capital gains. A Medicare premium
+/// target is measured in modified adjusted gross income, which the
+/// conversion raises by the same amount. Crossing a premium tier by a dollar
+/// costs the whole
+/// [`premium_increase_beyond`](RothConversion::premium_increase_beyond), two
+/// years later.
+///
+/// Returns [`None`] if the target has no top, as with the highest bracket or
+/// tier, or no bracket has the target rate.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `current_taxable_income` is
+/// negative, and the same errors as [`compute_tax`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{optimal_conversion, FilingStatus, FillTo, TaxYear};
+///
+/// let (year, status) = (TaxYear::Y2025, FilingStatus::MarriedFilingJointly);
+/// // The 22% bracket ends at $206,700.
+/// let conversion = optimal_conversion(year, status, 80_000, FillTo::Bracket(0.22))
+///     .unwrap()
+///     .unwrap();
+/// assert_eq!(conversion.amount, 126_700);
+/// assert_eq!(conversion.tax, 26_176);
+/// assert_eq!(conversion.rate_beyond, 0.24);
+///
+/// // Staying at the standard Medicare premium, with $150,000 of MAGI
+/// let target = FillTo::IrmaaTier { tier: 0, magi: 150_000 };
+/// let conversion = optimal_conversion(year, status, 120_000, target).unwrap().unwrap();
+/// assert_eq!(conversion.amount, 62_000);
+/// assert_eq!(conversion.premium_increase_beyond.to_string(), "$1,052.40");
+/// # }
+/// ```
+pub fn optimal_conversion(
+    year: TaxYear,
+    status: FilingStatus,
+    current_taxable_income: impl TryInto<i64>,
+    target: FillTo,
+) -> Result<Option<RothConversion>, TaxError> {
+    let taxable_income = income_to_i64(current_taxable_income)?;
+    if taxable_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let top_of_rate = |brackets: &[TaxBracket], rate: f64| {
+        brackets
+            .iter()
+            .find(|bracket| bracket.rate == rate)
+            .and_then(|bracket| bracket.income_max)
+    };
+    let (ceiling, premium_increase_beyond) = match target {
+        FillTo::Bracket(rate) => (
+            top_of_rate(data::rate_schedule(year, status), rate),
+            UsdAmount::ZERO,
+        ),
+        FillTo::CapitalGainRate(rate) => (
+            top_of_rate(ltcg_brackets(year, status), rate),
+            UsdAmount::ZERO,
+        ),
+        FillTo::IrmaaTier { tier, magi } => {
+            let tiers = irmaa_tiers(year, status);
+            match (tiers.get(tier), tiers.get(tier.saturating_add(1))) {
+                (Some(current), Some(next)) => (
+                    Some(taxable_income.saturating_add(next.magi_over.saturating_sub(magi))),
+                    UsdAmount::from_cents(
+                        next.annual_cost().cents() - current.annual_cost().cents(),
+                    ),
+                ),
+                _ => (None, UsdAmount::ZERO),
+            }
+        }
+    };
+    let Some(ceiling) = ceiling else {
+        return Ok(None);
+    };
+
+    let amount = ceiling.saturating_sub(taxable_income).max(0);
+    let filled = taxable_income.saturating_add(amount);
+    let beyond = filled.saturating_add(1);
+    Ok(Some(RothConversion {
+        amount,
+        tax: compute_tax(year, status, filled)? - compute_tax(year, status, taxable_income)?,
+        rate_beyond: marginal_rate(year, status, beyond)?,
+        capital_gain_rate_beyond: ltcg_rate_for(year, status, beyond)?,
+        premium_increase_beyond,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn tiers() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                let tiers = irmaa_tiers(year, status);
+                assert_eq!(tiers[0].magi_over, 0);
+                for pair in tiers.windows(2) {
+                    assert!(pair[0].magi_over < pair[1].magi_over, "{year:?} {status:?}");
+                    assert!(pair[0].annual_cost().cents() < pair[1].annual_cost().cents());
+                }
+            }
+            // The 500,000 tier was added for 2019.
+            let single = irmaa_tiers(year, FilingStatus::Single).len();
+            assert_eq!(single, if u16::from(year) == 2018 { 5 } else { 6 });
+            assert_eq!(
+                irmaa_tiers(year, FilingStatus::MarriedFilingSeparately).len(),
+                3
+            );
+        }
+        assert_eq!(
+            irmaa_tiers(TaxYear::Y2024, FilingStatus::QualifyingSurvivingSpouse),
+            irmaa_tiers(TaxYear::Y2024, FilingStatus::Single)
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn brackets() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+        let fill = |income, target| optimal_conversion(year, status, income, target).unwrap();
+        let conversion = fill(60_000, FillTo::Bracket(0.22)).unwrap();
+        assert_eq!(conversion.amount, 40_525);
+        assert_eq!(conversion.rate_beyond, 0.24);
+        // Already past the target
+        let conversion = fill(150_000, FillTo::Bracket(0.12)).unwrap();
+        assert_eq!(conversion.amount, 0);
+        assert_eq!(conversion.tax, 0);
+        assert_eq!(conversion.rate_beyond, 0.24);
+        // The top bracket has no top, and 0.25 is no bracket.
+        assert_eq!(fill(60_000, FillTo::Bracket(0.37)), None);
+        assert_eq!(fill(60_000, FillTo::Bracket(0.25)), None);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn capital_gain_rates() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::MarriedFilingJointly);
+        let conversion = optimal_conversion(year, status, 50_000, FillTo::CapitalGainRate(0.0))
+            .unwrap()
+            .unwrap();
+        assert_eq!(conversion.amount, 44_050);
+        assert_eq!(conversion.capital_gain_rate_beyond, 0.15);
+        assert_eq!(conversion.rate_beyond, 0.12);
+        assert_eq!(
+            optimal_conversion(year, status, 50_000, FillTo::CapitalGainRate(0.20)),
+            Ok(None)
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn irmaa() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+        let target = |tier, magi| FillTo::IrmaaTier { tier, magi };
+        let conversion = optimal_conversion(year, status, 70_000, target(1, 100_000))
+            .unwrap()
+            .unwrap();
+        assert_eq!(conversion.amount, 29_000);
+        // ($349.40 + $33.30 - $244.60 - $12.90) × 12
+        assert_eq!(conversion.premium_increase_beyond.cents(), 150_240);
+        let top = optimal_conversion(year, status, 70_000, target(5, 100_000)).unwrap();
+        assert_eq!(top, None);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn errors() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+        assert_eq!(
+            optimal_conversion(year, status, -1, FillTo::Bracket(0.22)),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            optimal_conversion(year, status, u64::MAX, FillTo::Bracket(0.22)),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
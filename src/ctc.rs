@@ -0,0 +1,254 @@
+//! The child tax credit and the credit for other dependents (Schedule 8812).
+
+use crate::compute::income_to_i64;
+use crate::data;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The earned income above which 15% counts toward the additional child
+/// tax credit.
+const EARNED_INCOME_FLOOR: i64 = 2_500;
+
+/// The child tax credit and credit for other dependents allowed on a return,
+/// returned by [`compute_ctc`].
+///
+/// The credit is limited by the tax, and what the tax does not absorb can
+/// be refunded as the additional child tax credit up to a per-child limit.
+/// [`nonrefundable`](Self::nonrefundable) and
+/// [`additional_child_tax_credit`](Self::additional_child_tax_credit) split
+/// it once the tax is known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ChildTaxCredit {
+    /// The year the credit is for.
+    pub year: TaxYear,
+    /// The credits for all children and other dependents before the
+    /// phaseout (Schedule 8812, line 8).
+    pub credit_before_phaseout: i64,
+    /// The reduction for modified adjusted gross income above the threshold
+    /// (line 11).
+    pub phaseout: i64,
+    /// The credit after the phaseout (line 12).
+    pub credit: i64,
+    /// The most that can be refunded: the refundable amount per child times
+    /// the number of children (line 16b).
+    pub refundable_limit: i64,
+}
+
+impl ChildTaxCredit {
+    /// The part of the credit used against `tax_liability`, the tax before
+    /// credits less any credits claimed before this one (line 14).
+    pub fn nonrefundable(&self, tax_liability: i64) -> i64 {
+        self.credit.min(tax_liability.max(0))
+    }
+
+    /// The refundable additional child tax credit (line 27): the credit left
+    /// over after [`nonrefundable`](Self::nonrefundable), up to the
+    /// [`refundable_limit`](Self::refundable_limit) and to 15% of
+    /// `earned_income` above $2,500.
+    ///
+    /// The alternative limit in Part II-B for three or more children, based
+    /// on Social Security and Medicare taxes paid, is not applied. For 2021
+    /// the credit is fully refundable and no earned income is needed.
+    pub fn additional_child_tax_credit(&self, tax_liability: i64, earned_income: i64) -> i64 {
+        let unused = self.credit - self.nonrefundable(tax_liability);
+        let limit = unused.min(self.refundable_limit);
+        if u16::from(self.year) == 2021 {
+            return limit;
+        }
+        let excess = earned_income.saturating_sub(EARNED_INCOME_FLOOR).max(0);
+        let earned_income_limit = ((i128::from(excess) * 15 + 50) / 100) as i64;
+        limit.min(earned_income_limit)
+    }
+}
+
+/// Compute the child tax credit for `qualifying_children` and the credit for
+/// `other_dependents` on Schedule 8812, for a return with modified adjusted
+/// gross income `magi`.
+///
+/// Each qualifying child under 17 gets $2,000 ($2,200 from 2025) and each
+/// other dependent $500. Together the credits are reduced by $50 for each
+/// $1,000, or part of $1,000, of `magi` above $400,000 for married couples
+/// filing jointly and $200,000 for everyone else.
+///
+/// For 2021 the American Rescue Plan Act raised the credit to $3,000 per
+/// child, with the increase phasing out first, above $150,000 for married
+/// couples filing jointly and qualifying surviving spouses, $112,500 for
+/// heads of household, and $75,000 for everyone else. The regular phaseout
+/// then reduces what is left of the whole credit. Children under 6, who got
+/// $3,600, are counted as older children here.
+///
+/// # Errors
+///
+/// Returns [`TaxError::IncomeOutOfRange`] if `magi` does not fit in an
+/// `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{compute_ctc, FilingStatus, TaxYear};
+///
+/// let status = FilingStatus::MarriedFilingJointly;
+/// let ctc = compute_ctc(TaxYear::Y2024, status, 120_000, 2, 1).unwrap();
+/// assert_eq!(ctc.credit, 4_500);
+///
+/// // $1,200 of tax absorbs part of the credit; the rest is refundable up
+/// // to $1,700 per child.
+/// assert_eq!(ctc.nonrefundable(1_200), 1_200);
+/// assert_eq!(ctc.additional_child_tax_credit(1_200, 60_000), 3_300);
+/// # }
+/// ```
+pub fn compute_ctc(
+    year: TaxYear,
+    status: FilingStatus,
+    magi: impl TryInto<i64>,
+    qualifying_children: u8,
+    other_dependents: u8,
+) -> Result<ChildTaxCredit, TaxError> {
+    let magi = income_to_i64(magi)?;
+    let amounts = data::child_tax_credit(year);
+    let children = i64::from(qualifying_children);
+    let credit_before_phaseout =
+        children * amounts.per_child + i64::from(other_dependents) * amounts.per_other_dependent;
+    let threshold = match status {
+        FilingStatus::MarriedFilingJointly => amounts.phaseout_threshold_joint,
+        _ => amounts.phaseout_threshold,
+    };
+    let credit = if u16::from(year) == 2021 {
+        let increase = children * (amounts.per_child - 2_000);
+        let increase_threshold = match status {
+            FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse => 150_000,
+            FilingStatus::HeadOfHousehold => 112_500,
+            FilingStatus::Single | FilingStatus::MarriedFilingSeparately => 75_000,
+        };
+        let increase_phaseout = reduction(magi, increase_threshold)
+            .min(increase)
+            .min((threshold - increase_threshold) / 20);
+        (credit_before_phaseout - increase_phaseout - reduction(magi, threshold)).max(0)
+    } else {
+        (credit_before_phaseout - reduction(magi, threshold)).max(0)
+    };
+    Ok(ChildTaxCredit {
+        year,
+        credit_before_phaseout,
+        phaseout: credit_before_phaseout - credit,
+        credit,
+        refundable_limit: children * amounts.refundable_per_child,
+    })
+}
+
+/// $50 for each $1,000, or part of $1,000, of `magi` above `threshold`.
+fn reduction(magi: i64, threshold: i64) -> i64 {
+    if magi <= threshold {
+        return 0;
+    }
+    (magi.abs_diff(threshold).div_ceil(1_000) * 50) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn credits_and_phaseout() {
+        let year = TaxYear::Y2024;
+        let single = FilingStatus::Single;
+        let joint = FilingStatus::MarriedFilingJointly;
+        assert_eq!(compute_ctc(year, single, 0, 0, 0).unwrap().credit, 0);
+        assert_eq!(
+            compute_ctc(year, single, 200_000, 1, 0).unwrap().credit,
+            2_000
+        );
+        // Part of $1,000 counts as a whole $1,000.
+        let ctc = compute_ctc(year, single, 210_001, 1, 0).unwrap();
+        assert_eq!((ctc.phaseout, ctc.credit), (550, 1_450));
+        assert_eq!(compute_ctc(year, single, 300_000, 1, 0).unwrap().credit, 0);
+        assert_eq!(
+            compute_ctc(year, joint, 400_000, 2, 1).unwrap().credit,
+            4_500
+        );
+        assert_eq!(
+            compute_ctc(year, joint, 400_001, 2, 1).unwrap().credit,
+            4_450
+        );
+        // Qualifying surviving spouses use the $200,000 threshold.
+        let ctc = compute_ctc(year, FilingStatus::QualifyingSurvivingSpouse, 250_000, 2, 0);
+        assert_eq!(ctc.unwrap().credit, 1_500);
+        assert_eq!(
+            compute_ctc(TaxYear::Y2025, joint, 50_000, 2, 0)
+                .unwrap()
+                .credit,
+            4_400
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn refundable_part() {
+        let ctc = compute_ctc(
+            TaxYear::Y2024,
+            FilingStatus::MarriedFilingJointly,
+            100_000,
+            2,
+            1,
+        )
+        .unwrap();
+        assert_eq!(ctc.refundable_limit, 3_400);
+        assert_eq!(ctc.nonrefundable(3_000), 3_000);
+        assert_eq!(ctc.additional_child_tax_credit(3_000, 100_000), 1_500);
+        assert_eq!(ctc.nonrefundable(10_000), 4_500);
+        assert_eq!(ctc.additional_child_tax_credit(10_000, 100_000), 0);
+        // 15% of earned income above $2,500
+        assert_eq!(ctc.additional_child_tax_credit(0, 10_000), 1_125);
+        assert_eq!(ctc.additional_child_tax_credit(0, 2_000), 0);
+        assert_eq!(ctc.additional_child_tax_credit(0, 100_000), 3_400);
+    }
+
+    #[cfg(feature = "year-2021")]
+    #[test]
+    fn american_rescue_plan() {
+        let year = TaxYear::Y2021;
+        let single = FilingStatus::Single;
+        assert_eq!(
+            compute_ctc(year, single, 75_000, 1, 0).unwrap().credit,
+            3_000
+        );
+        assert_eq!(
+            compute_ctc(year, single, 80_000, 1, 0).unwrap().credit,
+            2_750
+        );
+        assert_eq!(
+            compute_ctc(year, single, 100_000, 1, 0).unwrap().credit,
+            2_000
+        );
+        assert_eq!(
+            compute_ctc(year, single, 210_001, 1, 0).unwrap().credit,
+            1_450
+        );
+        // The increase phaseout is capped at 5% of the gap between the
+        // thresholds, and the regular phaseout reduces what is left.
+        let hoh = FilingStatus::HeadOfHousehold;
+        assert_eq!(
+            compute_ctc(year, hoh, 200_000, 5, 0).unwrap().credit,
+            10_625
+        );
+        assert_eq!(compute_ctc(year, hoh, 300_000, 5, 0).unwrap().credit, 5_625);
+        assert_eq!(compute_ctc(year, hoh, 1_000_000, 5, 0).unwrap().credit, 0);
+        // Fully refundable without earned income
+        let ctc = compute_ctc(year, single, 80_000, 1, 1).unwrap();
+        assert_eq!(ctc.credit, 3_250);
+        assert_eq!(ctc.additional_child_tax_credit(0, 0), 3_000);
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn errors() {
+        assert_eq!(
+            compute_ctc(TaxYear::Y2025, FilingStatus::Single, u64::MAX, 1, 0),
+            Err(TaxError::IncomeOutOfRange)
+        );
+        let ctc = compute_ctc(TaxYear::Y2025, FilingStatus::Single, i64::MAX, 1, 0).unwrap();
+        assert_eq!(ctc.credit, 0);
+    }
+}
@@ -1,44 +1,410 @@
 //! Embedded IRS tax data and CSV parsing.
 //!
 //! Tax data is scraped from the IRS Form 1040 instructions and stored as CSV
-//! files in the repository's `data/<year>/` directories. The CSV files are
-//! embedded into the binary at compile time using [`include_str!`], so no
-//! runtime file I/O is needed.
+//! files in the repository's `data/<year>/` directories. At build time,
+//! `build.rs` converts them into static arrays, so computations do no parsing
+//! or file I/O. The raw CSV text is also embedded with [`include_str!`] for
+//! [`validate_data`](crate::validate_data), which reports problems by line
+//! number.
 
-use crate::types::FilingStatus;
+use crate::conversion::IrmaaTier;
+use crate::money::UsdAmount;
+use crate::provenance::{DataFileDigest, DataProvenance, DataSource};
+use crate::schedule::TaxBracket;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+use crate::withholding::WithholdingBracket;
 
 // ---------------------------------------------------------------------------
 // Embedded CSV data
 // ---------------------------------------------------------------------------
 
+/// A CSV file's contents together with its path, for error reporting.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CsvFile<'a> {
+    /// Path of the file relative to the repository root, e.g.
+    /// `data/2024/tax_table.csv`.
+    pub path: &'a str,
+    /// The raw CSV text, including the header row.
+    pub contents: &'a str,
+}
+
+/// Embed `data/<year>/<file>` as a [`CsvFile`].
+macro_rules! embed_csv {
+    ($year:literal, $file:literal) => {
+        CsvFile {
+            path: concat!("data/", $year, "/", $file),
+            contents: include_str!(concat!("../data/", $year, "/", $file)),
+        }
+    };
+}
+
+/// Tax Table CSV for tax year 2018 (income $0–$99,999).
+#[cfg(feature = "year-2018")]
+const TAX_TABLE_CSV_2018: CsvFile<'static> = embed_csv!("2018", "tax_table.csv");
+
+/// Tax Computation Worksheet CSV for tax year 2018 (income $100,000+).
+#[cfg(feature = "year-2018")]
+const WORKSHEET_CSV_2018: CsvFile<'static> = embed_csv!("2018", "tax_computation_worksheet.csv");
+
+/// Tax Table CSV for tax year 2019 (income $0–$99,999).
+#[cfg(feature = "year-2019")]
+const TAX_TABLE_CSV_2019: CsvFile<'static> = embed_csv!("2019", "tax_table.csv");
+
+/// Tax Computation Worksheet CSV for tax year 2019 (income $100,000+).
+#[cfg(feature = "year-2019")]
+const WORKSHEET_CSV_2019: CsvFile<'static> = embed_csv!("2019", "tax_computation_worksheet.csv");
+
+/// Tax Table CSV for tax year 2020 (income $0–$99,999).
+#[cfg(feature = "year-2020")]
+const TAX_TABLE_CSV_2020: CsvFile<'static> = embed_csv!("2020", "tax_table.csv");
+
+/// Tax Computation Worksheet CSV for tax year 2020 (income $100,000+).
+#[cfg(feature = "year-2020")]
+const WORKSHEET_CSV_2020: CsvFile<'static> = embed_csv!("2020", "tax_computation_worksheet.csv");
+
+/// Tax Table CSV for tax year 2021 (income $0–$99,999).
+#[cfg(feature = "year-2021")]
+const TAX_TABLE_CSV_2021: CsvFile<'static> = embed_csv!("2021", "tax_table.csv");
+
+/// Tax Computation Worksheet CSV for tax year 2021 (income $100,000+).
+#[cfg(feature = "year-2021")]
+const WORKSHEET_CSV_2021: CsvFile<'static> = embed_csv!("2021", "tax_computation_worksheet.csv");
+
+/// Tax Table CSV for tax year 2022 (income $0–$99,999).
+#[cfg(feature = "year-2022")]
+const TAX_TABLE_CSV_2022: CsvFile<'static> = embed_csv!("2022", "tax_table.csv");
+
+/// Tax Computation Worksheet CSV for tax year 2022 (income $100,000+).
+#[cfg(feature = "year-2022")]
+const WORKSHEET_CSV_2022: CsvFile<'static> = embed_csv!("2022", "tax_computation_worksheet.csv");
+
 /// Tax Table CSV for tax year 2023 (income $0–$99,999).
-const TAX_TABLE_CSV_2023: &str = include_str!("../data/2023/tax_table.csv");
+#[cfg(feature = "year-2023")]
+const TAX_TABLE_CSV_2023: CsvFile<'static> = embed_csv!("2023", "tax_table.csv");
 
 /// Tax Computation Worksheet CSV for tax year 2023 (income $100,000+).
-const WORKSHEET_CSV_2023: &str = include_str!("../data/2023/tax_computation_worksheet.csv");
+#[cfg(feature = "year-2023")]
+const WORKSHEET_CSV_2023: CsvFile<'static> = embed_csv!("2023", "tax_computation_worksheet.csv");
 
 /// Tax Table CSV for tax year 2024 (income $0–$99,999).
-const TAX_TABLE_CSV_2024: &str = include_str!("../data/2024/tax_table.csv");
+#[cfg(feature = "year-2024")]
+const TAX_TABLE_CSV_2024: CsvFile<'static> = embed_csv!("2024", "tax_table.csv");
 
 /// Tax Computation Worksheet CSV for tax year 2024 (income $100,000+).
-const WORKSHEET_CSV_2024: &str = include_str!("../data/2024/tax_computation_worksheet.csv");
+#[cfg(feature = "year-2024")]
+const WORKSHEET_CSV_2024: CsvFile<'static> = embed_csv!("2024", "tax_computation_worksheet.csv");
 
 /// Tax Table CSV for tax year 2025 (income $0–$99,999).
-const TAX_TABLE_CSV_2025: &str = include_str!("../data/2025/tax_table.csv");
+#[cfg(feature = "year-2025")]
+const TAX_TABLE_CSV_2025: CsvFile<'static> = embed_csv!("2025", "tax_table.csv");
 
 /// Tax Computation Worksheet CSV for tax year 2025 (income $100,000+).
-const WORKSHEET_CSV_2025: &str = include_str!("../data/2025/tax_computation_worksheet.csv");
+#[cfg(feature = "year-2025")]
+const WORKSHEET_CSV_2025: CsvFile<'static> = embed_csv!("2025", "tax_computation_worksheet.csv");
+
+/// Tax Rate Schedules CSV for tax year 2018 (all incomes).
+#[cfg(feature = "year-2018")]
+const RATE_SCHEDULE_CSV_2018: CsvFile<'static> = embed_csv!("2018", "tax_rate_schedule.csv");
+
+/// Tax Rate Schedules CSV for tax year 2019 (all incomes).
+#[cfg(feature = "year-2019")]
+const RATE_SCHEDULE_CSV_2019: CsvFile<'static> = embed_csv!("2019", "tax_rate_schedule.csv");
+
+/// Tax Rate Schedules CSV for tax year 2020 (all incomes).
+#[cfg(feature = "year-2020")]
+const RATE_SCHEDULE_CSV_2020: CsvFile<'static> = embed_csv!("2020", "tax_rate_schedule.csv");
+
+/// Tax Rate Schedules CSV for tax year 2021 (all incomes).
+#[cfg(feature = "year-2021")]
+const RATE_SCHEDULE_CSV_2021: CsvFile<'static> = embed_csv!("2021", "tax_rate_schedule.csv");
+
+/// Tax Rate Schedules CSV for tax year 2022 (all incomes).
+#[cfg(feature = "year-2022")]
+const RATE_SCHEDULE_CSV_2022: CsvFile<'static> = embed_csv!("2022", "tax_rate_schedule.csv");
+
+/// Tax Rate Schedules CSV for tax year 2023 (all incomes).
+#[cfg(feature = "year-2023")]
+const RATE_SCHEDULE_CSV_2023: CsvFile<'static> = embed_csv!("2023", "tax_rate_schedule.csv");
+
+/// Tax Rate Schedules CSV for tax year 2024 (all incomes).
+#[cfg(feature = "year-2024")]
+const RATE_SCHEDULE_CSV_2024: CsvFile<'static> = embed_csv!("2024", "tax_rate_schedule.csv");
+
+/// Tax Rate Schedules CSV for tax year 2025 (all incomes).
+#[cfg(feature = "year-2025")]
+const RATE_SCHEDULE_CSV_2025: CsvFile<'static> = embed_csv!("2025", "tax_rate_schedule.csv");
 
 /// Return the embedded (Tax Table CSV, Worksheet CSV) for the given tax year.
-pub(crate) fn csv_for_year(year: crate::types::TaxYear) -> (&'static str, &'static str) {
-    use crate::types::TaxYear;
+pub(crate) fn csv_for_year(year: TaxYear) -> (CsvFile<'static>, CsvFile<'static>) {
     match year {
+        #[cfg(feature = "year-2018")]
+        TaxYear::Y2018 => (TAX_TABLE_CSV_2018, WORKSHEET_CSV_2018),
+        #[cfg(feature = "year-2019")]
+        TaxYear::Y2019 => (TAX_TABLE_CSV_2019, WORKSHEET_CSV_2019),
+        #[cfg(feature = "year-2020")]
+        TaxYear::Y2020 => (TAX_TABLE_CSV_2020, WORKSHEET_CSV_2020),
+        #[cfg(feature = "year-2021")]
+        TaxYear::Y2021 => (TAX_TABLE_CSV_2021, WORKSHEET_CSV_2021),
+        #[cfg(feature = "year-2022")]
+        TaxYear::Y2022 => (TAX_TABLE_CSV_2022, WORKSHEET_CSV_2022),
+        #[cfg(feature = "year-2023")]
         TaxYear::Y2023 => (TAX_TABLE_CSV_2023, WORKSHEET_CSV_2023),
+        #[cfg(feature = "year-2024")]
         TaxYear::Y2024 => (TAX_TABLE_CSV_2024, WORKSHEET_CSV_2024),
+        #[cfg(feature = "year-2025")]
         TaxYear::Y2025 => (TAX_TABLE_CSV_2025, WORKSHEET_CSV_2025),
     }
 }
 
+/// Return the embedded Tax Rate Schedules CSV for the given tax year.
+pub(crate) fn rate_schedule_csv_for_year(year: TaxYear) -> CsvFile<'static> {
+    match year {
+        #[cfg(feature = "year-2018")]
+        TaxYear::Y2018 => RATE_SCHEDULE_CSV_2018,
+        #[cfg(feature = "year-2019")]
+        TaxYear::Y2019 => RATE_SCHEDULE_CSV_2019,
+        #[cfg(feature = "year-2020")]
+        TaxYear::Y2020 => RATE_SCHEDULE_CSV_2020,
+        #[cfg(feature = "year-2021")]
+        TaxYear::Y2021 => RATE_SCHEDULE_CSV_2021,
+        #[cfg(feature = "year-2022")]
+        TaxYear::Y2022 => RATE_SCHEDULE_CSV_2022,
+        #[cfg(feature = "year-2023")]
+        TaxYear::Y2023 => RATE_SCHEDULE_CSV_2023,
+        #[cfg(feature = "year-2024")]
+        TaxYear::Y2024 => RATE_SCHEDULE_CSV_2024,
+        #[cfg(feature = "year-2025")]
+        TaxYear::Y2025 => RATE_SCHEDULE_CSV_2025,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Generated static data
+// ---------------------------------------------------------------------------
+
+// Defines `DATA_YEARS` and the `TAX_TABLES`, `WORKSHEETS`, `RATE_SCHEDULES`,
+// `FILING_THRESHOLDS`, and `PROVENANCE` arrays, indexed by year (in
+// `DATA_YEARS` order) and, for the per-status data, by `csv_status_index`.
+// Also `CPI_U` and, with the `historical` feature, `HISTORICAL_SCHEDULES`,
+// both sorted by year, and `LIFE_EXPECTANCY`, indexed by age.
+include!(concat!(env!("OUT_DIR"), "/tax_data.rs"));
+
+/// Position of `year` in the generated arrays.
+const fn year_index(year: TaxYear) -> usize {
+    let mut index = 0;
+    while index < DATA_YEARS.len() {
+        if DATA_YEARS[index] == year.calendar_year() {
+            return index;
+        }
+        index += 1;
+    }
+    panic!("build.rs generates data for every TaxYear")
+}
+
+/// Position of a filing status's CSV key in the generated arrays.
+pub(crate) const fn csv_status_index(status: FilingStatus) -> usize {
+    match status {
+        FilingStatus::Single => 0,
+        FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse => 1,
+        FilingStatus::MarriedFilingSeparately => 2,
+        FilingStatus::HeadOfHousehold => 3,
+    }
+}
+
+/// The Tax Table for `year`, sorted by income.
+pub(crate) const fn tax_table(year: TaxYear) -> &'static [TaxTableRow] {
+    TAX_TABLES[year_index(year)]
+}
+
+/// The Tax Computation Worksheet brackets for `year` and `status`.
+pub(crate) const fn worksheet(year: TaxYear, status: FilingStatus) -> &'static [WorksheetBracket] {
+    WORKSHEETS[year_index(year)][csv_status_index(status)]
+}
+
+/// The Tax Rate Schedule brackets for `year` and `status`.
+pub(crate) fn rate_schedule(year: TaxYear, status: FilingStatus) -> &'static [TaxBracket] {
+    RATE_SCHEDULES[year_index(year)][csv_status_index(status)]
+}
+
+/// Where the data for `year` came from.
+pub(crate) fn provenance(year: TaxYear) -> &'static DataProvenance {
+    &PROVENANCE[year_index(year)]
+}
+
+/// The annual average Consumer Price Index for `year`, or [`None`] if the
+/// series does not cover it.
+pub(crate) fn consumer_price_index(year: u16) -> Option<f64> {
+    let index = CPI_U.binary_search_by_key(&year, |&(year, _)| year).ok()?;
+    Some(CPI_U[index].1)
+}
+
+/// The Single Life Table and Uniform Lifetime Table distribution periods for
+/// `age`, in tenths of a year, with ages past the end of the tables using
+/// the last row.
+pub(crate) fn life_expectancy(age: u8) -> (i64, Option<i64>) {
+    LIFE_EXPECTANCY[usize::from(age).min(LIFE_EXPECTANCY.len() - 1)]
+}
+
+/// The Tax Rate Schedule brackets for a year before the embedded data, or
+/// [`None`] if the historical data does not cover it.
+#[cfg(feature = "historical")]
+pub(crate) fn historical_rate_schedule(
+    year: u16,
+    status: FilingStatus,
+) -> Option<&'static [TaxBracket]> {
+    let index = HISTORICAL_SCHEDULES
+        .binary_search_by_key(&year, |&(year, _)| year)
+        .ok()?;
+    Some(HISTORICAL_SCHEDULES[index].1[csv_status_index(status)])
+}
+
+/// The brackets of the 0%, 15%, and 20% rates on qualified dividends and
+/// net capital gain for `year` and `status`.
+pub(crate) fn capital_gains_schedule(year: TaxYear, status: FilingStatus) -> &'static [TaxBracket] {
+    CAPITAL_GAINS_SCHEDULES[year_index(year)][csv_status_index(status)]
+}
+
+/// The saver's credit rates by adjusted gross income for `year` and
+/// `status`. Unlike elsewhere, qualifying surviving spouses share the single
+/// tiers rather than the married filing jointly ones.
+pub(crate) fn savers_credit_tiers(year: TaxYear, status: FilingStatus) -> &'static [TaxBracket] {
+    let status = match status {
+        FilingStatus::QualifyingSurvivingSpouse => FilingStatus::Single,
+        status => status,
+    };
+    SAVERS_CREDIT_TIERS[year_index(year)][csv_status_index(status)]
+}
+
+/// The Medicare premium tiers by modified adjusted gross income for `year`
+/// and `status`. Qualifying surviving spouses share the single tiers.
+pub(crate) fn irmaa_tiers(year: TaxYear, status: FilingStatus) -> &'static [IrmaaTier] {
+    let status = match status {
+        FilingStatus::QualifyingSurvivingSpouse => FilingStatus::Single,
+        status => status,
+    };
+    IRMAA_TIERS[year_index(year)][csv_status_index(status)]
+}
+
+/// The Publication 15-T percentage method table for `year` and `status`, for
+/// a Form W-4 with or without the Step 2 box checked. Empty before 2020.
+pub(crate) fn withholding_table(
+    year: TaxYear,
+    status: FilingStatus,
+    step_2_checkbox: bool,
+) -> &'static [WithholdingBracket] {
+    let rows = WITHHOLDING_TABLES[year_index(year)][csv_status_index(status)];
+    let split = rows.partition_point(|row| !row.step_2_checkbox);
+    if step_2_checkbox {
+        &rows[split..]
+    } else {
+        &rows[..split]
+    }
+}
+
+/// The filing requirement thresholds for `year` and `status`.
+pub(crate) fn filing_thresholds(year: TaxYear, status: FilingStatus) -> &'static [FilingThreshold] {
+    FILING_THRESHOLDS[year_index(year)][csv_status_index(status)]
+}
+
+/// The standard deduction amounts for `year` and `status`.
+pub(crate) const fn standard_deduction(
+    year: TaxYear,
+    status: FilingStatus,
+) -> StandardDeductionAmounts {
+    STANDARD_DEDUCTIONS[year_index(year)][csv_status_index(status)]
+}
+
+/// The rate schedule for estates and trusts in `year`.
+pub(crate) fn trust_rate_schedule(year: TaxYear) -> &'static [TaxBracket] {
+    TRUST_SCHEDULES[year_index(year)]
+}
+
+/// The Social Security wage base for `year`.
+pub(crate) const fn social_security_wage_base(year: TaxYear) -> i64 {
+    SOCIAL_SECURITY_WAGE_BASES[year_index(year)]
+}
+
+/// The cost-of-living adjustment `year`'s Revenue Procedure applies to the
+/// statutory brackets.
+pub(crate) const fn cost_of_living_adjustment(year: TaxYear) -> f64 {
+    COST_OF_LIVING_ADJUSTMENTS[year_index(year)]
+}
+
+/// The alternative minimum tax exemption amounts for `year` and `status`.
+pub(crate) const fn amt_exemption(year: TaxYear, status: FilingStatus) -> AmtExemption {
+    AMT_EXEMPTIONS[year_index(year)][csv_status_index(status)]
+}
+
+/// The limited standard deduction for dependents in `year`.
+pub(crate) const fn dependent_standard_deduction(year: TaxYear) -> DependentStandardDeduction {
+    DEPENDENT_STANDARD_DEDUCTIONS[year_index(year)]
+}
+
+/// The estate and gift tax exclusion amounts for `year`.
+pub(crate) const fn estate_gift_exclusions(year: TaxYear) -> EstateGiftExclusions {
+    ESTATE_GIFT_EXCLUSIONS[year_index(year)]
+}
+
+/// The earned income credit amounts for `year` and `qualifying_children`,
+/// which must be at most 3.
+pub(crate) const fn eitc_parameters(year: TaxYear, qualifying_children: usize) -> EitcParameters {
+    EITC_PARAMETERS[year_index(year)][qualifying_children]
+}
+
+/// The investment income above which no earned income credit is allowed in
+/// `year`.
+pub(crate) const fn eitc_investment_income_limit(year: TaxYear) -> i64 {
+    EITC_INVESTMENT_INCOME_LIMITS[year_index(year)]
+}
+
+/// The child tax credit amounts for `year`.
+pub(crate) const fn child_tax_credit(year: TaxYear) -> ChildTaxCreditAmounts {
+    CHILD_TAX_CREDITS[year_index(year)]
+}
+
+/// The modified adjusted gross income ranges over which the American
+/// opportunity and lifetime learning credits phase out in `year`.
+pub(crate) const fn education_credit_phaseouts(year: TaxYear) -> [EducationCreditPhaseout; 2] {
+    EDUCATION_CREDIT_PHASEOUTS[year_index(year)]
+}
+
+/// The adoption credit amounts for `year`.
+pub(crate) const fn adoption_credit(year: TaxYear) -> AdoptionCreditAmounts {
+    ADOPTION_CREDITS[year_index(year)]
+}
+
+/// The applicable percentage table of the premium tax credit for `year`, in
+/// ascending order of household income.
+pub(crate) fn ptc_applicable_percentages(year: TaxYear) -> &'static [ApplicablePercentage] {
+    PTC_APPLICABLE_PERCENTAGES[year_index(year)]
+}
+
+/// The qualified business income deduction threshold for `year` and
+/// `status`. Qualifying surviving spouses use the single threshold.
+pub(crate) const fn qbi_threshold(year: TaxYear, status: FilingStatus) -> QbiThreshold {
+    let status = match status {
+        FilingStatus::QualifyingSurvivingSpouse => FilingStatus::Single,
+        status => status,
+    };
+    QBI_THRESHOLDS[year_index(year)][csv_status_index(status)]
+}
+
+/// The IRA contribution limits and Roth IRA phaseout ranges for `year`.
+pub(crate) const fn ira_limits(year: TaxYear) -> IraLimits {
+    IRA_LIMITS[year_index(year)]
+}
+
+/// The workplace plan and HSA contribution limits for `year`.
+pub(crate) const fn contribution_limits(year: TaxYear) -> ContributionLimitAmounts {
+    CONTRIBUTION_LIMITS[year_index(year)]
+}
+
+/// The poverty guidelines the premium tax credit uses for `year`, as the
+/// amount for the first person and for each additional person.
+pub(crate) const fn poverty_guidelines(year: TaxYear) -> (i64, i64) {
+    POVERTY_GUIDELINES[year_index(year)]
+}
+
 // ---------------------------------------------------------------------------
 // Internal data structures
 // ---------------------------------------------------------------------------
@@ -46,18 +412,39 @@ pub(crate) fn csv_for_year(year: crate::types::TaxYear) -> (&'static str, &'stat
 /// A single row from the IRS Tax Table.
 ///
 /// Each row covers a $50 income range and contains the pre-computed tax amount
-/// for every filing status.
-pub(crate) struct TaxTableRow {
+/// for every filing status. Qualifying surviving spouses use the married
+/// filing jointly column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaxTableRow {
     /// Lower bound of the income range (inclusive).
     pub income_min: i64,
     /// Upper bound of the income range (exclusive).
     pub income_max: i64,
+    /// Tax for single filers.
     pub single: i64,
+    /// Tax for married couples filing jointly.
     pub married_filing_jointly: i64,
+    /// Tax for married individuals filing separately.
     pub married_filing_separately: i64,
+    /// Tax for heads of household.
     pub head_of_household: i64,
 }
 
+impl TaxTableRow {
+    /// The tax amount in this row for the given filing status.
+    pub const fn tax_for(&self, status: FilingStatus) -> i64 {
+        match status {
+            FilingStatus::Single => self.single,
+            FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse => {
+                self.married_filing_jointly
+            }
+            FilingStatus::MarriedFilingSeparately => self.married_filing_separately,
+            FilingStatus::HeadOfHousehold => self.head_of_household,
+        }
+    }
+}
+
 /// A single bracket from the Tax Computation Worksheet.
 ///
 /// For incomes of $100,000 or more, the IRS provides a formula:
@@ -65,68 +452,424 @@ pub(crate) struct TaxTableRow {
 /// ```text
 /// tax = taxable_income × rate − subtraction_amount
 /// ```
-pub(crate) struct WorksheetBracket {
+///
+/// The rate and subtraction amount are held exactly, in basis points and
+/// cents.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WorksheetBracket {
     /// Lower bound of the bracket (inclusive for the first bracket, exclusive
     /// for "Over $X" brackets).
-    pub income_min: i64,
+    pub(crate) income_min: i64,
     /// Upper bound of the bracket (inclusive), or [`None`] for the highest
     /// (unbounded) bracket.
-    pub income_max: Option<i64>,
+    pub(crate) income_max: Option<i64>,
     /// Marginal-equivalent multiplication rate (e.g., 0.22 for 22%).
-    pub rate: f64,
+    pub(crate) rate: f64,
     /// Subtraction amount that, combined with the rate, yields the correct
     /// progressive tax.
-    pub subtraction_amount: f64,
+    pub(crate) subtraction_amount: f64,
+    /// [`rate`](Self::rate) in basis points (e.g., 2200 for 22%), parsed
+    /// without floating point.
+    pub(crate) rate_basis_points: i64,
+    /// [`subtraction_amount`](Self::subtraction_amount) in cents, parsed
+    /// without floating point.
+    pub(crate) subtraction_cents: i64,
+}
+
+impl WorksheetBracket {
+    /// A bracket from its bounds, its rate in basis points (2200 for 22%),
+    /// and its subtraction amount in cents.
+    ///
+    /// `income_max` is [`None`] for the highest (unbounded) bracket.
+    pub const fn new(
+        income_min: i64,
+        income_max: Option<i64>,
+        rate_basis_points: i64,
+        subtraction_cents: i64,
+    ) -> Self {
+        WorksheetBracket {
+            income_min,
+            income_max,
+            rate: rate_basis_points as f64 / 10_000.0,
+            subtraction_amount: subtraction_cents as f64 / 100.0,
+            rate_basis_points,
+            subtraction_cents,
+        }
+    }
+
+    /// Lower bound of the bracket.
+    pub const fn income_min(&self) -> i64 {
+        self.income_min
+    }
+
+    /// Upper bound of the bracket (inclusive), or [`None`] for the highest
+    /// bracket.
+    pub const fn income_max(&self) -> Option<i64> {
+        self.income_max
+    }
+
+    /// The rate in basis points (e.g., 2200 for 22%).
+    pub const fn rate_basis_points(&self) -> i64 {
+        self.rate_basis_points
+    }
+
+    /// The subtraction amount in cents.
+    pub const fn subtraction_cents(&self) -> i64 {
+        self.subtraction_cents
+    }
+
+    /// Whether `taxable_income` falls in this bracket: up to and including
+    /// the upper bound, or above the lower bound in the top bracket.
+    pub const fn contains(&self, taxable_income: i64) -> bool {
+        match self.income_max {
+            Some(max) => taxable_income >= self.income_min && taxable_income <= max,
+            None => taxable_income > self.income_min,
+        }
+    }
+}
+
+/// A row of Chart A in the Form 1040 instructions: the gross income at which
+/// a return must be filed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FilingThreshold {
+    /// Number of people on the return who are 65 or older.
+    pub age_65_or_older: u8,
+    /// Gross income at or above which a return is required.
+    pub gross_income: i64,
+}
+
+/// A filing status's standard deduction amounts from the Form 1040
+/// instructions.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StandardDeductionAmounts {
+    /// The standard deduction before any additional amounts.
+    pub basic: i64,
+    /// The additional amount for each box checked for age 65 or older or
+    /// blindness.
+    pub additional: i64,
+}
+
+/// A filing status's alternative minimum tax amounts from the Form 6251
+/// instructions.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AmtExemption {
+    /// The exemption before the phaseout.
+    pub exemption: i64,
+    /// The alternative minimum taxable income above which the exemption is
+    /// reduced by 25 cents per dollar.
+    pub phaseout_threshold: i64,
+    /// The income above which the 28% rate applies instead of 26%.
+    pub rate_28_threshold: i64,
+}
+
+/// The amounts of the Standard Deduction Worksheet for Dependents in the
+/// Form 1040 instructions.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DependentStandardDeduction {
+    /// The deduction with no earned income.
+    pub minimum: i64,
+    /// The amount added to earned income.
+    pub earned_income_addition: i64,
+}
+
+/// The estate and gift tax exclusion amounts, announced with the income tax
+/// brackets in each year's inflation adjustments.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EstateGiftExclusions {
+    /// The basic exclusion amount, sheltered from estate and gift tax over a
+    /// lifetime.
+    pub basic_exclusion: i64,
+    /// The annual exclusion for gifts to each recipient.
+    pub annual_exclusion: i64,
+}
+
+/// The earned income credit amounts for one number of qualifying children,
+/// from the IRS inflation adjustments. The rates are held exactly, in basis
+/// points.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EitcParameters {
+    /// The rate at which the credit phases in with income.
+    pub credit_rate_basis_points: i64,
+    /// The rate at which the credit phases out above the threshold.
+    pub phaseout_rate_basis_points: i64,
+    /// The income at which the credit reaches its maximum.
+    pub earned_income_amount: i64,
+    /// The maximum credit.
+    pub maximum_credit: i64,
+    /// The income above which the credit phases out.
+    pub phaseout_threshold: i64,
+    /// [`phaseout_threshold`](Self::phaseout_threshold) for married couples
+    /// filing jointly.
+    pub phaseout_threshold_joint: i64,
+}
+
+/// The amounts of Schedule 8812 for the child tax credit and the credit for
+/// other dependents.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ChildTaxCreditAmounts {
+    /// The credit for each qualifying child.
+    pub per_child: i64,
+    /// The credit for each other dependent.
+    pub per_other_dependent: i64,
+    /// The most of each child's credit that can be refunded.
+    pub refundable_per_child: i64,
+    /// The modified adjusted gross income above which the credits phase
+    /// out.
+    pub phaseout_threshold: i64,
+    /// [`phaseout_threshold`](Self::phaseout_threshold) for married couples
+    /// filing jointly.
+    pub phaseout_threshold_joint: i64,
+}
+
+/// The modified adjusted gross income range over which an education credit
+/// phases out on Form 8863.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EducationCreditPhaseout {
+    /// The income at which the phaseout starts.
+    pub start: i64,
+    /// [`start`](Self::start) for married couples filing jointly.
+    pub start_joint: i64,
+    /// The income at which the credit is gone.
+    pub end: i64,
+    /// [`end`](Self::end) for married couples filing jointly.
+    pub end_joint: i64,
+}
+
+/// The amounts of Form 8839 for the adoption credit and the exclusion of
+/// employer-provided adoption benefits.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AdoptionCreditAmounts {
+    /// The most expenses that can be credited or excluded for each child.
+    pub maximum_credit: i64,
+    /// The modified adjusted gross income above which the credit phases
+    /// out.
+    pub phaseout_threshold: i64,
+    /// The width of the phaseout range.
+    pub phaseout_range: i64,
+    /// The most of each child's credit that can be refunded.
+    pub refundable_maximum: i64,
+}
+
+/// A row of the applicable percentage table for the premium tax credit
+/// (Form 8962 instructions, Table 2): the share of household income
+/// expected toward the benchmark plan, rising linearly across the row.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ApplicablePercentage {
+    /// The household income, as a percentage of the poverty line, at which
+    /// the row starts.
+    pub fpl_min: i64,
+    /// The percentage at which the next row starts, or `None` for no limit.
+    pub fpl_max: Option<i64>,
+    /// The applicable percentage at `fpl_min`, in basis points.
+    pub initial_basis_points: i64,
+    /// The applicable percentage reached at `fpl_max`, in basis points.
+    pub final_basis_points: i64,
+}
+
+/// The taxable income threshold of the qualified business income deduction
+/// (Form 8995-A), above which the W-2 wage and property limits phase in and
+/// specified service businesses phase out.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct QbiThreshold {
+    /// The taxable income, before the deduction, at which the phase-in
+    /// starts.
+    pub threshold: i64,
+    /// The width of the phase-in range.
+    pub phase_in_range: i64,
+}
+
+/// The traditional and Roth IRA contribution limits from the IRS inflation
+/// adjustments (Publication 590-A).
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct IraLimits {
+    /// The most that can be contributed to all of a person's IRAs.
+    pub contribution_limit: i64,
+    /// The additional contribution allowed from age 50.
+    pub catch_up: i64,
+    /// The modified adjusted gross income at which the Roth IRA limit
+    /// starts to phase out.
+    pub roth_phaseout_start: i64,
+    /// The income at which no Roth IRA contribution is allowed.
+    pub roth_phaseout_end: i64,
+    /// [`roth_phaseout_start`](Self::roth_phaseout_start) for married
+    /// couples filing jointly and qualifying surviving spouses.
+    pub roth_phaseout_start_joint: i64,
+    /// [`roth_phaseout_end`](Self::roth_phaseout_end) for married couples
+    /// filing jointly and qualifying surviving spouses.
+    pub roth_phaseout_end_joint: i64,
+}
+
+/// The workplace retirement plan and health savings account contribution
+/// limits from the IRS inflation adjustments.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ContributionLimitAmounts {
+    /// The elective deferral limit.
+    pub elective_deferral: i64,
+    /// The additional elective deferral allowed from age 50.
+    pub elective_deferral_catch_up: i64,
+    /// The additional elective deferral allowed at ages 60 through 63.
+    pub elective_deferral_catch_up_60_to_63: i64,
+    /// The limit on annual additions to a defined contribution plan.
+    pub total_additions: i64,
+    /// The HSA limit with self-only coverage.
+    pub hsa_self_only: i64,
+    /// The HSA limit with family coverage.
+    pub hsa_family: i64,
+    /// The additional HSA contribution allowed from age 55.
+    pub hsa_catch_up: i64,
 }
 
 // ---------------------------------------------------------------------------
 // CSV parsing
 // ---------------------------------------------------------------------------
 
-/// Parse a Tax Table CSV into a sorted vector of [`TaxTableRow`]s.
-pub(crate) fn parse_tax_table(csv: &str) -> Vec<TaxTableRow> {
-    csv.lines()
+/// Parse every data row of a CSV file with `parse_row`, paired with its
+/// 1-based line number.
+///
+/// Blank lines are skipped. A row with the wrong number of columns, or one
+/// that `parse_row` rejects, fails the whole file with
+/// [`TaxError::MalformedData`] pointing at its line number.
+fn parse_rows<T>(
+    csv: &CsvFile<'_>,
+    columns: usize,
+    parse_row: impl Fn(&[&str]) -> Option<T>,
+) -> Result<Vec<(usize, T)>, TaxError> {
+    csv.contents
+        .lines()
+        .enumerate()
         .skip(1) // header
-        .filter_map(|line| {
-            let cols: Vec<&str> = line.split(',').collect();
-            if cols.len() < 6 {
-                return None;
-            }
-            Some(TaxTableRow {
-                income_min: cols[0].parse().ok()?,
-                income_max: cols[1].parse().ok()?,
-                single: cols[2].parse().ok()?,
-                married_filing_jointly: cols[3].parse().ok()?,
-                married_filing_separately: cols[4].parse().ok()?,
-                head_of_household: cols[5].parse().ok()?,
-            })
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let cols: Vec<&str> = line.trim_end().split(',').collect();
+            let row = if cols.len() == columns {
+                parse_row(&cols)
+            } else {
+                None
+            };
+            row.map(|row| (index + 1, row))
+                .ok_or_else(|| TaxError::MalformedData {
+                    file: csv.path.to_string(),
+                    line: index + 1,
+                })
         })
         .collect()
 }
 
-/// Parse a Tax Computation Worksheet CSV, returning only the brackets for the
-/// given filing status.
-pub(crate) fn parse_worksheet(csv: &str, status: FilingStatus) -> Vec<WorksheetBracket> {
-    let key = filing_status_csv_key(status);
-    csv.lines()
-        .skip(1) // header
-        .filter_map(|line| {
-            let cols: Vec<&str> = line.split(',').collect();
-            if cols.len() < 5 || cols[0] != key {
-                return None;
-            }
-            Some(WorksheetBracket {
-                income_min: cols[1].parse().ok()?,
-                income_max: if cols[2].is_empty() {
-                    None
-                } else {
-                    Some(cols[2].parse().ok()?)
-                },
-                rate: cols[3].parse().ok()?,
-                subtraction_amount: cols[4].parse().ok()?,
-            })
+/// Parse an optional upper bound, where an empty column means "no limit".
+fn parse_income_max(col: &str) -> Option<Option<i64>> {
+    if col.is_empty() {
+        Some(None)
+    } else {
+        col.parse().ok().map(Some)
+    }
+}
+
+/// Parse a Tax Table CSV, keeping each row's line number.
+pub(crate) fn parse_tax_table_lines(
+    csv: &CsvFile<'_>,
+) -> Result<Vec<(usize, TaxTableRow)>, TaxError> {
+    parse_rows(csv, 6, |cols| {
+        Some(TaxTableRow {
+            income_min: cols[0].parse().ok()?,
+            income_max: cols[1].parse().ok()?,
+            single: cols[2].parse().ok()?,
+            married_filing_jointly: cols[3].parse().ok()?,
+            married_filing_separately: cols[4].parse().ok()?,
+            head_of_household: cols[5].parse().ok()?,
         })
-        .collect()
+    })
+}
+
+/// Parse a Tax Computation Worksheet CSV for one filing status, keeping each
+/// row's line number.
+pub(crate) fn parse_worksheet_lines(
+    csv: &CsvFile<'_>,
+    status: FilingStatus,
+) -> Result<Vec<(usize, WorksheetBracket)>, TaxError> {
+    let key = filing_status_csv_key(status);
+    let rows = parse_rows(csv, 5, |cols| {
+        let bracket = WorksheetBracket {
+            income_min: cols[1].parse().ok()?,
+            income_max: parse_income_max(cols[2])?,
+            rate: cols[3].parse().ok()?,
+            subtraction_amount: cols[4].parse().ok()?,
+            rate_basis_points: parse_scaled(cols[3], 4)?,
+            subtraction_cents: parse_scaled(cols[4], 2)?,
+        };
+        Some((known_csv_key(cols[0])?, bracket))
+    })?;
+    Ok(rows
+        .into_iter()
+        .filter(|(_, (row_key, _))| *row_key == key)
+        .map(|(line, (_, bracket))| (line, bracket))
+        .collect())
+}
+
+/// Parse a Tax Rate Schedules CSV for one filing status, keeping each row's
+/// line number.
+pub(crate) fn parse_rate_schedule_lines(
+    csv: &CsvFile<'_>,
+    status: FilingStatus,
+) -> Result<Vec<(usize, TaxBracket)>, TaxError> {
+    let key = filing_status_csv_key(status);
+    let rows = parse_rows(csv, 4, |cols| {
+        let bracket = TaxBracket {
+            income_min: cols[1].parse().ok()?,
+            income_max: parse_income_max(cols[2])?,
+            rate: cols[3].parse().ok()?,
+        };
+        Some((known_csv_key(cols[0])?, bracket))
+    })?;
+    Ok(rows
+        .into_iter()
+        .filter(|(_, (row_key, _))| *row_key == key)
+        .map(|(line, (_, bracket))| (line, bracket))
+        .collect())
+}
+
+/// Parse a non-negative decimal string into an integer scaled by
+/// `10^decimals`, without going through floating point.
+///
+/// For example, `parse_scaled("0.22", 4)` is `Some(2200)` and
+/// `parse_scaled("30452.75", 2)` is `Some(3045275)`. Returns [`None`] if the
+/// string is malformed or has more fractional digits than `decimals`.
+fn parse_scaled(s: &str, decimals: u32) -> Option<i64> {
+    let (whole, frac) = s.split_once('.').unwrap_or((s, ""));
+    if whole.is_empty() || frac.len() > decimals as usize {
+        return None;
+    }
+    if !whole
+        .bytes()
+        .chain(frac.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let scale = 10_i64.pow(decimals);
+    let frac_scale = 10_i64.pow(decimals - frac.len() as u32);
+    let frac_value: i64 = if frac.is_empty() {
+        0
+    } else {
+        frac.parse().ok()?
+    };
+    whole
+        .parse::<i64>()
+        .ok()?
+        .checked_mul(scale)?
+        .checked_add(frac_value * frac_scale)
+}
+
+/// Return the static filing status key matching a CSV column, if it is one of
+/// the four keys used in the data files.
+fn known_csv_key(col: &str) -> Option<&'static str> {
+    [
+        "single",
+        "married_filing_jointly",
+        "married_filing_separately",
+        "head_of_household",
+    ]
+    .into_iter()
+    .find(|key| *key == col)
 }
 
 /// Map a [`FilingStatus`] to the corresponding key used in the CSV files.
@@ -140,3 +883,116 @@ fn filing_status_csv_key(status: FilingStatus) -> &'static str {
         FilingStatus::HeadOfHousehold => "head_of_household",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn csv(contents: &str) -> CsvFile<'_> {
+        CsvFile {
+            path: "data/test/file.csv",
+            contents,
+        }
+    }
+
+    fn rows<T>(parsed: Result<Vec<(usize, T)>, TaxError>) -> Vec<T> {
+        parsed.unwrap().into_iter().map(|(_, row)| row).collect()
+    }
+
+    #[test]
+    fn generated_years_match_tax_years() {
+        assert_eq!(DATA_YEARS, TaxYear::ALL.map(u16::from));
+    }
+
+    #[test]
+    fn generated_data_matches_csv() {
+        for year in TaxYear::ALL {
+            let (table, worksheet_csv) = csv_for_year(year);
+            assert_eq!(tax_table(year).len(), 2062);
+            assert_eq!(tax_table(year), rows(parse_tax_table_lines(&table)));
+            for status in FilingStatus::ALL {
+                assert_eq!(
+                    worksheet(year, status),
+                    rows(parse_worksheet_lines(&worksheet_csv, status))
+                );
+                assert_eq!(
+                    rate_schedule(year, status),
+                    rows(parse_rate_schedule_lines(
+                        &rate_schedule_csv_for_year(year),
+                        status
+                    ))
+                );
+                assert_eq!(rate_schedule(year, status).len(), 7);
+                assert!(!filing_thresholds(year, status).is_empty());
+                assert_eq!(capital_gains_schedule(year, status).len(), 3);
+            }
+        }
+    }
+
+    #[test]
+    fn worksheet_bracket_new_matches_parsed() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for bracket in worksheet(year, status) {
+                    let built = WorksheetBracket::new(
+                        bracket.income_min(),
+                        bracket.income_max(),
+                        bracket.rate_basis_points(),
+                        bracket.subtraction_cents(),
+                    );
+                    assert_eq!(&built, bracket);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_row_reports_line() {
+        let table = csv("income_min,income_max,single,mfj,mfs,hoh\n0,5,0,0,0,0\n5,15,1,x,1,1\n");
+        assert_eq!(
+            parse_tax_table_lines(&table).unwrap_err(),
+            TaxError::MalformedData {
+                file: "data/test/file.csv".to_string(),
+                line: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn malformed_row_for_other_status_is_reported() {
+        let worksheet = csv("header\nsingle,100000,,0.24,6600.0\nhead_of_household,100000,\n");
+        assert_eq!(
+            parse_worksheet_lines(&worksheet, FilingStatus::Single).unwrap_err(),
+            TaxError::MalformedData {
+                file: "data/test/file.csv".to_string(),
+                line: 3,
+            }
+        );
+        let unknown = csv("header\nsingel,0,,0.10\n");
+        assert!(parse_rate_schedule_lines(&unknown, FilingStatus::Single).is_err());
+    }
+
+    #[test]
+    fn blank_lines_and_crlf_are_accepted() {
+        let schedule = csv("header\r\nsingle,0,,0.10\r\n\r\n");
+        assert_eq!(
+            parse_rate_schedule_lines(&schedule, FilingStatus::Single)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn parse_scaled_values() {
+        assert_eq!(parse_scaled("0.22", 4), Some(2200));
+        assert_eq!(parse_scaled("0.375", 4), Some(3750));
+        assert_eq!(parse_scaled("30452.75", 2), Some(3_045_275));
+        assert_eq!(parse_scaled("5086.0", 2), Some(508_600));
+        assert_eq!(parse_scaled("5086", 2), Some(508_600));
+        assert_eq!(parse_scaled("0.12345", 4), None);
+        assert_eq!(parse_scaled("-1.0", 2), None);
+        assert_eq!(parse_scaled(".5", 2), None);
+        assert_eq!(parse_scaled("", 2), None);
+    }
+}
@@ -0,0 +1,79 @@
+//! Mapping calendar dates to tax years (requires the `chrono` feature).
+
+use chrono::Datelike;
+
+use crate::types::{TaxError, TaxYear};
+
+impl TaxYear {
+    /// The tax year containing a calendar date.
+    ///
+    /// Individual tax years follow the calendar year, so this is the date's
+    /// year. Accepts any [`chrono`] date or date-time, such as
+    /// [`NaiveDate`](chrono::NaiveDate) or [`DateTime`](chrono::DateTime).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::UnsupportedYear`] if the crate has no data for the
+    /// date's year, and [`TaxError::InvalidYear`] if the year is outside
+    /// `0..=65535`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "year-2024")] {
+    /// use chrono::NaiveDate;
+    /// use us_tax_brackets::{TaxError, TaxYear};
+    ///
+    /// let payday = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    /// assert_eq!(TaxYear::for_date(payday), Ok(TaxYear::Y2024));
+    ///
+    /// let future = NaiveDate::from_ymd_opt(2031, 1, 15).unwrap();
+    /// assert_eq!(TaxYear::for_date(future), Err(TaxError::UnsupportedYear(2031)));
+    /// # }
+    /// ```
+    pub fn for_date(date: impl Datelike) -> Result<TaxYear, TaxError> {
+        let year = date.year();
+        let year = u16::try_from(year).map_err(|_| TaxError::InvalidYear(year.to_string()))?;
+        TaxYear::try_from(year)
+    }
+
+    /// The tax year containing today's date in the local time zone.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::UnsupportedYear`] if the crate has no data for the
+    /// current year yet.
+    pub fn current() -> Result<TaxYear, TaxError> {
+        TaxYear::for_date(chrono::Local::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    #[cfg(all(feature = "year-2023", feature = "year-2025"))]
+    #[test]
+    fn year_boundaries() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(TaxYear::for_date(date(2023, 1, 1)), Ok(TaxYear::Y2023));
+        assert_eq!(TaxYear::for_date(date(2023, 12, 31)), Ok(TaxYear::Y2023));
+        assert_eq!(TaxYear::for_date(date(2025, 6, 30)), Ok(TaxYear::Y2025));
+        assert_eq!(
+            TaxYear::for_date(date(2017, 12, 31)),
+            Err(TaxError::UnsupportedYear(2017))
+        );
+        assert_eq!(
+            TaxYear::for_date(date(-5, 1, 1)),
+            Err(TaxError::InvalidYear("-5".to_string()))
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn date_times() {
+        let moment = Utc.with_ymd_and_hms(2024, 4, 15, 12, 0, 0).unwrap();
+        assert_eq!(TaxYear::for_date(moment), Ok(TaxYear::Y2024));
+    }
+}
@@ -0,0 +1,152 @@
+//! [`rust_decimal`] computation API (requires the `decimal` feature).
+//!
+//! Worksheet rates and subtraction amounts are converted to [`Decimal`] from
+//! the exact basis-point and cent values in the embedded data, so no `f64`
+//! arithmetic is involved anywhere on this path.
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+use crate::compute::{WORKSHEET_THRESHOLD, compute_from_tax_table};
+use crate::data::{self, WorksheetBracket};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+impl WorksheetBracket {
+    /// The worksheet rate as an exact decimal (e.g., `0.2200`).
+    fn rate_decimal(&self) -> Decimal {
+        Decimal::new(self.rate_basis_points, 4)
+    }
+
+    /// The worksheet subtraction amount as an exact decimal (e.g., `6957.50`).
+    fn subtraction_decimal(&self) -> Decimal {
+        Decimal::new(self.subtraction_cents, 2)
+    }
+}
+
+/// Compute federal income tax using [`Decimal`] arithmetic.
+///
+/// - **Income < $100,000** — Returns the whole-dollar Tax Table amount for the
+///   row containing `taxable_income`.
+///
+/// - **Income >= $100,000** — Returns the exact, unrounded worksheet result
+///   `taxable_income × rate − subtraction_amount`. Round it with
+///   [`Decimal::round_dp_with_strategy`] to the precision your application
+///   needs; [`compute_tax`](crate::compute_tax) rounds half-up to whole
+///   dollars.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `taxable_income` is negative.
+/// Returns [`TaxError::NoBracketFound`] if no matching bracket exists (should
+/// not occur with valid embedded data).
+/// Returns [`TaxError::IncomeOutOfRange`] if the tax does not fit in a
+/// [`Decimal`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use rust_decimal::Decimal;
+/// use us_tax_brackets::{compute_tax_decimal, FilingStatus, TaxYear};
+///
+/// // 2024: 150000.25 × 0.24 − 6957.50 = 29042.56
+/// let income = Decimal::new(15_000_025, 2);
+/// let tax = compute_tax_decimal(TaxYear::Y2024, FilingStatus::Single, income).unwrap();
+/// assert_eq!(tax, Decimal::new(2_904_256, 2));
+/// # }
+/// ```
+pub fn compute_tax_decimal(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: Decimal,
+) -> Result<Decimal, TaxError> {
+    if taxable_income < Decimal::ZERO {
+        return Err(TaxError::NegativeIncome);
+    }
+    if taxable_income.is_zero() {
+        return Ok(Decimal::ZERO);
+    }
+
+    // Whole-dollar part of the income, for table lookups and error reports
+    let dollars = taxable_income.trunc().to_i64().unwrap_or(i64::MAX);
+
+    if taxable_income < Decimal::from(WORKSHEET_THRESHOLD) {
+        let tax = compute_from_tax_table(year, status, dollars)?;
+        return Ok(Decimal::from(tax));
+    }
+
+    for bracket in data::worksheet(year, status) {
+        let min = Decimal::from(bracket.income_min);
+        let in_range = match bracket.income_max {
+            Some(max) => taxable_income >= min && taxable_income <= Decimal::from(max),
+            None => taxable_income > min,
+        };
+        if in_range {
+            return taxable_income
+                .checked_mul(bracket.rate_decimal())
+                .and_then(|product| product.checked_sub(bracket.subtraction_decimal()))
+                .ok_or(TaxError::IncomeOutOfRange);
+        }
+    }
+
+    Err(TaxError::NoBracketFound {
+        year,
+        status,
+        taxable_income: dollars,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::{compute_tax, compute_tax_cents};
+    use rust_decimal::RoundingStrategy;
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn zero_and_negative() {
+        assert_eq!(
+            compute_tax_decimal(TaxYear::Y2025, FilingStatus::Single, Decimal::ZERO),
+            Ok(Decimal::ZERO)
+        );
+        assert_eq!(
+            compute_tax_decimal(TaxYear::Y2025, FilingStatus::Single, Decimal::new(-1, 2)),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn table_below_100k() {
+        let tax = compute_tax_decimal(
+            TaxYear::Y2025,
+            FilingStatus::Single,
+            Decimal::new(9_999_999, 2),
+        )
+        .unwrap();
+        assert_eq!(tax, Decimal::from(16_909));
+    }
+
+    #[test]
+    fn matches_integer_apis_after_rounding() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for income in [100_000, 150_000, 412_345, 2_000_000] {
+                    let exact = compute_tax_decimal(year, status, Decimal::from(income)).unwrap();
+                    let dollars = exact
+                        .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+                        .to_i64()
+                        .unwrap();
+                    assert_eq!(dollars, compute_tax(year, status, income).unwrap());
+                    let cents = exact
+                        .round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero)
+                        * Decimal::from(100);
+                    assert_eq!(
+                        cents.to_i64().unwrap(),
+                        compute_tax_cents(year, status, income * 100).unwrap()
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,430 @@
+//! Standard deduction amounts, and tax computed from adjusted gross income.
+
+use crate::compute::{compute_tax, income_to_i64};
+use crate::data;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The basic standard deduction for `year` and `status`, before any
+/// additional amounts for age or blindness.
+///
+/// These are the amounts in the Form 1040 instructions, which taxable income
+/// is figured after. Qualifying surviving spouses get the married filing
+/// jointly amount. People who can be claimed as someone else's dependent get
+/// a smaller deduction.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{compute_tax, standard_deduction, FilingStatus, TaxYear};
+///
+/// let deduction = standard_deduction(TaxYear::Y2024, FilingStatus::Single);
+/// assert_eq!(deduction, 14_600);
+///
+/// let agi = 60_000;
+/// let tax = compute_tax(TaxYear::Y2024, FilingStatus::Single, agi - deduction).unwrap();
+/// assert_eq!(tax, 5_219);
+/// # }
+/// ```
+pub const fn standard_deduction(year: TaxYear, status: FilingStatus) -> i64 {
+    data::standard_deduction(year, status).basic
+}
+
+/// The additional standard deduction for each box checked on Form 1040 for
+/// being 65 or older or blind.
+///
+/// The amount is higher for single filers and heads of household than for
+/// married filers and qualifying surviving spouses.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{additional_standard_deduction, FilingStatus, TaxYear};
+///
+/// assert_eq!(additional_standard_deduction(TaxYear::Y2024, FilingStatus::Single), 1_950);
+/// assert_eq!(
+///     additional_standard_deduction(TaxYear::Y2024, FilingStatus::MarriedFilingJointly),
+///     1_550
+/// );
+/// # }
+/// ```
+pub const fn additional_standard_deduction(year: TaxYear, status: FilingStatus) -> i64 {
+    data::standard_deduction(year, status).additional
+}
+
+/// The standard deduction for a return with `age65` people 65 or older and
+/// `blind` people who are blind, as checked on Form 1040.
+///
+/// This is the [`standard_deduction`] plus one
+/// [`additional_standard_deduction`] per box checked. Married couples can
+/// check up to two boxes of each kind, since a married person filing
+/// separately may also claim their spouse's boxes if the spouse had no
+/// income and cannot be claimed as a dependent. Every other status is
+/// limited to one box of each kind; larger counts are treated as the
+/// maximum.
+///
+/// The separate deduction for seniors of 2025 through 2028 is taken on
+/// Schedule 1-A, not as part of the standard deduction, so it is not
+/// included.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{standard_deduction_for, FilingStatus, TaxYear};
+///
+/// // Both spouses 65 or older, one of them blind
+/// let deduction = standard_deduction_for(TaxYear::Y2025, FilingStatus::MarriedFilingJointly, 2, 1);
+/// assert_eq!(deduction, 31_500 + 3 * 1_600);
+/// # }
+/// ```
+pub fn standard_deduction_for(year: TaxYear, status: FilingStatus, age65: u8, blind: u8) -> i64 {
+    let max_boxes = match status {
+        FilingStatus::MarriedFilingJointly | FilingStatus::MarriedFilingSeparately => 2,
+        _ => 1,
+    };
+    let boxes = i64::from(age65.min(max_boxes) + blind.min(max_boxes));
+    standard_deduction(year, status) + boxes * additional_standard_deduction(year, status)
+}
+
+/// The standard deduction of someone who can be claimed as a dependent on
+/// another person's return, given their `earned_income`.
+///
+/// This follows the Standard Deduction Worksheet for Dependents in the Form
+/// 1040 instructions: the greater of a minimum amount ($1,300 for 2024) or
+/// earned income plus a fixed addition ($450 for 2024), but no more than the
+/// single filer's [`standard_deduction`]. Earned income includes wages and
+/// net self-employment earnings, and for this purpose taxable scholarships.
+/// The cap is the same for married dependents filing separately.
+///
+/// A dependent who is 65 or older or blind adds an
+/// [`additional_standard_deduction`] per box to the result.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `earned_income` is negative and
+/// [`TaxError::IncomeOutOfRange`] if it does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{dependent_standard_deduction, TaxYear};
+///
+/// // A student with only investment income gets the minimum
+/// assert_eq!(dependent_standard_deduction(TaxYear::Y2024, 0).unwrap(), 1_300);
+/// // Summer job wages plus $450
+/// assert_eq!(dependent_standard_deduction(TaxYear::Y2024, 4_000).unwrap(), 4_450);
+/// // Capped at the regular deduction
+/// assert_eq!(dependent_standard_deduction(TaxYear::Y2024, 20_000).unwrap(), 14_600);
+/// # }
+/// ```
+pub fn dependent_standard_deduction(
+    year: TaxYear,
+    earned_income: impl TryInto<i64>,
+) -> Result<i64, TaxError> {
+    let earned_income = income_to_i64(earned_income)?;
+    if earned_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let amounts = data::dependent_standard_deduction(year);
+    let deduction = earned_income
+        .saturating_add(amounts.earned_income_addition)
+        .max(amounts.minimum);
+    Ok(deduction.min(standard_deduction(year, FilingStatus::Single)))
+}
+
+/// The deduction subtracted from adjusted gross income by
+/// [`compute_tax_from_agi`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum DeductionChoice {
+    /// The basic [`standard_deduction`] for the filing status.
+    #[default]
+    Standard,
+    /// The standard deduction with the additional amounts for the people on
+    /// the return who are 65 or older or blind, as in
+    /// [`standard_deduction_for`].
+    StandardWithAdditional {
+        /// Number of people on the return who are 65 or older.
+        age65: u8,
+        /// Number of people on the return who are blind.
+        blind: u8,
+    },
+    /// The [`dependent_standard_deduction`] for someone claimed as a
+    /// dependent.
+    Dependent {
+        /// The dependent's earned income.
+        earned_income: i64,
+    },
+    /// Itemized deductions from Schedule A, in whole dollars.
+    Itemized(i64),
+}
+
+impl DeductionChoice {
+    /// The amount of the deduction for `year` and `status`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::NegativeIncome`] if an itemized amount or a
+    /// dependent's earned income is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "year-2024")] {
+    /// use us_tax_brackets::{DeductionChoice, FilingStatus, TaxYear};
+    ///
+    /// let choice = DeductionChoice::StandardWithAdditional { age65: 1, blind: 0 };
+    /// assert_eq!(choice.amount(TaxYear::Y2024, FilingStatus::Single), Ok(16_550));
+    /// # }
+    /// ```
+    pub fn amount(self, year: TaxYear, status: FilingStatus) -> Result<i64, TaxError> {
+        match self {
+            DeductionChoice::Standard => Ok(standard_deduction(year, status)),
+            DeductionChoice::StandardWithAdditional { age65, blind } => {
+                Ok(standard_deduction_for(year, status, age65, blind))
+            }
+            DeductionChoice::Dependent { earned_income } => {
+                dependent_standard_deduction(year, earned_income)
+            }
+            DeductionChoice::Itemized(amount) if amount < 0 => Err(TaxError::NegativeIncome),
+            DeductionChoice::Itemized(amount) => Ok(amount),
+        }
+    }
+}
+
+/// Compute federal income tax from adjusted gross income (Form 1040, line
+/// 11).
+///
+/// Subtracts the chosen `deduction` to get taxable income, treating a
+/// result below zero as zero, and computes the tax on it with
+/// [`compute_tax`]. A negative `agi`, as from a net operating loss, gives a
+/// taxable income of zero. The qualified business income deduction and the
+/// 2025 Schedule 1-A deductions are not included; subtract them from `agi`
+/// first.
+///
+/// # Errors
+///
+/// Returns [`TaxError::IncomeOutOfRange`] if `agi` does not fit in an
+/// `i64`, and the same errors as [`DeductionChoice::amount`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_tax, compute_tax_from_agi, DeductionChoice, FilingStatus, TaxYear};
+///
+/// let year = TaxYear::Y2025;
+/// let status = FilingStatus::Single;
+/// assert_eq!(
+///     compute_tax_from_agi(year, status, 90_750, DeductionChoice::Standard),
+///     compute_tax(year, status, 75_000)
+/// );
+/// assert_eq!(
+///     compute_tax_from_agi(year, status, 90_750, DeductionChoice::Itemized(20_750)),
+///     compute_tax(year, status, 70_000)
+/// );
+/// // No tax when the deduction exceeds income
+/// assert_eq!(compute_tax_from_agi(year, status, 9_000, DeductionChoice::Standard), Ok(0));
+/// # }
+/// ```
+pub fn compute_tax_from_agi(
+    year: TaxYear,
+    status: FilingStatus,
+    agi: impl TryInto<i64>,
+    deduction: DeductionChoice,
+) -> Result<i64, TaxError> {
+    let agi = income_to_i64(agi)?;
+    let deduction = deduction.amount(year, status)?;
+    compute_tax(year, status, agi.saturating_sub(deduction).max(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amounts_by_year() {
+        let single = TaxYear::ALL.map(|year| standard_deduction(year, FilingStatus::Single));
+        assert_eq!(
+            single,
+            TaxYear::select([
+                12_000, 12_200, 12_400, 12_550, 12_950, 13_850, 14_600, 15_750
+            ])
+        );
+        let head = TaxYear::ALL.map(|year| standard_deduction(year, FilingStatus::HeadOfHousehold));
+        assert_eq!(
+            head,
+            TaxYear::select([
+                18_000, 18_350, 18_650, 18_800, 19_400, 20_800, 21_900, 23_625
+            ])
+        );
+        for year in TaxYear::ALL {
+            let amount = |status| standard_deduction(year, status);
+            assert_eq!(
+                amount(FilingStatus::MarriedFilingJointly),
+                2 * amount(FilingStatus::Single)
+            );
+            assert_eq!(
+                amount(FilingStatus::QualifyingSurvivingSpouse),
+                amount(FilingStatus::MarriedFilingJointly)
+            );
+            assert_eq!(
+                amount(FilingStatus::MarriedFilingSeparately),
+                amount(FilingStatus::Single)
+            );
+        }
+    }
+
+    #[cfg(all(feature = "year-2018", feature = "year-2023", feature = "year-2025"))]
+    #[test]
+    fn additional_amounts() {
+        let additional = |year, status| additional_standard_deduction(year, status);
+        assert_eq!(additional(TaxYear::Y2018, FilingStatus::Single), 1_600);
+        assert_eq!(
+            additional(TaxYear::Y2018, FilingStatus::MarriedFilingJointly),
+            1_300
+        );
+        assert_eq!(
+            additional(TaxYear::Y2023, FilingStatus::HeadOfHousehold),
+            1_850
+        );
+        assert_eq!(
+            additional(TaxYear::Y2023, FilingStatus::MarriedFilingSeparately),
+            1_500
+        );
+        assert_eq!(
+            additional(TaxYear::Y2025, FilingStatus::QualifyingSurvivingSpouse),
+            1_600
+        );
+        for year in TaxYear::ALL {
+            assert!(
+                additional(year, FilingStatus::Single)
+                    > additional(year, FilingStatus::MarriedFilingJointly)
+            );
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn boxes_are_capped_by_status() {
+        let year = TaxYear::Y2024;
+        assert_eq!(
+            standard_deduction_for(year, FilingStatus::Single, 0, 0),
+            14_600
+        );
+        assert_eq!(
+            standard_deduction_for(year, FilingStatus::Single, 1, 1),
+            18_500
+        );
+        assert_eq!(
+            standard_deduction_for(year, FilingStatus::Single, 2, 2),
+            18_500
+        );
+        assert_eq!(
+            standard_deduction_for(year, FilingStatus::HeadOfHousehold, 1, 0),
+            23_850
+        );
+        assert_eq!(
+            standard_deduction_for(year, FilingStatus::MarriedFilingJointly, 2, 2),
+            29_200 + 4 * 1_550
+        );
+        assert_eq!(
+            standard_deduction_for(year, FilingStatus::MarriedFilingSeparately, 2, 0),
+            14_600 + 2 * 1_550
+        );
+        assert_eq!(
+            standard_deduction_for(year, FilingStatus::QualifyingSurvivingSpouse, 2, 0),
+            29_200 + 1_550
+        );
+        assert_eq!(
+            standard_deduction_for(year, FilingStatus::MarriedFilingJointly, u8::MAX, u8::MAX),
+            29_200 + 4 * 1_550
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn dependents() {
+        let minimums = TaxYear::ALL.map(|year| dependent_standard_deduction(year, 0).unwrap());
+        assert_eq!(
+            minimums,
+            TaxYear::select([1_050, 1_100, 1_100, 1_100, 1_150, 1_250, 1_300, 1_350])
+        );
+
+        let year = TaxYear::Y2025;
+        let deduction = |earned: i64| dependent_standard_deduction(year, earned).unwrap();
+        // Below $900 of earned income the minimum applies.
+        assert_eq!(deduction(899), 1_350);
+        assert_eq!(deduction(900), 1_350);
+        assert_eq!(deduction(901), 1_351);
+        assert_eq!(deduction(15_300), 15_750);
+        assert_eq!(deduction(15_301), 15_750);
+        assert_eq!(deduction(i64::MAX), 15_750);
+        assert_eq!(
+            dependent_standard_deduction(year, -1),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            dependent_standard_deduction(year, u64::MAX),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn tax_from_agi() {
+        let year = TaxYear::Y2024;
+        let from_agi =
+            |status, agi: i64, deduction| compute_tax_from_agi(year, status, agi, deduction);
+        for status in FilingStatus::ALL {
+            for agi in (0..400_000).step_by(7_919) {
+                let taxable = (agi - standard_deduction(year, status)).max(0);
+                assert_eq!(
+                    from_agi(status, agi, DeductionChoice::Standard),
+                    compute_tax(year, status, taxable),
+                    "{status} {agi}"
+                );
+            }
+            assert_eq!(from_agi(status, -50_000, DeductionChoice::Standard), Ok(0));
+            assert_eq!(
+                from_agi(status, i64::MIN, DeductionChoice::Itemized(1)),
+                Ok(0)
+            );
+        }
+
+        let status = FilingStatus::Single;
+        assert_eq!(
+            from_agi(
+                status,
+                40_000,
+                DeductionChoice::Dependent {
+                    earned_income: 4_000
+                }
+            ),
+            compute_tax(year, status, 35_550)
+        );
+        assert_eq!(
+            from_agi(
+                status,
+                40_000,
+                DeductionChoice::StandardWithAdditional { age65: 1, blind: 1 }
+            ),
+            compute_tax(year, status, 21_500)
+        );
+        assert_eq!(
+            from_agi(status, 40_000, DeductionChoice::Itemized(-1)),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            compute_tax_from_agi(year, status, u64::MAX, DeductionChoice::Standard),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
@@ -0,0 +1,277 @@
+//! The American opportunity and lifetime learning credits (Form 8863).
+
+use crate::compute::income_to_i64;
+use crate::data::{self, EducationCreditPhaseout};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The education credit claimed for a student. A student's expenses can be
+/// used for only one of the two credits in a year.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum EducationCredit {
+    /// The American opportunity credit, for the first four years of
+    /// postsecondary education: 100% of the first $2,000 of expenses and
+    /// 25% of the next $2,000, per student.
+    #[default]
+    AmericanOpportunity,
+    /// The lifetime learning credit: 20% of up to $10,000 of expenses, per
+    /// return.
+    LifetimeLearning,
+}
+
+/// One student's adjusted qualified education expenses, in whole dollars.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StudentExpenses {
+    /// The credit claimed for the student.
+    pub credit: EducationCredit,
+    /// Tuition, fees, and course materials, less tax-free assistance such
+    /// as scholarships.
+    pub qualified_expenses: i64,
+}
+
+/// The education credits on Form 8863, returned by
+/// [`compute_education_credits`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EducationCredits {
+    /// The American opportunity credit after the phaseout (line 7).
+    pub american_opportunity: i64,
+    /// The refundable 40% of the American opportunity credit (line 8).
+    pub refundable_american_opportunity: i64,
+    /// The lifetime learning credit after the phaseout (line 18).
+    pub lifetime_learning: i64,
+}
+
+impl EducationCredits {
+    /// The credits that can only reduce the tax: the rest of the American
+    /// opportunity credit plus the lifetime learning credit. The Credit
+    /// Limit Worksheet limits them to the tax, which is left to the caller.
+    pub fn nonrefundable(&self) -> i64 {
+        self.american_opportunity - self.refundable_american_opportunity + self.lifetime_learning
+    }
+
+    /// Both credits together, before any limit by the tax.
+    pub fn total(&self) -> i64 {
+        self.american_opportunity + self.lifetime_learning
+    }
+}
+
+/// Compute the American opportunity and lifetime learning credits on Form
+/// 8863 for a return with modified adjusted gross income `magi` and the
+/// `qualified_expenses` of each student.
+///
+/// Each credit is reduced in proportion as `magi` moves through its
+/// phaseout range, $80,000 to $90,000 ($160,000 to $180,000 married filing
+/// jointly); before 2021 the lifetime learning credit phased out over a
+/// lower, inflation-adjusted range. The fraction of the credit allowed is
+/// rounded to three decimal places and the credits to whole dollars.
+/// Married people filing separately get neither credit.
+///
+/// 40% of the American opportunity credit is refundable, unless the
+/// taxpayer is a student under 24 subject to the kiddie tax; that test, the
+/// four-year limit, and the limit by the tax are left to the caller.
+///
+/// # Errors
+///
+/// Returns [`TaxError::IncomeOutOfRange`] if `magi` does not fit in an
+/// `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{
+///     compute_education_credits, EducationCredit, FilingStatus, StudentExpenses, TaxYear,
+/// };
+///
+/// let students = [
+///     StudentExpenses { credit: EducationCredit::AmericanOpportunity, qualified_expenses: 6_000 },
+///     StudentExpenses { credit: EducationCredit::LifetimeLearning, qualified_expenses: 3_000 },
+/// ];
+/// let status = FilingStatus::MarriedFilingJointly;
+/// let credits = compute_education_credits(TaxYear::Y2024, status, 120_000, &students).unwrap();
+/// assert_eq!(credits.american_opportunity, 2_500);
+/// assert_eq!(credits.refundable_american_opportunity, 1_000);
+/// assert_eq!(credits.lifetime_learning, 600);
+///
+/// // Halfway through the phaseout, half of each credit is left.
+/// let credits = compute_education_credits(TaxYear::Y2024, status, 170_000, &students).unwrap();
+/// assert_eq!(credits.total(), 1_250 + 300);
+/// # }
+/// ```
+pub fn compute_education_credits(
+    year: TaxYear,
+    status: FilingStatus,
+    magi: impl TryInto<i64>,
+    qualified_expenses: &[StudentExpenses],
+) -> Result<EducationCredits, TaxError> {
+    let magi = income_to_i64(magi)?;
+    if status == FilingStatus::MarriedFilingSeparately {
+        return Ok(EducationCredits::default());
+    }
+    let mut american_opportunity = 0_i64;
+    let mut lifetime_learning_expenses = 0_i64;
+    for student in qualified_expenses {
+        let expenses = student.qualified_expenses.max(0);
+        match student.credit {
+            EducationCredit::AmericanOpportunity => {
+                // Rounded half up to the dollar
+                let over = (expenses.min(4_000) - 2_000).max(0);
+                american_opportunity += expenses.min(2_000) + (over + 2) / 4;
+            }
+            EducationCredit::LifetimeLearning => {
+                lifetime_learning_expenses = lifetime_learning_expenses.saturating_add(expenses);
+            }
+        }
+    }
+    let lifetime_learning = lifetime_learning_expenses.min(10_000) / 5;
+
+    let joint = status == FilingStatus::MarriedFilingJointly;
+    let [american_opportunity_phaseout, lifetime_learning_phaseout] =
+        data::education_credit_phaseouts(year);
+    let american_opportunity = phase_out(
+        american_opportunity,
+        &american_opportunity_phaseout,
+        joint,
+        magi,
+    );
+    Ok(EducationCredits {
+        american_opportunity,
+        refundable_american_opportunity: (american_opportunity * 4 + 5) / 10,
+        lifetime_learning: phase_out(lifetime_learning, &lifetime_learning_phaseout, joint, magi),
+    })
+}
+
+/// `credit` times the fraction of the phaseout range that `magi` is below
+/// its end (Form 8863, lines 2–7 and 13–18).
+fn phase_out(credit: i64, phaseout: &EducationCreditPhaseout, joint: bool, magi: i64) -> i64 {
+    let (start, end) = if joint {
+        (phaseout.start_joint, phaseout.end_joint)
+    } else {
+        (phaseout.start, phaseout.end)
+    };
+    if magi <= start {
+        return credit;
+    }
+    if magi >= end {
+        return 0;
+    }
+    // The fraction in thousandths, rounded half up
+    let range = end - start;
+    let thousandths = ((end - magi) * 2_000 / range + 1) / 2;
+    (credit * thousandths + 500) / 1_000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn student(credit: EducationCredit, qualified_expenses: i64) -> StudentExpenses {
+        StudentExpenses {
+            credit,
+            qualified_expenses,
+        }
+    }
+
+    const AOTC: EducationCredit = EducationCredit::AmericanOpportunity;
+    const LLC: EducationCredit = EducationCredit::LifetimeLearning;
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn american_opportunity_credit() {
+        let year = TaxYear::Y2024;
+        let status = FilingStatus::Single;
+        let credit = |expenses: i64| {
+            compute_education_credits(year, status, 50_000, &[student(AOTC, expenses)])
+                .unwrap()
+                .american_opportunity
+        };
+        assert_eq!(credit(0), 0);
+        assert_eq!(credit(-100), 0);
+        assert_eq!(credit(1_500), 1_500);
+        assert_eq!(credit(3_000), 2_250);
+        assert_eq!(credit(2_002), 2_001);
+        assert_eq!(credit(4_000), 2_500);
+        assert_eq!(credit(10_000), 2_500);
+
+        // Each student has their own limit.
+        let students = [student(AOTC, 4_000), student(AOTC, 4_000)];
+        let credits = compute_education_credits(year, status, 50_000, &students).unwrap();
+        assert_eq!(credits.american_opportunity, 5_000);
+        assert_eq!(credits.refundable_american_opportunity, 2_000);
+        assert_eq!(credits.nonrefundable(), 3_000);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn lifetime_learning_credit() {
+        let year = TaxYear::Y2024;
+        let status = FilingStatus::HeadOfHousehold;
+        // One $10,000 limit for the whole return
+        let students = [student(LLC, 6_000), student(LLC, 6_000)];
+        let credits = compute_education_credits(year, status, 50_000, &students).unwrap();
+        assert_eq!(credits.lifetime_learning, 2_000);
+        assert_eq!(credits.refundable_american_opportunity, 0);
+        assert_eq!(credits.nonrefundable(), 2_000);
+    }
+
+    #[cfg(all(feature = "year-2019", feature = "year-2021", feature = "year-2024"))]
+    #[test]
+    fn phaseouts() {
+        let students = [student(AOTC, 4_000), student(LLC, 10_000)];
+        let credits =
+            |year, status, magi| compute_education_credits(year, status, magi, &students).unwrap();
+        let single = FilingStatus::Single;
+        let credits_2024 = credits(TaxYear::Y2024, single, 85_000);
+        assert_eq!(credits_2024.american_opportunity, 1_250);
+        assert_eq!(credits_2024.refundable_american_opportunity, 500);
+        assert_eq!(credits_2024.lifetime_learning, 1_000);
+        // 0.1112 rounds to 0.111 of the credit.
+        assert_eq!(
+            credits(TaxYear::Y2024, single, 88_888).american_opportunity,
+            278
+        );
+        assert_eq!(credits(TaxYear::Y2024, single, 90_000).total(), 0);
+        assert_eq!(
+            credits(TaxYear::Y2024, FilingStatus::MarriedFilingJointly, 170_000).total(),
+            1_250 + 1_000
+        );
+        // The lifetime learning credit phased out sooner before 2021.
+        assert_eq!(
+            credits(TaxYear::Y2019, single, 63_000).lifetime_learning,
+            1_000
+        );
+        assert_eq!(credits(TaxYear::Y2019, single, 70_000).lifetime_learning, 0);
+        assert_eq!(
+            credits(TaxYear::Y2019, single, 70_000).american_opportunity,
+            2_500
+        );
+        assert_eq!(
+            credits(TaxYear::Y2021, single, 70_000).lifetime_learning,
+            2_000
+        );
+        // Married filing separately gets neither credit.
+        assert_eq!(
+            credits(
+                TaxYear::Y2024,
+                FilingStatus::MarriedFilingSeparately,
+                20_000
+            ),
+            EducationCredits::default()
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn errors() {
+        assert_eq!(
+            compute_education_credits(TaxYear::Y2025, FilingStatus::Single, u64::MAX, &[]),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! The earned income credit.
+
+use crate::compute::income_to_i64;
+use crate::data::{self, EitcParameters};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The maximum earned income credit in `year` with `qualifying_children`,
+/// where three or more children all get the same credit.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{eitc_maximum_credit, TaxYear};
+///
+/// assert_eq!(eitc_maximum_credit(TaxYear::Y2025, 0), 649);
+/// assert_eq!(eitc_maximum_credit(TaxYear::Y2025, 3), 8_046);
+/// assert_eq!(eitc_maximum_credit(TaxYear::Y2025, 5), 8_046);
+/// # }
+/// ```
+pub const fn eitc_maximum_credit(year: TaxYear, qualifying_children: u8) -> i64 {
+    parameters(year, qualifying_children).maximum_credit
+}
+
+/// The earned income at which the earned income credit reaches its
+/// [`eitc_maximum_credit`] in `year` with `qualifying_children`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{eitc_earned_income_amount, TaxYear};
+///
+/// assert_eq!(eitc_earned_income_amount(TaxYear::Y2025, 1), 12_730);
+/// # }
+/// ```
+pub const fn eitc_earned_income_amount(year: TaxYear, qualifying_children: u8) -> i64 {
+    parameters(year, qualifying_children).earned_income_amount
+}
+
+/// The investment income above which no earned income credit is allowed in
+/// `year`.
+///
+/// Investment income includes interest (taxable and tax-exempt), dividends,
+/// capital gain net income, and net rental and royalty income.
+/// [`compute_eitc`] does not check it.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "year-2020", feature = "year-2025"))] {
+/// use us_tax_brackets::{eitc_investment_income_limit, TaxYear};
+///
+/// assert_eq!(eitc_investment_income_limit(TaxYear::Y2020), 3_650);
+/// assert_eq!(eitc_investment_income_limit(TaxYear::Y2025), 11_950);
+/// # }
+/// ```
+pub const fn eitc_investment_income_limit(year: TaxYear) -> i64 {
+    data::eitc_investment_income_limit(year)
+}
+
+/// Compute the earned income credit as the EIC Worksheet A of the Form 1040
+/// instructions does, with `earned_income`, adjusted gross income `agi`,
+/// and `qualifying_children`.
+///
+/// The credit phases in with earned income up to its maximum and phases out
+/// above a threshold that is higher for married couples filing jointly. Like
+/// the EIC Table, it is computed at the middle of the $50 range containing
+/// each income and rounded to whole dollars. When `agi` differs from
+/// `earned_income` and reaches the phaseout threshold, the credit is the
+/// smaller of the amounts for the two incomes.
+///
+/// Married people filing separately get no credit before 2021; from 2021
+/// the credit is computed with the other filers' thresholds, for a spouse who
+/// lived apart and meets the separated spouse rules. The age, residency,
+/// and [`eitc_investment_income_limit`] tests are left to the caller.
+///
+/// # Errors
+///
+/// Returns [`TaxError::IncomeOutOfRange`] if `earned_income` or `agi` does
+/// not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_eitc, FilingStatus, TaxYear};
+///
+/// let status = FilingStatus::HeadOfHousehold;
+/// // Near the maximum, then in the phaseout
+/// assert_eq!(compute_eitc(TaxYear::Y2025, status, 20_000, 20_000, 2), Ok(7_152));
+/// assert_eq!(compute_eitc(TaxYear::Y2025, status, 30_000, 30_000, 2), Ok(5_746));
+/// // Married couples filing jointly phase out later
+/// let status = FilingStatus::MarriedFilingJointly;
+/// assert_eq!(compute_eitc(TaxYear::Y2025, status, 30_000, 30_000, 2), Ok(7_152));
+/// # }
+/// ```
+pub fn compute_eitc(
+    year: TaxYear,
+    status: FilingStatus,
+    earned_income: impl TryInto<i64>,
+    agi: impl TryInto<i64>,
+    qualifying_children: u8,
+) -> Result<i64, TaxError> {
+    let earned_income = income_to_i64(earned_income)?;
+    let agi = income_to_i64(agi)?;
+    if status == FilingStatus::MarriedFilingSeparately && u16::from(year) < 2021 {
+        return Ok(0);
+    }
+    let parameters = parameters(year, qualifying_children);
+    let threshold = match status {
+        FilingStatus::MarriedFilingJointly => parameters.phaseout_threshold_joint,
+        _ => parameters.phaseout_threshold,
+    };
+    let credit = table_credit(&parameters, threshold, earned_income);
+    if agi != earned_income && agi >= threshold {
+        return Ok(credit.min(table_credit(&parameters, threshold, agi)));
+    }
+    Ok(credit)
+}
+
+const fn parameters(year: TaxYear, qualifying_children: u8) -> EitcParameters {
+    let children = if qualifying_children > 3 {
+        3
+    } else {
+        qualifying_children
+    };
+    data::eitc_parameters(year, children as usize)
+}
+
+/// The EIC Table amount for `income`: the credit at the middle of its $50
+/// range, rounded half up to whole dollars. Incomes below $1, and from
+/// the point where the credit has fully phased out, get nothing.
+fn table_credit(parameters: &EitcParameters, threshold: i64, income: i64) -> i64 {
+    // Whole dollars times basis points are ten-thousandths of a dollar.
+    let credit = |income: i64| {
+        let phase_in = i128::from(income) * i128::from(parameters.credit_rate_basis_points);
+        let phaseout = i128::from(income.saturating_sub(threshold).max(0))
+            * i128::from(parameters.phaseout_rate_basis_points);
+        phase_in.min(i128::from(parameters.maximum_credit) * 10_000) - phaseout
+    };
+    if income < 1 || credit(income) <= 0 {
+        return 0;
+    }
+    let midpoint = income / 50 * 50 + 25;
+    ((credit(midpoint).max(0) + 5_000) / 10_000) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SINGLE: FilingStatus = FilingStatus::Single;
+
+    #[cfg(all(feature = "year-2021", feature = "year-2024"))]
+    #[test]
+    fn maximum_credits() {
+        let year = TaxYear::Y2024;
+        assert_eq!(
+            [0, 1, 2, 3].map(|children| eitc_maximum_credit(year, children)),
+            [632, 4_213, 6_960, 7_830]
+        );
+        // The 2021 expansion for workers without children
+        assert_eq!(eitc_maximum_credit(TaxYear::Y2021, 0), 1_502);
+        assert_eq!(
+            compute_eitc(TaxYear::Y2021, SINGLE, 10_000, 10_000, 0),
+            Ok(1_502)
+        );
+        for year in TaxYear::ALL {
+            for children in 0..=3 {
+                let plateau = eitc_earned_income_amount(year, children) + 50;
+                assert_eq!(
+                    compute_eitc(year, SINGLE, plateau, plateau, children),
+                    Ok(eitc_maximum_credit(year, children)),
+                    "{year:?} {children}"
+                );
+            }
+        }
+    }
+
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn table_ranges() {
+        let year = TaxYear::Y2025;
+        assert_eq!(compute_eitc(year, SINGLE, 0, 0, 2), Ok(0));
+        assert_eq!(compute_eitc(year, SINGLE, -500, -500, 2), Ok(0));
+        // $1 to $50 is computed at $25.
+        assert_eq!(compute_eitc(year, SINGLE, 1, 1, 2), Ok(10));
+        assert_eq!(compute_eitc(year, SINGLE, 49, 49, 0), Ok(2));
+        assert_eq!(compute_eitc(year, SINGLE, 8_450, 8_450, 0), Ok(648));
+        assert_eq!(
+            compute_eitc(TaxYear::Y2024, SINGLE, 25_000, 25_000, 1),
+            Ok(3_845)
+        );
+        // The credit ends where it has fully phased out, at $50,434.
+        assert_eq!(compute_eitc(year, SINGLE, 50_433, 50_433, 1), Ok(1));
+        assert_eq!(compute_eitc(year, SINGLE, 50_434, 50_434, 1), Ok(0));
+        assert_eq!(compute_eitc(year, SINGLE, 100_000, 100_000, 3), Ok(0));
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn agi_above_threshold() {
+        let year = TaxYear::Y2025;
+        // AGI limits the credit once it reaches the phaseout threshold.
+        assert_eq!(compute_eitc(year, SINGLE, 20_000, 30_000, 2), Ok(5_746));
+        assert_eq!(compute_eitc(year, SINGLE, 15_000, 40_000, 2), Ok(3_640));
+        // Below the threshold, only earned income counts.
+        assert_eq!(compute_eitc(year, SINGLE, 10_000, 20_000, 2), Ok(4_010));
+        // Above it, the larger of earned income and AGI sets the credit.
+        assert_eq!(compute_eitc(year, SINGLE, 30_000, 25_000, 2), Ok(5_746));
+
+        // A higher AGI never raises the credit.
+        let credits: Vec<i64> = (20_000..=60_000)
+            .step_by(5_000)
+            .map(|agi| compute_eitc(year, SINGLE, 20_000, agi, 2).unwrap())
+            .collect();
+        assert!(
+            credits.windows(2).all(|pair| pair[1] <= pair[0]),
+            "{credits:?}"
+        );
+        assert_eq!(credits.last(), Some(&0));
+    }
+
+    #[cfg(all(feature = "year-2020", feature = "year-2024"))]
+    #[test]
+    fn married_filing_separately() {
+        let status = FilingStatus::MarriedFilingSeparately;
+        assert_eq!(
+            compute_eitc(TaxYear::Y2020, status, 15_000, 15_000, 1),
+            Ok(0)
+        );
+        assert_eq!(
+            compute_eitc(TaxYear::Y2024, status, 25_000, 25_000, 1),
+            compute_eitc(TaxYear::Y2024, SINGLE, 25_000, 25_000, 1)
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn errors() {
+        assert_eq!(
+            compute_eitc(TaxYear::Y2025, SINGLE, u64::MAX, 0, 1),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
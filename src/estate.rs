@@ -0,0 +1,202 @@
+//! Estate and gift tax.
+
+use crate::compute::income_to_i64;
+use crate::data;
+use crate::schedule::TaxBracket;
+use crate::types::{TaxError, TaxYear};
+
+/// The unified rate schedule of section 2001(c), unchanged since 2013.
+static UNIFIED_RATE_SCHEDULE: [TaxBracket; 12] = [
+    bracket(0, Some(10_000), 0.18),
+    bracket(10_000, Some(20_000), 0.20),
+    bracket(20_000, Some(40_000), 0.22),
+    bracket(40_000, Some(60_000), 0.24),
+    bracket(60_000, Some(80_000), 0.26),
+    bracket(80_000, Some(100_000), 0.28),
+    bracket(100_000, Some(150_000), 0.30),
+    bracket(150_000, Some(250_000), 0.32),
+    bracket(250_000, Some(500_000), 0.34),
+    bracket(500_000, Some(750_000), 0.37),
+    bracket(750_000, Some(1_000_000), 0.39),
+    bracket(1_000_000, None, 0.40),
+];
+
+const fn bracket(income_min: i64, income_max: Option<i64>, rate: f64) -> TaxBracket {
+    TaxBracket {
+        income_min,
+        income_max,
+        rate,
+    }
+}
+
+/// The unified rate schedule for estate and gift taxes, in ascending order.
+///
+/// The same schedule applies to every supported year. In practice only the
+/// 40% rate matters: the tax on amounts up to the
+/// [`basic_exclusion_amount`] is cancelled by the applicable credit.
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::estate_tax_brackets;
+///
+/// let brackets = estate_tax_brackets();
+/// assert_eq!(brackets.len(), 12);
+/// assert_eq!(brackets[0].rate, 0.18);
+/// assert_eq!(brackets[11].income_min, 1_000_000);
+/// assert_eq!(brackets[11].rate, 0.40);
+/// ```
+pub fn estate_tax_brackets() -> &'static [TaxBracket] {
+    &UNIFIED_RATE_SCHEDULE
+}
+
+/// The basic exclusion amount for `year`: the estate and lifetime gifts
+/// that pass free of tax.
+///
+/// A surviving spouse can add their late spouse's unused exclusion, which
+/// is not included here.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "year-2018", feature = "year-2025"))] {
+/// use us_tax_brackets::{basic_exclusion_amount, TaxYear};
+///
+/// assert_eq!(basic_exclusion_amount(TaxYear::Y2018), 11_180_000);
+/// assert_eq!(basic_exclusion_amount(TaxYear::Y2025), 13_990_000);
+/// # }
+/// ```
+pub const fn basic_exclusion_amount(year: TaxYear) -> i64 {
+    data::estate_gift_exclusions(year).basic_exclusion
+}
+
+/// The annual gift exclusion for `year`: the gifts to each recipient that
+/// need no gift tax return and use none of the [`basic_exclusion_amount`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "year-2024", feature = "year-2025"))] {
+/// use us_tax_brackets::{annual_gift_exclusion, TaxYear};
+///
+/// assert_eq!(annual_gift_exclusion(TaxYear::Y2024), 18_000);
+/// assert_eq!(annual_gift_exclusion(TaxYear::Y2025), 19_000);
+/// # }
+/// ```
+pub const fn annual_gift_exclusion(year: TaxYear) -> i64 {
+    data::estate_gift_exclusions(year).annual_exclusion
+}
+
+/// Compute the estate tax for a decedent dying in `year` with
+/// `taxable_estate`, rounded to whole dollars.
+///
+/// This is the tentative tax on `taxable_estate` from the
+/// [`estate_tax_brackets`], less the applicable credit: the tentative tax on
+/// the [`basic_exclusion_amount`]. Pass the taxable estate plus any adjusted
+/// taxable gifts (Form 706, line 3c). Gift tax paid on those gifts, a
+/// deceased spouse's unused exclusion, and other credits are not applied.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `taxable_estate` is negative and
+/// [`TaxError::IncomeOutOfRange`] if it does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_estate_tax, TaxYear};
+///
+/// assert_eq!(compute_estate_tax(TaxYear::Y2025, 13_990_000), Ok(0));
+/// // 40% of the amount over the basic exclusion
+/// assert_eq!(compute_estate_tax(TaxYear::Y2025, 20_000_000), Ok(2_404_000));
+/// # }
+/// ```
+pub fn compute_estate_tax(
+    year: TaxYear,
+    taxable_estate: impl TryInto<i64>,
+) -> Result<i64, TaxError> {
+    let taxable_estate = income_to_i64(taxable_estate)?;
+    if taxable_estate < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let credit = tentative_tax_cents(basic_exclusion_amount(year));
+    let cents = (tentative_tax_cents(taxable_estate) - credit).max(0);
+    i64::try_from((cents + 50) / 100).map_err(|_| TaxError::IncomeOutOfRange)
+}
+
+/// The tax on `amount` from the unified rate schedule, in cents.
+fn tentative_tax_cents(amount: i64) -> i128 {
+    // Whole dollars times whole percentages are whole cents.
+    UNIFIED_RATE_SCHEDULE
+        .iter()
+        .filter(|bracket| bracket.income_min < amount)
+        .map(|bracket| {
+            let top = bracket.income_max.map_or(amount, |max| max.min(amount));
+            i128::from(top - bracket.income_min) * (bracket.rate * 100.0).round() as i128
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "year-2018", feature = "year-2024"))]
+    #[test]
+    fn tentative_tax() {
+        assert_eq!(tentative_tax_cents(0), 0);
+        assert_eq!(tentative_tax_cents(10_000), 1_800 * 100);
+        assert_eq!(tentative_tax_cents(1_000_000), 345_800 * 100);
+        // The applicable credit amounts published with each exclusion
+        assert_eq!(
+            tentative_tax_cents(basic_exclusion_amount(TaxYear::Y2018)),
+            4_417_800 * 100
+        );
+        assert_eq!(
+            tentative_tax_cents(basic_exclusion_amount(TaxYear::Y2024)),
+            5_389_800 * 100
+        );
+    }
+
+    #[test]
+    fn exclusions() {
+        assert_eq!(
+            TaxYear::ALL.map(annual_gift_exclusion),
+            TaxYear::select([
+                15_000, 15_000, 15_000, 15_000, 16_000, 17_000, 18_000, 19_000
+            ])
+        );
+        let bases = TaxYear::ALL.map(basic_exclusion_amount);
+        assert!(bases.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[cfg(all(feature = "year-2018", feature = "year-2024"))]
+    #[test]
+    fn estate_tax() {
+        let year = TaxYear::Y2024;
+        assert_eq!(compute_estate_tax(year, 0), Ok(0));
+        assert_eq!(compute_estate_tax(year, 13_610_000), Ok(0));
+        assert_eq!(compute_estate_tax(year, 13_610_001), Ok(0));
+        assert_eq!(compute_estate_tax(year, 13_610_002), Ok(1));
+        assert_eq!(compute_estate_tax(year, 23_610_000), Ok(4_000_000));
+        // The same estate owes less as the exclusion grows.
+        assert_eq!(
+            compute_estate_tax(TaxYear::Y2018, 23_610_000),
+            Ok(4_972_000)
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn errors() {
+        assert_eq!(
+            compute_estate_tax(TaxYear::Y2025, -1),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            compute_estate_tax(TaxYear::Y2025, u64::MAX),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
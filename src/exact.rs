@@ -0,0 +1,181 @@
+//! Exact rate-schedule computation at every income level.
+
+use crate::breakdown::compute_tax_breakdown;
+use crate::compute::{compute_tax, income_to_i64};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The IRS tax for an income next to the exact rate-schedule tax.
+///
+/// Returned by [`table_discrepancy`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableDiscrepancy {
+    /// The taxable income compared.
+    pub taxable_income: i64,
+    /// The tax from [`compute_tax`]: the Tax Table amount below $100,000, or
+    /// the rounded worksheet amount at $100,000 and above.
+    pub table_tax: i64,
+    /// The unrounded rate-schedule tax from [`compute_tax_exact`].
+    pub exact_tax: f64,
+    /// `table_tax − exact_tax`. Positive when the IRS amount is higher.
+    pub difference: f64,
+}
+
+/// Compute federal income tax by applying the Tax Rate Schedule directly, at
+/// every income level.
+///
+/// [`compute_tax`](crate::compute_tax) follows the IRS: below $100,000 it
+/// uses the Tax Table, which charges every income in a $50 band the tax on
+/// the band's midpoint. That makes it a step function. This function instead
+/// returns the unrounded schedule tax, which rises by exactly the marginal
+/// rate for every extra dollar. It is strictly increasing and continuous,
+/// which suits optimizers and solvers.
+///
+/// At $100,000 and above the result equals the worksheet amount before
+/// rounding. Below $100,000 it can differ from the Tax Table by a few
+/// dollars.
+///
+/// # Errors
+///
+/// Returns the same errors as [`compute_tax_breakdown`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_tax, compute_tax_exact, FilingStatus, TaxYear};
+///
+/// // 2025 single: 1,192.50 + 4,386.00 + 22% × 1,525 = 5,914.00
+/// let exact = compute_tax_exact(TaxYear::Y2025, FilingStatus::Single, 50_000).unwrap();
+/// assert!((exact - 5_914.0).abs() < 1e-9);
+///
+/// // The Tax Table charges the $50,000–$50,050 midpoint
+/// assert_eq!(compute_tax(TaxYear::Y2025, FilingStatus::Single, 50_000).unwrap(), 5_920);
+/// # }
+/// ```
+pub fn compute_tax_exact(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+) -> Result<f64, TaxError> {
+    let portions = compute_tax_breakdown(year, status, taxable_income)?;
+    Ok(portions.iter().map(|portion| portion.tax).sum())
+}
+
+/// Compare the IRS tax for an income with the exact rate-schedule tax.
+///
+/// Each Tax Table row charges the schedule tax at the midpoint of its $50
+/// band, rounded to whole dollars, so incomes near the bottom of a band pay a
+/// little more than the schedule and incomes near the top a little less. The
+/// difference is at most half the band width times the marginal rate, plus
+/// rounding: about $6 in the 24% bracket. At $100,000 and above only the
+/// worksheet's rounding to whole dollars remains, so the difference is at
+/// most 50 cents.
+///
+/// # Errors
+///
+/// Returns the same errors as [`compute_tax`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{table_discrepancy, FilingStatus, TaxYear};
+///
+/// let report = table_discrepancy(TaxYear::Y2025, FilingStatus::Single, 50_000).unwrap();
+/// assert_eq!(report.table_tax, 5_920);
+/// assert!((report.exact_tax - 5_914.0).abs() < 1e-9);
+/// assert!((report.difference - 6.0).abs() < 1e-9);
+/// # }
+/// ```
+pub fn table_discrepancy(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+) -> Result<TableDiscrepancy, TaxError> {
+    let taxable_income = income_to_i64(taxable_income)?;
+    let table_tax = compute_tax(year, status, taxable_income)?;
+    let exact_tax = compute_tax_exact(year, status, taxable_income)?;
+    Ok(TableDiscrepancy {
+        taxable_income,
+        table_tax,
+        exact_tax,
+        difference: table_tax as f64 - exact_tax,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn zero_and_negative() {
+        assert_eq!(
+            compute_tax_exact(TaxYear::Y2025, FilingStatus::Single, 0),
+            Ok(0.0)
+        );
+        assert_eq!(
+            compute_tax_exact(TaxYear::Y2025, FilingStatus::Single, -1),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn strictly_increasing() {
+        for status in FilingStatus::ALL {
+            let mut previous = -1.0;
+            for income in (0..120_000).step_by(7) {
+                let tax = compute_tax_exact(TaxYear::Y2024, status, income).unwrap();
+                assert!(tax > previous, "{status} {income}");
+                previous = tax;
+            }
+        }
+    }
+
+    #[test]
+    fn matches_worksheet_above_100k() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for income in [100_000, 250_001, 1_000_001] {
+                    let exact = compute_tax_exact(year, status, income).unwrap();
+                    let tax = compute_tax(year, status, income).unwrap();
+                    assert!(
+                        (exact - tax as f64).abs() <= 0.5 + 1e-6,
+                        "{year} {status} {income}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn table_discrepancy_is_bounded() {
+        // Check both ends of every $50 band, where the difference peaks
+        for status in FilingStatus::ALL {
+            let mut largest: f64 = 0.0;
+            for band in (0..100_000).step_by(50) {
+                for income in [band, band + 49] {
+                    let report = table_discrepancy(TaxYear::Y2025, status, income).unwrap();
+                    assert!(
+                        report.difference.abs() <= 6.5,
+                        "{status} {income}: {report:?}"
+                    );
+                    largest = largest.max(report.difference.abs());
+                }
+            }
+            assert!(largest > 5.0, "{status}: {largest}");
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn discrepancy_above_100k_is_rounding() {
+        for income in [100_000, 150_000, 2_000_001] {
+            let report = table_discrepancy(TaxYear::Y2024, FilingStatus::Single, income).unwrap();
+            assert!(report.difference.abs() <= 0.5 + 1e-6, "{report:?}");
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Tax Table excerpts laid out as in the Form 1040 instructions.
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::compute::WORKSHEET_THRESHOLD;
+use crate::data::{self, TaxTableRow};
+use crate::money::group_thousands;
+use crate::types::{TaxError, TaxYear};
+
+/// The column headings, left to right.
+const HEADINGS: [&str; 6] = [
+    "At least",
+    "But less than",
+    "Single",
+    "Married filing jointly*",
+    "Married filing separately",
+    "Head of a household",
+];
+
+/// A run of Tax Table rows, returned by [`tax_table_excerpt`].
+///
+/// [`Display`](fmt::Display) prints the rows the way the Form 1040
+/// instructions do: income bounds, then the tax for each filing status, with
+/// a heading at each $1,000 and the instructions' footnote for qualifying
+/// surviving spouses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TaxTableExcerpt {
+    /// The tax year of the table.
+    pub year: TaxYear,
+    /// The rows, in ascending order of income.
+    pub rows: Vec<TaxTableRow>,
+}
+
+impl fmt::Display for TaxTableExcerpt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} Tax Table", self.year)?;
+        writeln!(f, "If line 15 (taxable income) is—   And you are—")?;
+        writeln!(f, "{}", HEADINGS.join("  "))?;
+        writeln!(
+            f,
+            "{:>width$}",
+            "Your tax is—",
+            width = HEADINGS.join("  ").len()
+        )?;
+        for row in &self.rows {
+            if row.income_min >= 1_000 && row.income_min % 1_000 == 0 {
+                writeln!(f)?;
+                writeln!(f, "{}", group_thousands(row.income_min as u64))?;
+            }
+            let cells = [
+                row.income_min,
+                row.income_max,
+                row.single,
+                row.married_filing_jointly,
+                row.married_filing_separately,
+                row.head_of_household,
+            ];
+            let line: Vec<String> = cells
+                .iter()
+                .zip(HEADINGS)
+                .map(|(&amount, heading)| {
+                    format!(
+                        "{:>width$}",
+                        group_thousands(amount as u64),
+                        width = heading.len()
+                    )
+                })
+                .collect();
+            writeln!(f, "{}", line.join("  "))?;
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "* This column must also be used by a qualifying surviving spouse."
+        )
+    }
+}
+
+/// The Tax Table rows for `year` that cover any income in `incomes`.
+///
+/// Tax-preparation software can show the excerpt around a taxpayer's income
+/// so they can check the lookup against the printed instructions. The Tax
+/// Table stops below $100,000, so the part of `incomes` above it has no
+/// rows.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `incomes` starts below zero.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{tax_table_excerpt, TaxYear};
+///
+/// let excerpt = tax_table_excerpt(TaxYear::Y2025, 40_000..40_100).unwrap();
+/// assert_eq!(excerpt.rows.len(), 2);
+/// assert_eq!(excerpt.rows[0].single, 4_565);
+///
+/// let text = excerpt.to_string();
+/// assert!(text.contains("  40,000         40,050   4,565"));
+/// # }
+/// ```
+pub fn tax_table_excerpt(year: TaxYear, incomes: Range<i64>) -> Result<TaxTableExcerpt, TaxError> {
+    if incomes.start < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let table = data::tax_table(year);
+    let end = incomes.end.min(WORKSHEET_THRESHOLD);
+    let first = table.partition_point(|row| row.income_max <= incomes.start);
+    let last = table.partition_point(|row| row.income_min < end);
+    Ok(TaxTableExcerpt {
+        year,
+        rows: table[first..last.max(first)].to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::compute_tax;
+    use crate::types::FilingStatus;
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn selects_covering_rows() {
+        let rows = |incomes| tax_table_excerpt(TaxYear::Y2024, incomes).unwrap().rows;
+        assert_eq!(rows(0..1).len(), 1);
+        assert_eq!(rows(0..25).len(), 3);
+        // A range inside one band still shows that band.
+        let band = rows(40_010..40_020);
+        assert_eq!((band[0].income_min, band[0].income_max), (40_000, 40_050));
+        assert_eq!(rows(99_990..150_000).len(), 1);
+        assert!(rows(100_000..150_000).is_empty());
+        assert!(rows(500..500).is_empty());
+        assert_eq!(
+            tax_table_excerpt(TaxYear::Y2024, -5..5),
+            Err(TaxError::NegativeIncome)
+        );
+
+        for row in rows(60_000..61_000) {
+            for status in FilingStatus::ALL {
+                assert_eq!(
+                    compute_tax(TaxYear::Y2024, status, row.income_min),
+                    Ok(row.tax_for(status))
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn renders_like_the_instructions() {
+        let text = tax_table_excerpt(TaxYear::Y2025, 40_950..41_050)
+            .unwrap()
+            .to_string();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "2025 Tax Table");
+        assert_eq!(
+            lines[2],
+            "At least  But less than  Single  Married filing jointly*  Married filing separately  \
+             Head of a household"
+        );
+        assert!(lines[3].ends_with("Your tax is—"));
+        assert_eq!(lines[5], "");
+        assert_eq!(lines[6], "41,000");
+        assert_eq!(lines.len(), 10);
+        assert!(lines[9].starts_with("* This column"));
+        // Every row lines up under the headings.
+        assert!(
+            lines[4..8]
+                .iter()
+                .filter(|line| line.contains("  "))
+                .all(|line| line.chars().count() == lines[2].chars().count())
+        );
+    }
+}
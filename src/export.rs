@@ -0,0 +1,176 @@
+//! Export of the embedded rate schedules for spreadsheets and other tools.
+
+use std::fmt::Write;
+
+use crate::data;
+use crate::types::{FilingStatus, TaxYear};
+
+/// A text format for [`export_schedule`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row.
+    Csv,
+    /// A JSON object with the year, filing status, and a list of brackets.
+    Json,
+}
+
+/// Export the Tax Rate Schedule for a year and filing status as CSV or JSON.
+///
+/// Each bracket gives `income_min`, `income_max` (empty in CSV and `null` in
+/// JSON for the top bracket), `rate`, and `subtraction_amount`: the amount
+/// that, subtracted from income times the rate, gives the tax at any income
+/// in the bracket, as on the Tax Computation Worksheet. Subtraction amounts
+/// are computed exactly from the brackets for every bracket, including
+/// those below $100,000 that the worksheet does not list.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{export_schedule, ExportFormat, FilingStatus, TaxYear};
+///
+/// let csv = export_schedule(TaxYear::Y2025, FilingStatus::Single, ExportFormat::Csv);
+/// let mut lines = csv.lines();
+/// assert_eq!(lines.next(), Some("income_min,income_max,rate,subtraction_amount"));
+/// assert_eq!(lines.next(), Some("0,11925,0.10,0.00"));
+/// assert_eq!(lines.next(), Some("11925,48475,0.12,238.50"));
+/// assert_eq!(lines.last(), Some("626350,,0.37,42979.75"));
+///
+/// let json = export_schedule(TaxYear::Y2025, FilingStatus::Single, ExportFormat::Json);
+/// assert!(json.starts_with(r#"{"year":2025,"filing_status":"single","brackets":["#));
+/// # }
+/// ```
+pub fn export_schedule(year: TaxYear, status: FilingStatus, format: ExportFormat) -> String {
+    let brackets = data::rate_schedule(year, status);
+    let mut out = String::new();
+    match format {
+        ExportFormat::Csv => out.push_str("income_min,income_max,rate,subtraction_amount\n"),
+        ExportFormat::Json => write!(
+            out,
+            r#"{{"year":{year},"filing_status":"{}","brackets":["#,
+            status_key(status)
+        )
+        .expect("writing to a String cannot fail"),
+    }
+
+    // Tax at the bottom of the current bracket, in 1/10,000 dollar.
+    let mut tax_below = 0;
+    for (index, bracket) in brackets.iter().enumerate() {
+        let basis_points = (bracket.rate * 10_000.0).round() as i64;
+        let subtraction = bracket.income_min * basis_points - tax_below;
+        if let Some(max) = bracket.income_max {
+            tax_below += (max - bracket.income_min) * basis_points;
+        }
+        let rate = format_basis_points(basis_points);
+        // Whole-dollar thresholds times basis points are whole cents.
+        let subtraction = format!("{}.{:02}", subtraction / 10_000, subtraction % 10_000 / 100);
+        let result = match format {
+            ExportFormat::Csv => {
+                let max = bracket
+                    .income_max
+                    .map(|max| max.to_string())
+                    .unwrap_or_default();
+                writeln!(out, "{},{max},{rate},{subtraction}", bracket.income_min)
+            }
+            ExportFormat::Json => {
+                let separator = if index == 0 { "" } else { "," };
+                let max = bracket
+                    .income_max
+                    .map_or_else(|| "null".to_string(), |max| max.to_string());
+                write!(
+                    out,
+                    r#"{separator}{{"income_min":{},"income_max":{max},"rate":{rate},"subtraction_amount":{subtraction}}}"#,
+                    bracket.income_min
+                )
+            }
+        };
+        result.expect("writing to a String cannot fail");
+    }
+    if format == ExportFormat::Json {
+        out.push_str("]}\n");
+    }
+    out
+}
+
+/// A rate in basis points as a decimal with at least two places, e.g.
+/// `0.10` or `0.396`.
+fn format_basis_points(basis_points: i64) -> String {
+    let mut decimal = format!("{}.{:04}", basis_points / 10_000, basis_points % 10_000);
+    while decimal.ends_with('0') && decimal.len() > decimal.find('.').unwrap_or(0) + 3 {
+        decimal.pop();
+    }
+    decimal
+}
+
+/// The `snake_case` name of a filing status, as serde writes it.
+fn status_key(status: FilingStatus) -> &'static str {
+    match status {
+        FilingStatus::Single => "single",
+        FilingStatus::MarriedFilingJointly => "married_filing_jointly",
+        FilingStatus::MarriedFilingSeparately => "married_filing_separately",
+        FilingStatus::HeadOfHousehold => "head_of_household",
+        FilingStatus::QualifyingSurvivingSpouse => "qualifying_surviving_spouse",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subtraction_matches_worksheet() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                let csv = export_schedule(year, status, ExportFormat::Csv);
+                for bracket in data::worksheet(year, status) {
+                    // The worksheet's first bracket starts at $100,000, inside
+                    // a rate-schedule bracket, so match on the upper bound.
+                    let max = bracket
+                        .income_max
+                        .map(|max| max.to_string())
+                        .unwrap_or_default();
+                    let row = csv
+                        .lines()
+                        .find(|line| line.split(',').nth(1) == Some(max.as_str()))
+                        .unwrap_or_else(|| panic!("{year} {status}: no bracket ends at {max:?}"));
+                    let subtraction: f64 = row.split(',').nth(3).unwrap().parse().unwrap();
+                    assert_eq!(
+                        (subtraction * 100.0).round() as i64,
+                        bracket.subtraction_cents,
+                        "{year} {status} {max}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn json_lists_every_bracket() {
+        let json = export_schedule(
+            TaxYear::Y2024,
+            FilingStatus::QualifyingSurvivingSpouse,
+            ExportFormat::Json,
+        );
+        assert!(json.starts_with(
+            r#"{"year":2024,"filing_status":"qualifying_surviving_spouse","brackets":[{"income_min":0,"income_max":23200,"rate":0.10,"subtraction_amount":0.00},"#
+        ));
+        assert!(
+            json.trim_end()
+                .ends_with(r#""income_max":null,"rate":0.37,"subtraction_amount":73874.50}]}"#)
+        );
+        assert_eq!(json.matches("income_min").count(), 7);
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(format_basis_points(1_000), "0.10");
+        assert_eq!(format_basis_points(3_960), "0.396");
+        assert_eq!(format_basis_points(10_000), "1.00");
+    }
+}
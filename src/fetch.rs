@@ -0,0 +1,520 @@
+//! Downloading a year's data from the IRS (requires the `fetch` feature).
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use scraper::{ElementRef, Html, Selector};
+
+use crate::tax_data::TaxData;
+use crate::types::TaxError;
+
+/// The IRS page with the current year's Form 1040 instructions.
+pub const INSTRUCTIONS_URL: &str = "https://www.irs.gov/instructions/i1040gi";
+
+/// Worksheet section labels, in the order of the CSV filing status keys.
+const WORKSHEET_SECTIONS: [(&str, &str); 4] = [
+    ("Section A", "single"),
+    ("Section B", "married_filing_jointly"),
+    ("Section C", "married_filing_separately"),
+    ("Section D", "head_of_household"),
+];
+
+/// Tax Rate Schedule labels, in the order of the CSV filing status keys.
+const RATE_SCHEDULES: [(&str, &str); 4] = [
+    ("Schedule X", "single"),
+    ("Schedule Y-1", "married_filing_jointly"),
+    ("Schedule Y-2", "married_filing_separately"),
+    ("Schedule Z", "head_of_household"),
+];
+
+/// One year's data files, downloaded and converted to the crate's CSV
+/// format.
+///
+/// The CSV text matches the repository's `data/<year>/` files (with `\n`
+/// line endings), so a new
+/// year can be written out with [`write_to_dir`](Self::write_to_dir) and
+/// contributed upstream, or used right away with
+/// [`to_tax_data`](Self::to_tax_data). The Chart A filing thresholds are not
+/// fetched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchedYear {
+    /// The tax year the data covers.
+    pub year: u16,
+    /// Where the data was downloaded from.
+    pub source_url: String,
+    /// Contents of `tax_table.csv`.
+    pub tax_table: String,
+    /// Contents of `tax_computation_worksheet.csv`.
+    pub tax_computation_worksheet: String,
+    /// Contents of `tax_rate_schedule.csv`.
+    pub tax_rate_schedule: String,
+}
+
+impl FetchedYear {
+    /// Parse a saved copy of the HTML Form 1040 instructions for `year`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::UnreadableData`] if the page is for another year
+    /// or a table cannot be found, and [`TaxError::InvalidData`] if the
+    /// parsed tables fail the checks in
+    /// [`validate_data`](crate::validate_data).
+    pub fn from_html(year: u16, html: &str) -> Result<Self, TaxError> {
+        let unreadable = |message: String| TaxError::UnreadableData {
+            file: INSTRUCTIONS_URL.to_string(),
+            message,
+        };
+        let document = Html::parse_document(html);
+        match page_year(&document) {
+            Some(page_year) if page_year == year => {}
+            Some(page_year) => {
+                return Err(unreadable(format!(
+                    "the instructions are for {page_year}, not {year}; \
+                     prior years are only published as PDFs, which \
+                     scraper/scrape.py in the repository can read"
+                )));
+            }
+            None => return Err(unreadable("the page has no tax year".to_string())),
+        }
+
+        let fetched = FetchedYear {
+            year,
+            source_url: INSTRUCTIONS_URL.to_string(),
+            tax_table: tax_table_csv(&document).map_err(unreadable)?,
+            tax_computation_worksheet: worksheet_csv(&document).map_err(unreadable)?,
+            tax_rate_schedule: rate_schedule_csv(&document).map_err(unreadable)?,
+        };
+        fetched.to_tax_data()?;
+        Ok(fetched)
+    }
+
+    /// Load the fetched files as [`TaxData`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`TaxData::from_csv`].
+    pub fn to_tax_data(&self) -> Result<TaxData, TaxError> {
+        TaxData::from_csv(
+            &self.tax_table,
+            &self.tax_computation_worksheet,
+            &self.tax_rate_schedule,
+        )
+    }
+
+    /// Write `tax_table.csv`, `tax_computation_worksheet.csv`,
+    /// `tax_rate_schedule.csv`, and `provenance.csv` into `dir`, creating it
+    /// if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from creating the directory or writing a file.
+    pub fn write_to_dir(&self, dir: impl AsRef<Path>) -> io::Result<()> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        // The current instructions' address moves on to the next year, so
+        // record the archived PDF of the same revision.
+        let year = self.year;
+        let mut provenance = String::from("file,publication,source_url,revision\n");
+        for file in [
+            "tax_table.csv",
+            "tax_computation_worksheet.csv",
+            "tax_rate_schedule.csv",
+        ] {
+            provenance.push_str(&format!(
+                "{file},{year} Instructions for Form 1040,\
+                 https://www.irs.gov/pub/irs-prior/i1040gi--{year}.pdf,{year}\n"
+            ));
+        }
+        for (name, contents) in [
+            ("tax_table.csv", &self.tax_table),
+            (
+                "tax_computation_worksheet.csv",
+                &self.tax_computation_worksheet,
+            ),
+            ("tax_rate_schedule.csv", &self.tax_rate_schedule),
+            ("provenance.csv", &provenance),
+        ] {
+            fs::write(dir.join(name), contents)?;
+        }
+        Ok(())
+    }
+}
+
+/// Download the Form 1040 instructions for `year` from the IRS and convert
+/// their tables to the crate's CSV format (requires the `fetch` feature).
+///
+/// The IRS publishes only the current year's instructions as HTML, so this
+/// works for the year the IRS page currently covers; earlier years are PDFs,
+/// which the Python scraper in the repository handles. The parsed tables go
+/// through the same checks as [`validate_data`](crate::validate_data)
+/// before they are returned.
+///
+/// # Errors
+///
+/// Returns [`TaxError::UnreadableData`] if the download fails, and the same
+/// errors as [`FetchedYear::from_html`] for the page itself.
+///
+/// # Examples
+///
+/// ```no_run
+/// use us_tax_brackets::{fetch_year, FilingStatus};
+///
+/// let fetched = fetch_year(2026).unwrap();
+/// fetched.write_to_dir("data/2026").unwrap();
+///
+/// let data = fetched.to_tax_data().unwrap();
+/// let tax = data.compute(FilingStatus::Single, 75_000).unwrap();
+/// ```
+pub fn fetch_year(year: u16) -> Result<FetchedYear, TaxError> {
+    let unreadable = |error: ureq::Error| TaxError::UnreadableData {
+        file: INSTRUCTIONS_URL.to_string(),
+        message: error.to_string(),
+    };
+    let html = ureq::get(INSTRUCTIONS_URL)
+        .header("User-Agent", "us-tax-brackets")
+        .call()
+        .map_err(unreadable)?
+        .body_mut()
+        .read_to_string()
+        .map_err(unreadable)?;
+    FetchedYear::from_html(year, &html)
+}
+
+/// The tax year in a title such as `1040 (2025) | Internal Revenue Service`.
+fn page_year(document: &Html) -> Option<u16> {
+    let title = document.select(&selector("title")).next()?;
+    let title = text(title);
+    let (_, rest) = title.split_once("1040 (")?;
+    rest.get(..4)?.parse().ok()
+}
+
+/// Build `tax_table.csv` from the first large table after the "Tax Table"
+/// heading.
+fn tax_table_csv(document: &Html) -> Result<String, String> {
+    let table = table_after(document, |text| text == "Tax Table", 100)
+        .ok_or("could not find the Tax Table")?;
+    let mut csv = String::from(
+        "income_min,income_max,single,married_filing_jointly,married_filing_separately,\
+         head_of_household\n",
+    );
+    for row in rows(table) {
+        let amounts: Option<Vec<i64>> = row.iter().take(6).map(|cell| amount(cell)).collect();
+        if let Some(amounts) = amounts.filter(|amounts| amounts.len() == 6) {
+            let line: Vec<String> = amounts.iter().map(i64::to_string).collect();
+            writeln!(csv, "{}", line.join(",")).unwrap();
+        }
+    }
+    Ok(csv)
+}
+
+/// Build `tax_computation_worksheet.csv` from Sections A through D.
+fn worksheet_csv(document: &Html) -> Result<String, String> {
+    let mut csv = String::from("filing_status,income_min,income_max,rate,subtraction_amount\n");
+    for (label, status) in WORKSHEET_SECTIONS {
+        let table = table_after(document, |text| labels_status(text, label), 1)
+            .ok_or_else(|| format!("could not find worksheet {label}"))?;
+        for row in rows(table) {
+            let [range, _, rate, _, subtraction, ..] = row.as_slice() else {
+                continue;
+            };
+            if !range.contains("100,000") && !range.contains("Over") {
+                continue;
+            }
+            let Some(rate) = rate
+                .split_once('(')
+                .and_then(|(_, rest)| rest.split_once(')'))
+                .map(|(rate, _)| rate)
+                .filter(|rate| rate.parse::<f64>().is_ok())
+            else {
+                continue;
+            };
+            let subtraction = decimal(subtraction).unwrap_or(0.0);
+            let (income_min, income_max) = match range.split_once("not over") {
+                Some((min, max)) => (amount(min), Some(amount(max))),
+                None => (amount(range), None),
+            };
+            let income_min = income_min.ok_or_else(|| format!("{label}: bad range {range:?}"))?;
+            let income_max = match income_max {
+                Some(Some(max)) => max.to_string(),
+                Some(None) => return Err(format!("{label}: bad range {range:?}")),
+                None => String::new(),
+            };
+            writeln!(
+                csv,
+                "{status},{income_min},{income_max},{rate},{subtraction:?}"
+            )
+            .unwrap();
+        }
+    }
+    Ok(csv)
+}
+
+/// Build `tax_rate_schedule.csv` from Schedules X, Y-1, Y-2, and Z.
+fn rate_schedule_csv(document: &Html) -> Result<String, String> {
+    let mut csv = String::from("filing_status,income_min,income_max,rate\n");
+    for (label, status) in RATE_SCHEDULES {
+        let table = table_after(document, |text| labels_status(text, label), 1)
+            .ok_or_else(|| format!("could not find {label}"))?;
+        for row in rows(table) {
+            let [over, not_over, tax, ..] = row.as_slice() else {
+                continue;
+            };
+            let Some(income_min) = amount(over) else {
+                continue;
+            };
+            let Some(percent) = tax
+                .split_once('%')
+                .and_then(|(before, _)| before.rsplit(|c: char| !c.is_ascii_digit()).next())
+                .and_then(|digits| digits.parse::<u32>().ok())
+            else {
+                continue;
+            };
+            let income_max = amount(not_over).map_or(String::new(), |max| max.to_string());
+            writeln!(
+                csv,
+                "{status},{income_min},{income_max},{}.{:02}",
+                percent / 100,
+                percent % 100
+            )
+            .unwrap();
+        }
+    }
+    Ok(csv)
+}
+
+/// Whether a heading such as "Schedule X—If your filing status is Single"
+/// introduces the table for `label`.
+fn labels_status(text: &str, label: &str) -> bool {
+    text.to_lowercase().contains("filing status")
+        && text
+            .split_once(label)
+            .is_some_and(|(_, rest)| !rest.starts_with(|c: char| c == '-' || c.is_alphanumeric()))
+}
+
+/// The first table with at least `min_rows` rows that follows a heading or
+/// paragraph whose text satisfies `heading`.
+fn table_after<'a>(
+    document: &'a Html,
+    heading: impl Fn(&str) -> bool,
+    min_rows: usize,
+) -> Option<ElementRef<'a>> {
+    let candidates = selector("h1, h2, h3, h4, h5, h6, p, caption, strong, b, table");
+    let mut found = false;
+    for element in document.select(&candidates) {
+        if element.value().name() == "table" {
+            if found && element.select(&selector("tr")).count() >= min_rows {
+                return Some(element);
+            }
+        } else if !found && heading(&text(element)) {
+            found = true;
+        }
+    }
+    None
+}
+
+/// The text of each cell in each row of `table`.
+fn rows(table: ElementRef<'_>) -> Vec<Vec<String>> {
+    let cell = selector("th, td");
+    table
+        .select(&selector("tr"))
+        .map(|row| row.select(&cell).map(text).collect())
+        .collect()
+}
+
+/// An element's text with runs of whitespace collapsed to one space.
+fn text(element: ElementRef<'_>) -> String {
+    let text: String = element.text().collect();
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The first whole-dollar amount in `text`, such as `$11,925` or `100,000`.
+fn amount(text: &str) -> Option<i64> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let digits: String = text[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ',')
+        .filter(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+/// The first decimal amount in `text`, such as `$ 5,086.00`.
+fn decimal(text: &str) -> Option<f64> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let number: String = text[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ',' || *c == '.')
+        .filter(|c| *c != ',')
+        .collect();
+    number.parse().ok()
+}
+
+fn selector(selectors: &str) -> Selector {
+    Selector::parse(selectors).expect("selectors in this module are valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{FilingStatus, TaxYear};
+    use crate::validate::DataIssue;
+
+    /// A page laid out like the IRS HTML instructions, built from the
+    /// embedded 2025 data.
+    fn instructions_page() -> String {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/data/2025");
+        let read = |name: &str| fs::read_to_string(format!("{dir}/{name}")).unwrap();
+        let mut html = String::from(
+            "<html><head><title>1040 (2025) | Internal Revenue Service</title></head><body>\
+             <h2>Tax Table</h2><table><tr><th>If line 15 is</th><th>And you are</th></tr>",
+        );
+        for line in read("tax_table.csv").lines().skip(1) {
+            let cells: Vec<&str> = line.split(',').collect();
+            html += "<tr>";
+            for cell in cells {
+                let amount: i64 = cell.parse().unwrap();
+                write!(html, "<td>{}</td>", crate::UsdAmount::from_dollars(amount)).unwrap();
+            }
+            html += "</tr>";
+        }
+        html += "</table>";
+
+        let worksheet = read("tax_computation_worksheet.csv");
+        for (label, status) in WORKSHEET_SECTIONS {
+            write!(
+                html,
+                "<p><strong>{label}—Use if your filing status is {status}.</strong></p><table>"
+            )
+            .unwrap();
+            for line in worksheet.lines().filter(|line| line.starts_with(status)) {
+                let c: Vec<&str> = line.split(',').collect();
+                let dollars = |cell: &str| crate::UsdAmount::from_dollars(cell.parse().unwrap());
+                let min = dollars(c[1]);
+                let range = match c[2] {
+                    "" => format!("Over {min}"),
+                    max if c[1] == "100000" => {
+                        format!("At least {min} but not over {}", dollars(max))
+                    }
+                    max => format!("Over {min} but not over {}", dollars(max)),
+                };
+                write!(
+                    html,
+                    "<tr><td>{range}</td><td>$</td><td>× {}% ({})</td><td>$</td><td>$ {}</td></tr>",
+                    c[3].parse::<f64>().unwrap() * 100.0,
+                    c[3],
+                    c[4]
+                )
+                .unwrap();
+            }
+            html += "</table>";
+        }
+
+        let schedule = read("tax_rate_schedule.csv");
+        for (label, status) in RATE_SCHEDULES {
+            write!(
+                html,
+                "<h3>{label}—If your filing status is {status}</h3><table>\
+                 <tr><th>If your taxable income is: Over—</th><th>But not over—</th>\
+                 <th>The tax is:</th><th>of the amount over—</th></tr>"
+            )
+            .unwrap();
+            for line in schedule.lines().filter(|line| line.starts_with(status)) {
+                let c: Vec<&str> = line.split(',').collect();
+                let percent = (c[3].parse::<f64>().unwrap() * 100.0).round();
+                write!(
+                    html,
+                    "<tr><td>${}</td><td>{}</td><td>$1,000 + {percent}%</td><td>{}</td></tr>",
+                    c[1], c[2], c[1]
+                )
+                .unwrap();
+            }
+            html += "</table>";
+        }
+        html + "</body></html>"
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn parses_instructions() {
+        let fetched = FetchedYear::from_html(2025, &instructions_page()).unwrap();
+        assert_eq!(
+            fetched.to_tax_data().unwrap(),
+            TaxData::embedded(TaxYear::Y2025)
+        );
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/data/2025");
+        for (name, contents) in [
+            ("tax_table.csv", &fetched.tax_table),
+            (
+                "tax_computation_worksheet.csv",
+                &fetched.tax_computation_worksheet,
+            ),
+            ("tax_rate_schedule.csv", &fetched.tax_rate_schedule),
+        ] {
+            assert_eq!(
+                contents,
+                &fs::read_to_string(format!("{dir}/{name}"))
+                    .unwrap()
+                    .replace("\r\n", "\n"),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn wrong_year() {
+        let error = FetchedYear::from_html(2026, &instructions_page()).unwrap_err();
+        assert!(
+            matches!(&error, TaxError::UnreadableData { message, .. } if message.contains("for 2025, not 2026")),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn checks_parsed_tables() {
+        // A transcription error in one Tax Table cell
+        let page = instructions_page().replacen("<td>$4,565</td>", "<td>$4,556</td>", 1);
+        let Err(TaxError::InvalidData(issues)) = FetchedYear::from_html(2025, &page) else {
+            panic!("the bad cell was not reported");
+        };
+        assert!(matches!(
+            issues[..],
+            [DataIssue::TableMismatch {
+                status: FilingStatus::Single,
+                table_tax: 4_556,
+                expected_tax: 4_565,
+                ..
+            }]
+        ));
+
+        let page = instructions_page().replace("Schedule Z", "Schedule W");
+        assert!(matches!(
+            FetchedYear::from_html(2025, &page),
+            Err(TaxError::UnreadableData { message, .. }) if message == "could not find Schedule Z"
+        ));
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn write_to_dir() {
+        let fetched = FetchedYear::from_html(2025, &instructions_page()).unwrap();
+        let dir = std::env::temp_dir().join(format!("us-tax-fetch-{}", std::process::id()));
+        fetched.write_to_dir(&dir).unwrap();
+        assert_eq!(
+            TaxData::load_from_dir(&dir).unwrap(),
+            TaxData::embedded(TaxYear::Y2025)
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("provenance.csv")).unwrap(),
+            "file,publication,source_url,revision\n\
+             tax_table.csv,2025 Instructions for Form 1040,\
+             https://www.irs.gov/pub/irs-prior/i1040gi--2025.pdf,2025\n\
+             tax_computation_worksheet.csv,2025 Instructions for Form 1040,\
+             https://www.irs.gov/pub/irs-prior/i1040gi--2025.pdf,2025\n\
+             tax_rate_schedule.csv,2025 Instructions for Form 1040,\
+             https://www.irs.gov/pub/irs-prior/i1040gi--2025.pdf,2025\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
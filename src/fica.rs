@@ -0,0 +1,251 @@
+//! Social Security and Medicare (FICA) taxes on wages.
+
+use crate::compute::income_to_i64;
+use crate::data;
+use crate::money::UsdAmount;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The Social Security tax rate paid by the employee and again by the
+/// employer, on wages up to the [`social_security_wage_base`].
+pub const SOCIAL_SECURITY_RATE: f64 = 0.062;
+
+/// The Medicare tax rate paid by the employee and again by the employer, on
+/// all wages.
+pub const MEDICARE_RATE: f64 = 0.0145;
+
+/// The Additional Medicare Tax rate, paid by the employee alone on wages
+/// above a threshold.
+pub const ADDITIONAL_MEDICARE_RATE: f64 = 0.009;
+
+/// The wages above which an employer withholds Additional Medicare Tax,
+/// whatever the employee's filing status.
+pub const ADDITIONAL_MEDICARE_WITHHOLDING_THRESHOLD: i64 = 200_000;
+
+/// The maximum wages subject to Social Security tax in `year`, as announced
+/// by the Social Security Administration.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "year-2024", feature = "year-2025"))] {
+/// use us_tax_brackets::{social_security_wage_base, TaxYear};
+///
+/// assert_eq!(social_security_wage_base(TaxYear::Y2024), 168_600);
+/// assert_eq!(social_security_wage_base(TaxYear::Y2025), 176_100);
+/// # }
+/// ```
+pub const fn social_security_wage_base(year: TaxYear) -> i64 {
+    data::social_security_wage_base(year)
+}
+
+/// The wages and self-employment income above which Additional Medicare
+/// Tax is owed on Form 8959.
+///
+/// The tax owed depends on the combined wages of a married couple, so it
+/// can differ from what [`compute_fica`] withholds above
+/// [`ADDITIONAL_MEDICARE_WITHHOLDING_THRESHOLD`]. The thresholds are set by
+/// statute and not adjusted for inflation.
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::{additional_medicare_threshold, FilingStatus};
+///
+/// assert_eq!(additional_medicare_threshold(FilingStatus::MarriedFilingJointly), 250_000);
+/// assert_eq!(additional_medicare_threshold(FilingStatus::MarriedFilingSeparately), 125_000);
+/// ```
+pub const fn additional_medicare_threshold(status: FilingStatus) -> i64 {
+    match status {
+        FilingStatus::MarriedFilingJointly => 250_000,
+        FilingStatus::MarriedFilingSeparately => 125_000,
+        FilingStatus::Single
+        | FilingStatus::HeadOfHousehold
+        | FilingStatus::QualifyingSurvivingSpouse => 200_000,
+    }
+}
+
+/// The Social Security and Medicare taxes on one employee's wages, returned
+/// by [`compute_fica`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FicaTax {
+    /// Wages subject to Social Security tax: the wages up to the wage base.
+    pub social_security_wages: i64,
+    /// The Social Security tax paid by each of the employee and the
+    /// employer.
+    pub social_security: UsdAmount,
+    /// The Medicare tax paid by each of the employee and the employer.
+    pub medicare: UsdAmount,
+    /// The Additional Medicare Tax withheld from the employee.
+    pub additional_medicare: UsdAmount,
+}
+
+impl FicaTax {
+    /// The employee's share, withheld from their pay.
+    pub fn employee_total(&self) -> UsdAmount {
+        UsdAmount::from_cents(
+            self.social_security
+                .cents()
+                .saturating_add(self.medicare.cents())
+                .saturating_add(self.additional_medicare.cents()),
+        )
+    }
+
+    /// The employer's share, paid on top of the wages.
+    pub fn employer_total(&self) -> UsdAmount {
+        UsdAmount::from_cents(
+            self.social_security
+                .cents()
+                .saturating_add(self.medicare.cents()),
+        )
+    }
+}
+
+/// Compute the Social Security and Medicare taxes on `wages` paid by one
+/// employer in `year`.
+///
+/// Social Security tax applies up to the [`social_security_wage_base`] and
+/// Medicare tax to all wages, each at the same rate for the employee and the
+/// employer. The employee also has Additional Medicare Tax withheld on wages
+/// above [`ADDITIONAL_MEDICARE_WITHHOLDING_THRESHOLD`]. Each amount is
+/// rounded to the cent.
+///
+/// Someone with several employers has Social Security tax withheld by each
+/// up to the wage base and claims the excess back as a credit on their
+/// return.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `wages` is negative and
+/// [`TaxError::IncomeOutOfRange`] if it does not fit in an `i64` or the tax
+/// does not fit in [`UsdAmount`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_fica, TaxYear};
+///
+/// let fica = compute_fica(TaxYear::Y2025, 80_000).unwrap();
+/// assert_eq!(fica.social_security.to_string(), "$4,960");
+/// assert_eq!(fica.medicare.to_string(), "$1,160");
+/// assert_eq!(fica.employee_total().to_string(), "$6,120");
+///
+/// // Above the wage base, only Medicare tax grows
+/// let fica = compute_fica(TaxYear::Y2025, 250_000).unwrap();
+/// assert_eq!(fica.social_security_wages, 176_100);
+/// assert_eq!(fica.additional_medicare.to_string(), "$450");
+/// # }
+/// ```
+pub fn compute_fica(year: TaxYear, wages: impl TryInto<i64>) -> Result<FicaTax, TaxError> {
+    let wages = income_to_i64(wages)?;
+    if wages < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let social_security_wages = wages.min(social_security_wage_base(year));
+    let excess = (wages - ADDITIONAL_MEDICARE_WITHHOLDING_THRESHOLD).max(0);
+    Ok(FicaTax {
+        social_security_wages,
+        social_security: percent_of(social_security_wages, 620)?,
+        medicare: percent_of(wages, 145)?,
+        additional_medicare: percent_of(excess, 90)?,
+    })
+}
+
+/// `hundredths` hundredths of a percent of a whole-dollar amount, rounded
+/// half up to the cent.
+fn percent_of(dollars: i64, hundredths: i64) -> Result<UsdAmount, TaxError> {
+    // dollars × hundredths / 10,000 dollars = dollars × hundredths / 100 cents
+    let cents = (i128::from(dollars) * i128::from(hundredths) + 50) / 100;
+    i64::try_from(cents)
+        .map(UsdAmount::from_cents)
+        .map_err(|_| TaxError::IncomeOutOfRange)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wage_bases() {
+        let bases = TaxYear::ALL.map(social_security_wage_base);
+        assert_eq!(
+            bases,
+            TaxYear::select([
+                128_400, 132_900, 137_700, 142_800, 147_000, 160_200, 168_600, 176_100
+            ])
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn rates_match_constants() {
+        let fica = compute_fica(TaxYear::Y2024, 100_000).unwrap();
+        assert_eq!(
+            fica.social_security.cents() as f64,
+            (100_000.0 * SOCIAL_SECURITY_RATE * 100.0).round()
+        );
+        assert_eq!(
+            fica.medicare.cents() as f64,
+            (100_000.0 * MEDICARE_RATE * 100.0).round()
+        );
+        let fica = compute_fica(TaxYear::Y2024, 300_000).unwrap();
+        assert_eq!(
+            fica.additional_medicare.cents() as f64,
+            (100_000.0 * ADDITIONAL_MEDICARE_RATE * 100.0).round()
+        );
+    }
+
+    #[cfg(feature = "year-2023")]
+    #[test]
+    fn employee_and_employer_shares() {
+        let fica = compute_fica(TaxYear::Y2023, 160_200).unwrap();
+        assert_eq!(fica.social_security, UsdAmount::from_cents(993_240));
+        assert_eq!(fica.additional_medicare, UsdAmount::ZERO);
+        assert_eq!(fica.employee_total(), fica.employer_total());
+
+        let fica = compute_fica(TaxYear::Y2023, 400_000).unwrap();
+        assert_eq!(fica.social_security, UsdAmount::from_cents(993_240));
+        assert_eq!(fica.medicare, UsdAmount::from_dollars(5_800));
+        assert_eq!(fica.additional_medicare, UsdAmount::from_dollars(1_800));
+        assert_eq!(
+            fica.employee_total().cents() - fica.employer_total().cents(),
+            180_000
+        );
+
+        // Fractions of a cent round half up.
+        assert_eq!(
+            compute_fica(TaxYear::Y2023, 1).unwrap().medicare,
+            UsdAmount::from_cents(1)
+        );
+        assert_eq!(
+            compute_fica(TaxYear::Y2023, 0).unwrap().employee_total(),
+            UsdAmount::ZERO
+        );
+    }
+
+    #[test]
+    fn totals_saturate() {
+        let fica = FicaTax {
+            social_security_wages: 0,
+            social_security: UsdAmount::from_cents(i64::MAX),
+            medicare: UsdAmount::from_cents(1),
+            additional_medicare: UsdAmount::from_cents(1),
+        };
+        assert_eq!(fica.employee_total(), UsdAmount::from_cents(i64::MAX));
+        assert_eq!(fica.employer_total(), UsdAmount::from_cents(i64::MAX));
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn errors() {
+        assert_eq!(
+            compute_fica(TaxYear::Y2025, -1),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            compute_fica(TaxYear::Y2025, i64::MAX),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
@@ -0,0 +1,376 @@
+//! Gross-income filing requirements.
+
+use crate::compute::income_to_i64;
+use crate::data;
+use crate::deduction::{
+    additional_standard_deduction, dependent_standard_deduction, standard_deduction,
+};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The gross income at or above which a return must be filed.
+///
+/// These are the Chart A amounts ("For Most People") in the Form 1040
+/// instructions. `age65` is the number of people on the return who were 65
+/// or older at the end of the year: you, and your spouse if filing jointly.
+/// Counts above what the filing status allows are treated as the maximum, so
+/// `age65` is effectively a flag for every status except married filing
+/// jointly.
+///
+/// Chart A does not apply to dependents, whose requirements depend on their
+/// earned and unearned income.
+///
+/// # Errors
+///
+/// Returns [`TaxError::MalformedData`] if the data has no threshold for this
+/// many people 65 or older (should not occur).
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{filing_threshold, FilingStatus, TaxYear};
+///
+/// assert_eq!(filing_threshold(TaxYear::Y2024, FilingStatus::Single, 0).unwrap(), 14_600);
+/// assert_eq!(
+///     filing_threshold(TaxYear::Y2024, FilingStatus::MarriedFilingJointly, 2).unwrap(),
+///     32_300
+/// );
+/// # }
+/// ```
+pub fn filing_threshold(year: TaxYear, status: FilingStatus, age65: u8) -> Result<i64, TaxError> {
+    // A qualifying surviving spouse files alone, so at most one person can be
+    // 65 or older.
+    let age65 = match status {
+        FilingStatus::QualifyingSurvivingSpouse => age65.min(1),
+        _ => age65,
+    };
+    data::filing_thresholds(year, status)
+        .iter()
+        .filter(|threshold| threshold.age_65_or_older <= age65)
+        .max_by_key(|threshold| threshold.age_65_or_older)
+        .map(|threshold| threshold.gross_income)
+        .ok_or_else(|| TaxError::MalformedData {
+            file: format!("data/{year}/filing_thresholds.csv"),
+            line: 1,
+        })
+}
+
+/// Whether a return must be filed, based on gross income alone.
+///
+/// Compares `gross_income` with [`filing_threshold`]. `age65` and `blind` are
+/// the numbers of people on the return who are 65 or older and who are
+/// blind, as checked on Form 1040. Blindness does not change the Chart A
+/// thresholds; it only matters for dependents, which Chart A does not cover.
+///
+/// A return can be required below the threshold for other reasons, such as
+/// self-employment income or advance premium tax credit payments, and filing
+/// may still be worthwhile to claim a refund.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `gross_income` is negative and
+/// [`TaxError::IncomeOutOfRange`] if it does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{must_file, FilingStatus, TaxYear};
+///
+/// let status = FilingStatus::HeadOfHousehold;
+/// assert!(must_file(TaxYear::Y2025, status, 23_625, 0, 0).unwrap());
+/// assert!(!must_file(TaxYear::Y2025, status, 23_625, 1, 0).unwrap());
+/// # }
+/// ```
+pub fn must_file(
+    year: TaxYear,
+    status: FilingStatus,
+    gross_income: impl TryInto<i64>,
+    age65: u8,
+    blind: u8,
+) -> Result<bool, TaxError> {
+    let gross_income = income_to_i64(gross_income)?;
+    if gross_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    // Chart A depends on age only
+    let _ = blind;
+    Ok(gross_income >= filing_threshold(year, status, age65)?)
+}
+
+/// Someone who can be claimed as a dependent, for
+/// [`dependent_filing_thresholds`] and [`dependent_must_file`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dependent {
+    /// Wages, tips, net self-employment earnings, and taxable scholarships.
+    pub earned_income: i64,
+    /// Investment income, such as interest, dividends, and capital gains,
+    /// and other income that is not earned, such as unemployment
+    /// compensation and taxable Social Security benefits.
+    pub unearned_income: i64,
+    /// Whether the dependent was 65 or older at the end of the year.
+    pub age65: bool,
+    /// Whether the dependent is blind.
+    pub blind: bool,
+    /// Whether the dependent is married.
+    pub married: bool,
+    /// For a married dependent, whether their spouse files a separate return
+    /// and itemizes deductions.
+    pub spouse_itemizes: bool,
+}
+
+/// The incomes above which a [`Dependent`] must file, returned by
+/// [`dependent_filing_thresholds`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DependentFilingThresholds {
+    /// The most unearned income without a return.
+    pub unearned_income: i64,
+    /// The most earned income without a return.
+    pub earned_income: i64,
+    /// The most gross income, earned and unearned together, without a
+    /// return.
+    pub gross_income: i64,
+}
+
+/// The filing requirement thresholds for `dependent` in `year`, from Table 2
+/// ("For Dependents") of Publication 501.
+///
+/// A dependent must file if unearned income is more than the
+/// [`dependent_standard_deduction`] minimum, earned income is more than the
+/// single [`standard_deduction`], or gross income is more than the
+/// dependent's standard deduction for their earned income. Each is raised
+/// by an [`additional_standard_deduction`] for being 65 or older and another
+/// for being blind, at the married filing separately amount for a married
+/// dependent. A married dependent whose spouse files separately and
+/// itemizes must file with gross income of $5 or more, so the gross income
+/// threshold is $4. The thresholds come from the year's embedded standard
+/// deduction amounts.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if the earned income is negative.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{dependent_filing_thresholds, Dependent, TaxYear};
+///
+/// let student = Dependent { earned_income: 3_000, ..Dependent::default() };
+/// let thresholds = dependent_filing_thresholds(TaxYear::Y2024, &student).unwrap();
+/// assert_eq!(thresholds.unearned_income, 1_300);
+/// assert_eq!(thresholds.earned_income, 14_600);
+/// // $3,000 of earned income plus $450
+/// assert_eq!(thresholds.gross_income, 3_450);
+/// # }
+/// ```
+pub fn dependent_filing_thresholds(
+    year: TaxYear,
+    dependent: &Dependent,
+) -> Result<DependentFilingThresholds, TaxError> {
+    let status = if dependent.married {
+        FilingStatus::MarriedFilingSeparately
+    } else {
+        FilingStatus::Single
+    };
+    let boxes = i64::from(dependent.age65) + i64::from(dependent.blind);
+    let additional = boxes * additional_standard_deduction(year, status);
+    let gross_income = if dependent.married && dependent.spouse_itemizes {
+        4
+    } else {
+        dependent_standard_deduction(year, dependent.earned_income)? + additional
+    };
+    Ok(DependentFilingThresholds {
+        unearned_income: data::dependent_standard_deduction(year).minimum + additional,
+        earned_income: standard_deduction(year, FilingStatus::Single) + additional,
+        gross_income,
+    })
+}
+
+/// Whether `dependent` must file a return for `year`: whether any of their
+/// unearned, earned, or gross income is more than its
+/// [`dependent_filing_thresholds`].
+///
+/// Like [`must_file`], this looks at income alone; a dependent with
+/// self-employment earnings of $400 or more, for example, must file
+/// regardless.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if either income is negative and
+/// [`TaxError::IncomeOutOfRange`] if their sum does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{dependent_must_file, Dependent, TaxYear};
+///
+/// // A summer job and a little interest
+/// let student = Dependent {
+///     earned_income: 3_000,
+///     unearned_income: 400,
+///     ..Dependent::default()
+/// };
+/// assert!(!dependent_must_file(TaxYear::Y2024, &student).unwrap());
+/// // Interest over $1,300 requires a return even with no wages
+/// let saver = Dependent { unearned_income: 1_400, ..Dependent::default() };
+/// assert!(dependent_must_file(TaxYear::Y2024, &saver).unwrap());
+/// # }
+/// ```
+pub fn dependent_must_file(year: TaxYear, dependent: &Dependent) -> Result<bool, TaxError> {
+    let Dependent {
+        earned_income,
+        unearned_income,
+        ..
+    } = *dependent;
+    if earned_income < 0 || unearned_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let gross_income = earned_income
+        .checked_add(unearned_income)
+        .ok_or(TaxError::IncomeOutOfRange)?;
+    let thresholds = dependent_filing_thresholds(year, dependent)?;
+    Ok(unearned_income > thresholds.unearned_income
+        || earned_income > thresholds.earned_income
+        || gross_income > thresholds.gross_income)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn thresholds_2024() {
+        let threshold = |status, age65| filing_threshold(TaxYear::Y2024, status, age65).unwrap();
+        assert_eq!(threshold(FilingStatus::Single, 1), 16_550);
+        assert_eq!(threshold(FilingStatus::Single, 2), 16_550);
+        assert_eq!(threshold(FilingStatus::MarriedFilingJointly, 0), 29_200);
+        assert_eq!(threshold(FilingStatus::MarriedFilingJointly, 1), 30_750);
+        assert_eq!(threshold(FilingStatus::MarriedFilingSeparately, 0), 5);
+        assert_eq!(threshold(FilingStatus::MarriedFilingSeparately, 1), 5);
+        assert_eq!(threshold(FilingStatus::HeadOfHousehold, 1), 23_850);
+        assert_eq!(
+            threshold(FilingStatus::QualifyingSurvivingSpouse, 0),
+            29_200
+        );
+        assert_eq!(
+            threshold(FilingStatus::QualifyingSurvivingSpouse, 2),
+            30_750
+        );
+    }
+
+    #[test]
+    fn thresholds_rise_with_age() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                let under = filing_threshold(year, status, 0).unwrap();
+                let over = filing_threshold(year, status, 2).unwrap();
+                assert!(over >= under, "{year} {status}");
+            }
+        }
+    }
+
+    #[cfg(feature = "year-2023")]
+    #[test]
+    fn must_file_at_threshold() {
+        let single = |income| must_file(TaxYear::Y2023, FilingStatus::Single, income, 0, 0);
+        assert_eq!(single(13_849), Ok(false));
+        assert_eq!(single(13_850), Ok(true));
+        assert_eq!(single(-1), Err(TaxError::NegativeIncome));
+        assert_eq!(
+            must_file(
+                TaxYear::Y2023,
+                FilingStatus::MarriedFilingSeparately,
+                5,
+                1,
+                1
+            ),
+            Ok(true)
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn dependents_2024() {
+        let year = TaxYear::Y2024;
+        let thresholds =
+            |dependent: Dependent| dependent_filing_thresholds(year, &dependent).unwrap();
+        let single = thresholds(Dependent::default());
+        assert_eq!(
+            (
+                single.unearned_income,
+                single.earned_income,
+                single.gross_income
+            ),
+            (1_300, 14_600, 1_300)
+        );
+        // The gross income test never goes above the earned income test.
+        let earner = thresholds(Dependent {
+            earned_income: 20_000,
+            ..Dependent::default()
+        });
+        assert_eq!(earner.gross_income, 14_600);
+
+        // Publication 501, Table 2: 65 or older and blind adds $1,950 each.
+        let both = thresholds(Dependent {
+            age65: true,
+            blind: true,
+            ..Dependent::default()
+        });
+        assert_eq!(
+            (both.unearned_income, both.earned_income, both.gross_income),
+            (5_200, 18_500, 5_200)
+        );
+        // Married dependents add $1,550.
+        let married = thresholds(Dependent {
+            blind: true,
+            married: true,
+            ..Dependent::default()
+        });
+        assert_eq!(
+            (
+                married.unearned_income,
+                married.earned_income,
+                married.gross_income
+            ),
+            (2_850, 16_150, 2_850)
+        );
+        let spouse_itemizes = thresholds(Dependent {
+            married: true,
+            spouse_itemizes: true,
+            ..Dependent::default()
+        });
+        assert_eq!(spouse_itemizes.gross_income, 4);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn dependents_must_file() {
+        let year = TaxYear::Y2024;
+        let must_file = |earned_income, unearned_income| {
+            dependent_must_file(
+                year,
+                &Dependent {
+                    earned_income,
+                    unearned_income,
+                    ..Dependent::default()
+                },
+            )
+        };
+        assert_eq!(must_file(0, 1_300), Ok(false));
+        assert_eq!(must_file(0, 1_301), Ok(true));
+        assert_eq!(must_file(14_600, 0), Ok(false));
+        assert_eq!(must_file(14_601, 0), Ok(true));
+        // $1,000 of each is $2,000 of gross income, over the $1,450 the
+        // earned income allows.
+        assert_eq!(must_file(1_000, 1_000), Ok(true));
+        assert_eq!(must_file(1_000, 450), Ok(false));
+        assert_eq!(must_file(-1, 0), Err(TaxError::NegativeIncome));
+        assert_eq!(must_file(0, -1), Err(TaxError::NegativeIncome));
+        assert_eq!(must_file(i64::MAX, 1), Err(TaxError::IncomeOutOfRange));
+    }
+}
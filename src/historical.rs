@@ -0,0 +1,201 @@
+//! Rate schedules for years before the embedded Tax Tables (requires the
+//! `historical` feature).
+
+use crate::compute::income_to_i64;
+use crate::data;
+use crate::schedule::TaxSchedule;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The first year of the historical rate schedules: the first with the 31%
+/// bracket of the Omnibus Budget Reconciliation Act of 1990, after the 33%
+/// "bubble" of 1988–1990.
+const FIRST_YEAR: u16 = 1991;
+
+/// Every year [`historical_schedule`] covers, in ascending order.
+///
+/// These are the historical years from 1991 through 2017, followed by
+/// [`TaxYear::ALL`]. A year whose `year-<year>` feature is disabled is left
+/// out.
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::historical_years;
+///
+/// let years: Vec<u16> = historical_years().collect();
+/// assert_eq!(years[0], 1991);
+/// assert!(years.windows(2).all(|pair| pair[0] < pair[1]));
+/// ```
+pub fn historical_years() -> impl Iterator<Item = u16> {
+    (FIRST_YEAR..)
+        .take_while(|&year| data::historical_rate_schedule(year, FilingStatus::Single).is_some())
+        .chain(TaxYear::ALL.into_iter().map(u16::from))
+}
+
+/// The Tax Rate Schedule for any year from 1991, for long-run series of
+/// effective and marginal rates.
+///
+/// An embedded year returns its [`TaxSchedule::new`]. Earlier years come
+/// from the rate schedules in each year's Form 1040 instructions, which are
+/// embedded without Tax Tables; their schedules have no
+/// [`year`](TaxSchedule::year). Qualifying surviving spouses use the married
+/// filing jointly schedule throughout.
+///
+/// The 2001 schedules include the new 10% bracket, although that year the
+/// IRS delivered it as advance payments and the rate reduction credit rather
+/// than in the printed schedules, so the tax is the liability after that
+/// credit.
+///
+/// # Errors
+///
+/// Returns [`TaxError::UnsupportedYear`] for a year outside
+/// [`historical_years`].
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::{historical_schedule, FilingStatus};
+///
+/// let schedule = historical_schedule(1995, FilingStatus::Single).unwrap();
+/// let rates: Vec<f64> = schedule.iter().map(|bracket| bracket.rate).collect();
+/// assert_eq!(rates, [0.15, 0.28, 0.31, 0.36, 0.396]);
+/// assert_eq!(schedule.thresholds().next(), Some(23_350));
+/// ```
+pub fn historical_schedule(year: u16, status: FilingStatus) -> Result<TaxSchedule, TaxError> {
+    if let Ok(year) = TaxYear::try_from(year) {
+        return TaxSchedule::new(year, status);
+    }
+    let brackets =
+        data::historical_rate_schedule(year, status).ok_or(TaxError::UnsupportedYear(year))?;
+    brackets
+        .iter()
+        .fold(TaxSchedule::builder(), |builder, bracket| {
+            builder.bracket(bracket.income_min, bracket.rate)
+        })
+        .build()
+}
+
+/// Compute federal income tax for any year from 1991 by applying its
+/// [`historical_schedule`] exactly.
+///
+/// Like [`compute_tax_exact`](crate::compute_tax_exact), the result is the
+/// unrounded schedule tax at every income, not the Tax Table amount, so a
+/// series across years changes only with the law and the brackets.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `taxable_income` is negative, and
+/// the same errors as [`historical_schedule`].
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::{compute_tax_historical, FilingStatus};
+///
+/// // 1993 married filing jointly: 15% × 36,900 + 28% × 13,100
+/// let tax = compute_tax_historical(1993, FilingStatus::MarriedFilingJointly, 50_000).unwrap();
+/// assert!((tax - 9_203.0).abs() < 1e-9);
+/// ```
+pub fn compute_tax_historical(
+    year: u16,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+) -> Result<f64, TaxError> {
+    let taxable_income = income_to_i64(taxable_income)?;
+    if taxable_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    historical_schedule(year, status)?.tax(taxable_income)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exact::compute_tax_exact;
+
+    #[cfg(feature = "year-2018")]
+    #[test]
+    fn every_year_has_contiguous_brackets() {
+        let years: Vec<u16> = historical_years().collect();
+        assert_eq!(years[0], FIRST_YEAR);
+        for pair in years.windows(2) {
+            assert_eq!(pair[1], pair[0] + 1, "gap after {}", pair[0]);
+        }
+        for year in years {
+            for status in FilingStatus::ALL {
+                let schedule = historical_schedule(year, status).unwrap();
+                let brackets = schedule.brackets();
+                assert_eq!(brackets[0].income_min, 0, "{year} {status}");
+                assert!(
+                    brackets.windows(2).all(|pair| pair[0].rate < pair[1].rate),
+                    "{year} {status}"
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "year-2018")]
+    #[test]
+    fn statutory_rates() {
+        let top_rate = |year| {
+            historical_schedule(year, FilingStatus::Single)
+                .unwrap()
+                .iter()
+                .last()
+                .unwrap()
+                .rate
+        };
+        assert_eq!(top_rate(1991), 0.31);
+        assert_eq!(top_rate(2000), 0.396);
+        assert_eq!(top_rate(2001), 0.391);
+        assert_eq!(top_rate(2002), 0.386);
+        assert_eq!(top_rate(2003), 0.35);
+        assert_eq!(top_rate(2017), 0.396);
+        assert_eq!(top_rate(2018), 0.37);
+
+        // From 2003, the married filing jointly 10% and 15% brackets are
+        // twice the single ones.
+        for year in 2003..=2017 {
+            let single: Vec<i64> = historical_schedule(year, FilingStatus::Single)
+                .unwrap()
+                .thresholds()
+                .take(2)
+                .collect();
+            let joint: Vec<i64> = historical_schedule(year, FilingStatus::MarriedFilingJointly)
+                .unwrap()
+                .thresholds()
+                .take(2)
+                .collect();
+            assert_eq!(joint, [2 * single[0], 2 * single[1]], "{year}");
+        }
+    }
+
+    #[test]
+    fn embedded_years_match_compute_tax_exact() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for income in (0..700_000).step_by(12_347) {
+                    assert_eq!(
+                        compute_tax_historical(u16::from(year), status, income),
+                        compute_tax_exact(year, status, income)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            historical_schedule(1990, FilingStatus::Single),
+            Err(TaxError::UnsupportedYear(1990))
+        );
+        assert_eq!(
+            compute_tax_historical(2010, FilingStatus::Single, -1),
+            Err(TaxError::NegativeIncome)
+        );
+        // 2010 head of household: 10% × 11,950 + 15% × 33,600 + 25% × 4,450
+        let tax = compute_tax_historical(2010, FilingStatus::HeadOfHousehold, 50_000).unwrap();
+        assert!((tax - 7_347.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,408 @@
+//! Rate schedules rebuilt from the statute and the annual inflation
+//! adjustment.
+
+use crate::data::{self, csv_status_index};
+use crate::schedule::TaxSchedule;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+use crate::validate::{CSV_STATUSES, DataIssue};
+
+/// A statutory rate structure: seven rates and the thresholds between them
+/// for 2018, which later years adjust for inflation.
+pub(crate) struct Statute {
+    /// The rates, in basis points.
+    pub(crate) rates: [i64; 7],
+    /// The 2018 thresholds, in [`CSV_STATUSES`] order.
+    pub(crate) thresholds: [[i64; 6]; 4],
+    /// The multiple each status's adjusted thresholds are rounded down to,
+    /// in [`CSV_STATUSES`] order.
+    pub(crate) rounding: [i64; 4],
+}
+
+/// The brackets of IRC §1(j)(2), enacted by the Tax Cuts and Jobs Act.
+const TCJA: Statute = Statute {
+    rates: [1_000, 1_200, 2_200, 2_400, 3_200, 3_500, 3_700],
+    thresholds: [
+        [9_525, 38_700, 82_500, 157_500, 200_000, 500_000],
+        [19_050, 77_400, 165_000, 315_000, 400_000, 600_000],
+        [9_525, 38_700, 82_500, 157_500, 200_000, 300_000],
+        [13_600, 51_800, 82_500, 157_500, 200_000, 500_000],
+    ],
+    rounding: [25, 50, 25, 50],
+};
+
+/// Rebuild a Tax Rate Schedule from the statutory 2018 brackets and a
+/// cost-of-living adjustment, as the annual Revenue Procedure does.
+///
+/// Each threshold of IRC §1(j)(2) is multiplied by
+/// `cost_of_living_adjustment` (the ratio of the chained CPI for the year
+/// to its 2017 base) and rounded down to a multiple of $25, or $50 for
+/// married filing jointly and head of household. This gives a year's
+/// schedule as soon as the Revenue Procedure's factor is known, before the
+/// Form 1040 instructions are published, and an independent check on
+/// scraped data. The result is a custom schedule, with no year or status.
+///
+/// # Errors
+///
+/// Returns [`TaxError::InvalidSchedule`] if `cost_of_living_adjustment` is
+/// not a finite number of at least 1.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2023")] {
+/// use us_tax_brackets::{inflation_adjusted_schedule, FilingStatus, TaxSchedule, TaxYear};
+///
+/// let schedule = inflation_adjusted_schedule(FilingStatus::Single, 1.15625).unwrap();
+/// let published = TaxSchedule::new(TaxYear::Y2023, FilingStatus::Single).unwrap();
+/// assert_eq!(schedule.brackets(), published.brackets());
+/// # }
+/// ```
+pub fn inflation_adjusted_schedule(
+    status: FilingStatus,
+    cost_of_living_adjustment: f64,
+) -> Result<TaxSchedule, TaxError> {
+    statutory_schedule(&TCJA, status, cost_of_living_adjustment)
+}
+
+/// Adjust `statute`'s thresholds for `status` by `cost_of_living_adjustment`.
+pub(crate) fn statutory_schedule(
+    statute: &Statute,
+    status: FilingStatus,
+    cost_of_living_adjustment: f64,
+) -> Result<TaxSchedule, TaxError> {
+    if !(cost_of_living_adjustment.is_finite() && cost_of_living_adjustment >= 1.0) {
+        return Err(TaxError::InvalidSchedule(format!(
+            "cost-of-living adjustment {cost_of_living_adjustment} is not a finite factor of \
+             at least 1"
+        )));
+    }
+    let index = csv_status_index(status);
+    let step = statute.rounding[index];
+    let thresholds = statute.thresholds[index].map(|base| {
+        // The tolerance keeps floating-point error in a factor that lands
+        // exactly on a multiple from rounding it a step down.
+        let increase = base as f64 * (cost_of_living_adjustment - 1.0) + 1e-6;
+        base + (increase / step as f64).floor() as i64 * step
+    });
+
+    let rate = |basis_points: i64| basis_points as f64 / 10_000.0;
+    let mut builder = TaxSchedule::builder().bracket(0, rate(statute.rates[0]));
+    for (threshold, &basis_points) in thresholds.into_iter().zip(&statute.rates[1..]) {
+        builder = builder.bracket(threshold, rate(basis_points));
+    }
+    builder.build()
+}
+
+/// The cost-of-living adjustment a year's Revenue Procedure applies to the
+/// statutory brackets of IRC §1(j)(2).
+///
+/// The factor is the chained CPI-U for the twelve months ending the
+/// previous August over that for 2017, embedded from
+/// `data/cost_of_living_adjustment.csv`. It is 1 for 2018, the year of the
+/// statutory brackets.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{cost_of_living_adjustment, TaxYear};
+///
+/// assert_eq!(cost_of_living_adjustment(TaxYear::Y2024), 1.21874);
+/// # }
+/// ```
+pub fn cost_of_living_adjustment(year: TaxYear) -> f64 {
+    data::cost_of_living_adjustment(year)
+}
+
+/// Check a year's embedded Tax Rate Schedules against the statutory
+/// brackets adjusted by its [`cost_of_living_adjustment`].
+///
+/// The factor is embedded separately from the scraped schedules, so
+/// agreement is an independent confirmation of every threshold.
+///
+/// # Errors
+///
+/// Returns [`TaxError::InvalidData`] with a [`DataIssue::InflationMismatch`]
+/// for each threshold that differs from the adjusted statutory bracket.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{check_inflation_adjustment, TaxYear};
+///
+/// assert_eq!(check_inflation_adjustment(TaxYear::Y2024), Ok(()));
+/// # }
+/// ```
+pub fn check_inflation_adjustment(year: TaxYear) -> Result<(), TaxError> {
+    let thresholds = CSV_STATUSES.map(|status| {
+        data::rate_schedule(year, status)
+            .iter()
+            .filter_map(|bracket| bracket.income_max)
+            .collect()
+    });
+    let issues = inflation_mismatches(&thresholds, cost_of_living_adjustment(year));
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(TaxError::InvalidData(issues))
+    }
+}
+
+/// The first year of the statutory brackets, whose cost-of-living adjustment
+/// is 1.
+const BASE_YEAR: u16 = 2018;
+
+/// Project a Tax Rate Schedule for a year after the embedded data, assuming
+/// annual inflation of `assumed_inflation` (e.g. `0.025` for 2.5%).
+///
+/// The latest embedded year's [`cost_of_living_adjustment`] is grown
+/// by `assumed_inflation` for each year after it, and the schedule rebuilt
+/// with [`inflation_adjusted_schedule`], so the projection rounds each
+/// threshold the way the IRS will. [`historical_inflation_rate`] is a
+/// reasonable default assumption. The projection keeps the current statutory
+/// brackets and rates; it does not anticipate changes in law.
+///
+/// An embedded year returns its published schedule, so a multi-year plan can
+/// call this for every year.
+///
+/// # Errors
+///
+/// Returns [`TaxError::UnsupportedYear`] for a year before the latest
+/// embedded year that has no data, and [`TaxError::InvalidSchedule`] if
+/// `assumed_inflation` is not finite or would shrink the adjustment below
+/// the statutory brackets.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{projected_schedule, FilingStatus, TaxSchedule, TaxYear};
+///
+/// let current = TaxSchedule::new(TaxYear::Y2025, FilingStatus::Single).unwrap();
+/// let projected = projected_schedule(2028, FilingStatus::Single, 0.025).unwrap();
+/// assert!(projected.thresholds().zip(current.thresholds()).all(|(new, old)| new > old));
+/// assert_eq!(projected.source_year(), None);
+/// # }
+/// ```
+pub fn projected_schedule(
+    year: u16,
+    status: FilingStatus,
+    assumed_inflation: f64,
+) -> Result<TaxSchedule, TaxError> {
+    if let Ok(year) = TaxYear::try_from(year) {
+        return TaxSchedule::new(year, status);
+    }
+    inflation_adjusted_schedule(status, projected_adjustment(year, assumed_inflation)?)
+}
+
+/// The cost-of-living adjustment for `year`: the embedded factor for an
+/// embedded year, or the latest year's grown by `assumed_inflation` for a
+/// later one.
+pub(crate) fn projected_adjustment(year: u16, assumed_inflation: f64) -> Result<f64, TaxError> {
+    if let Ok(year) = TaxYear::try_from(year) {
+        return Ok(cost_of_living_adjustment(year));
+    }
+    let (latest, factor) = latest_adjustment();
+    if year < u16::from(latest) {
+        return Err(TaxError::UnsupportedYear(year));
+    }
+    if !assumed_inflation.is_finite() {
+        return Err(TaxError::InvalidSchedule(format!(
+            "assumed inflation {assumed_inflation} is not finite"
+        )));
+    }
+    let years = i32::from(year - u16::from(latest));
+    Ok(factor * (1.0 + assumed_inflation).powi(years))
+}
+
+/// The ratio of `to`'s bracket inflation adjustment to `from`'s.
+///
+/// Multiplying an amount in `from` dollars by this factor expresses it in
+/// `to` dollars the way the IRS moves the brackets between the years, so
+/// an income keeps its place relative to the thresholds. The factor is the
+/// ratio of the years' [`cost_of_living_adjustment`]s, and is below 1 when
+/// `to` is the earlier year.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "year-2024", feature = "year-2025"))] {
+/// use us_tax_brackets::{bracket_inflation_factor, TaxYear};
+///
+/// let factor = bracket_inflation_factor(TaxYear::Y2024, TaxYear::Y2025);
+/// assert!((factor - 1.028).abs() < 0.001);
+///
+/// // $80,000 of 2024 income in 2025 bracket terms
+/// assert_eq!((80_000.0 * factor).round(), 82_231.0);
+/// # }
+/// ```
+pub fn bracket_inflation_factor(from: TaxYear, to: TaxYear) -> f64 {
+    cost_of_living_adjustment(to) / cost_of_living_adjustment(from)
+}
+
+/// The average annual growth of the cost-of-living adjustment from 2018 to
+/// the latest embedded year.
+///
+/// This is the inflation the brackets have actually seen, for use as the
+/// assumption in [`projected_schedule`] when no forecast is at hand.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::historical_inflation_rate;
+///
+/// let rate = historical_inflation_rate();
+/// assert!(0.02 < rate && rate < 0.05);
+/// # }
+/// ```
+pub fn historical_inflation_rate() -> f64 {
+    let (latest, factor) = latest_adjustment();
+    let years = (u16::from(latest) - BASE_YEAR).max(1);
+    factor.powf(1.0 / f64::from(years)) - 1.0
+}
+
+/// The latest embedded year and its cost-of-living adjustment.
+fn latest_adjustment() -> (TaxYear, f64) {
+    let latest = TaxYear::ALL[TaxYear::ALL.len() - 1];
+    (latest, cost_of_living_adjustment(latest))
+}
+
+/// The thresholds, in [`CSV_STATUSES`] order, that differ from the
+/// statutory brackets adjusted by `factor`.
+fn inflation_mismatches(thresholds: &[Vec<i64>; 4], factor: f64) -> Vec<DataIssue> {
+    let mut issues = Vec::new();
+    for (status, thresholds) in CSV_STATUSES.into_iter().zip(thresholds) {
+        let schedule =
+            inflation_adjusted_schedule(status, factor).expect("embedded factors are at least 1");
+        for (threshold, expected) in thresholds.iter().copied().zip(schedule.thresholds()) {
+            if threshold != expected {
+                issues.push(DataIssue::InflationMismatch {
+                    status,
+                    threshold,
+                    expected,
+                });
+            }
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reconstructs_embedded_schedules() {
+        for year in TaxYear::ALL {
+            assert_eq!(check_inflation_adjustment(year), Ok(()), "{year}");
+            for status in FilingStatus::ALL {
+                let published = TaxSchedule::new(year, status).unwrap();
+                let schedule =
+                    inflation_adjusted_schedule(status, cost_of_living_adjustment(year)).unwrap();
+                assert_eq!(schedule.brackets(), published.brackets(), "{year} {status}");
+            }
+        }
+    }
+
+    #[test]
+    fn exact_factor() {
+        #[cfg(feature = "year-2018")]
+        assert_eq!(cost_of_living_adjustment(TaxYear::Y2018), 1.0);
+        let schedule = inflation_adjusted_schedule(FilingStatus::HeadOfHousehold, 1.0).unwrap();
+        assert_eq!(schedule.thresholds().next(), Some(13_600));
+    }
+
+    #[test]
+    fn reports_mismatched_thresholds() {
+        let mut thresholds = CSV_STATUSES.map(|status| {
+            inflation_adjusted_schedule(status, 1.25)
+                .unwrap()
+                .thresholds()
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(inflation_mismatches(&thresholds, 1.25), []);
+
+        // A transcription error in the 24% threshold for head of household
+        thresholds[3][3] += 1_000;
+        assert_eq!(
+            inflation_mismatches(&thresholds, 1.25),
+            [DataIssue::InflationMismatch {
+                status: FilingStatus::HeadOfHousehold,
+                threshold: 197_850,
+                expected: 196_850,
+            }]
+        );
+
+        // A factor off by more than a rounding step moves every threshold.
+        assert_eq!(inflation_mismatches(&thresholds, 1.26).len(), 24);
+    }
+
+    #[test]
+    fn projections() {
+        let latest = TaxYear::ALL[TaxYear::ALL.len() - 1];
+        let next = u16::from(latest) + 1;
+        for status in FilingStatus::ALL {
+            let published = TaxSchedule::new(latest, status).unwrap();
+            assert_eq!(
+                projected_schedule(latest.into(), status, 0.1),
+                Ok(published.clone())
+            );
+
+            // No inflation leaves the thresholds where they are.
+            let flat = projected_schedule(next, status, 0.0).unwrap();
+            assert_eq!(flat.brackets(), published.brackets());
+
+            let near = projected_schedule(next, status, 0.03).unwrap();
+            let far = projected_schedule(next + 4, status, 0.03).unwrap();
+            for ((old, near), far) in published
+                .thresholds()
+                .zip(near.thresholds())
+                .zip(far.thresholds())
+            {
+                assert!(old < near && near < far, "{status}: {old} {near} {far}");
+            }
+        }
+        assert_eq!(
+            projected_schedule(2017, FilingStatus::Single, 0.03),
+            Err(TaxError::UnsupportedYear(2017))
+        );
+        assert!(matches!(
+            projected_schedule(next, FilingStatus::Single, f64::NAN),
+            Err(TaxError::InvalidSchedule(_))
+        ));
+    }
+
+    #[test]
+    fn factor_between_years() {
+        for from in TaxYear::ALL {
+            assert_eq!(bracket_inflation_factor(from, from), 1.0);
+            for to in TaxYear::ALL {
+                let factor = bracket_inflation_factor(from, to);
+                let back = bracket_inflation_factor(to, from);
+                assert!((factor * back - 1.0).abs() < 1e-12);
+                for status in FilingStatus::ALL {
+                    let old = TaxSchedule::new(from, status).unwrap();
+                    let new = TaxSchedule::new(to, status).unwrap();
+                    // Each threshold moves with the factor, give or take one
+                    // rounding step.
+                    for (old, new) in old.thresholds().zip(new.thresholds()) {
+                        let moved = old as f64 * factor;
+                        assert!((moved - new as f64).abs() < 50.0 * factor.max(1.0));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_factor() {
+        for cola in [0.9, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                inflation_adjusted_schedule(FilingStatus::Single, cola),
+                Err(TaxError::InvalidSchedule(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,291 @@
+//! Estimated tax installments under the annualized income installment
+//! method (Form 2210, Schedule AI).
+
+use crate::compute::compute_tax;
+use crate::deduction::DeductionChoice;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The amounts annualizing each period's income (line 2), in tenths.
+const ANNUALIZATION: [i64; 4] = [40, 24, 15, 10];
+
+/// The share of the annualized tax due by each installment (line 18), in
+/// tenths of a percent.
+const APPLICABLE_PERCENTAGE: [i64; 4] = [225, 450, 675, 900];
+
+/// `amount * numerator / denominator`, rounded half away from zero and
+/// saturating at the bounds of `i64`.
+fn scale(amount: i64, numerator: i64, denominator: i64) -> i64 {
+    let product = i128::from(amount) * i128::from(numerator);
+    let half = i128::from(denominator) / 2;
+    let rounded = if product < 0 {
+        (product - half) / i128::from(denominator)
+    } else {
+        (product + half) / i128::from(denominator)
+    };
+    i64::try_from(rounded).unwrap_or(if rounded < 0 { i64::MIN } else { i64::MAX })
+}
+
+/// Compute the required annual payment of estimated tax (Form 2210, line 9):
+/// the smaller of 90% of this year's tax and 100% of last year's.
+///
+/// Last year's share rises to 110% if its adjusted gross income was over
+/// $150,000, or $75,000 married filing separately. Pass [`None`] for
+/// `prior_year_tax` if there was no return for a full 12-month prior year,
+/// which leaves only the 90% test. Both taxes are the total tax after
+/// credits, before subtracting withholding.
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::{required_annual_payment, FilingStatus};
+///
+/// let status = FilingStatus::Single;
+/// assert_eq!(required_annual_payment(status, 30_000, Some(20_000), 100_000), 20_000);
+/// // 110% of last year's tax above $150,000
+/// assert_eq!(required_annual_payment(status, 30_000, Some(20_000), 160_000), 22_000);
+/// assert_eq!(required_annual_payment(status, 30_000, None, 0), 27_000);
+/// ```
+pub fn required_annual_payment(
+    status: FilingStatus,
+    current_year_tax: i64,
+    prior_year_tax: Option<i64>,
+    prior_year_agi: i64,
+) -> i64 {
+    let current = scale(current_year_tax.max(0), 9, 10);
+    let Some(prior_year_tax) = prior_year_tax else {
+        return current;
+    };
+    let threshold = match status {
+        FilingStatus::MarriedFilingSeparately => 75_000,
+        _ => 150_000,
+    };
+    let prior = if prior_year_agi > threshold {
+        scale(prior_year_tax.max(0), 11, 10)
+    } else {
+        prior_year_tax.max(0)
+    };
+    current.min(prior)
+}
+
+/// Income and deductions through the end of each annualization period, for
+/// [`annualized_installments`], in whole dollars.
+///
+/// The periods all start on January 1 and end on March 31, May 31, August
+/// 31, and December 31, so each amount includes the ones before it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IncomePeriods {
+    /// Adjusted gross income through the end of each period (line 1).
+    pub agi: [i64; 4],
+    /// Itemized deductions paid through the end of each period (line 4).
+    pub itemized_deductions: [i64; 4],
+    /// The standard deduction for the whole year, used when it is larger
+    /// than the annualized itemized deductions (line 7).
+    pub standard_deduction: DeductionChoice,
+}
+
+/// One column of Schedule AI, returned by [`annualized_installments`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnnualizedInstallment {
+    /// The period's income at a full year's pace (line 3).
+    pub annualized_income: i64,
+    /// The larger of the annualized itemized deductions and the standard
+    /// deduction (line 8).
+    pub deduction: i64,
+    /// Annualized income less the deduction, and at least 0 (line 11).
+    pub taxable_income: i64,
+    /// The tax on [`taxable_income`](Self::taxable_income) (line 17).
+    pub annualized_tax: i64,
+    /// The installment required by the end of the period: the smaller of
+    /// the annualized amount and the regular installment plus any unused
+    /// before it (line 25).
+    pub installment: i64,
+}
+
+/// Compute the four estimated tax installments with the annualized income
+/// installment method of Form 2210, Schedule AI.
+///
+/// Each period's income is annualized by 4, 2.4, 1.5, and 1, taxed with
+/// [`compute_tax`], and 22.5%, 45%, 67.5%, and 90% of that tax, less the
+/// installments before it, is due. An installment is never more than the
+/// regular quarter of `required_annual_payment`, from
+/// [`required_annual_payment`], plus whatever earlier installments did not
+/// use, so income that arrives late in the year moves the payments with it.
+///
+/// Self-employment tax (Part II), other taxes, credits, and the
+/// qualified business income deduction are not included, and capital gains
+/// are taxed at ordinary rates.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if an itemized deduction or
+/// `required_annual_payment` is negative, and the same errors as
+/// [`DeductionChoice::amount`] and [`compute_tax`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{
+///     annualized_installments, compute_tax, FilingStatus, IncomePeriods, TaxYear,
+/// };
+///
+/// // Little income until a large gain late in the year
+/// let (year, status) = (TaxYear::Y2025, FilingStatus::Single);
+/// let periods = IncomePeriods {
+///     agi: [10_000, 16_000, 24_000, 200_000],
+///     ..IncomePeriods::default()
+/// };
+/// let required = compute_tax(year, status, 184_250).unwrap() * 9 / 10;
+/// let installments = annualized_installments(year, status, &periods, required).unwrap();
+/// assert!(installments[0].installment < required / 4);
+/// assert!(installments[3].installment > required / 2);
+/// let total: i64 = installments.iter().map(|column| column.installment).sum();
+/// assert_eq!(total, required);
+/// # }
+/// ```
+pub fn annualized_installments(
+    year: TaxYear,
+    status: FilingStatus,
+    periods: &IncomePeriods,
+    required_annual_payment: i64,
+) -> Result<[AnnualizedInstallment; 4], TaxError> {
+    if required_annual_payment < 0 || periods.itemized_deductions.iter().any(|&amount| amount < 0) {
+        return Err(TaxError::NegativeIncome);
+    }
+    let standard_deduction = periods.standard_deduction.amount(year, status)?;
+
+    let mut columns = [AnnualizedInstallment {
+        annualized_income: 0,
+        deduction: 0,
+        taxable_income: 0,
+        annualized_tax: 0,
+        installment: 0,
+    }; 4];
+    let mut paid = 0;
+    for (index, column) in columns.iter_mut().enumerate() {
+        let annualized_income = scale(periods.agi[index], ANNUALIZATION[index], 10);
+        let deduction = scale(periods.itemized_deductions[index], ANNUALIZATION[index], 10)
+            .max(standard_deduction);
+        let taxable_income = annualized_income.saturating_sub(deduction).max(0);
+        let annualized_tax = compute_tax(year, status, taxable_income)?;
+        let annualized_due =
+            (scale(annualized_tax, APPLICABLE_PERCENTAGE[index], 1_000) - paid).max(0);
+        // A quarter of the required payment for each installment so far,
+        // less what has been paid (lines 22–24)
+        let regular_due = scale(required_annual_payment, index as i64 + 1, 4) - paid;
+        let installment = annualized_due.min(regular_due);
+        paid += installment;
+        *column = AnnualizedInstallment {
+            annualized_income,
+            deduction,
+            taxable_income,
+            annualized_tax,
+            installment,
+        };
+    }
+    Ok(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_payment() {
+        let status = FilingStatus::MarriedFilingSeparately;
+        assert_eq!(
+            required_annual_payment(status, 10_000, Some(12_000), 70_000),
+            9_000
+        );
+        assert_eq!(
+            required_annual_payment(status, 20_000, Some(12_000), 80_000),
+            13_200
+        );
+        // No tax last year means no estimated tax was required.
+        assert_eq!(required_annual_payment(status, 20_000, Some(0), 0), 0);
+    }
+
+    #[test]
+    fn scale_saturates() {
+        assert_eq!(scale(i64::MAX, 11, 10), i64::MAX);
+        assert_eq!(scale(i64::MIN, 4, 1), i64::MIN);
+        assert_eq!(scale(-15, 1, 10), -2);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn even_income_matches_regular_installments() {
+        // Income earned evenly, with the periods' 3, 5, 8, and 12 months
+        let (year, status) = (TaxYear::Y2024, FilingStatus::MarriedFilingJointly);
+        let periods = IncomePeriods {
+            agi: [30_000, 50_000, 80_000, 120_000],
+            ..IncomePeriods::default()
+        };
+        let tax = compute_tax(year, status, 120_000 - 29_200).unwrap();
+        let required = required_annual_payment(status, tax, None, 0);
+        let columns = annualized_installments(year, status, &periods, required).unwrap();
+        for column in &columns {
+            assert_eq!(column.annualized_income, 120_000);
+            assert_eq!(column.deduction, 29_200);
+            assert_eq!(column.annualized_tax, tax);
+        }
+        let installments = columns.map(|column| column.installment);
+        let quarter = scale(required, 1, 4);
+        assert_eq!(installments, [quarter; 4]);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn late_income_defers_installments() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+        let periods = IncomePeriods {
+            agi: [5_000, 8_000, 60_000, 150_000],
+            itemized_deductions: [2_000, 3_000, 4_000, 20_000],
+            standard_deduction: DeductionChoice::Standard,
+        };
+        let columns = annualized_installments(year, status, &periods, 20_000).unwrap();
+        // $5,000 × 4 less the standard deduction; the annualized itemized
+        // deductions of $8,000 are smaller.
+        assert_eq!(columns[0].annualized_income, 20_000);
+        assert_eq!(columns[0].deduction, 14_600);
+        assert_eq!(
+            columns[0].installment,
+            scale(columns[0].annualized_tax, 225, 1_000)
+        );
+        assert_eq!(
+            columns[1].installment,
+            scale(columns[1].annualized_tax, 450, 1_000) - columns[0].installment
+        );
+        // Itemizing only beats the standard deduction for the whole year.
+        assert_eq!(columns[2].annualized_income, 90_000);
+        assert_eq!(columns[2].deduction, 14_600);
+        assert_eq!(columns[3].deduction, 20_000);
+        // The last installment makes up what the earlier ones deferred.
+        let total: i64 = columns.iter().map(|column| column.installment).sum();
+        assert_eq!(total, 20_000);
+        for column in &columns {
+            assert!(column.installment <= 20_000);
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn errors() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+        let periods = IncomePeriods::default();
+        assert_eq!(
+            annualized_installments(year, status, &periods, -1),
+            Err(TaxError::NegativeIncome)
+        );
+        let periods = IncomePeriods {
+            itemized_deductions: [0, 0, -1, 0],
+            ..periods
+        };
+        assert_eq!(
+            annualized_installments(year, status, &periods, 0),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+}
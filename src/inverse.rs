@@ -0,0 +1,156 @@
+//! Inverse solver: taxable income for a target tax.
+
+use std::ops::RangeInclusive;
+
+use crate::compute::compute_tax;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// Return the range of taxable incomes whose tax is exactly `target_tax`.
+///
+/// [`compute_tax`] never decreases as income rises, so the incomes producing a
+/// given tax form a contiguous range. The range is found by binary search over
+/// the Tax Table and worksheet.
+///
+/// Returns [`None`] if no whole-dollar income produces exactly `target_tax`.
+/// This happens for negative targets, and for targets that fall between two
+/// adjacent Tax Table rows or two consecutive worksheet dollars.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NoBracketFound`] if the embedded data has no bracket
+/// for an income visited during the search (should not occur with valid
+/// embedded data).
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2023")] {
+/// use us_tax_brackets::{income_for_tax, FilingStatus, TaxYear};
+///
+/// // 2023 head of household: $75,000–$75,049 is taxed $10,207
+/// let range = income_for_tax(TaxYear::Y2023, FilingStatus::HeadOfHousehold, 10_207).unwrap();
+/// assert_eq!(range, Some(75_000..=75_049));
+///
+/// // No income is taxed exactly $10,208 (the next row jumps to $10,218)
+/// let range = income_for_tax(TaxYear::Y2023, FilingStatus::HeadOfHousehold, 10_208).unwrap();
+/// assert_eq!(range, None);
+/// # }
+/// ```
+pub fn income_for_tax(
+    year: TaxYear,
+    status: FilingStatus,
+    target_tax: i64,
+) -> Result<Option<RangeInclusive<i64>>, TaxError> {
+    if target_tax < 0 {
+        return Ok(None);
+    }
+
+    let low = first_income_taxed_above(year, status, target_tax - 1)?;
+    if compute_tax(year, status, low)? != target_tax {
+        return Ok(None);
+    }
+    let high = first_income_taxed_above(year, status, target_tax)? - 1;
+    Ok(Some(low..=high))
+}
+
+/// Return the smallest income whose tax exceeds `tax`.
+///
+/// Doubles an upper bound until its tax exceeds `tax`, then bisects. The
+/// bound is capped at `i64::MAX`, whose tax exceeds any target a caller can
+/// produce from this crate's data.
+fn first_income_taxed_above(
+    year: TaxYear,
+    status: FilingStatus,
+    tax: i64,
+) -> Result<i64, TaxError> {
+    if compute_tax(year, status, 0)? > tax {
+        return Ok(0);
+    }
+
+    // Invariant: tax(low) <= tax < tax(high)
+    let mut low = 0_i64;
+    let mut high = 1_i64;
+    while compute_tax(year, status, high)? <= tax {
+        low = high;
+        high = high.checked_mul(2).unwrap_or(i64::MAX);
+        if low == i64::MAX {
+            return Ok(i64::MAX);
+        }
+    }
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if compute_tax(year, status, mid)? <= tax {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Ok(high)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn zero_tax() {
+        // The $0–$5 row has no tax
+        assert_eq!(
+            income_for_tax(TaxYear::Y2025, FilingStatus::Single, 0).unwrap(),
+            Some(0..=4)
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn negative_target() {
+        assert_eq!(
+            income_for_tax(TaxYear::Y2025, FilingStatus::Single, -10).unwrap(),
+            None
+        );
+    }
+
+    #[cfg(feature = "year-2023")]
+    #[test]
+    fn worksheet_single_income() {
+        // 2023 MFJ: 200000 × 0.24 − 13200 = 34800, and each extra dollar adds $0.24
+        let range =
+            income_for_tax(TaxYear::Y2023, FilingStatus::MarriedFilingJointly, 34_800).unwrap();
+        let range = range.unwrap();
+        assert!(range.contains(&200_000));
+        for income in range.clone() {
+            assert_eq!(
+                compute_tax(TaxYear::Y2023, FilingStatus::MarriedFilingJointly, income).unwrap(),
+                34_800
+            );
+        }
+        let (start, end) = range.into_inner();
+        assert!(
+            compute_tax(
+                TaxYear::Y2023,
+                FilingStatus::MarriedFilingJointly,
+                start - 1
+            )
+            .unwrap()
+                < 34_800
+        );
+        assert!(
+            compute_tax(TaxYear::Y2023, FilingStatus::MarriedFilingJointly, end + 1).unwrap()
+                > 34_800
+        );
+    }
+
+    #[test]
+    fn round_trips_compute_tax() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for income in [12_345, 99_999, 100_000, 640_000, 3_000_000] {
+                    let tax = compute_tax(year, status, income).unwrap();
+                    let range = income_for_tax(year, status, tax).unwrap().unwrap();
+                    assert!(range.contains(&income), "{year} {status} {income}");
+                }
+            }
+        }
+    }
+}
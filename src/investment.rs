@@ -0,0 +1,395 @@
+//! The tax cost of holding investments in a taxable account.
+
+use crate::capital_gains::{CapitalGains, compute_tax_with_gains, ltcg_rate_for};
+use crate::compute::compute_tax;
+use crate::deduction::DeductionChoice;
+use crate::rates::marginal_rate;
+use crate::tax_return::{niit_threshold, percent_of};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// A portfolio's taxable income for one year, for [`tax_drag`], in whole
+/// dollars.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PortfolioIncome {
+    /// Taxable interest.
+    pub interest: i64,
+    /// Dividends that are not qualified, such as those from REITs and money
+    /// market funds.
+    pub nonqualified_dividends: i64,
+    /// Qualified dividends.
+    pub qualified_dividends: i64,
+    /// Net short-term capital gain realized, including fund distributions.
+    pub short_term_gains: i64,
+    /// Net long-term capital gain realized, including capital gain
+    /// distributions.
+    pub long_term_gains: i64,
+}
+
+impl PortfolioIncome {
+    /// The income taxed at ordinary rates: interest, nonqualified dividends,
+    /// and short-term gains.
+    pub fn ordinary(&self) -> i64 {
+        [
+            self.interest,
+            self.nonqualified_dividends,
+            self.short_term_gains,
+        ]
+        .into_iter()
+        .fold(0_i64, i64::saturating_add)
+    }
+
+    /// The income taxed at the long-term capital gain rates: qualified
+    /// dividends and long-term gains.
+    pub fn preferential(&self) -> i64 {
+        self.qualified_dividends
+            .saturating_add(self.long_term_gains)
+    }
+
+    /// All of the income.
+    pub fn total(&self) -> i64 {
+        self.ordinary().saturating_add(self.preferential())
+    }
+}
+
+/// The yearly tax on a portfolio's income, returned by [`tax_drag`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaxDrag {
+    /// The income tax added by the ordinary income, in whole dollars.
+    pub ordinary_tax: i64,
+    /// The income tax added by the qualified dividends and long-term gains
+    /// on top of it.
+    pub preferential_tax: i64,
+    /// The 3.8% net investment income tax on the portfolio's income.
+    pub net_investment_income_tax: i64,
+    /// The sum of the three.
+    pub tax: i64,
+    /// [`tax`](Self::tax) as a share of the portfolio's value, in basis
+    /// points, or 0 for an empty portfolio.
+    pub basis_points: f64,
+}
+
+/// Compute the yearly federal tax on a portfolio's `income` for someone
+/// with adjusted gross income of `other_agi` from everything else, and what
+/// it costs as a share of the `portfolio_value`.
+///
+/// The tax is the difference between the returns with and without the
+/// portfolio: [`compute_tax`] on the other income, then with the ordinary
+/// income added, then [`compute_tax_with_gains`] with the qualified
+/// dividends and long-term gains stacked on top. The net investment income
+/// tax applies to the portfolio's income above the statutory thresholds of
+/// modified adjusted gross income, taking `other_agi` to include no
+/// investment income of its own. Held in a tax-advantaged account, the same
+/// portfolio would have no drag, so [`basis_points`](TaxDrag::basis_points)
+/// is what the taxable account gives up each year.
+///
+/// Net capital losses are not netted against other income; pass zero for
+/// the gains.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if any of `income` or the
+/// `portfolio_value` is negative, [`TaxError::IncomeOutOfRange`] if the
+/// income does not fit in an `i64` with `other_agi`, and the same errors
+/// as [`DeductionChoice::amount`] and [`compute_tax_with_gains`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{tax_drag, DeductionChoice, FilingStatus, PortfolioIncome, TaxYear};
+///
+/// // A $500,000 portfolio yielding 1% in interest and 1.5% in qualified dividends
+/// let income = PortfolioIncome {
+///     interest: 5_000,
+///     qualified_dividends: 7_500,
+///     ..PortfolioIncome::default()
+/// };
+/// let (year, status) = (TaxYear::Y2025, FilingStatus::MarriedFilingJointly);
+/// let drag = tax_drag(year, status, 150_000, DeductionChoice::Standard, &income, 500_000).unwrap();
+/// // 22% of the interest and 15% of the dividends
+/// assert_eq!(drag.ordinary_tax, 1_100);
+/// assert_eq!(drag.preferential_tax, 1_125);
+/// assert_eq!(drag.net_investment_income_tax, 0);
+/// assert_eq!(drag.basis_points, 44.5);
+/// # }
+/// ```
+pub fn tax_drag(
+    year: TaxYear,
+    status: FilingStatus,
+    other_agi: i64,
+    deduction: DeductionChoice,
+    income: &PortfolioIncome,
+    portfolio_value: i64,
+) -> Result<TaxDrag, TaxError> {
+    let PortfolioIncome {
+        interest,
+        nonqualified_dividends,
+        qualified_dividends,
+        short_term_gains,
+        long_term_gains,
+    } = *income;
+    if [
+        interest,
+        nonqualified_dividends,
+        qualified_dividends,
+        short_term_gains,
+        long_term_gains,
+        portfolio_value,
+    ]
+    .iter()
+    .any(|&amount| amount < 0)
+    {
+        return Err(TaxError::NegativeIncome);
+    }
+    let deduction = deduction.amount(year, status)?;
+    let taxable = |agi: i64| agi.saturating_sub(deduction).max(0);
+    let add = |amount: i64, more: i64| amount.checked_add(more).ok_or(TaxError::IncomeOutOfRange);
+
+    let ordinary_agi = add(other_agi, income.ordinary())?;
+    let agi = add(ordinary_agi, income.preferential())?;
+    let base_tax = compute_tax(year, status, taxable(other_agi))?;
+    let ordinary_tax = compute_tax(year, status, taxable(ordinary_agi))? - base_tax;
+    let gains = CapitalGains {
+        qualified_dividends,
+        net_capital_gain: long_term_gains,
+        ..CapitalGains::default()
+    };
+    let preferential_tax =
+        compute_tax_with_gains(year, status, taxable(agi), &gains)? - base_tax - ordinary_tax;
+
+    let excess_agi = agi.saturating_sub(niit_threshold(status)).max(0);
+    let net_investment_income_tax = percent_of(income.total().min(excess_agi), 38);
+    let tax = ordinary_tax + preferential_tax + net_investment_income_tax;
+    Ok(TaxDrag {
+        ordinary_tax,
+        preferential_tax,
+        net_investment_income_tax,
+        tax,
+        basis_points: if portfolio_value == 0 {
+            0.0
+        } else {
+            tax as f64 * 10_000.0 / portfolio_value as f64
+        },
+    })
+}
+
+/// How a yield is taxed, for [`after_tax_yield`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum YieldType {
+    /// Taxed at ordinary rates: interest on bonds and deposits, nonqualified
+    /// dividends, and short-term gains.
+    Ordinary,
+    /// Taxed at the long-term capital gain rates: qualified dividends and
+    /// long-term gains.
+    Qualified,
+    /// Exempt from federal income tax, such as municipal bond interest.
+    TaxExempt,
+}
+
+/// The taxpayer whose rates [`after_tax_yield`] applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaxpayerContext {
+    /// The tax year.
+    pub year: TaxYear,
+    /// The filing status.
+    pub status: FilingStatus,
+    /// Taxable income, in whole dollars, which sets the marginal rates.
+    pub taxable_income: i64,
+    /// Modified adjusted gross income, for the net investment income tax,
+    /// or [`None`] to leave that tax out.
+    pub modified_agi: Option<i64>,
+}
+
+impl TaxpayerContext {
+    /// A context without the net investment income tax.
+    pub fn new(year: TaxYear, status: FilingStatus, taxable_income: i64) -> Self {
+        TaxpayerContext {
+            year,
+            status,
+            taxable_income,
+            modified_agi: None,
+        }
+    }
+
+    /// The federal tax rate on the next dollar of `yield_type`: the
+    /// [`marginal_rate`] for ordinary income or the [`ltcg_rate_for`]
+    /// qualified income, plus 3.8% when the
+    /// [`modified_agi`](Self::modified_agi) is over the net investment income
+    /// tax threshold, and 0 for tax-exempt income.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`marginal_rate`] and [`ltcg_rate_for`].
+    pub fn tax_rate(&self, yield_type: YieldType) -> Result<f64, TaxError> {
+        let (year, status, taxable_income) = (self.year, self.status, self.taxable_income);
+        let income_tax_rate = match yield_type {
+            YieldType::Ordinary => marginal_rate(year, status, taxable_income)?,
+            YieldType::Qualified => ltcg_rate_for(year, status, taxable_income)?,
+            YieldType::TaxExempt => return Ok(0.0),
+        };
+        let niit = self
+            .modified_agi
+            .is_some_and(|magi| magi > niit_threshold(status));
+        Ok(if niit {
+            income_tax_rate + 0.038
+        } else {
+            income_tax_rate
+        })
+    }
+}
+
+/// Compute what a `pre_tax_yield` of `yield_type` keeps after federal tax for
+/// the taxpayer in `context`: the yield times one less
+/// [`TaxpayerContext::tax_rate`].
+///
+/// This is the inverse of [`tax_equivalent_yield`], for more kinds of
+/// income. Comparing the after-tax yields of the same holdings shows which
+/// belong in a taxable account: those taxed least, while the ones taxed at
+/// ordinary rates gain most from a tax-advantaged account. Yields are
+/// fractions (`0.045` for 4.5%); state tax is not considered.
+///
+/// [`tax_equivalent_yield`]: crate::tax_equivalent_yield
+///
+/// # Errors
+///
+/// Returns the same errors as [`TaxpayerContext::tax_rate`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{after_tax_yield, FilingStatus, TaxYear, TaxpayerContext, YieldType};
+///
+/// // 2025 single with $150,000 of taxable income: 24% ordinary, 15% qualified
+/// let context = TaxpayerContext::new(TaxYear::Y2025, FilingStatus::Single, 150_000);
+/// let bond = after_tax_yield(0.045, YieldType::Ordinary, &context).unwrap();
+/// let stock = after_tax_yield(0.04, YieldType::Qualified, &context).unwrap();
+/// let muni = after_tax_yield(0.035, YieldType::TaxExempt, &context).unwrap();
+/// assert!((bond - 0.0342).abs() < 1e-12);
+/// assert!((stock - 0.034).abs() < 1e-12);
+/// assert_eq!(muni, 0.035);
+/// # }
+/// ```
+pub fn after_tax_yield(
+    pre_tax_yield: f64,
+    yield_type: YieldType,
+    context: &TaxpayerContext,
+) -> Result<f64, TaxError> {
+    Ok(pre_tax_yield * (1.0 - context.tax_rate(yield_type)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn high_income_pays_niit() {
+        let income = PortfolioIncome {
+            interest: 10_000,
+            nonqualified_dividends: 2_000,
+            qualified_dividends: 20_000,
+            short_term_gains: 3_000,
+            long_term_gains: 15_000,
+        };
+        assert_eq!(income.ordinary(), 15_000);
+        assert_eq!(income.preferential(), 35_000);
+        let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+        let deduction = DeductionChoice::Standard;
+        let drag = tax_drag(year, status, 300_000, deduction, &income, 1_000_000).unwrap();
+        // 35% on the ordinary income and 15% on the rest
+        assert_eq!(drag.ordinary_tax, 5_250);
+        assert_eq!(drag.preferential_tax, 5_250);
+        // All $50,000 is over the $200,000 threshold.
+        assert_eq!(drag.net_investment_income_tax, 1_900);
+        assert_eq!(drag.tax, 12_400);
+        assert_eq!(drag.basis_points, 124.0);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn low_income_gains_are_untaxed() {
+        let income = PortfolioIncome {
+            long_term_gains: 20_000,
+            ..PortfolioIncome::default()
+        };
+        let (year, status) = (TaxYear::Y2024, FilingStatus::MarriedFilingJointly);
+        let deduction = DeductionChoice::Standard;
+        let drag = tax_drag(year, status, 50_000, deduction, &income, 400_000).unwrap();
+        assert_eq!(drag.tax, 0);
+        assert_eq!(drag.basis_points, 0.0);
+
+        // Part of the NIIT base is below the threshold.
+        let drag = tax_drag(year, status, 240_000, deduction, &income, 0).unwrap();
+        assert_eq!(drag.net_investment_income_tax, 380);
+        assert_eq!(drag.basis_points, 0.0);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn after_tax_yields() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::MarriedFilingJointly);
+        let context = TaxpayerContext::new(year, status, 50_000);
+        assert_eq!(context.tax_rate(YieldType::Ordinary), Ok(0.12));
+        assert_eq!(context.tax_rate(YieldType::Qualified), Ok(0.0));
+        assert_eq!(
+            after_tax_yield(0.04, YieldType::Qualified, &context),
+            Ok(0.04)
+        );
+
+        // The net investment income tax applies over $250,000.
+        let context = TaxpayerContext {
+            modified_agi: Some(430_000),
+            ..TaxpayerContext::new(year, status, 400_000)
+        };
+        assert_eq!(context.tax_rate(YieldType::Ordinary), Ok(0.32 + 0.038));
+        assert_eq!(context.tax_rate(YieldType::Qualified), Ok(0.15 + 0.038));
+        assert_eq!(context.tax_rate(YieldType::TaxExempt), Ok(0.0));
+        let below = TaxpayerContext {
+            modified_agi: Some(250_000),
+            ..context
+        };
+        assert_eq!(below.tax_rate(YieldType::Qualified), Ok(0.15));
+
+        let negative = TaxpayerContext::new(year, status, -1);
+        assert_eq!(
+            after_tax_yield(0.05, YieldType::Ordinary, &negative),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn errors() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+        let deduction = DeductionChoice::Standard;
+        let income = PortfolioIncome {
+            short_term_gains: -1,
+            ..PortfolioIncome::default()
+        };
+        assert_eq!(
+            tax_drag(year, status, 0, deduction, &income, 0),
+            Err(TaxError::NegativeIncome)
+        );
+        let income = PortfolioIncome {
+            interest: 1,
+            ..PortfolioIncome::default()
+        };
+        assert_eq!(
+            tax_drag(year, status, 0, deduction, &income, -1),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            tax_drag(year, status, i64::MAX, deduction, &income, 0),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! Roth IRA contribution limits (Publication 590-A).
+
+use crate::compute::income_to_i64;
+use crate::data;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The modified adjusted gross income range over which the Roth IRA
+/// contribution limit phases out in `year` for `status`, as the first income
+/// with a reduced limit and the income at which nothing can be contributed.
+///
+/// Married couples filing jointly and qualifying surviving spouses have
+/// their own range. Married people filing separately who lived with their
+/// spouse during the year phase out from $0 to $10,000, a range that is not
+/// adjusted for inflation; those who did not should use
+/// [`FilingStatus::Single`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{roth_ira_phaseout, FilingStatus, TaxYear};
+///
+/// assert_eq!(roth_ira_phaseout(TaxYear::Y2025, FilingStatus::Single), (150_000, 165_000));
+/// assert_eq!(
+///     roth_ira_phaseout(TaxYear::Y2025, FilingStatus::MarriedFilingJointly),
+///     (236_000, 246_000)
+/// );
+/// # }
+/// ```
+pub const fn roth_ira_phaseout(year: TaxYear, status: FilingStatus) -> (i64, i64) {
+    let limits = data::ira_limits(year);
+    match status {
+        FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse => (
+            limits.roth_phaseout_start_joint,
+            limits.roth_phaseout_end_joint,
+        ),
+        FilingStatus::MarriedFilingSeparately => (0, 10_000),
+        FilingStatus::Single | FilingStatus::HeadOfHousehold => {
+            (limits.roth_phaseout_start, limits.roth_phaseout_end)
+        }
+    }
+}
+
+/// The most that can be contributed to Roth IRAs in `year` by someone with
+/// modified adjusted gross income `magi`, using Worksheet 2-2 of Publication
+/// 590-A.
+///
+/// The limit starts from the IRA contribution limit, plus the catch-up
+/// contribution if `age_50_or_older`, and is reduced in proportion as `magi`
+/// moves through the [`roth_ira_phaseout`]. A reduced limit is rounded up to
+/// the next $10, and is at least $200 until the end of the range. The limit
+/// is shared with any traditional IRA contributions and cannot exceed the
+/// person's taxable compensation, both of which are left to the caller.
+///
+/// # Errors
+///
+/// Returns [`TaxError::IncomeOutOfRange`] if `magi` does not fit in an
+/// `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{roth_contribution_limit, FilingStatus, TaxYear};
+///
+/// let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+/// assert_eq!(roth_contribution_limit(year, status, 100_000, false), Ok(7_000));
+/// assert_eq!(roth_contribution_limit(year, status, 100_000, true), Ok(8_000));
+/// // 11/15 of $7,000 is $5,133.33, rounded up to $5,140.
+/// assert_eq!(roth_contribution_limit(year, status, 150_000, false), Ok(5_140));
+/// assert_eq!(roth_contribution_limit(year, status, 161_000, false), Ok(0));
+/// # }
+/// ```
+pub fn roth_contribution_limit(
+    year: TaxYear,
+    status: FilingStatus,
+    magi: impl TryInto<i64>,
+    age_50_or_older: bool,
+) -> Result<i64, TaxError> {
+    let magi = income_to_i64(magi)?;
+    let limits = data::ira_limits(year);
+    let limit = if age_50_or_older {
+        limits.contribution_limit + limits.catch_up
+    } else {
+        limits.contribution_limit
+    };
+    let (start, end) = roth_ira_phaseout(year, status);
+    if magi <= start {
+        return Ok(limit);
+    }
+    if magi >= end {
+        return Ok(0);
+    }
+    // Rounded up to the next $10
+    let tens = (end - start) * 10;
+    let reduced = (limit * (end - magi) + tens - 1) / tens * 10;
+    Ok(reduced.max(200))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "year-2018", feature = "year-2021"))]
+    #[test]
+    fn phaseout_ranges() {
+        for year in TaxYear::ALL {
+            let (start, end) = roth_ira_phaseout(year, FilingStatus::HeadOfHousehold);
+            assert_eq!(end - start, 15_000, "{year:?}");
+            let (start, end) = roth_ira_phaseout(year, FilingStatus::QualifyingSurvivingSpouse);
+            assert_eq!(end - start, 10_000, "{year:?}");
+        }
+        assert_eq!(
+            roth_ira_phaseout(TaxYear::Y2018, FilingStatus::Single),
+            (120_000, 135_000)
+        );
+        assert_eq!(
+            roth_ira_phaseout(TaxYear::Y2021, FilingStatus::MarriedFilingSeparately),
+            (0, 10_000)
+        );
+    }
+
+    #[cfg(feature = "year-2023")]
+    #[test]
+    fn limits() {
+        let single = FilingStatus::Single;
+        let limit = |year| roth_contribution_limit(year, single, 0, false).unwrap();
+        assert_eq!(
+            TaxYear::ALL.map(limit),
+            TaxYear::select([5_500, 6_000, 6_000, 6_000, 6_000, 6_500, 7_000, 7_000])
+        );
+        assert_eq!(
+            roth_contribution_limit(TaxYear::Y2023, single, -1_000, true),
+            Ok(7_500)
+        );
+    }
+
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn phaseout() {
+        let year = TaxYear::Y2024;
+        let single = FilingStatus::Single;
+        let limit = |magi, catch_up| roth_contribution_limit(year, single, magi, catch_up).unwrap();
+        assert_eq!(limit(146_000, false), 7_000);
+        assert_eq!(limit(150_000, true), 5_870);
+        // $23.33 is raised to the $200 minimum.
+        assert_eq!(limit(160_950, false), 200);
+        assert_eq!(limit(i64::MAX, false), 0);
+
+        let joint = FilingStatus::MarriedFilingJointly;
+        assert_eq!(
+            roth_contribution_limit(TaxYear::Y2025, joint, 241_000, false),
+            Ok(3_500)
+        );
+        let separate = FilingStatus::MarriedFilingSeparately;
+        assert_eq!(
+            roth_contribution_limit(TaxYear::Y2025, separate, 5_000, false),
+            Ok(3_500)
+        );
+        assert_eq!(
+            roth_contribution_limit(TaxYear::Y2025, separate, 10_000, false),
+            Ok(0)
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn errors() {
+        assert_eq!(
+            roth_contribution_limit(TaxYear::Y2025, FilingStatus::Single, u64::MAX, false),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
@@ -0,0 +1,298 @@
+//! Comparison of itemized deductions with the standard deduction.
+
+use crate::compute::{compute_tax, income_to_i64};
+use crate::deduction::{DeductionChoice, standard_deduction_for};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// Itemized deduction amounts before the limits of Schedule A, in whole
+/// dollars.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ItemizedDeductions {
+    /// State and local income (or sales), real estate, and personal property
+    /// taxes paid.
+    pub state_and_local_taxes: i64,
+    /// Deductible home mortgage interest and points. The limit on the
+    /// mortgage debt whose interest is deductible depends on the loan
+    /// balances, so this should already reflect it.
+    pub mortgage_interest: i64,
+    /// Gifts to charity by cash or check.
+    pub charitable_contributions: i64,
+    /// Total medical and dental expenses, before the floor of 7.5% of
+    /// adjusted gross income.
+    pub medical_expenses: i64,
+}
+
+impl ItemizedDeductions {
+    /// The total itemized deduction (Schedule A, line 17) for a return with
+    /// adjusted gross income `agi`.
+    ///
+    /// Medical expenses count only above 7.5% of `agi`. State and local taxes
+    /// are capped at $10,000 ($5,000 married filing separately); for 2025 the
+    /// cap is $40,000 ($20,000), reduced by 30% of `agi` above $500,000
+    /// ($250,000) but not below $10,000 ($5,000). Cash contributions are
+    /// limited to 60% of `agi`, or 100% in 2020 and 2021. Amounts below zero
+    /// count as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "year-2024")] {
+    /// use us_tax_brackets::{FilingStatus, ItemizedDeductions, TaxYear};
+    ///
+    /// let itemized = ItemizedDeductions {
+    ///     state_and_local_taxes: 14_000,
+    ///     mortgage_interest: 9_000,
+    ///     charitable_contributions: 2_000,
+    ///     medical_expenses: 8_000,
+    /// };
+    /// // $10,000 of taxes, plus the medical expenses above $7,500
+    /// let total = itemized.total(TaxYear::Y2024, FilingStatus::MarriedFilingJointly, 100_000);
+    /// assert_eq!(total, 10_000 + 9_000 + 2_000 + 500);
+    /// # }
+    /// ```
+    pub fn total(&self, year: TaxYear, status: FilingStatus, agi: i64) -> i64 {
+        let agi = agi.max(0);
+        let medical_floor = (i128::from(agi) * 75 + 500) / 1_000;
+        let medical = (i128::from(self.medical_expenses) - medical_floor).max(0) as i64;
+        let charitable_limit = match u16::from(year) {
+            2020 | 2021 => agi,
+            _ => (i128::from(agi) * 60 / 100) as i64,
+        };
+        [
+            self.state_and_local_taxes
+                .clamp(0, state_and_local_tax_cap(year, status, agi)),
+            self.mortgage_interest.max(0),
+            self.charitable_contributions.clamp(0, charitable_limit),
+            medical,
+        ]
+        .into_iter()
+        .fold(0_i64, i64::saturating_add)
+    }
+}
+
+/// The limit on the deduction for state and local taxes.
+pub(crate) fn state_and_local_tax_cap(year: TaxYear, status: FilingStatus, agi: i64) -> i64 {
+    // Married filing separately gets half of every amount.
+    let divisor = match status {
+        FilingStatus::MarriedFilingSeparately => 2,
+        _ => 1,
+    };
+    match u16::from(year) {
+        2025.. => {
+            let excess = agi.saturating_sub(500_000 / divisor).max(0);
+            let reduction = (i128::from(excess) * 3 / 10) as i64;
+            (40_000 / divisor - reduction.min(40_000)).max(10_000 / divisor)
+        }
+        _ => 10_000 / divisor,
+    }
+}
+
+/// The tax with the standard deduction and with itemized deductions, returned
+/// by [`compare_deductions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DeductionComparison {
+    /// The standard deduction, including any additional amounts for age or
+    /// blindness.
+    pub standard: DeductionChoice,
+    /// The amount of the standard deduction.
+    pub standard_deduction: i64,
+    /// The tax with the standard deduction.
+    pub standard_tax: i64,
+    /// The total itemized deduction, after the limits of Schedule A.
+    pub itemized_deduction: i64,
+    /// The tax with itemized deductions.
+    pub itemized_tax: i64,
+}
+
+impl DeductionComparison {
+    /// Whether itemizing gives a lower tax. When the taxes are equal, the
+    /// standard deduction is preferred, since it needs no records.
+    pub fn should_itemize(&self) -> bool {
+        self.itemized_tax < self.standard_tax
+    }
+
+    /// The choice with the lower tax.
+    pub fn best(&self) -> DeductionChoice {
+        if self.should_itemize() {
+            DeductionChoice::Itemized(self.itemized_deduction)
+        } else {
+            self.standard
+        }
+    }
+
+    /// How much less tax the better choice gives, in whole dollars.
+    pub fn tax_savings(&self) -> i64 {
+        (self.standard_tax - self.itemized_tax).abs()
+    }
+}
+
+/// Compare the tax with the standard deduction and with `itemized`
+/// deductions, for a return with adjusted gross income `agi`.
+///
+/// The standard deduction is [`standard_deduction_for`] with `age65` people
+/// 65 or older and `blind` people who are blind. The itemized deduction is
+/// [`ItemizedDeductions::total`]. Each tax is computed as in
+/// [`compute_tax_from_agi`](crate::compute_tax_from_agi).
+///
+/// A married person filing separately must itemize if their spouse does,
+/// regardless of which choice this reports.
+///
+/// # Errors
+///
+/// Returns [`TaxError::IncomeOutOfRange`] if `agi` does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{compare_deductions, FilingStatus, ItemizedDeductions, TaxYear};
+///
+/// let itemized = ItemizedDeductions {
+///     state_and_local_taxes: 12_000,
+///     mortgage_interest: 6_000,
+///     ..Default::default()
+/// };
+/// let comparison =
+///     compare_deductions(TaxYear::Y2024, FilingStatus::Single, 80_000, &itemized, 0, 0).unwrap();
+/// // $16,000 itemized beats the $14,600 standard deduction
+/// assert!(comparison.should_itemize());
+/// assert_eq!(comparison.itemized_deduction, 16_000);
+/// assert_eq!(comparison.tax_savings(), 308);
+/// # }
+/// ```
+pub fn compare_deductions(
+    year: TaxYear,
+    status: FilingStatus,
+    agi: impl TryInto<i64>,
+    itemized: &ItemizedDeductions,
+    age65: u8,
+    blind: u8,
+) -> Result<DeductionComparison, TaxError> {
+    let agi = income_to_i64(agi)?;
+    let tax = |deduction: i64| compute_tax(year, status, agi.saturating_sub(deduction).max(0));
+    let standard_deduction = standard_deduction_for(year, status, age65, blind);
+    let itemized_deduction = itemized.total(year, status, agi);
+    Ok(DeductionComparison {
+        standard: DeductionChoice::StandardWithAdditional { age65, blind },
+        standard_deduction,
+        standard_tax: tax(standard_deduction)?,
+        itemized_deduction,
+        itemized_tax: tax(itemized_deduction)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deduction::compute_tax_from_agi;
+
+    #[cfg(all(feature = "year-2018", feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn state_and_local_tax_caps() {
+        let cap = state_and_local_tax_cap;
+        assert_eq!(cap(TaxYear::Y2018, FilingStatus::Single, 1_000_000), 10_000);
+        assert_eq!(
+            cap(
+                TaxYear::Y2024,
+                FilingStatus::MarriedFilingSeparately,
+                50_000
+            ),
+            5_000
+        );
+        assert_eq!(cap(TaxYear::Y2025, FilingStatus::Single, 500_000), 40_000);
+        assert_eq!(cap(TaxYear::Y2025, FilingStatus::Single, 550_000), 25_000);
+        assert_eq!(cap(TaxYear::Y2025, FilingStatus::Single, 600_000), 10_000);
+        assert_eq!(cap(TaxYear::Y2025, FilingStatus::Single, i64::MAX), 10_000);
+        assert_eq!(
+            cap(
+                TaxYear::Y2025,
+                FilingStatus::MarriedFilingSeparately,
+                250_000
+            ),
+            20_000
+        );
+        assert_eq!(
+            cap(
+                TaxYear::Y2025,
+                FilingStatus::MarriedFilingSeparately,
+                300_000
+            ),
+            5_000
+        );
+    }
+
+    #[cfg(all(feature = "year-2021", feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn limits() {
+        let itemized = ItemizedDeductions {
+            state_and_local_taxes: 0,
+            mortgage_interest: -5,
+            charitable_contributions: 50_000,
+            medical_expenses: 3_000,
+        };
+        // 60% of AGI, and no medical expenses below 7.5% of AGI
+        assert_eq!(
+            itemized.total(TaxYear::Y2024, FilingStatus::Single, 40_000),
+            24_000
+        );
+        assert_eq!(
+            itemized.total(TaxYear::Y2021, FilingStatus::Single, 40_000),
+            40_000
+        );
+        // A loss year allows no charitable deduction but all medical expenses.
+        assert_eq!(
+            itemized.total(TaxYear::Y2024, FilingStatus::Single, -1_000),
+            3_000
+        );
+        assert_eq!(
+            ItemizedDeductions::default().total(TaxYear::Y2025, FilingStatus::Single, 0),
+            0
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn comparison_matches_compute_tax_from_agi() {
+        let year = TaxYear::Y2025;
+        let status = FilingStatus::MarriedFilingJointly;
+        for mortgage_interest in (0..40_000).step_by(2_500) {
+            let itemized = ItemizedDeductions {
+                state_and_local_taxes: 15_000,
+                mortgage_interest,
+                ..Default::default()
+            };
+            let comparison = compare_deductions(year, status, 180_000, &itemized, 1, 0).unwrap();
+            assert_eq!(comparison.standard_deduction, 33_100);
+            assert_eq!(
+                Ok(comparison.standard_tax),
+                compute_tax_from_agi(year, status, 180_000, comparison.standard)
+            );
+            let best = compute_tax_from_agi(year, status, 180_000, comparison.best()).unwrap();
+            assert_eq!(
+                best,
+                comparison.standard_tax.min(comparison.itemized_tax),
+                "{mortgage_interest}"
+            );
+            assert_eq!(
+                comparison.should_itemize(),
+                15_000 + mortgage_interest > 33_100
+            );
+        }
+
+        // Equal deductions prefer the standard deduction.
+        let itemized = ItemizedDeductions {
+            mortgage_interest: 15_750,
+            ..Default::default()
+        };
+        let comparison =
+            compare_deductions(year, FilingStatus::Single, 60_000, &itemized, 0, 0).unwrap();
+        assert!(!comparison.should_itemize());
+        assert_eq!(comparison.tax_savings(), 0);
+        assert_eq!(
+            comparison.best(),
+            DeductionChoice::StandardWithAdditional { age65: 0, blind: 0 }
+        );
+    }
+}
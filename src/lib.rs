@@ -14,21 +14,210 @@
 //! - **Tax Computation Worksheet** — For taxable incomes of $100,000 or more.
 //!   Uses the formula: `tax = taxable_income × rate − subtraction_amount`, where
 //!   the rate and subtraction amount depend on the income bracket and filing status.
+//!   The formula is evaluated in integer arithmetic (rates in basis points,
+//!   subtraction amounts in cents), so results are reproducible bit for bit.
 //!
 //! This crate embeds both datasets at compile time and exposes a single
 //! [`compute_tax`] function that automatically selects the correct method.
+//! [`compute_tax_with_method`] also reports which [`Method`] was used, and
+//! [`compute_tax_cents`] works in integer cents without floating point.
+//! [`compute_tax_const`] is a `const fn`, for checking golden values at
+//! compile time.
+//! With the `decimal` feature, `compute_tax_decimal` does the same with
+//! [`rust_decimal`](https://docs.rs/rust_decimal) values.
+//! [`compute_tax_detailed`] returns a [`TaxComputation`] with every
+//! intermediate value for auditing, and [`income_for_tax`] inverts the
+//! computation to find the incomes that produce a given tax.
+//!
+//! [`TaxCalculator`] loads and validates one year's data up front and offers
+//! the same computations as methods, and [`TaxData`] does the same for a year's
+//! CSV files read from a directory at run time. With the `serde` feature,
+//! `TaxData` and [`TaxSchedule`] also load from and export to JSON or TOML.
+//! [`compute_tax_from`] takes its Tax Table rows and worksheet brackets from
+//! any [`BracketSource`], such as [`EmbeddedData`] or a database of your own,
+//! and [`DataOverrides`] patches individual rows or brackets of a source,
+//! recording each correction in the [`compute_tax_detailed_from`] trace.
+//! [`compute_tax_batch`] evaluates a slice of incomes in one call; with the
+//! `rayon` feature, `compute_tax_batch_par` spreads a batch across threads.
+//! [`compute_tax_column`] sorts a large column of incomes and walks the data
+//! once, and with the `arrow` feature, `compute_tax_arrow` does the same over
+//! Arrow year, status, and income columns. [`UsdAmount`] formats and parses
+//! dollar amounts such as `$11,420`, and [`compute_tax_with_options`] returns
+//! one with a chosen [`Rounding`] policy. [`hourly_income`] turns an hourly
+//! rate into income for a number of weeks, and a [`PartialYear`] annualizes
+//! income earned over part of a year and prorates the results back.
+//!
+//! The Tax Rate Schedules from the same instructions are also embedded, so
+//! [`marginal_rate`] can report the statutory bracket rate at any income.
+//! [`effective_rate`] divides the computed tax by taxable income, and
+//! [`rate_curve`] samples the tax and both rates across a range of incomes
+//! for plotting. [`tax_equivalent_yield`] uses the marginal rate to compare
+//! tax-exempt and taxable yields. [`compute_tax_breakdown`] splits income
+//! across the brackets, and [`incremental_tax`] reports the tax on extra
+//! income, such as a bonus, and the brackets it spans.
+//! [`compute_tax_exact`] applies the schedule at every income, without the
+//! Tax Table's $50 steps, and [`table_discrepancy`] compares the two. The
+//! brackets themselves are available through [`TaxSchedule`], and
+//! [`bracket_for_income`] returns the bracket containing a given income.
+//! [`headroom_in_current_bracket`] reports how much more income fits before
+//! the marginal rate rises. [`TaxSchedule::builder`] defines custom
+//! progressive schedules for modeling reforms, [`TaxSchedule::to_builder`]
+//! modifies an existing one, and [`reform_impact`] compares the revenue two
+//! schedules raise from a distribution of incomes. [`export_schedule`] writes
+//! a schedule, with worksheet-style subtraction amounts, as CSV or JSON, and
+//! [`tax_table_excerpt`] prints Tax Table rows as the instructions lay them
+//! out.
+//!
+//! [`validate_data`] checks the embedded files for a year for gaps, overlaps,
+//! and disagreement between the Tax Table and worksheet, returning each
+//! [`DataIssue`] found. [`check_inflation_adjustment`] cross-checks the
+//! rate schedules against the statutory brackets and each year's embedded
+//! [`cost_of_living_adjustment`], and
+//! [`inflation_adjusted_schedule`] rebuilds a schedule from a Revenue
+//! Procedure's cost-of-living adjustment. [`projected_schedule`] extends the
+//! latest year's brackets into future years at an assumed inflation rate,
+//! such as the [`historical_inflation_rate`], and
+//! [`bracket_inflation_factor`] converts amounts between years. With the
+//! `scenarios` feature, `compute_tax_under_law` compares current law with
+//! alternatives such as the TCJA sunset for the same year. With the
+//! `historical` feature, `historical_schedule` and `compute_tax_historical`
+//! extend the rate schedules back to 1991.
+//! [`tax_in_real_terms`] and [`real_bracket_thresholds`] restate amounts in
+//! constant dollars using the [`consumer_price_index`], and
+//! [`bracket_creep`] follows a fixed real income across the years to show
+//! how its effective rate drifts, with and without indexed brackets.
+//! [`present_value_of_taxes`] discounts a projected stream of yearly
+//! [`TaxPayment`]s to one year, to compare strategies that pay tax at
+//! different times.
+//!
+//! [`must_file`] checks gross income against the filing requirement
+//! thresholds ([`filing_threshold`]) from the same instructions, and
+//! [`standard_deduction`] and [`standard_deduction_for`] return the standard
+//! deduction to subtract before computing the tax.
+//! [`dependent_standard_deduction`] applies the limited deduction for people
+//! claimed as dependents, [`dependent_must_file`] applies their filing
+//! requirement to a [`Dependent`]'s earned and unearned income, and [`compute_tax_from_agi`] subtracts a
+//! [`DeductionChoice`] from adjusted gross income before computing the tax.
+//! [`compare_deductions`] applies the Schedule A limits to
+//! [`ItemizedDeductions`] and reports in a [`DeductionComparison`] whether
+//! itemizing lowers the tax. [`schedule_d_tax_worksheet`] taxes
+//! [`CapitalGains`] at the capital gain rates, including the 28% rate on
+//! collectibles and the 25% rate on unrecaptured section 1250 gain, and
+//! [`compute_tax_with_gains`] returns just the tax. [`ltcg_brackets`] and
+//! [`ltcg_rate_for`] give the 0%, 15%, and 20% capital gain brackets, and
+//! [`gain_harvesting_headroom`] how much gain still fits in the 0% and 15%
+//! brackets on top of ordinary income. [`tax_drag`] adds a portfolio's
+//! [`PortfolioIncome`] to the rest of a return and reports the yearly tax on
+//! it in dollars and basis points, and [`after_tax_yield`] what a yield of
+//! each [`YieldType`] keeps at a [`TaxpayerContext`]'s rates.
+//! [`compute_amt`] checks the regular tax against the alternative minimum
+//! tax of Form 6251. [`compute_trust_tax`] applies the compressed
+//! [`trust_brackets`] of Form 1041 to estates and non-grantor trusts.
+//! [`compute_estate_tax`] applies the unified [`estate_tax_brackets`] above
+//! the [`basic_exclusion_amount`], and [`annual_gift_exclusion`] gives the
+//! gifts each recipient can receive tax-free.
+//!
+//! [`compute_eitc`] computes the earned income credit from the same
+//! instructions' EIC Worksheet and Table. [`eitc_earned_income_amount`],
+//! [`eitc_maximum_credit`], and [`eitc_investment_income_limit`] give its
+//! parameters. [`compute_ctc`] applies the Schedule 8812 phaseout to the
+//! child tax credit and credit for other dependents, and the
+//! [`ChildTaxCredit`] it returns splits the credit into the part used
+//! against the tax and the refundable additional child tax credit.
+//! [`compute_education_credits`] applies Form 8863 to each student's
+//! [`StudentExpenses`], phasing out the [`EducationCredit`]s with income.
+//! [`compute_savers_credit`] applies the Form 8880 rate from the
+//! [`savers_credit_tiers`] to retirement contributions.
+//! [`compute_adoption_credit`] phases out the Form 8839 credit for each
+//! adopted child over the [`adoption_credit_phaseout`] range, and
+//! [`adoption_credit_limit`] also caps the exclusion for employer-provided
+//! adoption benefits. [`clean_vehicle_income_test`] checks the income
+//! limits of the [`CleanVehicle`] credits against this year's or last
+//! year's income. [`compute_ptc`] computes the Form 8962 premium tax credit
+//! from household income as a share of the [`federal_poverty_line`] and the
+//! [`ptc_applicable_figure`] for that share.
+//! [`compute_qbi_deduction`] applies the section 199A deduction for
+//! qualified business income, with the wage and property limits and the
+//! specified service business phaseout above the [`qbi_threshold`].
+//! [`roth_contribution_limit`] reduces the IRA contribution limit over the
+//! [`roth_ira_phaseout`] range. [`contribution_limits`] gives each year's
+//! 401(k), IRA, and HSA [`ContributionLimits`].
+//! [`required_minimum_distribution`] divides an IRA balance by the
+//! [`uniform_lifetime_period`], and [`single_life_expectancy`] gives the
+//! Single Life Table for inherited IRAs. [`early_withdrawal_penalty`]
+//! applies the 10% additional tax on early distributions after any
+//! [`EarlyWithdrawalException`]s. [`compute_lump_sum_tax`] applies the
+//! Form 4972 10-year tax option to a [`LumpSumDistribution`] with the 1986
+//! [`lump_sum_brackets`].
+//! [`taxable_social_security`] applies the Social Security Benefits
+//! Worksheet.
+//!
+//! [`TaxReturn::builder`] puts these together into a whole Form 1040: from
+//! wages, interest, dividends, capital gains, adjustments, deductions,
+//! dependents, and withholding, the [`TaxReturn`] it builds has adjusted
+//! gross income, taxable income, the tax from the right worksheet, the
+//! alternative minimum tax, credits, other taxes, and the refund or amount
+//! owed. [`compare_married_filing`] builds a couple's joint and separate
+//! returns from each spouse's [`SpouseFinances`] and reports the credits
+//! lost by filing separately.
+//! [`optimal_conversion`] sizes a Roth conversion to fill a bracket, stay
+//! under a capital gain rate, or stay within one of the Medicare
+//! [`irmaa_tiers`], and reports what converting past the target costs.
+//! [`find_cliffs`] scans a range of incomes for a [`CliffProfile`] and
+//! returns each [`Cliff`] where the next dollar costs a bracket step, a
+//! Medicare premium tier, or a lost credit.
+//! [`plan_withdrawals`] draws a year's spending from the
+//! [`RetirementAccounts`] of a [`WithdrawalPlan`] in each
+//! [`WithdrawalOrder`] and reports the tax, taxable Social Security, and
+//! Medicare premium surcharges of each in a [`WithdrawalOutcome`].
+//! [`compare_scenarios`] computes the tax for a list of [`Scenario`]s,
+//! such as other years, statuses, or adjustments, and reports each one's
+//! change from the first in a [`ComparisonTable`].
+//! [`estimate_refund`] reconciles the tax on taxable income and any
+//! [`Credits`] with withholding and estimated payments to give the refund or
+//! balance due.
+//! [`annualized_installments`] spreads the [`required_annual_payment`] of
+//! estimated tax over the year with the annualized income installment method
+//! of Form 2210, Schedule AI, for income that arrives unevenly.
+//!
+//! [`compute_fica`] splits the Social Security and Medicare taxes on wages
+//! between employee and employer, using each year's
+//! [`social_security_wage_base`]. [`federal_withholding`] computes the
+//! income tax withheld from a paycheck for a [`FormW4`] with the
+//! Publication 15-T percentage method, and [`recommend_w4`] suggests the
+//! Step 3 credits or Step 4(c) extra withholding that bring a year's
+//! withholding close to the tax owed. [`take_home`] puts the two together
+//! into the net pay from a paycheck after [`PretaxDeductions`].
 //!
 //! # Supported tax years
 //!
 //! | Year | Variant |
 //! |------|---------|
+//! | 2018 | [`TaxYear::Y2018`] |
+//! | 2019 | [`TaxYear::Y2019`] |
+//! | 2020 | [`TaxYear::Y2020`] |
+//! | 2021 | [`TaxYear::Y2021`] |
+//! | 2022 | [`TaxYear::Y2022`] |
 //! | 2023 | [`TaxYear::Y2023`] |
 //! | 2024 | [`TaxYear::Y2024`] |
 //! | 2025 | [`TaxYear::Y2025`] |
 //!
+//! With the `chrono` feature, `TaxYear::for_date` maps a calendar date to its
+//! tax year and `TaxYear::current` returns the year for today.
+//!
+//! With the `python` feature, the crate builds as a Python extension module
+//! (via maturin and the repository's `pyproject.toml`) exposing
+//! `compute_tax`, `compute_tax_breakdown`, the rate functions, and
+//! [`FilingStatus`]. With the `capi` feature, it exports a C ABI
+//! (`ustax_compute`, `ustax_marginal_rate`, and friends) described by the
+//! generated header `include/us_tax_brackets.h`. With the `cli` feature, the
+//! `us-tax` binary computes taxes, lists brackets, compares filing statuses,
+//! and runs CSV files of incomes from the terminal.
+//!
 //! # Examples
 //!
 //! ```
+//! # #[cfg(feature = "year-2025")] {
 //! use us_tax_brackets::{FilingStatus, TaxYear, compute_tax};
 //!
 //! // Single filer, $75,000 taxable income (uses Tax Table)
@@ -42,18 +231,241 @@
 //! // Head of household, $300,000 taxable income
 //! let tax = compute_tax(TaxYear::Y2025, FilingStatus::HeadOfHousehold, 300_000).unwrap();
 //! assert_eq!(tax, 72_809);
+//! # }
 //! ```
 //!
 //! # Data sources
 //!
 //! All tax data is scraped from the official IRS Form 1040 instructions using
 //! the BeautifulSoup-based scraper included in the `scraper/` directory of the
-//! repository. The CSV files are stored in `data/<year>/` and embedded into the
-//! binary at compile time via [`include_str!`].
+//! repository. The CSV files are stored in `data/<year>/`, and the build script
+//! converts them into static arrays, so a malformed row fails the build and
+//! computations do no parsing at run time. [`data_provenance`] reports the
+//! publications each embedded file came from, such as the Form 1040
+//! instructions or a revenue procedure, and its SHA-256.
+//! With the `fetch` feature, `fetch_year` downloads and parses a newly
+//! published year's tables from the IRS website.
+//!
+//! Each year's data is behind a `year-<year>` Cargo feature, all enabled by
+//! default. Builds that need only some years, such as WASM or embedded
+//! targets, can disable default features and enable just those years.
+
+// Tests that name a year left out are compiled away, which leaves some of the
+// imports and helpers they share unused.
+#![cfg_attr(
+    all(
+        test,
+        not(all(
+            feature = "year-2018",
+            feature = "year-2019",
+            feature = "year-2020",
+            feature = "year-2021",
+            feature = "year-2022",
+            feature = "year-2023",
+            feature = "year-2024",
+            feature = "year-2025",
+        ))
+    ),
+    allow(dead_code, unused_imports)
+)]
+
+#[cfg(not(any(
+    feature = "year-2018",
+    feature = "year-2019",
+    feature = "year-2020",
+    feature = "year-2021",
+    feature = "year-2022",
+    feature = "year-2023",
+    feature = "year-2024",
+    feature = "year-2025",
+)))]
+compile_error!("enable at least one tax year feature, such as `year-2025`");
 
+mod adoption;
+mod amt;
+mod annualize;
+#[cfg(feature = "arrow")]
+mod arrow;
+mod batch;
+mod breakdown;
+mod calculator;
+#[cfg(feature = "capi")]
+mod capi;
+mod capital_gains;
+mod cliffs;
+mod comparison;
 mod compute;
+mod conversion;
+mod ctc;
 mod data;
+#[cfg(feature = "chrono")]
+mod dates;
+#[cfg(feature = "decimal")]
+mod decimal;
+mod deduction;
+mod education;
+mod eitc;
+mod estate;
+mod exact;
+mod excerpt;
+mod export;
+#[cfg(feature = "fetch")]
+mod fetch;
+mod fica;
+mod filing;
+#[cfg(feature = "historical")]
+mod historical;
+mod inflation;
+mod installment;
+mod inverse;
+mod investment;
+mod ira;
+mod itemized;
+mod limits;
+mod lump_sum;
+mod married;
+mod money;
+mod options;
+mod overrides;
+mod paycheck;
+mod penalty;
+mod present_value;
+mod provenance;
+mod ptc;
+#[cfg(feature = "python")]
+mod python;
+mod qbi;
+mod rates;
+mod real;
+mod reform;
+mod refund;
+mod rmd;
+mod savers;
+#[cfg(feature = "scenarios")]
+mod scenario;
+mod schedule;
+mod social_security;
+mod source;
+mod tax_data;
+mod tax_return;
+mod trace;
+mod trust;
 mod types;
+mod validate;
+mod vehicle;
+mod withdrawal;
+mod withholding;
 
-pub use compute::compute_tax;
-pub use types::{FilingStatus, TaxError, TaxYear};
+pub use adoption::{
+    AdoptionCredit, adoption_credit_limit, adoption_credit_phaseout, compute_adoption_credit,
+};
+pub use amt::{AmtComputation, compute_amt};
+pub use annualize::{PartialYear, hourly_income};
+#[cfg(feature = "arrow")]
+pub use arrow::compute_tax_arrow;
+#[cfg(feature = "rayon")]
+pub use batch::compute_tax_batch_par;
+pub use batch::{compute_tax_batch, compute_tax_column};
+pub use breakdown::{BracketPortion, compute_tax_breakdown};
+pub use calculator::TaxCalculator;
+pub use capital_gains::{
+    CapitalGains, GainHarvestingHeadroom, ScheduleDWorksheet, compute_tax_with_gains,
+    gain_harvesting_headroom, ltcg_brackets, ltcg_rate_for, schedule_d_tax_worksheet,
+};
+pub use cliffs::{Cliff, CliffKind, CliffProfile, find_cliffs};
+pub use comparison::{ComparisonRow, ComparisonTable, Scenario, compare_scenarios};
+pub use compute::{compute_tax, compute_tax_cents, compute_tax_const, compute_tax_with_method};
+pub use conversion::{FillTo, IrmaaTier, RothConversion, irmaa_tiers, optimal_conversion};
+pub use ctc::{ChildTaxCredit, compute_ctc};
+pub use data::{TaxTableRow, WorksheetBracket};
+#[cfg(feature = "decimal")]
+pub use decimal::compute_tax_decimal;
+pub use deduction::{
+    DeductionChoice, additional_standard_deduction, compute_tax_from_agi,
+    dependent_standard_deduction, standard_deduction, standard_deduction_for,
+};
+pub use education::{
+    EducationCredit, EducationCredits, StudentExpenses, compute_education_credits,
+};
+pub use eitc::{
+    compute_eitc, eitc_earned_income_amount, eitc_investment_income_limit, eitc_maximum_credit,
+};
+pub use estate::{
+    annual_gift_exclusion, basic_exclusion_amount, compute_estate_tax, estate_tax_brackets,
+};
+pub use exact::{TableDiscrepancy, compute_tax_exact, table_discrepancy};
+pub use excerpt::{TaxTableExcerpt, tax_table_excerpt};
+pub use export::{ExportFormat, export_schedule};
+#[cfg(feature = "fetch")]
+pub use fetch::{FetchedYear, INSTRUCTIONS_URL, fetch_year};
+pub use fica::{
+    ADDITIONAL_MEDICARE_RATE, ADDITIONAL_MEDICARE_WITHHOLDING_THRESHOLD, FicaTax, MEDICARE_RATE,
+    SOCIAL_SECURITY_RATE, additional_medicare_threshold, compute_fica, social_security_wage_base,
+};
+pub use filing::{
+    Dependent, DependentFilingThresholds, dependent_filing_thresholds, dependent_must_file,
+    filing_threshold, must_file,
+};
+#[cfg(feature = "historical")]
+pub use historical::{compute_tax_historical, historical_schedule, historical_years};
+pub use inflation::{
+    bracket_inflation_factor, check_inflation_adjustment, cost_of_living_adjustment,
+    historical_inflation_rate, inflation_adjusted_schedule, projected_schedule,
+};
+pub use installment::{
+    AnnualizedInstallment, IncomePeriods, annualized_installments, required_annual_payment,
+};
+pub use inverse::income_for_tax;
+pub use investment::{
+    PortfolioIncome, TaxDrag, TaxpayerContext, YieldType, after_tax_yield, tax_drag,
+};
+pub use ira::{roth_contribution_limit, roth_ira_phaseout};
+pub use itemized::{DeductionComparison, ItemizedDeductions, compare_deductions};
+pub use limits::{ContributionLimits, contribution_limits};
+pub use lump_sum::{LumpSumDistribution, compute_lump_sum_tax, lump_sum_brackets};
+pub use married::{MarriedFilingComparison, SpouseFinances, compare_married_filing};
+pub use money::UsdAmount;
+pub use options::{Rounding, TaxOptions, compute_tax_with_options};
+pub use overrides::DataOverrides;
+pub use paycheck::{Paycheck, PretaxDeductions, take_home};
+pub use penalty::{EarlyWithdrawalException, early_withdrawal_penalty};
+pub use present_value::{TaxPayment, present_value_of_taxes};
+pub use provenance::{DataFileDigest, DataProvenance, DataSource, data_provenance};
+pub use ptc::{compute_ptc, federal_poverty_line, ptc_applicable_figure};
+pub use qbi::{compute_qbi_deduction, qbi_threshold};
+pub use rates::{
+    IncrementalTax, RatePoint, effective_rate, incremental_tax, marginal_rate, rate_curve,
+    tax_equivalent_yield,
+};
+pub use real::{
+    BracketCreepYear, bracket_creep, consumer_price_index, real_bracket_thresholds,
+    tax_in_real_terms,
+};
+pub use reform::{BracketRevenue, IncomeImpact, ReformImpact, reform_impact};
+pub use refund::{Credits, RefundEstimate, estimate_refund};
+pub use rmd::{required_minimum_distribution, single_life_expectancy, uniform_lifetime_period};
+pub use savers::{compute_savers_credit, savers_credit_tiers};
+#[cfg(feature = "scenarios")]
+pub use scenario::{LawScenario, compute_tax_under_law};
+pub use schedule::{
+    TaxBracket, TaxSchedule, TaxScheduleBuilder, bracket_for_income, headroom_in_current_bracket,
+};
+pub use social_security::taxable_social_security;
+pub use source::{BracketSource, EmbeddedData, compute_tax_from, compute_tax_with_method_from};
+pub use tax_data::TaxData;
+pub use tax_return::{TaxReturn, TaxReturnBuilder};
+pub use trace::{
+    Calculation, ExplanationStep, TableLookup, TaxComputation, WorksheetCalculation,
+    compute_tax_detailed, compute_tax_detailed_from,
+};
+pub use trust::{compute_trust_tax, trust_brackets};
+pub use types::{FilingStatus, Method, TaxError, TaxYear};
+pub use validate::{DataIssue, validate_data};
+pub use vehicle::{CleanVehicle, clean_vehicle_income_limit, clean_vehicle_income_test};
+pub use withdrawal::{
+    RetirementAccounts, WithdrawalOrder, WithdrawalOutcome, WithdrawalPlan, plan_withdrawals,
+};
+pub use withholding::{
+    FormW4, PayFrequency, W4Recommendation, WithholdingBracket, federal_withholding, recommend_w4,
+    withholding_table,
+};
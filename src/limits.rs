@@ -0,0 +1,108 @@
+//! Retirement plan, IRA, and health savings account contribution limits.
+
+use crate::data;
+use crate::types::TaxYear;
+
+/// The contribution limits for a year from the IRS inflation adjustments,
+/// returned by [`contribution_limits`]. Each is per person.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ContributionLimits {
+    /// The limit on elective deferrals to 401(k), 403(b), and most 457(b)
+    /// plans.
+    pub elective_deferral: i64,
+    /// The additional elective deferral allowed from age 50.
+    pub elective_deferral_catch_up: i64,
+    /// The additional elective deferral allowed at ages 60 through 63,
+    /// instead of [`elective_deferral_catch_up`](Self::elective_deferral_catch_up).
+    /// Before 2025 it is the same.
+    pub elective_deferral_catch_up_60_to_63: i64,
+    /// The limit on total annual additions to a defined contribution plan,
+    /// from both employee and employer, not counting catch-up contributions.
+    pub total_additions: i64,
+    /// The limit on contributions to all traditional and Roth IRAs.
+    pub ira: i64,
+    /// The additional IRA contribution allowed from age 50.
+    pub ira_catch_up: i64,
+    /// The HSA limit with self-only coverage under a high-deductible health
+    /// plan.
+    pub hsa_self_only: i64,
+    /// The HSA limit with family coverage.
+    pub hsa_family: i64,
+    /// The additional HSA contribution allowed from age 55.
+    pub hsa_catch_up: i64,
+}
+
+/// The contribution limits for `year`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{contribution_limits, TaxYear};
+///
+/// let limits = contribution_limits(TaxYear::Y2025);
+/// assert_eq!(limits.elective_deferral, 23_500);
+/// assert_eq!(limits.elective_deferral_catch_up_60_to_63, 11_250);
+/// assert_eq!(limits.ira, 7_000);
+/// assert_eq!(limits.hsa_family, 8_550);
+/// # }
+/// ```
+pub const fn contribution_limits(year: TaxYear) -> ContributionLimits {
+    let plan = data::contribution_limits(year);
+    let ira = data::ira_limits(year);
+    ContributionLimits {
+        elective_deferral: plan.elective_deferral,
+        elective_deferral_catch_up: plan.elective_deferral_catch_up,
+        elective_deferral_catch_up_60_to_63: plan.elective_deferral_catch_up_60_to_63,
+        total_additions: plan.total_additions,
+        ira: ira.contribution_limit,
+        ira_catch_up: ira.catch_up,
+        hsa_self_only: plan.hsa_self_only,
+        hsa_family: plan.hsa_family,
+        hsa_catch_up: plan.hsa_catch_up,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits_by_year() {
+        let limits = TaxYear::ALL.map(contribution_limits);
+        assert_eq!(
+            limits.map(|limits| limits.elective_deferral),
+            TaxYear::select([
+                18_500, 19_000, 19_500, 19_500, 20_500, 22_500, 23_000, 23_500
+            ])
+        );
+        assert_eq!(
+            limits.map(|limits| limits.total_additions),
+            TaxYear::select([
+                55_000, 56_000, 57_000, 58_000, 61_000, 66_000, 69_000, 70_000
+            ])
+        );
+        assert_eq!(
+            limits.map(|limits| limits.hsa_self_only),
+            TaxYear::select([3_450, 3_500, 3_550, 3_600, 3_650, 3_850, 4_150, 4_300])
+        );
+        for limits in limits {
+            assert!(limits.hsa_family > limits.hsa_self_only);
+            assert_eq!((limits.ira_catch_up, limits.hsa_catch_up), (1_000, 1_000));
+            assert!(
+                limits.elective_deferral_catch_up_60_to_63 >= limits.elective_deferral_catch_up
+            );
+        }
+    }
+
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn catch_up_at_60_to_63() {
+        let limits = contribution_limits(TaxYear::Y2024);
+        assert_eq!(limits.elective_deferral_catch_up, 7_500);
+        assert_eq!(limits.elective_deferral_catch_up_60_to_63, 7_500);
+        let limits = contribution_limits(TaxYear::Y2025);
+        assert_eq!(limits.elective_deferral_catch_up_60_to_63, 11_250);
+    }
+}
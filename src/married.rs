@@ -0,0 +1,349 @@
+//! Choosing between filing jointly and filing separately.
+
+use crate::deduction::DeductionChoice;
+use crate::education::StudentExpenses;
+use crate::itemized::ItemizedDeductions;
+use crate::tax_return::{TaxReturn, TaxReturnBuilder};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// One spouse's income, deductions, and credit inputs for
+/// [`compare_married_filing`], in whole dollars.
+///
+/// Dependents and students belong to the spouse who would claim them on a
+/// separate return; on the joint return both spouses' are claimed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpouseFinances {
+    /// Wages, salaries, and tips.
+    pub wages: i64,
+    /// Taxable interest.
+    pub taxable_interest: i64,
+    /// Ordinary dividends, including the qualified dividends.
+    pub ordinary_dividends: i64,
+    /// Qualified dividends.
+    pub qualified_dividends: i64,
+    /// Net short-term capital gain or loss.
+    pub short_term_gain: i64,
+    /// Net long-term capital gain or loss.
+    pub long_term_gain: i64,
+    /// Adjustments to income from Schedule 1.
+    pub adjustments: i64,
+    /// Itemized deductions paid by this spouse.
+    pub itemized: ItemizedDeductions,
+    /// Whether this spouse is 65 or older.
+    pub age65: bool,
+    /// Whether this spouse is blind.
+    pub blind: bool,
+    /// Qualifying children under 17.
+    pub qualifying_children: u8,
+    /// Other dependents.
+    pub other_dependents: u8,
+    /// Qualified education expenses for each student.
+    pub education_expenses: Vec<StudentExpenses>,
+    /// Federal income tax withheld.
+    pub withholding: i64,
+}
+
+impl SpouseFinances {
+    /// A return for these finances with `status` and the standard
+    /// deduction.
+    fn builder(&self, year: TaxYear, status: FilingStatus) -> TaxReturnBuilder {
+        TaxReturn::builder(year, status)
+            .wages(self.wages)
+            .interest(self.taxable_interest)
+            .dividends(self.ordinary_dividends, self.qualified_dividends)
+            .capital_gains(self.short_term_gain, self.long_term_gain)
+            .adjustments(self.adjustments)
+            .dependents(self.qualifying_children, self.other_dependents)
+            .education_expenses(&self.education_expenses)
+            .withholding(self.withholding)
+    }
+
+    /// Both spouses' amounts added together.
+    fn combined(&self, other: &SpouseFinances) -> SpouseFinances {
+        let add = i64::saturating_add;
+        SpouseFinances {
+            wages: add(self.wages, other.wages),
+            taxable_interest: add(self.taxable_interest, other.taxable_interest),
+            ordinary_dividends: add(self.ordinary_dividends, other.ordinary_dividends),
+            qualified_dividends: add(self.qualified_dividends, other.qualified_dividends),
+            short_term_gain: add(self.short_term_gain, other.short_term_gain),
+            long_term_gain: add(self.long_term_gain, other.long_term_gain),
+            adjustments: add(self.adjustments, other.adjustments),
+            itemized: ItemizedDeductions {
+                state_and_local_taxes: add(
+                    self.itemized.state_and_local_taxes,
+                    other.itemized.state_and_local_taxes,
+                ),
+                mortgage_interest: add(
+                    self.itemized.mortgage_interest,
+                    other.itemized.mortgage_interest,
+                ),
+                charitable_contributions: add(
+                    self.itemized.charitable_contributions,
+                    other.itemized.charitable_contributions,
+                ),
+                medical_expenses: add(
+                    self.itemized.medical_expenses,
+                    other.itemized.medical_expenses,
+                ),
+            },
+            age65: self.age65 || other.age65,
+            blind: self.blind || other.blind,
+            qualifying_children: self
+                .qualifying_children
+                .saturating_add(other.qualifying_children),
+            other_dependents: self.other_dependents.saturating_add(other.other_dependents),
+            education_expenses: [
+                self.education_expenses.as_slice(),
+                &other.education_expenses,
+            ]
+            .concat(),
+            withholding: add(self.withholding, other.withholding),
+        }
+    }
+}
+
+/// A joint return and the two separate returns for the same couple,
+/// returned by [`compare_married_filing`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MarriedFilingComparison {
+    /// The return filing jointly.
+    pub joint: TaxReturn,
+    /// Each spouse's return filing separately, in the order given.
+    pub separate: [TaxReturn; 2],
+    /// The earned income credit on the joint return that neither separate
+    /// return gets.
+    pub earned_income_credit_lost: i64,
+    /// The education credits on the joint return that neither separate
+    /// return gets.
+    pub education_credits_lost: i64,
+}
+
+impl MarriedFilingComparison {
+    /// The tax on the joint return less its refundable credits.
+    pub fn joint_tax(&self) -> i64 {
+        net_tax(&self.joint)
+    }
+
+    /// The tax on both separate returns less their refundable credits.
+    pub fn separate_tax(&self) -> i64 {
+        self.separate.iter().map(net_tax).sum()
+    }
+
+    /// The filing status with the lower tax. When the taxes are equal,
+    /// filing jointly is preferred.
+    pub fn best(&self) -> FilingStatus {
+        if self.separate_tax() < self.joint_tax() {
+            FilingStatus::MarriedFilingSeparately
+        } else {
+            FilingStatus::MarriedFilingJointly
+        }
+    }
+
+    /// How much less tax the better choice gives, in whole dollars.
+    pub fn tax_savings(&self) -> i64 {
+        (self.joint_tax() - self.separate_tax()).abs()
+    }
+}
+
+fn net_tax(tax_return: &TaxReturn) -> i64 {
+    tax_return.total_tax - tax_return.refundable_credits()
+}
+
+/// Compute a married couple's returns filing jointly and filing separately,
+/// with [`TaxReturn::builder`], to see which status owes less.
+///
+/// The joint return combines both spouses' [`SpouseFinances`] and itemizes
+/// when that beats the standard deduction. If one spouse itemizes on a
+/// separate return the other must too, so the separate returns either both
+/// take the standard deduction or both itemize, whichever costs less
+/// together. The spouses are taken to have lived together, so the separate
+/// returns get no earned income credit; education credits are never allowed
+/// filing separately. What the joint return gets from those credits is
+/// reported as lost.
+///
+/// Filing separately usually costs more, but can win when one spouse has
+/// large medical expenses, since only those above 7.5% of their own
+/// adjusted gross income need count instead of 7.5% of both spouses'
+/// income. Miscellaneous
+/// itemized deductions, another reason to file separately before 2018, are
+/// not deductible in the years covered. Community property rules, which
+/// split income between the spouses, are left to the caller.
+///
+/// # Errors
+///
+/// Returns the same errors as [`TaxReturnBuilder::build`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{compare_married_filing, FilingStatus, SpouseFinances, TaxYear};
+///
+/// let first = SpouseFinances { wages: 90_000, ..SpouseFinances::default() };
+/// let second = SpouseFinances { wages: 30_000, ..SpouseFinances::default() };
+/// let comparison = compare_married_filing(TaxYear::Y2024, &first, &second).unwrap();
+/// assert_eq!(comparison.best(), FilingStatus::MarriedFilingJointly);
+/// assert_eq!(comparison.joint.agi, 120_000);
+/// assert_eq!(comparison.separate[1].agi, 30_000);
+/// assert!(comparison.tax_savings() > 0);
+/// # }
+/// ```
+pub fn compare_married_filing(
+    year: TaxYear,
+    spouse: &SpouseFinances,
+    other_spouse: &SpouseFinances,
+) -> Result<MarriedFilingComparison, TaxError> {
+    let combined = spouse.combined(other_spouse);
+    let joint = combined
+        .builder(year, FilingStatus::MarriedFilingJointly)
+        .deduction(DeductionChoice::StandardWithAdditional {
+            age65: u8::from(spouse.age65) + u8::from(other_spouse.age65),
+            blind: u8::from(spouse.blind) + u8::from(other_spouse.blind),
+        })
+        .itemized_deductions(combined.itemized)
+        .build()?;
+
+    let separate_return = |finances: &SpouseFinances, itemize: bool| {
+        let builder = finances
+            .builder(year, FilingStatus::MarriedFilingSeparately)
+            .without_earned_income_credit();
+        let builder = if itemize {
+            builder
+                .deduction(DeductionChoice::Itemized(0))
+                .itemized_deductions(finances.itemized)
+        } else {
+            builder.deduction(DeductionChoice::StandardWithAdditional {
+                age65: u8::from(finances.age65),
+                blind: u8::from(finances.blind),
+            })
+        };
+        builder.build()
+    };
+    let standard = [
+        separate_return(spouse, false)?,
+        separate_return(other_spouse, false)?,
+    ];
+    let itemized = [
+        separate_return(spouse, true)?,
+        separate_return(other_spouse, true)?,
+    ];
+    let total = |returns: &[TaxReturn; 2]| returns.iter().map(net_tax).sum::<i64>();
+    let separate = if total(&itemized) < total(&standard) {
+        itemized
+    } else {
+        standard
+    };
+
+    let lost = |joint: i64, separate: [i64; 2]| (joint - separate[0] - separate[1]).max(0);
+    Ok(MarriedFilingComparison {
+        joint,
+        separate,
+        earned_income_credit_lost: lost(
+            joint.earned_income_credit,
+            separate.map(|tax_return| tax_return.earned_income_credit),
+        ),
+        education_credits_lost: lost(
+            joint.education_credit + joint.refundable_education_credit,
+            separate.map(|tax_return| {
+                tax_return.education_credit + tax_return.refundable_education_credit
+            }),
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wages(wages: i64) -> SpouseFinances {
+        SpouseFinances {
+            wages,
+            ..SpouseFinances::default()
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn joint_usually_wins() {
+        let comparison =
+            compare_married_filing(TaxYear::Y2024, &wages(150_000), &wages(0)).unwrap();
+        assert_eq!(comparison.best(), FilingStatus::MarriedFilingJointly);
+        assert_eq!(
+            comparison.tax_savings(),
+            comparison.separate_tax() - comparison.joint_tax()
+        );
+        assert_eq!(comparison.separate[1].total_tax, 0);
+
+        // With equal incomes the brackets match, apart from the Tax Table's
+        // rounding on each return.
+        let comparison =
+            compare_married_filing(TaxYear::Y2024, &wages(80_000), &wages(80_000)).unwrap();
+        assert_eq!(comparison.best(), FilingStatus::MarriedFilingJointly);
+        assert!(comparison.tax_savings() < 50);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn lost_credits() {
+        let parent = SpouseFinances {
+            wages: 20_000,
+            qualifying_children: 2,
+            education_expenses: vec![StudentExpenses {
+                qualified_expenses: 4_000,
+                ..StudentExpenses::default()
+            }],
+            ..SpouseFinances::default()
+        };
+        let comparison = compare_married_filing(TaxYear::Y2024, &parent, &wages(10_000)).unwrap();
+        assert!(comparison.joint.earned_income_credit > 0);
+        assert_eq!(
+            comparison.earned_income_credit_lost,
+            comparison.joint.earned_income_credit
+        );
+        // The refundable $1,000 and whatever the joint tax absorbed
+        assert_eq!(
+            comparison.education_credits_lost,
+            1_000 + comparison.joint.education_credit
+        );
+        assert_eq!(comparison.separate.map(|r| r.earned_income_credit), [0, 0]);
+        assert_eq!(comparison.best(), FilingStatus::MarriedFilingJointly);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn medical_expenses_can_favor_separate() {
+        // Only medical expenses above 7.5% of AGI count, so a spouse with
+        // large expenses deducts more alone, even though the other spouse
+        // then has to itemize too.
+        let healthy = wages(80_000);
+        let ill = SpouseFinances {
+            wages: 80_000,
+            itemized: ItemizedDeductions {
+                medical_expenses: 40_000,
+                ..ItemizedDeductions::default()
+            },
+            ..SpouseFinances::default()
+        };
+        let comparison = compare_married_filing(TaxYear::Y2024, &healthy, &ill).unwrap();
+        assert!(comparison.separate[1].itemized);
+        assert_eq!(comparison.separate[1].deduction, 40_000 - 6_000);
+        assert_eq!(comparison.separate[0].deduction, 0);
+        // Together, the expenses are below the joint floor of $12,000 plus
+        // the standard deduction.
+        assert!(!comparison.joint.itemized);
+        assert_eq!(comparison.best(), FilingStatus::MarriedFilingSeparately);
+        assert!(comparison.tax_savings() > 0);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn errors() {
+        assert_eq!(
+            compare_married_filing(TaxYear::Y2024, &wages(-1), &wages(0)),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! Dollar amount formatting and parsing.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::types::TaxError;
+
+/// A U.S. dollar amount, stored as a whole number of cents.
+///
+/// Displays the way the IRS instructions print amounts: with a dollar sign and
+/// thousands separators, and with cents only when there are any (`$11,420`,
+/// `$6,957.50`). Parses the same forms back, with or without the dollar sign
+/// and separators.
+///
+/// With the `serde` feature, an amount serializes as its integer number of
+/// cents.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_tax, FilingStatus, TaxYear, UsdAmount};
+///
+/// let income: UsdAmount = "$75,000".parse().unwrap();
+/// let tax = compute_tax(TaxYear::Y2025, FilingStatus::Single, income.dollars()).unwrap();
+/// assert_eq!(UsdAmount::from_dollars(tax).to_string(), "$11,420");
+///
+/// assert_eq!(UsdAmount::from_cents(695_750).to_string(), "$6,957.50");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct UsdAmount {
+    cents: i64,
+}
+
+impl UsdAmount {
+    /// Zero dollars.
+    pub const ZERO: UsdAmount = UsdAmount { cents: 0 };
+
+    /// An amount of whole dollars.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dollars × 100` overflows an `i64`.
+    pub const fn from_dollars(dollars: i64) -> Self {
+        match dollars.checked_mul(100) {
+            Some(cents) => UsdAmount { cents },
+            None => panic!("dollar amount out of range"),
+        }
+    }
+
+    /// An amount in cents.
+    pub const fn from_cents(cents: i64) -> Self {
+        UsdAmount { cents }
+    }
+
+    /// The amount in cents.
+    pub const fn cents(self) -> i64 {
+        self.cents
+    }
+
+    /// The whole-dollar part of the amount, truncated toward zero.
+    pub const fn dollars(self) -> i64 {
+        self.cents / 100
+    }
+
+    /// Whether the amount has no cents.
+    pub const fn is_whole_dollars(self) -> bool {
+        self.cents % 100 == 0
+    }
+}
+
+impl fmt::Display for UsdAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.cents < 0 { "-" } else { "" };
+        let cents = self.cents.unsigned_abs();
+        write!(f, "{sign}${}", group_thousands(cents / 100))?;
+        if !cents.is_multiple_of(100) {
+            write!(f, ".{:02}", cents % 100)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for UsdAmount {
+    type Err = TaxError;
+
+    /// Parse an amount such as `"$75,000"`, `"75000"`, `"$6,957.50"`, or
+    /// `"-$1.5"`.
+    ///
+    /// The dollar sign and thousands separators are optional, but separators
+    /// must fall every three digits. At most two decimal places are accepted.
+    /// Returns [`TaxError::InvalidAmount`] for anything else, including
+    /// amounts too large for an `i64` number of cents.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || TaxError::InvalidAmount(s.to_string());
+
+        let trimmed = s.trim();
+        let (negative, rest) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed),
+        };
+        let rest = rest.strip_prefix('$').unwrap_or(rest);
+        let (whole, fraction) = match rest.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (rest, None),
+        };
+
+        let groups: Vec<&str> = whole.split(',').collect();
+        let grouped_ok = groups.len() == 1
+            || (matches!(groups[0].len(), 1..=3) && groups[1..].iter().all(|g| g.len() == 3));
+        if !grouped_ok || groups.iter().any(|g| g.is_empty()) {
+            return Err(invalid());
+        }
+        let mut dollars: i64 = 0;
+        for c in groups.concat().chars() {
+            let digit = c.to_digit(10).ok_or_else(invalid)?;
+            dollars = dollars
+                .checked_mul(10)
+                .and_then(|d| d.checked_add(i64::from(digit)))
+                .ok_or_else(invalid)?;
+        }
+
+        let fraction_cents = match fraction {
+            None => 0,
+            Some(f) if matches!(f.len(), 1..=2) && f.bytes().all(|b| b.is_ascii_digit()) => {
+                let value: i64 = f.parse().map_err(|_| invalid())?;
+                if f.len() == 1 { value * 10 } else { value }
+            }
+            Some(_) => return Err(invalid()),
+        };
+
+        let cents = dollars
+            .checked_mul(100)
+            .and_then(|c| c.checked_add(fraction_cents))
+            .ok_or_else(invalid)?;
+        Ok(UsdAmount::from_cents(if negative { -cents } else { cents }))
+    }
+}
+
+/// Format a non-negative integer with comma thousands separators, e.g.
+/// `1,234,567`.
+pub(crate) fn group_thousands(n: u64) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(UsdAmount::ZERO.to_string(), "$0");
+        assert_eq!(UsdAmount::from_dollars(999).to_string(), "$999");
+        assert_eq!(UsdAmount::from_dollars(11_420).to_string(), "$11,420");
+        assert_eq!(UsdAmount::from_dollars(1_234_567).to_string(), "$1,234,567");
+        assert_eq!(UsdAmount::from_cents(5).to_string(), "$0.05");
+        assert_eq!(UsdAmount::from_cents(-250_050).to_string(), "-$2,500.50");
+    }
+
+    #[test]
+    fn parse() {
+        let parse = |s: &str| s.parse::<UsdAmount>().map(UsdAmount::cents);
+        assert_eq!(parse("$75,000"), Ok(7_500_000));
+        assert_eq!(parse("75000"), Ok(7_500_000));
+        assert_eq!(parse(" $6,957.50 "), Ok(695_750));
+        assert_eq!(parse("1.5"), Ok(150));
+        assert_eq!(parse("-$1,000"), Ok(-100_000));
+        assert_eq!(parse("$0.07"), Ok(7));
+    }
+
+    #[test]
+    fn parse_rejects_malformed() {
+        for input in [
+            "",
+            "$",
+            "$,000",
+            "75,00",
+            "7,5000",
+            "$1,000.",
+            "1.234",
+            "$1e3",
+            "$$5",
+            "1,,000",
+            "12345678901234567890",
+        ] {
+            assert_eq!(
+                input.parse::<UsdAmount>(),
+                Err(TaxError::InvalidAmount(input.to_string())),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for cents in [
+            0,
+            1,
+            99,
+            100,
+            4_999_950,
+            -12_345_678,
+            i64::MAX,
+            i64::MIN + 1,
+        ] {
+            let amount = UsdAmount::from_cents(cents);
+            assert_eq!(amount.to_string().parse(), Ok(amount));
+        }
+    }
+
+    #[test]
+    fn dollars_and_cents() {
+        let amount = UsdAmount::from_cents(695_750);
+        assert_eq!(amount.dollars(), 6_957);
+        assert!(!amount.is_whole_dollars());
+        assert!(UsdAmount::from_dollars(50).is_whole_dollars());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_as_cents() {
+        let amount = UsdAmount::from_cents(695_750);
+        assert_eq!(serde_json::to_string(&amount).unwrap(), "695750");
+        assert_eq!(serde_json::from_str::<UsdAmount>("695750").unwrap(), amount);
+    }
+}
@@ -0,0 +1,221 @@
+//! Computation with caller-selected options.
+
+use crate::compute::{
+    WORKSHEET_THRESHOLD, compute_from_tax_table, find_worksheet_bracket, income_to_i64,
+};
+use crate::money::UsdAmount;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// How the Tax Computation Worksheet result is rounded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Rounding {
+    /// Round to the nearest dollar, with 50 cents rounding up. This is what
+    /// the IRS instructions specify and what [`compute_tax`](crate::compute_tax)
+    /// does.
+    #[default]
+    HalfUp,
+    /// Round to the nearest dollar, with 50 cents rounding to the even dollar
+    /// (banker's rounding).
+    HalfEven,
+    /// Drop the cents.
+    Truncate,
+    /// Keep the cents, rounding any fraction of a cent half-up.
+    Cents,
+}
+
+impl Rounding {
+    /// Round an amount in millionths of a dollar, returning cents.
+    fn round_micros(self, micros: i128) -> i128 {
+        const DOLLAR: i128 = 1_000_000;
+        const CENT: i128 = 10_000;
+        match self {
+            Rounding::HalfUp => (micros + DOLLAR / 2).div_euclid(DOLLAR) * 100,
+            Rounding::HalfEven => {
+                let (dollars, rem) = (micros.div_euclid(DOLLAR), micros.rem_euclid(DOLLAR));
+                let round_up = rem > DOLLAR / 2 || (rem == DOLLAR / 2 && dollars % 2 != 0);
+                (dollars + i128::from(round_up)) * 100
+            }
+            Rounding::Truncate => micros.div_euclid(DOLLAR) * 100,
+            Rounding::Cents => (micros + CENT / 2).div_euclid(CENT),
+        }
+    }
+}
+
+/// Options for [`compute_tax_with_options`].
+///
+/// The [`Default`] options reproduce [`compute_tax`](crate::compute_tax).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaxOptions {
+    /// How the worksheet result is rounded.
+    pub rounding: Rounding,
+}
+
+/// Compute federal income tax with the given [`TaxOptions`].
+///
+/// Tax Table amounts (income under $100,000) are already whole dollars and
+/// are returned unchanged. At $100,000 and above, the worksheet formula is
+/// evaluated exactly in integer arithmetic and then rounded as
+/// [`options.rounding`](TaxOptions::rounding) specifies.
+///
+/// # Errors
+///
+/// Returns the same errors as [`compute_tax`](crate::compute_tax), and
+/// [`TaxError::IncomeOutOfRange`] if the tax in cents does not fit in an
+/// `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{compute_tax_with_options, FilingStatus, Rounding, TaxOptions, TaxYear};
+///
+/// // 2024: 150,000 × 0.24 − 6,957.50 = 29,042.50
+/// let tax = |rounding| {
+///     let options = TaxOptions { rounding };
+///     compute_tax_with_options(TaxYear::Y2024, FilingStatus::Single, 150_000, &options)
+///         .unwrap()
+///         .to_string()
+/// };
+/// assert_eq!(tax(Rounding::HalfUp), "$29,043");
+/// assert_eq!(tax(Rounding::HalfEven), "$29,042");
+/// assert_eq!(tax(Rounding::Truncate), "$29,042");
+/// assert_eq!(tax(Rounding::Cents), "$29,042.50");
+/// # }
+/// ```
+pub fn compute_tax_with_options(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+    options: &TaxOptions,
+) -> Result<UsdAmount, TaxError> {
+    let taxable_income = income_to_i64(taxable_income)?;
+    if taxable_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    if taxable_income == 0 {
+        return Ok(UsdAmount::ZERO);
+    }
+
+    if taxable_income < WORKSHEET_THRESHOLD {
+        let tax = compute_from_tax_table(year, status, taxable_income)?;
+        return Ok(UsdAmount::from_dollars(tax));
+    }
+
+    let bracket = find_worksheet_bracket(year, status, taxable_income)?;
+    // Tax in millionths of a dollar: dollars × basis points × 100, less the
+    // subtraction in cents × 10,000
+    let micros = i128::from(taxable_income) * i128::from(bracket.rate_basis_points) * 100
+        - i128::from(bracket.subtraction_cents) * 10_000;
+    let cents = i64::try_from(options.rounding.round_micros(micros))
+        .map_err(|_| TaxError::IncomeOutOfRange)?;
+    Ok(UsdAmount::from_cents(cents))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::{compute_tax, compute_tax_cents};
+
+    fn with(rounding: Rounding) -> TaxOptions {
+        TaxOptions { rounding }
+    }
+
+    #[test]
+    fn default_matches_compute_tax() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for income in [0, 1, 99_999, 100_000, 150_000, 731_200, 10_000_000] {
+                    let tax =
+                        compute_tax_with_options(year, status, income, &TaxOptions::default())
+                            .unwrap();
+                    assert_eq!(
+                        tax.cents(),
+                        compute_tax(year, status, income).unwrap() * 100
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cents_matches_compute_tax_cents() {
+        for year in TaxYear::ALL {
+            for income in [100_000, 150_001, 412_345] {
+                let tax = compute_tax_with_options(
+                    year,
+                    FilingStatus::Single,
+                    income,
+                    &with(Rounding::Cents),
+                )
+                .unwrap();
+                assert_eq!(
+                    tax.cents(),
+                    compute_tax_cents(year, FilingStatus::Single, income * 100).unwrap()
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn table_amounts_are_not_rounded() {
+        for rounding in [Rounding::HalfEven, Rounding::Truncate, Rounding::Cents] {
+            let tax = compute_tax_with_options(
+                TaxYear::Y2025,
+                FilingStatus::Single,
+                75_000,
+                &with(rounding),
+            )
+            .unwrap();
+            assert_eq!(tax, UsdAmount::from_dollars(11_420));
+        }
+    }
+
+    #[test]
+    fn round_micros() {
+        let cents = |rounding: Rounding, micros| rounding.round_micros(micros);
+        assert_eq!(cents(Rounding::HalfUp, 1_500_000), 200);
+        assert_eq!(cents(Rounding::HalfEven, 1_500_000), 200);
+        assert_eq!(cents(Rounding::HalfEven, 2_500_000), 200);
+        assert_eq!(cents(Rounding::HalfEven, 2_500_001), 300);
+        assert_eq!(cents(Rounding::Truncate, 2_999_999), 200);
+        assert_eq!(cents(Rounding::Cents, 2_994_999), 299);
+        assert_eq!(cents(Rounding::Cents, 2_995_000), 300);
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn negative_income() {
+        assert_eq!(
+            compute_tax_with_options(
+                TaxYear::Y2025,
+                FilingStatus::Single,
+                -1,
+                &TaxOptions::default()
+            ),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn tax_out_of_range() {
+        for rounding in [Rounding::HalfUp, Rounding::Cents] {
+            assert_eq!(
+                compute_tax_with_options(
+                    TaxYear::Y2025,
+                    FilingStatus::Single,
+                    i64::MAX,
+                    &with(rounding)
+                ),
+                Err(TaxError::IncomeOutOfRange)
+            );
+        }
+    }
+}
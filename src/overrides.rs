@@ -0,0 +1,334 @@
+//! Corrections applied on top of another source's data.
+
+use std::borrow::Cow;
+
+use crate::compute::{WORKSHEET_THRESHOLD, lookup_tax_table, lookup_worksheet};
+use crate::data::{TaxTableRow, WorksheetBracket, csv_status_index};
+use crate::source::{BracketSource, EmbeddedData};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// A [`BracketSource`] that replaces individual Tax Table rows and worksheet
+/// brackets of another source, such as the embedded data.
+///
+/// When the IRS publishes a correction to a single amount, production
+/// software can apply it with [`table_row`](Self::table_row) or
+/// [`worksheet_bracket`](Self::worksheet_bracket) instead of waiting for a
+/// crate release. Each override carries a reason, which
+/// [`compute_tax_detailed_from`](crate::compute_tax_detailed_from) records
+/// in the computation trace whenever the override is applied.
+///
+/// A row override replaces the source's row with the same bounds, and a
+/// bracket override the source's bracket with the same lower bound. A later
+/// override of the same row or bracket replaces an earlier one. The table or
+/// worksheet of a year with overrides is copied on each lookup; the others
+/// are passed through unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{
+///     compute_tax_detailed_from, compute_tax_from, tax_table_excerpt, DataOverrides,
+///     FilingStatus, TaxTableRow, TaxYear,
+/// };
+///
+/// let year = TaxYear::Y2025;
+/// let row = tax_table_excerpt(year, 40_000..40_001).unwrap().rows[0];
+/// let overrides = DataOverrides::new().table_row(
+///     year,
+///     TaxTableRow { single: 4_566, ..row },
+///     "Hypothetical correction notice",
+/// );
+///
+/// assert_eq!(compute_tax_from(&overrides, year, FilingStatus::Single, 40_010), Ok(4_566));
+/// // Other columns of the row are unchanged
+/// assert_eq!(compute_tax_from(&overrides, year, FilingStatus::HeadOfHousehold, 40_010), Ok(4_463));
+///
+/// let detail = compute_tax_detailed_from(&overrides, year, FilingStatus::Single, 40_010).unwrap();
+/// assert_eq!(detail.correction.as_deref(), Some("Hypothetical correction notice"));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataOverrides<S = EmbeddedData> {
+    source: S,
+    rows: Vec<RowOverride>,
+    brackets: Vec<BracketOverride>,
+}
+
+/// A replacement Tax Table row.
+#[derive(Debug, Clone, PartialEq)]
+struct RowOverride {
+    year: TaxYear,
+    row: TaxTableRow,
+    reason: String,
+}
+
+/// A replacement worksheet bracket for one filing status column.
+#[derive(Debug, Clone, PartialEq)]
+struct BracketOverride {
+    year: TaxYear,
+    status_index: usize,
+    bracket: WorksheetBracket,
+    reason: String,
+}
+
+impl DataOverrides {
+    /// Overrides on top of the embedded data, with none registered yet.
+    pub fn new() -> Self {
+        DataOverrides::over(EmbeddedData)
+    }
+}
+
+impl Default for DataOverrides {
+    fn default() -> Self {
+        DataOverrides::new()
+    }
+}
+
+impl<S: BracketSource> DataOverrides<S> {
+    /// Overrides on top of `source`, with none registered yet.
+    pub fn over(source: S) -> Self {
+        DataOverrides {
+            source,
+            rows: Vec::new(),
+            brackets: Vec::new(),
+        }
+    }
+
+    /// Replace the Tax Table row for `year` with the same bounds as `row`.
+    ///
+    /// A lookup in the year's table fails with
+    /// [`TaxError::InvalidSchedule`] if the source has no row with those
+    /// bounds.
+    pub fn table_row(mut self, year: TaxYear, row: TaxTableRow, reason: impl Into<String>) -> Self {
+        self.rows.retain(|o| {
+            !(o.year == year
+                && (o.row.income_min, o.row.income_max) == (row.income_min, row.income_max))
+        });
+        self.rows.push(RowOverride {
+            year,
+            row,
+            reason: reason.into(),
+        });
+        self
+    }
+
+    /// Replace the worksheet bracket for `year` and `status` that starts at
+    /// the same income as `bracket`.
+    ///
+    /// Qualifying surviving spouses share the married filing jointly
+    /// worksheet, so an override for either status applies to both. A lookup
+    /// in the worksheet fails with [`TaxError::InvalidSchedule`] if the
+    /// source has no bracket starting there.
+    pub fn worksheet_bracket(
+        mut self,
+        year: TaxYear,
+        status: FilingStatus,
+        bracket: WorksheetBracket,
+        reason: impl Into<String>,
+    ) -> Self {
+        let status_index = csv_status_index(status);
+        self.brackets.retain(|o| {
+            !(o.year == year
+                && o.status_index == status_index
+                && o.bracket.income_min == bracket.income_min)
+        });
+        self.brackets.push(BracketOverride {
+            year,
+            status_index,
+            bracket,
+            reason: reason.into(),
+        });
+        self
+    }
+
+    /// The source the overrides are applied to.
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+}
+
+impl<S: BracketSource> BracketSource for DataOverrides<S> {
+    fn tax_table(&self, year: TaxYear) -> Result<Cow<'_, [TaxTableRow]>, TaxError> {
+        let mut table = self.source.tax_table(year)?;
+        for o in self.rows.iter().filter(|o| o.year == year) {
+            let bounds = |row: &TaxTableRow| (row.income_min, row.income_max);
+            let index = table
+                .binary_search_by_key(&bounds(&o.row), bounds)
+                .map_err(|_| {
+                    TaxError::InvalidSchedule(format!(
+                        "the {year} Tax Table has no row from {} to {}",
+                        o.row.income_min, o.row.income_max
+                    ))
+                })?;
+            table.to_mut()[index] = o.row;
+        }
+        Ok(table)
+    }
+
+    fn worksheet(
+        &self,
+        year: TaxYear,
+        status: FilingStatus,
+    ) -> Result<Cow<'_, [WorksheetBracket]>, TaxError> {
+        let mut brackets = self.source.worksheet(year, status)?;
+        let status_index = csv_status_index(status);
+        for o in self
+            .brackets
+            .iter()
+            .filter(|o| o.year == year && o.status_index == status_index)
+        {
+            let index = brackets
+                .iter()
+                .position(|bracket| bracket.income_min == o.bracket.income_min)
+                .ok_or_else(|| {
+                    TaxError::InvalidSchedule(format!(
+                        "the {year} {status} worksheet has no bracket starting at {}",
+                        o.bracket.income_min
+                    ))
+                })?;
+            brackets.to_mut()[index] = o.bracket;
+        }
+        Ok(brackets)
+    }
+
+    fn correction(
+        &self,
+        year: TaxYear,
+        status: FilingStatus,
+        taxable_income: i64,
+    ) -> Option<String> {
+        let reason = if taxable_income < WORKSHEET_THRESHOLD {
+            self.rows
+                .iter()
+                .find(|o| o.year == year && lookup_tax_table(&[o.row], taxable_income).is_some())
+                .map(|o| &o.reason)
+        } else {
+            let status_index = csv_status_index(status);
+            self.brackets
+                .iter()
+                .find(|o| {
+                    o.year == year
+                        && o.status_index == status_index
+                        && lookup_worksheet(&[o.bracket], taxable_income).is_some()
+                })
+                .map(|o| &o.reason)
+        };
+        reason
+            .cloned()
+            .or_else(|| self.source.correction(year, status, taxable_income))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::compute_tax;
+    use crate::source::compute_tax_from;
+    use crate::trace::compute_tax_detailed_from;
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn no_overrides_changes_nothing() {
+        let overrides = DataOverrides::new();
+        for status in FilingStatus::ALL {
+            for income in (0..500_000).step_by(9_973) {
+                assert_eq!(
+                    compute_tax_from(&overrides, TaxYear::Y2024, status, income),
+                    compute_tax(TaxYear::Y2024, status, income)
+                );
+                let detail =
+                    compute_tax_detailed_from(&overrides, TaxYear::Y2024, status, income).unwrap();
+                assert_eq!(detail.correction, None);
+            }
+        }
+    }
+
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn worksheet_bracket() {
+        let year = TaxYear::Y2024;
+        let status = FilingStatus::QualifyingSurvivingSpouse;
+        let bracket = crate::data::worksheet(year, status)[0];
+        let corrected = WorksheetBracket::new(
+            bracket.income_min(),
+            bracket.income_max(),
+            bracket.rate_basis_points(),
+            bracket.subtraction_cents() + 100,
+        );
+        let overrides = DataOverrides::new().worksheet_bracket(
+            year,
+            FilingStatus::MarriedFilingJointly,
+            corrected,
+            "Subtraction amount corrected",
+        );
+
+        let before = compute_tax(year, status, 150_000).unwrap();
+        assert_eq!(
+            compute_tax_from(&overrides, year, status, 150_000),
+            Ok(before - 1)
+        );
+        let detail = compute_tax_detailed_from(&overrides, year, status, 150_000).unwrap();
+        assert_eq!(detail.explain()[0].label, "Correction");
+        assert_eq!(detail.explain()[0].text, "Subtraction amount corrected");
+
+        // Other brackets, statuses, and years are untouched.
+        let detail = compute_tax_detailed_from(&overrides, year, status, 900_000).unwrap();
+        assert_eq!(detail.correction, None);
+        assert_eq!(
+            compute_tax_from(&overrides, year, FilingStatus::Single, 150_000),
+            compute_tax(year, FilingStatus::Single, 150_000)
+        );
+        assert_eq!(
+            compute_tax_from(&overrides, TaxYear::Y2025, status, 150_000),
+            compute_tax(TaxYear::Y2025, status, 150_000)
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn later_overrides_replace_earlier_ones() {
+        let year = TaxYear::Y2025;
+        let row = crate::data::tax_table(year)[1_000];
+        let overrides = DataOverrides::new()
+            .table_row(year, TaxTableRow { single: 1, ..row }, "first")
+            .table_row(year, TaxTableRow { single: 2, ..row }, "second");
+        let income = row.income_min;
+        assert_eq!(
+            compute_tax_from(&overrides, year, FilingStatus::Single, income),
+            Ok(2)
+        );
+        let detail =
+            compute_tax_detailed_from(&overrides, year, FilingStatus::Single, income).unwrap();
+        assert_eq!(detail.correction.as_deref(), Some("second"));
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn unmatched_overrides() {
+        let year = TaxYear::Y2025;
+        let row = TaxTableRow {
+            income_min: 40_010,
+            income_max: 40_060,
+            single: 0,
+            married_filing_jointly: 0,
+            married_filing_separately: 0,
+            head_of_household: 0,
+        };
+        let overrides = DataOverrides::new().table_row(year, row, "misaligned");
+        assert!(matches!(
+            compute_tax_from(&overrides, year, FilingStatus::Single, 10),
+            Err(TaxError::InvalidSchedule(_))
+        ));
+        // The worksheet is not affected by a bad row override.
+        assert!(compute_tax_from(&overrides, year, FilingStatus::Single, 200_000).is_ok());
+
+        let bracket = WorksheetBracket::new(123_456, None, 3_700, 0);
+        let overrides =
+            DataOverrides::new().worksheet_bracket(year, FilingStatus::Single, bracket, "none");
+        assert!(matches!(
+            compute_tax_from(&overrides, year, FilingStatus::Single, 200_000),
+            Err(TaxError::InvalidSchedule(_))
+        ));
+    }
+}
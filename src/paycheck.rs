@@ -0,0 +1,244 @@
+//! Net pay after federal withholding and FICA taxes.
+
+use crate::fica::compute_fica;
+use crate::money::UsdAmount;
+use crate::types::{TaxError, TaxYear};
+use crate::withholding::{FormW4, PayFrequency, federal_withholding};
+
+/// Deductions taken from each paycheck before tax, for [`take_home`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PretaxDeductions {
+    /// Elective deferrals to a 401(k), 403(b), or similar plan, which lower
+    /// income tax withholding but not Social Security and Medicare tax.
+    pub retirement: UsdAmount,
+    /// Contributions to a section 125 cafeteria plan, such as health
+    /// insurance premiums or a flexible spending account, which lower both.
+    pub cafeteria: UsdAmount,
+}
+
+impl PretaxDeductions {
+    /// Both deductions together.
+    pub fn total(&self) -> UsdAmount {
+        UsdAmount::from_cents(self.retirement.cents() + self.cafeteria.cents())
+    }
+}
+
+/// One paycheck, returned by [`take_home`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Paycheck {
+    /// Gross pay for the period.
+    pub gross: UsdAmount,
+    /// The pretax deductions taken out.
+    pub pretax_deductions: UsdAmount,
+    /// Federal income tax withheld, from [`federal_withholding`].
+    pub federal_income_tax: UsdAmount,
+    /// Social Security tax withheld.
+    pub social_security: UsdAmount,
+    /// Medicare tax withheld, including any Additional Medicare Tax.
+    pub medicare: UsdAmount,
+    /// Gross pay less the deductions and taxes.
+    pub net_pay: UsdAmount,
+}
+
+/// Compute the net pay from one paycheck of `gross_per_period`, after
+/// `pretax_deductions`, federal income tax withholding for `w4`, and the
+/// employee's Social Security and Medicare taxes.
+///
+/// Income tax is withheld with [`federal_withholding`] on gross pay less
+/// both kinds of [`PretaxDeductions`]; Social Security and Medicare tax
+/// apply to gross pay less cafeteria plan contributions only. Every
+/// paycheck of the year is taken to be the same, and the year's
+/// [`compute_fica`] on them is spread evenly: in practice Social Security
+/// tax stops once wages for the year reach the wage base and Additional
+/// Medicare Tax starts above $200,000, so high earners' paychecks change
+/// during the year. State and local taxes and after-tax deductions are left
+/// out.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `gross_per_period` or a deduction
+/// is negative, [`TaxError::InvalidAmount`] if the deductions are more than
+/// the gross pay, and the same errors as [`federal_withholding`] and
+/// [`compute_fica`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{
+///     take_home, FilingStatus, FormW4, PayFrequency, PretaxDeductions, TaxYear, UsdAmount,
+/// };
+///
+/// let w4 = FormW4::new(FilingStatus::Single);
+/// let deductions = PretaxDeductions {
+///     retirement: UsdAmount::from_dollars(200),
+///     ..PretaxDeductions::default()
+/// };
+/// let gross = UsdAmount::from_dollars(2_200);
+/// let pay = take_home(TaxYear::Y2024, gross, PayFrequency::Biweekly, deductions, &w4).unwrap();
+/// // Income tax on $2,000, as in the federal_withholding example
+/// assert_eq!(pay.federal_income_tax.to_string(), "$163.69");
+/// // FICA on the full $2,200
+/// assert_eq!(pay.social_security.to_string(), "$136.40");
+/// assert_eq!(pay.medicare.to_string(), "$31.90");
+/// assert_eq!(pay.net_pay.to_string(), "$1,668.01");
+/// # }
+/// ```
+pub fn take_home(
+    year: TaxYear,
+    gross_per_period: UsdAmount,
+    frequency: PayFrequency,
+    pretax_deductions: PretaxDeductions,
+    w4: &FormW4,
+) -> Result<Paycheck, TaxError> {
+    let PretaxDeductions {
+        retirement,
+        cafeteria,
+    } = pretax_deductions;
+    if gross_per_period.cents() < 0 || retirement.cents() < 0 || cafeteria.cents() < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let deductions = i128::from(retirement.cents()) + i128::from(cafeteria.cents());
+    let gross = i128::from(gross_per_period.cents());
+    if deductions > gross {
+        return Err(TaxError::InvalidAmount(format!(
+            "pretax deductions of {} are more than gross pay of {gross_per_period}",
+            pretax_deductions.total()
+        )));
+    }
+    let cents = |amount: i128| UsdAmount::from_cents(amount as i64);
+    let income_tax_wages = cents(gross - deductions);
+    let fica_wages = gross - i128::from(cafeteria.cents());
+
+    let federal_income_tax = federal_withholding(year, w4, frequency, income_tax_wages)?;
+    // The year's FICA taxes, spread evenly over its paychecks
+    let periods = i128::from(frequency.periods_per_year());
+    let annual_dollars =
+        i64::try_from((fica_wages * periods + 50) / 100).map_err(|_| TaxError::IncomeOutOfRange)?;
+    let fica = compute_fica(year, annual_dollars)?;
+    let per_period = |amount: UsdAmount| (i128::from(amount.cents()) * 2 + periods) / (periods * 2);
+    let social_security = per_period(fica.social_security);
+    let medicare = per_period(fica.medicare) + per_period(fica.additional_medicare);
+
+    let net_pay =
+        gross - deductions - i128::from(federal_income_tax.cents()) - social_security - medicare;
+    Ok(Paycheck {
+        gross: gross_per_period,
+        pretax_deductions: cents(deductions),
+        federal_income_tax,
+        social_security: cents(social_security),
+        medicare: cents(medicare),
+        net_pay: cents(net_pay),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::FilingStatus;
+
+    fn dollars(dollars: i64) -> UsdAmount {
+        UsdAmount::from_dollars(dollars)
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn deductions() {
+        let year = TaxYear::Y2025;
+        let w4 = FormW4::new(FilingStatus::MarriedFilingJointly);
+        let frequency = PayFrequency::Semimonthly;
+        let pay = |deductions| take_home(year, dollars(5_000), frequency, deductions, &w4).unwrap();
+
+        let none = pay(PretaxDeductions::default());
+        assert_eq!(none.social_security, dollars(310));
+        assert_eq!(none.medicare.to_string(), "$72.50");
+        assert_eq!(
+            none.federal_income_tax,
+            federal_withholding(year, &w4, frequency, dollars(5_000)).unwrap()
+        );
+
+        // A 401(k) deferral lowers income tax but not FICA.
+        let retirement = pay(PretaxDeductions {
+            retirement: dollars(500),
+            ..PretaxDeductions::default()
+        });
+        assert_eq!(retirement.social_security, none.social_security);
+        assert!(retirement.federal_income_tax.cents() < none.federal_income_tax.cents());
+        // A cafeteria plan lowers both.
+        let cafeteria = pay(PretaxDeductions {
+            cafeteria: dollars(500),
+            ..PretaxDeductions::default()
+        });
+        assert_eq!(cafeteria.federal_income_tax, retirement.federal_income_tax);
+        assert_eq!(cafeteria.social_security, dollars(279));
+        assert!(cafeteria.net_pay.cents() > retirement.net_pay.cents());
+
+        for paycheck in [none, retirement, cafeteria] {
+            assert_eq!(
+                paycheck.net_pay.cents(),
+                paycheck.gross.cents()
+                    - paycheck.pretax_deductions.cents()
+                    - paycheck.federal_income_tax.cents()
+                    - paycheck.social_security.cents()
+                    - paycheck.medicare.cents()
+            );
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn high_earner() {
+        // $26,000 a month is $312,000 a year: Social Security on the wage
+        // base and Additional Medicare Tax on $112,000, spread over 12
+        // paychecks.
+        let w4 = FormW4::new(FilingStatus::Single);
+        let pay = take_home(
+            TaxYear::Y2024,
+            dollars(26_000),
+            PayFrequency::Monthly,
+            PretaxDeductions::default(),
+            &w4,
+        )
+        .unwrap();
+        assert_eq!(pay.social_security.to_string(), "$871.10");
+        assert_eq!(pay.medicare.to_string(), "$461");
+    }
+
+    #[cfg(all(feature = "year-2019", feature = "year-2024"))]
+    #[test]
+    fn errors() {
+        let w4 = FormW4::new(FilingStatus::Single);
+        let take = |gross, deductions| {
+            take_home(TaxYear::Y2024, gross, PayFrequency::Weekly, deductions, &w4)
+        };
+        assert_eq!(
+            take(dollars(-1), PretaxDeductions::default()),
+            Err(TaxError::NegativeIncome)
+        );
+        let too_much = PretaxDeductions {
+            retirement: dollars(600),
+            cafeteria: dollars(500),
+        };
+        assert!(matches!(
+            take(dollars(1_000), too_much),
+            Err(TaxError::InvalidAmount(_))
+        ));
+        assert_eq!(
+            take(dollars(1_000), PretaxDeductions::default())
+                .map(|pay| pay.federal_income_tax.cents() > 0),
+            Ok(true)
+        );
+        assert_eq!(
+            take_home(
+                TaxYear::Y2019,
+                dollars(1_000),
+                PayFrequency::Weekly,
+                PretaxDeductions::default(),
+                &w4
+            ),
+            Err(TaxError::UnsupportedYear(2019))
+        );
+    }
+}
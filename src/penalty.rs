@@ -0,0 +1,211 @@
+//! The additional tax on early distributions from retirement plans and IRAs
+//! (Form 5329, Part I).
+
+use crate::compute::income_to_i64;
+use crate::types::TaxError;
+
+/// An exception to the 10% additional tax on a distribution before age
+/// 59½, from the Form 5329 instructions.
+///
+/// Exceptions that cover the whole distribution have no amount. The others
+/// carry the amount that qualifies, such as the expenses paid, and are
+/// limited to the most the law allows for each, as noted. Several of them
+/// apply only to IRAs or only to workplace plans; which one the
+/// distribution came from is left to the caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum EarlyWithdrawalException {
+    /// Paid to a beneficiary after the owner's death.
+    Death,
+    /// The owner is totally and permanently disabled.
+    Disability,
+    /// The owner is terminally ill.
+    TerminalIllness,
+    /// Part of a series of substantially equal periodic payments.
+    SubstantiallyEqualPayments,
+    /// From a workplace plan after separating from service in or after the
+    /// year the owner turned 55.
+    SeparationFromServiceAfter55,
+    /// Paid to an alternate payee under a qualified domestic relations
+    /// order.
+    QualifiedDomesticRelationsOrder,
+    /// Paid to a reservist called to active duty for more than 179 days.
+    QualifiedReservist,
+    /// Taken by an IRS levy on the plan.
+    IrsLevy(i64),
+    /// Unreimbursed medical expenses above 7.5% of adjusted gross income.
+    MedicalExpenses(i64),
+    /// Health insurance premiums paid from an IRA while unemployed.
+    HealthInsurancePremiums(i64),
+    /// Qualified higher education expenses paid from an IRA.
+    HigherEducation(i64),
+    /// A first home bought with IRA money, up to $10,000 over a lifetime.
+    FirstHome(i64),
+    /// Within a year of the birth or adoption of a child, up to $5,000 per
+    /// child.
+    BirthOrAdoption(i64),
+    /// A personal or family emergency expense, up to $1,000 a year, from
+    /// 2024.
+    EmergencyExpense(i64),
+    /// By a victim of domestic abuse, up to $10,000 or half the account,
+    /// from 2024.
+    DomesticAbuse(i64),
+    /// Because of a federally declared disaster, up to $22,000 per disaster.
+    Disaster(i64),
+}
+
+impl EarlyWithdrawalException {
+    /// The part of a distribution of `amount` that the exception covers.
+    fn excepted(self, amount: i64) -> i64 {
+        let (qualifying, limit) = match self {
+            EarlyWithdrawalException::Death
+            | EarlyWithdrawalException::Disability
+            | EarlyWithdrawalException::TerminalIllness
+            | EarlyWithdrawalException::SubstantiallyEqualPayments
+            | EarlyWithdrawalException::SeparationFromServiceAfter55
+            | EarlyWithdrawalException::QualifiedDomesticRelationsOrder
+            | EarlyWithdrawalException::QualifiedReservist => return amount,
+            EarlyWithdrawalException::IrsLevy(qualifying)
+            | EarlyWithdrawalException::MedicalExpenses(qualifying)
+            | EarlyWithdrawalException::HealthInsurancePremiums(qualifying)
+            | EarlyWithdrawalException::HigherEducation(qualifying) => (qualifying, amount),
+            EarlyWithdrawalException::FirstHome(qualifying) => (qualifying, 10_000),
+            EarlyWithdrawalException::BirthOrAdoption(qualifying) => (qualifying, 5_000),
+            EarlyWithdrawalException::EmergencyExpense(qualifying) => (qualifying, 1_000),
+            EarlyWithdrawalException::DomesticAbuse(qualifying) => (qualifying, 10_000),
+            EarlyWithdrawalException::Disaster(qualifying) => (qualifying, 22_000),
+        };
+        qualifying.clamp(0, limit)
+    }
+}
+
+/// Compute the 10% additional tax on an early distribution of `amount`,
+/// the taxable part of distributions before age 59½, after the
+/// `exceptions` that apply to it, rounded to whole dollars (Form 5329,
+/// lines 1–4).
+///
+/// The excepted amounts are added up and can cover at most the whole
+/// distribution. The 25% rate on SIMPLE IRA distributions in the first two
+/// years of participation is not applied.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `amount` is negative and
+/// [`TaxError::IncomeOutOfRange`] if it does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::{early_withdrawal_penalty, EarlyWithdrawalException};
+///
+/// assert_eq!(early_withdrawal_penalty(20_000, &[]), Ok(2_000));
+/// // Only $10,000 of a first home purchase is excepted.
+/// let exceptions = [EarlyWithdrawalException::FirstHome(15_000)];
+/// assert_eq!(early_withdrawal_penalty(20_000, &exceptions), Ok(1_000));
+/// let exceptions = [EarlyWithdrawalException::Disability];
+/// assert_eq!(early_withdrawal_penalty(20_000, &exceptions), Ok(0));
+/// ```
+pub fn early_withdrawal_penalty(
+    amount: impl TryInto<i64>,
+    exceptions: &[EarlyWithdrawalException],
+) -> Result<i64, TaxError> {
+    let amount = income_to_i64(amount)?;
+    if amount < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let excepted = exceptions
+        .iter()
+        .fold(0_i64, |total, exception| {
+            total.saturating_add(exception.excepted(amount))
+        })
+        .min(amount);
+    // 10%, rounded half up
+    let taxable = amount - excepted;
+    Ok(taxable / 10 + i64::from(taxable % 10 >= 5))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn penalty() {
+        assert_eq!(early_withdrawal_penalty(0, &[]), Ok(0));
+        assert_eq!(early_withdrawal_penalty(12_345, &[]), Ok(1_235));
+        assert_eq!(
+            early_withdrawal_penalty(i64::MAX, &[]),
+            Ok(i64::MAX / 10 + 1)
+        );
+    }
+
+    #[test]
+    fn whole_distribution_exceptions() {
+        for exception in [
+            EarlyWithdrawalException::Death,
+            EarlyWithdrawalException::Disability,
+            EarlyWithdrawalException::TerminalIllness,
+            EarlyWithdrawalException::SubstantiallyEqualPayments,
+            EarlyWithdrawalException::SeparationFromServiceAfter55,
+            EarlyWithdrawalException::QualifiedDomesticRelationsOrder,
+            EarlyWithdrawalException::QualifiedReservist,
+        ] {
+            assert_eq!(early_withdrawal_penalty(50_000, &[exception]), Ok(0));
+        }
+    }
+
+    #[test]
+    fn limited_exceptions() {
+        let penalty = |exception| early_withdrawal_penalty(30_000, &[exception]).unwrap();
+        assert_eq!(
+            penalty(EarlyWithdrawalException::MedicalExpenses(4_000)),
+            2_600
+        );
+        assert_eq!(
+            penalty(EarlyWithdrawalException::HigherEducation(50_000)),
+            0
+        );
+        assert_eq!(penalty(EarlyWithdrawalException::IrsLevy(30_000)), 0);
+        assert_eq!(
+            penalty(EarlyWithdrawalException::BirthOrAdoption(8_000)),
+            2_500
+        );
+        assert_eq!(
+            penalty(EarlyWithdrawalException::EmergencyExpense(3_000)),
+            2_900
+        );
+        assert_eq!(
+            penalty(EarlyWithdrawalException::DomesticAbuse(12_000)),
+            2_000
+        );
+        assert_eq!(penalty(EarlyWithdrawalException::Disaster(25_000)), 800);
+        assert_eq!(
+            penalty(EarlyWithdrawalException::HealthInsurancePremiums(-500)),
+            3_000
+        );
+
+        // Exceptions add up, to at most the whole distribution.
+        let exceptions = [
+            EarlyWithdrawalException::FirstHome(10_000),
+            EarlyWithdrawalException::BirthOrAdoption(5_000),
+            EarlyWithdrawalException::BirthOrAdoption(5_000),
+        ];
+        assert_eq!(early_withdrawal_penalty(30_000, &exceptions), Ok(1_000));
+        assert_eq!(early_withdrawal_penalty(15_000, &exceptions), Ok(0));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            early_withdrawal_penalty(-1, &[]),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            early_withdrawal_penalty(u64::MAX, &[]),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
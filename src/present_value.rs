@@ -0,0 +1,159 @@
+//! Discounting taxes paid over several years to a single present value.
+
+use crate::real::BracketCreepYear;
+use crate::types::TaxError;
+
+/// The tax paid in one calendar year, for [`present_value_of_taxes`].
+///
+/// Any year-by-year projection can supply these: a `(year, tax)` pair of
+/// whole dollars converts directly, as does a [`BracketCreepYear`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaxPayment {
+    /// The calendar year the tax is paid.
+    pub year: u16,
+    /// The tax paid, in dollars; negative for a refund or a credit.
+    pub tax: f64,
+}
+
+impl From<(u16, i64)> for TaxPayment {
+    fn from((year, tax): (u16, i64)) -> Self {
+        TaxPayment {
+            year,
+            tax: tax as f64,
+        }
+    }
+}
+
+impl From<(u16, f64)> for TaxPayment {
+    fn from((year, tax): (u16, f64)) -> Self {
+        TaxPayment { year, tax }
+    }
+}
+
+impl From<&BracketCreepYear> for TaxPayment {
+    fn from(year: &BracketCreepYear) -> Self {
+        TaxPayment {
+            year: year.year,
+            tax: year.tax,
+        }
+    }
+}
+
+/// Discount a stream of yearly tax `payments` to `base_year` at
+/// `discount_rate`, and add them up.
+///
+/// Each payment is divided by `(1 + discount_rate)` raised to the number of
+/// years after `base_year` it is paid, so a payment before `base_year` is
+/// grown instead. Two strategies that move taxes between years, such as
+/// converting to a Roth IRA now or withdrawing later, can then be compared
+/// by the present values of their streams: the lower one costs less. The
+/// rate is the return the money would earn in the meantime, as a fraction
+/// (`0.05` for 5%); to compare in constant dollars, pass a real rate or
+/// restate the taxes first with [`tax_in_real_terms`].
+///
+/// [`tax_in_real_terms`]: crate::tax_in_real_terms
+///
+/// # Errors
+///
+/// Returns [`TaxError::InvalidAmount`] if `discount_rate` is not finite or
+/// is -100% or less, or a payment is not finite.
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::present_value_of_taxes;
+///
+/// // Convert now: $11,000 of tax in 2025. Convert later: $12,000 in 2030.
+/// let now = present_value_of_taxes([(2025, 11_000)], 2025, 0.05).unwrap();
+/// let later = present_value_of_taxes([(2030, 12_000)], 2025, 0.05).unwrap();
+/// assert_eq!(now, 11_000.0);
+/// assert!((later - 9_402.31).abs() < 0.01);
+/// // At 1%, converting now costs less.
+/// let later = present_value_of_taxes([(2030, 12_000)], 2025, 0.01).unwrap();
+/// assert!(later > now);
+/// ```
+pub fn present_value_of_taxes<I>(
+    payments: I,
+    base_year: u16,
+    discount_rate: f64,
+) -> Result<f64, TaxError>
+where
+    I: IntoIterator,
+    I::Item: Into<TaxPayment>,
+{
+    if !discount_rate.is_finite() || discount_rate <= -1.0 {
+        return Err(TaxError::InvalidAmount(format!(
+            "discount rate {discount_rate}"
+        )));
+    }
+    payments
+        .into_iter()
+        .map(Into::into)
+        .try_fold(0.0, |total, TaxPayment { year, tax }| {
+            if !tax.is_finite() {
+                return Err(TaxError::InvalidAmount(format!("tax of {tax} in {year}")));
+            }
+            let years = i32::from(year) - i32::from(base_year);
+            Ok(total + tax / (1.0 + discount_rate).powi(years))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::real::bracket_creep;
+    use crate::types::FilingStatus;
+
+    fn close(present_value: Result<f64, TaxError>, expected: f64) -> bool {
+        present_value.is_ok_and(|value| (value - expected).abs() < 1e-9)
+    }
+
+    #[test]
+    fn discounting() {
+        let payments = [(2024, 1_000), (2025, 1_000), (2026, 1_100)];
+        assert_eq!(present_value_of_taxes(payments, 2024, 0.0), Ok(3_100.0));
+        assert!(close(
+            present_value_of_taxes(payments, 2024, 0.1),
+            1_000.0 + 2_000.0 / 1.1
+        ));
+        // Earlier payments are grown to the base year.
+        assert!(close(
+            present_value_of_taxes([(2024, 1_000)], 2026, 0.1),
+            1_210.0
+        ));
+        // A refund counts against the tax.
+        assert_eq!(
+            present_value_of_taxes([(2025, 500.0), (2025, -200.0)], 2025, 0.05),
+            Ok(300.0)
+        );
+        assert_eq!(
+            present_value_of_taxes(Vec::<(u16, i64)>::new(), 2025, 0.05),
+            Ok(0.0)
+        );
+    }
+
+    #[cfg(all(feature = "year-2018", feature = "year-2019"))]
+    #[test]
+    fn projections() {
+        let creep = bracket_creep(FilingStatus::Single, 60_000, 2018).unwrap();
+        let first = creep[0].year;
+        let total: f64 = creep.iter().map(|year| year.tax).sum();
+        assert!(close(present_value_of_taxes(&creep, first, 0.0), total));
+        assert!(present_value_of_taxes(&creep, first, 0.03).unwrap() < total);
+    }
+
+    #[test]
+    fn errors() {
+        for rate in [f64::NAN, f64::INFINITY, -1.0, -2.0] {
+            assert!(matches!(
+                present_value_of_taxes([(2025, 1_000)], 2025, rate),
+                Err(TaxError::InvalidAmount(_))
+            ));
+        }
+        assert!(matches!(
+            present_value_of_taxes([(2025, f64::NAN)], 2025, 0.05),
+            Err(TaxError::InvalidAmount(_))
+        ));
+    }
+}
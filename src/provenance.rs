@@ -0,0 +1,139 @@
+//! Where each year's embedded data came from.
+
+use crate::data;
+use crate::types::TaxYear;
+
+/// The sources and checksums of one tax year's embedded data.
+///
+/// The sources are recorded in `data/<year>/provenance.csv`, and the
+/// checksums are computed from the data files when the crate is built, so
+/// they always describe the numbers the crate computes with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DataProvenance {
+    /// Every CSV file for the year, including `provenance.csv`, with its
+    /// sources and checksum.
+    pub files: &'static [DataFileDigest],
+}
+
+impl DataProvenance {
+    /// The embedded file with the given name, e.g. `tax_table.csv`.
+    pub fn file(&self, name: &str) -> Option<&'static DataFileDigest> {
+        self.files.iter().find(|file| file.name == name)
+    }
+}
+
+/// The sources and SHA-256 checksum of one embedded data file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DataFileDigest {
+    /// File name within `data/<year>/`, e.g. `tax_table.csv`.
+    pub name: &'static str,
+    /// SHA-256 of the file's bytes, as 64 lowercase hex digits.
+    pub sha256: &'static str,
+    /// The publications the file's figures were taken from. Empty only for
+    /// `provenance.csv` and for files with no rows, such as a year before
+    /// the percentage method tables in Publication 15-T.
+    pub sources: &'static [DataSource],
+}
+
+/// A publication that some of a year's embedded figures were taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DataSource {
+    /// Title of the publication, e.g. `2024 Instructions for Form 1040` or
+    /// `Rev. Proc. 2023-34`.
+    pub publication: &'static str,
+    /// URL of the revision the figures were taken from.
+    ///
+    /// A year scraped from the current HTML instructions, whose address
+    /// moves on to the next year, records the archived PDF of the same
+    /// revision.
+    pub url: &'static str,
+    /// Revision printed on the publication, or [`None`] for documents
+    /// without a revision line, such as revenue procedures and notices.
+    pub revision: Option<&'static str>,
+}
+
+/// The source publications and file checksums for a year's embedded data.
+///
+/// Compliance reviews can record these to show exactly which publication,
+/// and which transcription of it, produced each figure behind a computed
+/// tax. The checksums match `sha256sum data/<year>/*.csv` in the repository.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{data_provenance, TaxYear};
+///
+/// let provenance = data_provenance(TaxYear::Y2024);
+/// let table = provenance.file("tax_table.csv").unwrap();
+/// assert_eq!(table.sources[0].publication, "2024 Instructions for Form 1040");
+/// assert_eq!(table.sources[0].revision, Some("2024"));
+/// assert_eq!(table.sha256.len(), 64);
+///
+/// let exclusions = provenance.file("estate_gift_exclusions.csv").unwrap();
+/// assert_eq!(exclusions.sources[0].publication, "Rev. Proc. 2023-34");
+/// # }
+/// ```
+pub fn data_provenance(year: TaxYear) -> DataProvenance {
+    *data::provenance(year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn checksums_match_files() {
+        for year in TaxYear::ALL {
+            let provenance = data_provenance(year);
+            assert_eq!(provenance.files.len(), 23);
+            for file in provenance.files {
+                let path = format!("{}/data/{year}/{}", env!("CARGO_MANIFEST_DIR"), file.name);
+                let digest = Sha256::digest(std::fs::read(path).unwrap());
+                let hex: String = digest.iter().map(|byte| format!("{byte:02x}")).collect();
+                assert_eq!(file.sha256, hex, "{year} {}", file.name);
+            }
+        }
+    }
+
+    #[test]
+    fn tax_table_comes_from_form_1040_instructions() {
+        for year in TaxYear::ALL {
+            let table = data_provenance(year).file("tax_table.csv").unwrap();
+            let [source] = table.sources else {
+                panic!("{year}: {:?}", table.sources);
+            };
+            assert_eq!(
+                source.publication,
+                format!("{year} Instructions for Form 1040")
+            );
+            assert_eq!(
+                source.url,
+                format!("https://www.irs.gov/pub/irs-prior/i1040gi--{year}.pdf")
+            );
+            assert_eq!(source.revision, Some(year.to_string().as_str()));
+        }
+    }
+
+    #[test]
+    fn every_file_with_data_has_a_source() {
+        for year in TaxYear::ALL {
+            for file in data_provenance(year).files {
+                let path = format!("{}/data/{year}/{}", env!("CARGO_MANIFEST_DIR"), file.name);
+                let rows = std::fs::read_to_string(path).unwrap().lines().count() - 1;
+                if file.name == "provenance.csv" || rows == 0 {
+                    assert!(file.sources.is_empty(), "{year} {}", file.name);
+                } else {
+                    assert!(!file.sources.is_empty(), "{year} {}", file.name);
+                }
+                for source in file.sources {
+                    assert!(source.url.starts_with("https://"), "{source:?}");
+                }
+            }
+        }
+    }
+}
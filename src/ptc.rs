@@ -0,0 +1,230 @@
+//! The premium tax credit for health insurance bought through a Marketplace
+//! (Form 8962).
+
+use crate::compute::income_to_i64;
+use crate::data::{self, ApplicablePercentage};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The federal poverty line for a household of `household_size` people that
+/// the premium tax credit uses in `year`.
+///
+/// The credit uses the poverty guidelines for the 48 contiguous states
+/// published in the year before; Alaska and Hawaii have higher guidelines,
+/// which are not included. A household size of 0 counts as 1.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{federal_poverty_line, TaxYear};
+///
+/// assert_eq!(federal_poverty_line(TaxYear::Y2024, 1), 14_580);
+/// assert_eq!(federal_poverty_line(TaxYear::Y2024, 4), 30_000);
+/// # }
+/// ```
+pub const fn federal_poverty_line(year: TaxYear, household_size: u8) -> i64 {
+    let (first_person, additional_person) = data::poverty_guidelines(year);
+    let additional = if household_size > 1 {
+        household_size as i64 - 1
+    } else {
+        0
+    };
+    first_person + additional * additional_person
+}
+
+/// The applicable figure for household income of `household_income_percent`
+/// of the federal poverty line in `year` (Form 8962, line 7): the share of
+/// household income expected toward the benchmark plan, rounded to four
+/// decimal places.
+///
+/// Returns `None` above 400% of the poverty line before 2021, where no credit
+/// is allowed. From 2021 the American Rescue Plan Act and the Inflation
+/// Reduction Act lowered the percentages and capped them at 8.5% with no
+/// upper income limit.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "year-2020", feature = "year-2024"))] {
+/// use us_tax_brackets::{ptc_applicable_figure, TaxYear};
+///
+/// assert_eq!(ptc_applicable_figure(TaxYear::Y2024, 205), Some(0.022));
+/// assert_eq!(ptc_applicable_figure(TaxYear::Y2024, 600), Some(0.085));
+/// assert_eq!(ptc_applicable_figure(TaxYear::Y2020, 401), None);
+/// # }
+/// ```
+pub fn ptc_applicable_figure(year: TaxYear, household_income_percent: i64) -> Option<f64> {
+    applicable_basis_points(year, household_income_percent).map(|bp| bp as f64 / 10_000.0)
+}
+
+/// Compute the premium tax credit on Form 8962 for a household of
+/// `household_size` people with household income `magi` and an annual
+/// `benchmark_premium`, the premium of the second lowest cost silver plan.
+///
+/// Household income is taken as a percentage of the
+/// [`federal_poverty_line`], rounded down (line 5), and multiplied by the
+/// [`ptc_applicable_figure`] to get the annual contribution toward the
+/// benchmark plan, rounded to whole dollars (line 8a). The credit is what is
+/// left of `benchmark_premium`. Households below 100% of the poverty line,
+/// above 400% before 2021, or married filing separately get no credit.
+///
+/// The credit is also limited to the premiums actually paid for the
+/// coverage, and advance payments must be reconciled against it; both are
+/// left to the caller.
+///
+/// # Errors
+///
+/// Returns [`TaxError::IncomeOutOfRange`] if `magi` does not fit in an
+/// `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{compute_ptc, FilingStatus, TaxYear};
+///
+/// let status = FilingStatus::Single;
+/// // 205% of the poverty line, so 2.2% of income, $660, is expected.
+/// assert_eq!(compute_ptc(TaxYear::Y2024, status, 30_000, 1, 6_000), Ok(5_340));
+/// // 8.5% of income is more than the benchmark premium.
+/// assert_eq!(compute_ptc(TaxYear::Y2024, status, 100_000, 1, 6_000), Ok(0));
+/// # }
+/// ```
+pub fn compute_ptc(
+    year: TaxYear,
+    status: FilingStatus,
+    magi: impl TryInto<i64>,
+    household_size: u8,
+    benchmark_premium: i64,
+) -> Result<i64, TaxError> {
+    let magi = income_to_i64(magi)?;
+    if status == FilingStatus::MarriedFilingSeparately {
+        return Ok(0);
+    }
+    let poverty_line = federal_poverty_line(year, household_size);
+    let percent = (i128::from(magi) * 100 / i128::from(poverty_line)) as i64;
+    if percent < 100 {
+        return Ok(0);
+    }
+    let Some(basis_points) = applicable_basis_points(year, percent) else {
+        return Ok(0);
+    };
+    let contribution = (i128::from(magi) * i128::from(basis_points) + 5_000) / 10_000;
+    Ok((i128::from(benchmark_premium) - contribution).max(0) as i64)
+}
+
+/// The applicable figure in basis points, interpolated within its row of the
+/// table and rounded half up.
+fn applicable_basis_points(year: TaxYear, percent: i64) -> Option<i64> {
+    let table = data::ptc_applicable_percentages(year);
+    let row = table
+        .iter()
+        .find(|row| percent >= row.fpl_min && row.fpl_max.is_none_or(|max| percent < max))
+        // The last row of a bounded table includes its upper end.
+        .or_else(|| table.last().filter(|row| row.fpl_max == Some(percent)))?;
+    Some(interpolate(row, percent))
+}
+
+/// The applicable figure in basis points at `percent` within `row`.
+fn interpolate(row: &ApplicablePercentage, percent: i64) -> i64 {
+    let Some(fpl_max) = row.fpl_max else {
+        return row.initial_basis_points;
+    };
+    let rise = row.final_basis_points - row.initial_basis_points;
+    let range = fpl_max - row.fpl_min;
+    row.initial_basis_points + (rise * (percent - row.fpl_min) * 2 / range + 1) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "year-2018", feature = "year-2021", feature = "year-2025"))]
+    #[test]
+    fn poverty_lines() {
+        assert_eq!(federal_poverty_line(TaxYear::Y2018, 1), 12_060);
+        assert_eq!(federal_poverty_line(TaxYear::Y2018, 0), 12_060);
+        assert_eq!(federal_poverty_line(TaxYear::Y2021, 3), 12_760 + 2 * 4_480);
+        assert_eq!(federal_poverty_line(TaxYear::Y2025, 2), 15_060 + 5_380);
+        assert_eq!(
+            federal_poverty_line(TaxYear::Y2025, u8::MAX),
+            15_060 + 254 * 5_380
+        );
+    }
+
+    #[cfg(all(feature = "year-2018", feature = "year-2024"))]
+    #[test]
+    fn applicable_figures() {
+        let year = TaxYear::Y2018;
+        assert_eq!(ptc_applicable_figure(year, 50), Some(0.0201));
+        assert_eq!(ptc_applicable_figure(year, 132), Some(0.0201));
+        assert_eq!(ptc_applicable_figure(year, 133), Some(0.0302));
+        // 3.02% + 7/17 of the way to 4.03%, 3.4359%
+        assert_eq!(ptc_applicable_figure(year, 140), Some(0.0344));
+        assert_eq!(ptc_applicable_figure(year, 150), Some(0.0403));
+        assert_eq!(ptc_applicable_figure(year, 400), Some(0.0956));
+        assert_eq!(ptc_applicable_figure(year, 401), None);
+
+        let year = TaxYear::Y2024;
+        assert_eq!(ptc_applicable_figure(year, 150), Some(0.0));
+        assert_eq!(ptc_applicable_figure(year, 175), Some(0.01));
+        assert_eq!(ptc_applicable_figure(year, 350), Some(0.0725));
+        assert_eq!(ptc_applicable_figure(year, 400), Some(0.085));
+        assert_eq!(ptc_applicable_figure(year, 10_000), Some(0.085));
+    }
+
+    #[cfg(all(feature = "year-2021", feature = "year-2024"))]
+    #[test]
+    fn credit() {
+        let year = TaxYear::Y2024;
+        let single = FilingStatus::Single;
+        // $60,000 is 200% of the poverty line for four, so 2% is expected.
+        assert_eq!(
+            compute_ptc(year, FilingStatus::MarriedFilingJointly, 60_000, 4, 15_000),
+            Ok(13_800)
+        );
+        assert_eq!(compute_ptc(year, single, 100_000, 1, 10_000), Ok(1_500));
+        // Below the poverty line
+        assert_eq!(compute_ptc(year, single, 14_000, 1, 6_000), Ok(0));
+        assert_eq!(compute_ptc(year, single, -5_000, 1, 6_000), Ok(0));
+        assert_eq!(compute_ptc(year, single, i64::MAX, 1, 6_000), Ok(0));
+        assert_eq!(
+            compute_ptc(
+                year,
+                FilingStatus::MarriedFilingSeparately,
+                30_000,
+                1,
+                6_000
+            ),
+            Ok(0)
+        );
+        // Nothing is expected up to 150% of the poverty line from 2021.
+        assert_eq!(
+            compute_ptc(TaxYear::Y2021, single, 19_140, 1, 6_000),
+            Ok(6_000)
+        );
+    }
+
+    #[cfg(feature = "year-2018")]
+    #[test]
+    fn cliff_before_2021() {
+        let year = TaxYear::Y2018;
+        let single = FilingStatus::Single;
+        // 3.44% of $16,884 is $580.81.
+        assert_eq!(compute_ptc(year, single, 16_884, 1, 5_000), Ok(4_419));
+        // 9.56% of $48,240 is $4,611.74 at exactly 400%, and reaching 401%
+        // loses the whole credit.
+        assert_eq!(compute_ptc(year, single, 48_240, 1, 6_000), Ok(1_388));
+        assert_eq!(compute_ptc(year, single, 48_360, 1, 6_000), Ok(1_377));
+        assert_eq!(compute_ptc(year, single, 48_361, 1, 6_000), Ok(0));
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn errors() {
+        assert_eq!(
+            compute_ptc(TaxYear::Y2025, FilingStatus::Single, u64::MAX, 1, 6_000),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
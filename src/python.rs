@@ -0,0 +1,141 @@
+//! Python bindings (requires the `python` feature).
+//!
+//! Build the extension module with [maturin](https://www.maturin.rs), which
+//! reads the repository's `pyproject.toml`:
+//!
+//! ```sh
+//! maturin develop --release
+//! ```
+//!
+//! ```python
+//! from us_tax_brackets import FilingStatus, compute_tax, compute_tax_breakdown
+//!
+//! compute_tax(2025, FilingStatus.Single, 75_000)  # 11420
+//! [p.rate for p in compute_tax_breakdown(2025, FilingStatus.Single, 75_000)]
+//! # [0.1, 0.12, 0.22]
+//! ```
+//!
+//! Years are passed as calendar years. Every [`TaxError`] is raised as a
+//! Python `ValueError` carrying the error's message.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::breakdown::BracketPortion;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+impl From<TaxError> for PyErr {
+    fn from(error: TaxError) -> PyErr {
+        PyValueError::new_err(error.to_string())
+    }
+}
+
+/// Compute federal income tax in whole dollars.
+#[pyfunction]
+#[pyo3(name = "compute_tax")]
+fn py_compute_tax(year: u16, status: FilingStatus, taxable_income: i64) -> PyResult<i64> {
+    Ok(crate::compute_tax(
+        TaxYear::try_from(year)?,
+        status,
+        taxable_income,
+    )?)
+}
+
+/// Split taxable income across the Tax Rate Schedule brackets.
+#[pyfunction]
+#[pyo3(name = "compute_tax_breakdown")]
+fn py_compute_tax_breakdown(
+    year: u16,
+    status: FilingStatus,
+    taxable_income: i64,
+) -> PyResult<Vec<BracketPortion>> {
+    Ok(crate::compute_tax_breakdown(
+        TaxYear::try_from(year)?,
+        status,
+        taxable_income,
+    )?)
+}
+
+/// The marginal tax rate at a taxable income.
+#[pyfunction]
+#[pyo3(name = "marginal_rate")]
+fn py_marginal_rate(year: u16, status: FilingStatus, taxable_income: i64) -> PyResult<f64> {
+    Ok(crate::marginal_rate(
+        TaxYear::try_from(year)?,
+        status,
+        taxable_income,
+    )?)
+}
+
+/// The effective (average) tax rate at a taxable income.
+#[pyfunction]
+#[pyo3(name = "effective_rate")]
+fn py_effective_rate(year: u16, status: FilingStatus, taxable_income: i64) -> PyResult<f64> {
+    Ok(crate::effective_rate(
+        TaxYear::try_from(year)?,
+        status,
+        taxable_income,
+    )?)
+}
+
+/// The `us_tax_brackets` Python module.
+#[pymodule]
+fn us_tax_brackets(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<FilingStatus>()?;
+    module.add_class::<BracketPortion>()?;
+    module.add_function(wrap_pyfunction!(py_compute_tax, module)?)?;
+    module.add_function(wrap_pyfunction!(py_compute_tax_breakdown, module)?)?;
+    module.add_function(wrap_pyfunction!(py_marginal_rate, module)?)?;
+    module.add_function(wrap_pyfunction!(py_effective_rate, module)?)?;
+    module.add("SUPPORTED_YEARS", TaxYear::ALL.map(u16::from).to_vec())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    fn run(code: &str) -> PyResult<()> {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "us_tax_brackets")?;
+            us_tax_brackets(&module)?;
+            let globals = PyDict::new(py);
+            globals.set_item("ustax", module)?;
+            py.run(&std::ffi::CString::new(code).unwrap(), Some(&globals), None)
+        })
+    }
+
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn compute_tax_from_python() {
+        run("assert ustax.compute_tax(2025, ustax.FilingStatus.Single, 75_000) == 11_420").unwrap();
+        run("assert ustax.compute_tax(2024, ustax.FilingStatus.MarriedFilingJointly, 150_000) == 23_106")
+            .unwrap();
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn breakdown_from_python() {
+        run(concat!(
+            "portions = ustax.compute_tax_breakdown(2025, ustax.FilingStatus.Single, 75_000)\n",
+            "assert [p.rate for p in portions] == [0.10, 0.12, 0.22]\n",
+            "assert sum(p.income for p in portions) == 75_000\n",
+            "assert portions[-1].income_max == 103_350\n",
+        ))
+        .unwrap();
+    }
+
+    #[test]
+    fn errors_raise_value_error() {
+        run(concat!(
+            "try:\n",
+            "    ustax.compute_tax(1999, ustax.FilingStatus.Single, 1)\n",
+            "    raise AssertionError('no error')\n",
+            "except ValueError as error:\n",
+            "    assert '1999' in str(error)\n",
+        ))
+        .unwrap();
+    }
+}
@@ -0,0 +1,214 @@
+//! The qualified business income deduction of section 199A (Forms 8995 and
+//! 8995-A).
+
+use crate::compute::income_to_i64;
+use crate::data;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The taxable income range, before the deduction, over which the W-2 wage
+/// and property limits phase in and the deduction for a specified service
+/// trade or business phases out in `year`, as the threshold and the income
+/// at which the phase-in is complete.
+///
+/// Married couples filing jointly have a range of $100,000 and everyone else
+/// $50,000; qualifying surviving spouses use the single threshold.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{qbi_threshold, FilingStatus, TaxYear};
+///
+/// assert_eq!(qbi_threshold(TaxYear::Y2024, FilingStatus::Single), (191_950, 241_950));
+/// assert_eq!(
+///     qbi_threshold(TaxYear::Y2024, FilingStatus::MarriedFilingJointly),
+///     (383_900, 483_900)
+/// );
+/// # }
+/// ```
+pub const fn qbi_threshold(year: TaxYear, status: FilingStatus) -> (i64, i64) {
+    let threshold = data::qbi_threshold(year, status);
+    (
+        threshold.threshold,
+        threshold.threshold + threshold.phase_in_range,
+    )
+}
+
+/// Compute the qualified business income deduction for one business, rounded
+/// to whole dollars.
+///
+/// The deduction is 20% of `qbi`, limited to 20% of
+/// `taxable_income_before_qbi`. Up to the [`qbi_threshold`] nothing else
+/// applies. Above it the deduction is also limited to the greater of 50% of
+/// `w2_wages` or 25% of `w2_wages` plus 2.5% of `ubia`, the unadjusted basis
+/// of qualified property. The limit phases in across the range, as in Form
+/// 8995-A Part III. A specified service trade or business (`is_sstb`) counts
+/// only the applicable percentage of its income, wages, and property, which
+/// falls from 100% to 0% across the same range.
+///
+/// A loss gives no deduction and carries forward, which is left to the
+/// caller, as are the aggregation of several businesses, the REIT dividend
+/// and publicly traded partnership component, and the subtraction of net
+/// capital gain from taxable income for the income limit.
+///
+/// # Errors
+///
+/// Returns [`TaxError::IncomeOutOfRange`] if `taxable_income_before_qbi`
+/// does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{compute_qbi_deduction, FilingStatus, TaxYear};
+///
+/// let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+/// // Below the threshold, 20% of the business income
+/// let deduction = compute_qbi_deduction(year, status, 100_000, 150_000, 0, 0, false);
+/// assert_eq!(deduction, Ok(20_000));
+/// // Above the phase-in range, limited to 50% of W-2 wages
+/// let deduction = compute_qbi_deduction(year, status, 300_000, 350_000, 30_000, 0, false);
+/// assert_eq!(deduction, Ok(15_000));
+/// // And nothing for a specified service business
+/// let deduction = compute_qbi_deduction(year, status, 300_000, 350_000, 30_000, 0, true);
+/// assert_eq!(deduction, Ok(0));
+/// # }
+/// ```
+pub fn compute_qbi_deduction(
+    year: TaxYear,
+    status: FilingStatus,
+    qbi: i64,
+    taxable_income_before_qbi: impl TryInto<i64>,
+    w2_wages: i64,
+    ubia: i64,
+    is_sstb: bool,
+) -> Result<i64, TaxError> {
+    let taxable_income = income_to_i64(taxable_income_before_qbi)?;
+    if qbi <= 0 || taxable_income <= 0 {
+        return Ok(0);
+    }
+    let threshold = data::qbi_threshold(year, status);
+    let range = i128::from(threshold.phase_in_range);
+    let excess = i128::from(taxable_income.saturating_sub(threshold.threshold)).clamp(0, range);
+    // The applicable percentage of a specified service business, over
+    // `range`
+    let applicable = if is_sstb { range - excess } else { range };
+
+    // Amounts in tenths of a percent of the applicable share, over
+    // `1_000 * range`
+    let (qbi, w2_wages, ubia) = (
+        i128::from(qbi),
+        i128::from(w2_wages.max(0)),
+        i128::from(ubia.max(0)),
+    );
+    let deduction = qbi * applicable * 200;
+    let wage_limit =
+        (w2_wages * applicable * 500).max(w2_wages * applicable * 250 + ubia * applicable * 25);
+    // The phase-in reduction (lines 24–26), over `1_000 * range * range`
+    let component = if wage_limit >= deduction {
+        deduction * range
+    } else {
+        deduction * range - (deduction - wage_limit) * excess
+    };
+    let denominator = 1_000 * range * range;
+    let income_limit = i128::from(taxable_income) * 200 * range * range;
+    let deduction = component.min(income_limit);
+    Ok(((deduction * 2 + denominator) / (2 * denominator)) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "year-2018", feature = "year-2019", feature = "year-2025"))]
+    #[test]
+    fn thresholds() {
+        let year = TaxYear::Y2019;
+        assert_eq!(
+            qbi_threshold(year, FilingStatus::MarriedFilingSeparately),
+            (160_725, 210_725)
+        );
+        assert_eq!(
+            qbi_threshold(year, FilingStatus::QualifyingSurvivingSpouse),
+            qbi_threshold(year, FilingStatus::Single)
+        );
+        assert_eq!(
+            qbi_threshold(TaxYear::Y2018, FilingStatus::HeadOfHousehold),
+            (157_500, 207_500)
+        );
+        assert_eq!(
+            qbi_threshold(TaxYear::Y2025, FilingStatus::MarriedFilingJointly),
+            (394_600, 494_600)
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn below_threshold() {
+        let year = TaxYear::Y2024;
+        let single = FilingStatus::Single;
+        let deduction = |qbi, taxable_income, is_sstb| {
+            compute_qbi_deduction(year, single, qbi, taxable_income, 0, 0, is_sstb).unwrap()
+        };
+        assert_eq!(deduction(50_000, 191_950, true), 10_000);
+        assert_eq!(deduction(50_003, 191_950, false), 10_001);
+        // Limited to 20% of taxable income
+        assert_eq!(deduction(80_000, 30_000, false), 6_000);
+        assert_eq!(deduction(-20_000, 100_000, false), 0);
+        assert_eq!(deduction(20_000, -5_000, false), 0);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn wage_and_property_limits() {
+        let year = TaxYear::Y2024;
+        let joint = FilingStatus::MarriedFilingJointly;
+        let deduction = |taxable_income, w2_wages, ubia| {
+            compute_qbi_deduction(year, joint, 400_000, taxable_income, w2_wages, ubia, false)
+                .unwrap()
+        };
+        // 25% of wages plus 2.5% of property beats 50% of wages.
+        assert_eq!(deduction(600_000, 20_000, 2_000_000), 55_000);
+        assert_eq!(deduction(600_000, 100_000, 0), 50_000);
+        assert_eq!(deduction(600_000, 200_000, 0), 80_000);
+        // Halfway through the phase-in, half of the $60,000 excess over the
+        // $20,000 wage limit is lost.
+        assert_eq!(deduction(433_900, 40_000, 0), 50_000);
+        // At the threshold only the income limit applies.
+        assert_eq!(deduction(383_900, 40_000, 0), 76_780);
+        assert_eq!(deduction(483_900, 40_000, 0), 20_000);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn specified_service_business() {
+        let year = TaxYear::Y2024;
+        let single = FilingStatus::Single;
+        let deduction = |taxable_income, w2_wages| {
+            compute_qbi_deduction(year, single, 100_000, taxable_income, w2_wages, 0, true).unwrap()
+        };
+        // 60% applicable: 20% of $60,000, less 40% of any excess over 50%
+        // of 60% of the wages
+        assert_eq!(deduction(211_950, 40_000), 12_000);
+        assert_eq!(deduction(211_950, 0), 12_000 - 4_800);
+        assert_eq!(deduction(241_950, 100_000), 0);
+        assert_eq!(deduction(i64::MAX, 100_000), 0);
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn errors() {
+        assert_eq!(
+            compute_qbi_deduction(
+                TaxYear::Y2025,
+                FilingStatus::Single,
+                10_000,
+                u64::MAX,
+                0,
+                0,
+                false
+            ),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
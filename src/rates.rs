@@ -0,0 +1,488 @@
+//! Marginal and effective tax rates.
+
+use crate::batch::BatchData;
+use crate::breakdown::BracketPortion;
+use crate::compute::{compute_tax, income_to_i64};
+use crate::schedule::{TaxSchedule, bracket_for_income};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// Return the marginal tax rate for a given tax year, filing status, and
+/// taxable income.
+///
+/// The marginal rate is the statutory rate of the Tax Rate Schedule bracket
+/// containing `taxable_income`. Brackets follow the IRS "Over $X but not over
+/// $Y" wording, so an income exactly at a bracket threshold is taxed at the
+/// lower bracket's rate.
+///
+/// # Errors
+///
+/// Returns the same errors as [`bracket_for_income`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{marginal_rate, FilingStatus, TaxYear};
+///
+/// let rate = marginal_rate(TaxYear::Y2025, FilingStatus::Single, 75_000).unwrap();
+/// assert_eq!(rate, 0.22);
+///
+/// // $48,475 is the top of the 12% bracket for 2025 single filers
+/// let rate = marginal_rate(TaxYear::Y2025, FilingStatus::Single, 48_475).unwrap();
+/// assert_eq!(rate, 0.12);
+/// # }
+/// ```
+pub fn marginal_rate(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+) -> Result<f64, TaxError> {
+    bracket_for_income(year, status, taxable_income).map(|bracket| bracket.rate)
+}
+
+/// Return the effective tax rate for a given tax year, filing status, and
+/// taxable income.
+///
+/// The effective rate is the total tax from [`compute_tax`] divided by
+/// `taxable_income`. Zero income has an effective rate of `0.0`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`compute_tax`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{effective_rate, FilingStatus, TaxYear};
+///
+/// // $5,920 of tax on $50,000 of taxable income
+/// let rate = effective_rate(TaxYear::Y2025, FilingStatus::Single, 50_000).unwrap();
+/// assert_eq!(rate, 0.1184);
+///
+/// assert_eq!(effective_rate(TaxYear::Y2025, FilingStatus::Single, 0).unwrap(), 0.0);
+/// # }
+/// ```
+pub fn effective_rate(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+) -> Result<f64, TaxError> {
+    let taxable_income = income_to_i64(taxable_income)?;
+    let tax = compute_tax(year, status, taxable_income)?;
+    if taxable_income == 0 {
+        return Ok(0.0);
+    }
+    Ok(tax as f64 / taxable_income as f64)
+}
+
+/// Convert a tax-exempt yield into the taxable yield that gives the same
+/// after-tax return.
+///
+/// Returns `muni_yield / (1 − marginal rate)`, using [`marginal_rate`] at
+/// `taxable_income`. Yields are fractions (`0.035` for 3.5%). Only federal
+/// income tax is considered; state tax and the net investment income tax
+/// are not.
+///
+/// # Errors
+///
+/// Returns the same errors as [`marginal_rate`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{tax_equivalent_yield, FilingStatus, TaxYear};
+///
+/// // A 3% municipal bond in the 24% bracket: 0.03 / 0.76 ≈ 3.95% taxable
+/// let taxable = tax_equivalent_yield(TaxYear::Y2025, FilingStatus::Single, 150_000, 0.03).unwrap();
+/// assert!((taxable - 0.03947).abs() < 1e-5);
+/// # }
+/// ```
+pub fn tax_equivalent_yield(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+    muni_yield: f64,
+) -> Result<f64, TaxError> {
+    let rate = marginal_rate(year, status, taxable_income)?;
+    Ok(muni_yield / (1.0 - rate))
+}
+
+/// The tax on income added on top of other income, returned by
+/// [`incremental_tax`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IncrementalTax {
+    /// The additional tax from [`compute_tax`], in whole dollars.
+    pub tax: i64,
+    /// [`tax`](Self::tax) divided by the extra income: the average rate on
+    /// it, between the marginal rates of the brackets it spans.
+    pub blended_rate: f64,
+    /// The part of the extra income in each bracket it spans, in ascending
+    /// order.
+    pub portions: Vec<BracketPortion>,
+}
+
+/// Compute the tax on `extra_income`, such as a bonus, added on top of
+/// `base_income`.
+///
+/// The tax is the difference between [`compute_tax`] with and without the
+/// extra income, so it includes the Tax Table's rounding below $100,000. The
+/// [`portions`](IncrementalTax::portions) split the extra income across the
+/// Tax Rate Schedule brackets, as [`compute_tax_breakdown`] does, without
+/// the base income below it. No extra income has a blended rate of `0.0`.
+///
+/// Both amounts are taxable income. Withholding on supplemental wages, often
+/// a flat 22%, only changes when the tax is paid.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if either amount is negative,
+/// [`TaxError::IncomeOutOfRange`] if they do not fit in an `i64` together,
+/// and the same errors as [`compute_tax`].
+///
+/// [`compute_tax_breakdown`]: crate::compute_tax_breakdown
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{incremental_tax, FilingStatus, TaxYear};
+///
+/// // A $20,000 bonus on $90,000 crosses from the 22% into the 24% bracket
+/// let bonus = incremental_tax(TaxYear::Y2025, FilingStatus::Single, 90_000, 20_000).unwrap();
+/// assert_eq!(bonus.tax, 4_527);
+/// assert_eq!(bonus.portions.len(), 2);
+/// assert_eq!((bonus.portions[0].income, bonus.portions[0].rate), (13_350, 0.22));
+/// assert_eq!((bonus.portions[1].income, bonus.portions[1].rate), (6_650, 0.24));
+/// assert!(bonus.blended_rate > 0.22 && bonus.blended_rate < 0.24);
+/// # }
+/// ```
+pub fn incremental_tax(
+    year: TaxYear,
+    status: FilingStatus,
+    base_income: impl TryInto<i64>,
+    extra_income: impl TryInto<i64>,
+) -> Result<IncrementalTax, TaxError> {
+    let base_income = income_to_i64(base_income)?;
+    let extra_income = income_to_i64(extra_income)?;
+    if base_income < 0 || extra_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let total = base_income
+        .checked_add(extra_income)
+        .ok_or(TaxError::IncomeOutOfRange)?;
+    let tax = compute_tax(year, status, total)? - compute_tax(year, status, base_income)?;
+
+    let portions = TaxSchedule::new(year, status)?
+        .breakdown(total)?
+        .into_iter()
+        .filter(|portion| portion.income_min + portion.income > base_income)
+        .map(|portion| {
+            let income = portion.income - (base_income - portion.income_min).max(0);
+            BracketPortion {
+                income,
+                tax: income as f64 * portion.rate,
+                ..portion
+            }
+        })
+        .collect();
+    Ok(IncrementalTax {
+        tax,
+        blended_rate: if extra_income == 0 {
+            0.0
+        } else {
+            tax as f64 / extra_income as f64
+        },
+        portions,
+    })
+}
+
+/// One sample of [`rate_curve`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RatePoint {
+    /// Taxable income in whole dollars.
+    pub income: i64,
+    /// Tax from [`compute_tax`].
+    pub tax: i64,
+    /// Rate from [`marginal_rate`].
+    pub marginal_rate: f64,
+    /// Rate from [`effective_rate`].
+    pub effective_rate: f64,
+}
+
+/// Sample tax, marginal rate, and effective rate from $0 to `max_income`, for
+/// plotting.
+///
+/// Points are taken every `step` dollars starting at zero. The last point is
+/// always `max_income`, even if it is not a multiple of `step`. A negative
+/// `max_income` yields an empty curve.
+///
+/// # Errors
+///
+/// Returns [`TaxError::InvalidAmount`] if `step` is not positive, and
+/// otherwise the same errors as [`compute_tax`] and [`marginal_rate`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{rate_curve, FilingStatus, TaxYear};
+///
+/// let curve = rate_curve(TaxYear::Y2025, FilingStatus::Single, 200_000, 25_000).unwrap();
+/// assert_eq!(curve.len(), 9);
+/// assert_eq!(curve[3].income, 75_000);
+/// assert_eq!(curve[3].tax, 11_420);
+/// assert_eq!(curve[3].marginal_rate, 0.22);
+/// # }
+/// ```
+pub fn rate_curve(
+    year: TaxYear,
+    status: FilingStatus,
+    max_income: i64,
+    step: i64,
+) -> Result<Vec<RatePoint>, TaxError> {
+    if step <= 0 {
+        return Err(TaxError::InvalidAmount(format!("step of {step}")));
+    }
+    let batch = BatchData::load(year, status);
+    let schedule = TaxSchedule::new(year, status)?;
+
+    let step = usize::try_from(step).unwrap_or(usize::MAX);
+    let mut incomes: Vec<i64> = (0..=max_income).step_by(step).collect();
+    if incomes.last().is_some_and(|&last| last != max_income) {
+        incomes.push(max_income);
+    }
+
+    incomes
+        .into_iter()
+        .map(|income| {
+            let tax = batch.tax(income)?;
+            let bracket = schedule
+                .bracket_for(income)
+                .ok_or(TaxError::NoBracketFound {
+                    year,
+                    status,
+                    taxable_income: income,
+                })?;
+            Ok(RatePoint {
+                income,
+                tax,
+                marginal_rate: bracket.rate,
+                effective_rate: if income == 0 {
+                    0.0
+                } else {
+                    tax as f64 / income as f64
+                },
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn zero_income_is_lowest_bracket() {
+        assert_eq!(
+            marginal_rate(TaxYear::Y2024, FilingStatus::Single, 0).unwrap(),
+            0.10
+        );
+    }
+
+    #[cfg(feature = "year-2023")]
+    #[test]
+    fn negative_income() {
+        assert_eq!(
+            marginal_rate(TaxYear::Y2023, FilingStatus::Single, -1),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn threshold_belongs_to_lower_bracket() {
+        // 2024 single: 12% bracket is over $11,600 but not over $47,150
+        assert_eq!(
+            marginal_rate(TaxYear::Y2024, FilingStatus::Single, 11_600).unwrap(),
+            0.10
+        );
+        assert_eq!(
+            marginal_rate(TaxYear::Y2024, FilingStatus::Single, 11_601).unwrap(),
+            0.12
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn every_status_top_bracket() {
+        for status in [
+            FilingStatus::Single,
+            FilingStatus::MarriedFilingJointly,
+            FilingStatus::MarriedFilingSeparately,
+            FilingStatus::HeadOfHousehold,
+            FilingStatus::QualifyingSurvivingSpouse,
+        ] {
+            assert_eq!(
+                marginal_rate(TaxYear::Y2025, status, 10_000_000).unwrap(),
+                0.37
+            );
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn effective_rate_zero_income() {
+        assert_eq!(
+            effective_rate(TaxYear::Y2024, FilingStatus::HeadOfHousehold, 0).unwrap(),
+            0.0
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn effective_rate_negative_income() {
+        assert_eq!(
+            effective_rate(TaxYear::Y2024, FilingStatus::Single, -100),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[cfg(feature = "year-2023")]
+    #[test]
+    fn effective_rate_below_marginal_rate() {
+        // 2023 MFJ, $200,000: $34,800 tax → 17.4% effective vs 24% marginal
+        let effective =
+            effective_rate(TaxYear::Y2023, FilingStatus::MarriedFilingJointly, 200_000).unwrap();
+        assert_eq!(effective, 0.174);
+        let marginal =
+            marginal_rate(TaxYear::Y2023, FilingStatus::MarriedFilingJointly, 200_000).unwrap();
+        assert!(effective < marginal);
+    }
+
+    #[cfg(all(feature = "year-2023", feature = "year-2025"))]
+    #[test]
+    fn matches_worksheet_rate_above_100k() {
+        // 2023 MFJ: $200,000 falls in the 24% worksheet bracket
+        assert_eq!(
+            marginal_rate(TaxYear::Y2023, FilingStatus::MarriedFilingJointly, 200_000).unwrap(),
+            0.24
+        );
+        // 2025 HoH: $300,000 falls in the 35% worksheet bracket
+        assert_eq!(
+            marginal_rate(TaxYear::Y2025, FilingStatus::HeadOfHousehold, 300_000).unwrap(),
+            0.35
+        );
+    }
+
+    #[cfg(feature = "year-2023")]
+    #[test]
+    fn rate_curve_matches_point_functions() {
+        let curve = rate_curve(
+            TaxYear::Y2023,
+            FilingStatus::HeadOfHousehold,
+            123_456,
+            10_000,
+        )
+        .unwrap();
+        assert_eq!(curve.len(), 14);
+        assert_eq!(curve.last().unwrap().income, 123_456);
+        for point in &curve {
+            let (year, status) = (TaxYear::Y2023, FilingStatus::HeadOfHousehold);
+            assert_eq!(point.tax, compute_tax(year, status, point.income).unwrap());
+            assert_eq!(
+                point.marginal_rate,
+                marginal_rate(year, status, point.income).unwrap()
+            );
+            assert_eq!(
+                point.effective_rate,
+                effective_rate(year, status, point.income).unwrap()
+            );
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn tax_equivalent_yield_top_bracket() {
+        let taxable = tax_equivalent_yield(
+            TaxYear::Y2024,
+            FilingStatus::MarriedFilingJointly,
+            1_000_000,
+            0.037,
+        )
+        .unwrap();
+        assert!((taxable - 0.037 / 0.63).abs() < 1e-12);
+        assert_eq!(
+            tax_equivalent_yield(TaxYear::Y2024, FilingStatus::Single, -5, 0.03),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn incremental_tax_spans_brackets() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::MarriedFilingJointly);
+        // Above $100,000 the worksheet tax is exact.
+        let extra = incremental_tax(year, status, 150_000, 300_000).unwrap();
+        let rates: Vec<f64> = extra.portions.iter().map(|p| p.rate).collect();
+        assert_eq!(rates, [0.22, 0.24, 0.32]);
+        assert_eq!(
+            extra.portions.iter().map(|p| p.income).sum::<i64>(),
+            300_000
+        );
+        let exact: f64 = extra.portions.iter().map(|p| p.tax).sum();
+        assert_eq!(exact.round() as i64, extra.tax);
+        assert_eq!(
+            extra.tax,
+            compute_tax(year, status, 450_000).unwrap()
+                - compute_tax(year, status, 150_000).unwrap()
+        );
+
+        // Within one bracket
+        let extra = incremental_tax(year, status, 250_000, 1_000).unwrap();
+        assert_eq!(extra.portions.len(), 1);
+        assert_eq!(extra.portions[0].income_min, 201_050);
+        assert_eq!(extra.blended_rate, 0.24);
+
+        let none = incremental_tax(year, status, 250_000, 0).unwrap();
+        assert_eq!((none.tax, none.blended_rate), (0, 0.0));
+        assert!(none.portions.is_empty());
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn incremental_tax_errors() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+        assert_eq!(
+            incremental_tax(year, status, -1, 1_000),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            incremental_tax(year, status, 1_000, -1),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            incremental_tax(year, status, i64::MAX, 1),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn rate_curve_edges() {
+        let curve = |max| rate_curve(TaxYear::Y2024, FilingStatus::Single, max, 50).unwrap();
+        assert!(curve(-1).is_empty());
+        assert_eq!(curve(0).len(), 1);
+        assert_eq!(curve(100).len(), 3);
+        for step in [0, -50] {
+            assert!(matches!(
+                rate_curve(TaxYear::Y2024, FilingStatus::Single, 100, step),
+                Err(TaxError::InvalidAmount(_))
+            ));
+        }
+    }
+}
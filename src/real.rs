@@ -0,0 +1,289 @@
+//! Conversion between nominal and constant (real) dollars.
+
+use crate::data;
+#[cfg(feature = "historical")]
+use crate::historical::{historical_schedule, historical_years};
+use crate::schedule::TaxSchedule;
+#[cfg(not(feature = "historical"))]
+use crate::types::TaxYear;
+use crate::types::{FilingStatus, TaxError};
+
+/// The annual average Consumer Price Index for All Urban Consumers (CPI-U,
+/// 1982–84 = 100) for `year`, as published by the Bureau of Labor
+/// Statistics.
+///
+/// The series covers 1991 through the latest complete calendar year.
+///
+/// # Errors
+///
+/// Returns [`TaxError::UnsupportedYear`] for a year the series does not
+/// cover.
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::consumer_price_index;
+///
+/// assert_eq!(consumer_price_index(2020).unwrap(), 258.811);
+/// assert!(consumer_price_index(1900).is_err());
+/// ```
+pub fn consumer_price_index(year: u16) -> Result<f64, TaxError> {
+    data::consumer_price_index(year).ok_or(TaxError::UnsupportedYear(year))
+}
+
+/// Express an amount paid in `year_paid` in the dollars of `base_year`.
+///
+/// The amount is scaled by the ratio of the two years' annual average
+/// [`consumer_price_index`], so a series of taxes from different years can be
+/// compared in constant dollars. Despite the name, any dollar amount can be
+/// converted, such as an income or a refund.
+///
+/// # Errors
+///
+/// Returns [`TaxError::UnsupportedYear`] if the price index does not cover
+/// either year.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2018")] {
+/// use us_tax_brackets::{compute_tax, tax_in_real_terms, FilingStatus, TaxYear};
+///
+/// let tax = compute_tax(TaxYear::Y2018, FilingStatus::Single, 50_000).unwrap();
+/// let in_2024_dollars = tax_in_real_terms(2018, 2024, tax as f64).unwrap();
+/// assert!((in_2024_dollars - 8_675.9).abs() < 0.1);
+/// # }
+/// ```
+pub fn tax_in_real_terms(year_paid: u16, base_year: u16, tax: f64) -> Result<f64, TaxError> {
+    Ok(tax * consumer_price_index(base_year)? / consumer_price_index(year_paid)?)
+}
+
+/// The bracket thresholds of `year`'s Tax Rate Schedule in the dollars of
+/// `base_year`, in ascending order.
+///
+/// This shows how far the brackets have moved in real terms: thresholds
+/// indexed to a different measure of inflation than the CPI-U, or not
+/// indexed at all, drift against constant dollars. With the `historical`
+/// feature, `year` can be any year `historical_schedule` covers.
+///
+/// # Errors
+///
+/// Returns [`TaxError::UnsupportedYear`] if the crate has no schedule for
+/// `year` or the price index does not cover either year.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2018")] {
+/// use us_tax_brackets::{real_bracket_thresholds, FilingStatus};
+///
+/// let thresholds = real_bracket_thresholds(2018, FilingStatus::Single, 2024).unwrap();
+/// assert_eq!(thresholds.len(), 6);
+/// // The 10% bracket ended at $9,525, about $11,900 in 2024 dollars
+/// assert!((thresholds[0] - 11_899.0).abs() < 1.0);
+/// # }
+/// ```
+pub fn real_bracket_thresholds(
+    year: u16,
+    status: FilingStatus,
+    base_year: u16,
+) -> Result<Vec<f64>, TaxError> {
+    let schedule = schedule(year, status)?;
+    let factor = tax_in_real_terms(year, base_year, 1.0)?;
+    Ok(schedule
+        .thresholds()
+        .map(|threshold| threshold as f64 * factor)
+        .collect())
+}
+
+/// One year of [`bracket_creep`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BracketCreepYear {
+    /// The calendar year.
+    pub year: u16,
+    /// The fixed real income in this year's dollars, rounded to whole
+    /// dollars.
+    pub income: i64,
+    /// The exact rate-schedule tax on [`income`](Self::income) with this
+    /// year's brackets.
+    pub tax: f64,
+    /// [`tax`](Self::tax) divided by [`income`](Self::income).
+    pub effective_rate: f64,
+    /// The tax on [`income`](Self::income) had the base year's brackets been
+    /// frozen, never adjusted for inflation.
+    pub frozen_tax: f64,
+    /// [`frozen_tax`](Self::frozen_tax) divided by [`income`](Self::income).
+    pub frozen_effective_rate: f64,
+}
+
+/// Follow a taxable income that stays the same in real terms, `real_income`
+/// in `base_year` dollars, across every year with both a Tax Rate Schedule
+/// and a [`consumer_price_index`].
+///
+/// Each year's income is `real_income` restated with
+/// [`tax_in_real_terms`] and taxed exactly, without the Tax Table's $50
+/// bands, first with that year's brackets and then with the base year's
+/// brackets frozen. With indexing that matched the CPI-U, the effective rate
+/// would not change; any drift in it is bracket creep, from indexing to a
+/// different measure or from changes in the law. The frozen column shows the
+/// creep with no indexing at all. The standard deduction, which is indexed
+/// the same way, is left out. With the `historical` feature, the years run
+/// from 1991.
+///
+/// # Errors
+///
+/// Returns [`TaxError::UnsupportedYear`] if the crate has no schedule or
+/// price index for `base_year`, and [`TaxError::NegativeIncome`] if
+/// `real_income` is negative.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "year-2018", feature = "year-2024"))] {
+/// use us_tax_brackets::{bracket_creep, FilingStatus};
+///
+/// let creep = bracket_creep(FilingStatus::Single, 60_000, 2018).unwrap();
+/// assert_eq!(creep.last().unwrap().year, 2024);
+/// let in_2024 = creep.iter().find(|year| year.year == 2024).unwrap();
+/// assert!((in_2024.income - 74_953).abs() <= 1);
+/// // Frozen 2018 brackets would have taxed the same real income more.
+/// assert!(in_2024.frozen_effective_rate > in_2024.effective_rate);
+/// # }
+/// ```
+pub fn bracket_creep(
+    status: FilingStatus,
+    real_income: i64,
+    base_year: u16,
+) -> Result<Vec<BracketCreepYear>, TaxError> {
+    if real_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let frozen = schedule(base_year, status)?;
+    consumer_price_index(base_year)?;
+    years()
+        .filter(|&year| data::consumer_price_index(year).is_some())
+        .map(|year| {
+            let income = tax_in_real_terms(base_year, year, real_income as f64)?.round() as i64;
+            let tax = schedule(year, status)?.tax(income)?;
+            let frozen_tax = frozen.tax(income)?;
+            let rate = |tax: f64| {
+                if income == 0 {
+                    0.0
+                } else {
+                    tax / income as f64
+                }
+            };
+            Ok(BracketCreepYear {
+                year,
+                income,
+                tax,
+                effective_rate: rate(tax),
+                frozen_tax,
+                frozen_effective_rate: rate(frozen_tax),
+            })
+        })
+        .collect()
+}
+
+/// The calendar years [`schedule`] covers, in ascending order.
+fn years() -> impl Iterator<Item = u16> {
+    #[cfg(feature = "historical")]
+    return historical_years();
+    #[cfg(not(feature = "historical"))]
+    TaxYear::ALL.into_iter().map(u16::from)
+}
+
+/// The Tax Rate Schedule for a calendar year.
+fn schedule(year: u16, status: FilingStatus) -> Result<TaxSchedule, TaxError> {
+    #[cfg(feature = "historical")]
+    return historical_schedule(year, status);
+    #[cfg(not(feature = "historical"))]
+    TaxSchedule::new(TaxYear::try_from(year)?, status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TaxYear;
+
+    #[test]
+    fn series_has_no_gaps() {
+        for year in 1991..=2024 {
+            assert!(consumer_price_index(year).unwrap() > 0.0, "{year}");
+        }
+        assert_eq!(consumer_price_index(1991), Ok(136.2));
+        assert_eq!(
+            consumer_price_index(1990),
+            Err(TaxError::UnsupportedYear(1990))
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(tax_in_real_terms(2020, 2020, 1_234.0), Ok(1_234.0));
+        let there = tax_in_real_terms(2000, 2024, 1_000.0).unwrap();
+        assert!((there - 1_000.0 * 313.689 / 172.2).abs() < 1e-9);
+        let back = tax_in_real_terms(2024, 2000, there).unwrap();
+        assert!((back - 1_000.0).abs() < 1e-9);
+        assert_eq!(
+            tax_in_real_terms(2024, 1800, 1.0),
+            Err(TaxError::UnsupportedYear(1800))
+        );
+    }
+
+    #[cfg(all(feature = "year-2018", feature = "year-2023", feature = "year-2024"))]
+    #[test]
+    fn thresholds() {
+        let nominal: Vec<f64> = TaxSchedule::new(TaxYear::Y2023, FilingStatus::HeadOfHousehold)
+            .unwrap()
+            .thresholds()
+            .map(|threshold| threshold as f64)
+            .collect();
+        assert_eq!(
+            real_bracket_thresholds(2023, FilingStatus::HeadOfHousehold, 2023),
+            Ok(nominal)
+        );
+        // Since 2018 the brackets follow the chained CPI, which has risen a
+        // few percent less than the CPI-U.
+        let real = real_bracket_thresholds(2024, FilingStatus::Single, 2018).unwrap();
+        let base = TaxSchedule::new(TaxYear::Y2018, FilingStatus::Single).unwrap();
+        for (real, base) in real.iter().zip(base.thresholds()) {
+            let drift = real / base as f64 - 1.0;
+            assert!(-0.05 < drift && drift < 0.0, "{real} {base}");
+        }
+        assert_eq!(
+            real_bracket_thresholds(2025, FilingStatus::Single, 2024),
+            Err(TaxError::UnsupportedYear(2025))
+        );
+    }
+
+    #[cfg(all(feature = "year-2018", feature = "year-2020", feature = "year-2024"))]
+    #[test]
+    fn creep() {
+        let creep = bracket_creep(FilingStatus::MarriedFilingJointly, 100_000, 2020).unwrap();
+        let base = creep.iter().find(|year| year.year == 2020).unwrap();
+        assert_eq!(base.income, 100_000);
+        assert_eq!(base.tax, base.frozen_tax);
+        for year in &creep {
+            assert!(year.tax >= 0.0 && year.effective_rate < 0.24, "{year:?}");
+            // Later thresholds are higher, so freezing them raises the tax.
+            if year.year > 2020 {
+                assert!(year.frozen_tax > year.tax, "{year:?}");
+            }
+        }
+        assert!(creep.iter().any(|year| year.year == 2018));
+        assert!(creep.iter().all(|year| year.year != 2025));
+
+        let zero = bracket_creep(FilingStatus::Single, 0, 2024).unwrap();
+        assert!(zero.iter().all(|year| year.effective_rate == 0.0));
+        assert_eq!(
+            bracket_creep(FilingStatus::Single, -1, 2024),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            bracket_creep(FilingStatus::Single, 1_000, 2025),
+            Err(TaxError::UnsupportedYear(2025))
+        );
+    }
+}
@@ -0,0 +1,237 @@
+//! Revenue effects of a modified rate schedule.
+
+use crate::schedule::TaxSchedule;
+use crate::types::TaxError;
+
+/// The tax one group of taxpayers pays under the baseline and the reform.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IncomeImpact {
+    /// Taxable income, in whole dollars.
+    pub taxable_income: i64,
+    /// Number of returns (or any other weight) at this income.
+    pub weight: f64,
+    /// Tax on one return under the baseline schedule, unrounded.
+    pub baseline_tax: f64,
+    /// Tax on one return under the reform schedule, unrounded.
+    pub reform_tax: f64,
+}
+
+impl IncomeImpact {
+    /// Change in tax on one return: reform less baseline.
+    pub fn change(&self) -> f64 {
+        self.reform_tax - self.baseline_tax
+    }
+}
+
+/// The revenue one bracket of a schedule raises across a distribution of
+/// incomes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BracketRevenue {
+    /// Lower bound of the bracket ("Over $X").
+    pub income_min: i64,
+    /// Upper bound of the bracket ("But not over $Y"), or [`None`] for the
+    /// highest (unbounded) bracket.
+    pub income_max: Option<i64>,
+    /// Marginal rate applied within the bracket (e.g., 0.22 for 22%).
+    pub rate: f64,
+    /// Weighted taxable income that falls within the bracket.
+    pub income: f64,
+    /// Weighted tax raised within the bracket (`income × rate`).
+    pub revenue: f64,
+}
+
+/// The result of [`reform_impact`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReformImpact {
+    /// Each income in the distribution, in the order given.
+    pub incomes: Vec<IncomeImpact>,
+    /// Revenue by bracket of the baseline schedule, in ascending order.
+    pub baseline_brackets: Vec<BracketRevenue>,
+    /// Revenue by bracket of the reform schedule, in ascending order.
+    pub reform_brackets: Vec<BracketRevenue>,
+}
+
+impl ReformImpact {
+    /// Total weighted tax under the baseline schedule.
+    pub fn baseline_revenue(&self) -> f64 {
+        self.baseline_brackets.iter().map(|b| b.revenue).sum()
+    }
+
+    /// Total weighted tax under the reform schedule.
+    pub fn reform_revenue(&self) -> f64 {
+        self.reform_brackets.iter().map(|b| b.revenue).sum()
+    }
+
+    /// Change in total revenue: reform less baseline.
+    pub fn revenue_change(&self) -> f64 {
+        self.reform_revenue() - self.baseline_revenue()
+    }
+}
+
+/// Compare the tax a distribution of incomes pays under two schedules.
+///
+/// `incomes` pairs each taxable income with a weight, such as the number of
+/// returns at that income in a sample. Taxes are the unrounded schedule
+/// amounts ([`TaxSchedule::tax`]), and revenue is reported both per income
+/// and per bracket of each schedule, so a reform built with
+/// [`TaxSchedule::to_builder`] can be costed without changing the crate's
+/// data.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if an income is negative, and
+/// [`TaxError::InvalidAmount`] if a weight is negative or not finite.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{reform_impact, FilingStatus, TaxSchedule, TaxYear};
+///
+/// let current = TaxSchedule::new(TaxYear::Y2025, FilingStatus::Single).unwrap();
+/// // Restore a 39.6% top rate
+/// let reform = current.to_builder().set_rate(626_350, 0.396).build().unwrap();
+///
+/// let incomes = [(50_000, 1_000.0), (250_000, 100.0), (1_000_000, 10.0)];
+/// let impact = reform_impact(&current, &reform, &incomes).unwrap();
+///
+/// // Only the top bracket changes: 10 returns × $373,650 × 2.6 points
+/// assert!((impact.revenue_change() - 97_149.0).abs() < 1e-6);
+/// assert_eq!(impact.incomes[0].change(), 0.0);
+/// # }
+/// ```
+pub fn reform_impact(
+    baseline: &TaxSchedule,
+    reform: &TaxSchedule,
+    incomes: &[(i64, f64)],
+) -> Result<ReformImpact, TaxError> {
+    if let Some(&(_, weight)) = incomes
+        .iter()
+        .find(|(_, weight)| !(weight.is_finite() && *weight >= 0.0))
+    {
+        return Err(TaxError::InvalidAmount(format!("weight {weight}")));
+    }
+
+    let mut baseline_brackets = bracket_revenue(baseline);
+    let mut reform_brackets = bracket_revenue(reform);
+    let mut impacts = Vec::with_capacity(incomes.len());
+    for &(taxable_income, weight) in incomes {
+        let baseline_tax = add_breakdown(baseline, taxable_income, weight, &mut baseline_brackets)?;
+        let reform_tax = add_breakdown(reform, taxable_income, weight, &mut reform_brackets)?;
+        impacts.push(IncomeImpact {
+            taxable_income,
+            weight,
+            baseline_tax,
+            reform_tax,
+        });
+    }
+    Ok(ReformImpact {
+        incomes: impacts,
+        baseline_brackets,
+        reform_brackets,
+    })
+}
+
+/// An empty revenue total for each of `schedule`'s brackets.
+fn bracket_revenue(schedule: &TaxSchedule) -> Vec<BracketRevenue> {
+    schedule
+        .iter()
+        .map(|bracket| BracketRevenue {
+            income_min: bracket.income_min,
+            income_max: bracket.income_max,
+            rate: bracket.rate,
+            income: 0.0,
+            revenue: 0.0,
+        })
+        .collect()
+}
+
+/// Add `weight` returns at `taxable_income` to `totals`, and return the tax
+/// on one of them.
+fn add_breakdown(
+    schedule: &TaxSchedule,
+    taxable_income: i64,
+    weight: f64,
+    totals: &mut [BracketRevenue],
+) -> Result<f64, TaxError> {
+    let portions = schedule.breakdown(taxable_income)?;
+    // The breakdown lists the brackets from the bottom, up to the income.
+    for (total, portion) in totals.iter_mut().zip(&portions) {
+        total.income += portion.income as f64 * weight;
+        total.revenue += portion.tax * weight;
+    }
+    Ok(portions.iter().map(|portion| portion.tax).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{FilingStatus, TaxYear};
+
+    fn flat(rate: f64) -> TaxSchedule {
+        TaxSchedule::builder().bracket(0, rate).build().unwrap()
+    }
+
+    #[test]
+    fn per_income_and_per_bracket() {
+        let baseline = flat(0.1);
+        let reform = baseline
+            .to_builder()
+            .insert_bracket(100_000, 0.3)
+            .build()
+            .unwrap();
+        let impact = reform_impact(
+            &baseline,
+            &reform,
+            &[(50_000, 2.0), (150_000, 1.0), (0, 5.0)],
+        )
+        .unwrap();
+
+        let changes: Vec<f64> = impact.incomes.iter().map(IncomeImpact::change).collect();
+        assert_eq!(changes, [0.0, 10_000.0, 0.0]);
+        assert_eq!(impact.baseline_revenue(), 25_000.0);
+        assert_eq!(impact.reform_revenue(), 35_000.0);
+        assert_eq!(impact.revenue_change(), 10_000.0);
+
+        assert_eq!(impact.baseline_brackets.len(), 1);
+        assert_eq!(impact.baseline_brackets[0].income, 250_000.0);
+        let top = impact.reform_brackets[1];
+        assert_eq!(
+            (top.income_min, top.income, top.revenue),
+            (100_000, 50_000.0, 15_000.0)
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn identical_schedules_change_nothing() {
+        let schedule = TaxSchedule::new(TaxYear::Y2025, FilingStatus::HeadOfHousehold).unwrap();
+        let incomes: Vec<(i64, f64)> = (0..2_000_000).step_by(12_345).map(|i| (i, 1.5)).collect();
+        let impact = reform_impact(&schedule, &schedule, &incomes).unwrap();
+        assert_eq!(impact.revenue_change(), 0.0);
+        assert_eq!(impact.baseline_brackets, impact.reform_brackets);
+        let expected: f64 = incomes
+            .iter()
+            .map(|&(income, weight)| schedule.tax(income).unwrap() * weight)
+            .sum();
+        assert!((impact.baseline_revenue() - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        let schedule = flat(0.2);
+        assert_eq!(
+            reform_impact(&schedule, &schedule, &[(-1, 1.0)]),
+            Err(TaxError::NegativeIncome)
+        );
+        for weight in [-1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                reform_impact(&schedule, &schedule, &[(1, weight)]),
+                Err(TaxError::InvalidAmount(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Reconciling withholding and estimated payments with the tax.
+
+use crate::compute::{compute_tax, income_to_i64};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// Credits for [`estimate_refund`], in whole dollars.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Credits {
+    /// Credits that can reduce the tax to zero but no further, such as the
+    /// child tax credit from
+    /// [`ChildTaxCredit::nonrefundable`](crate::ChildTaxCredit::nonrefundable)
+    /// or the saver's credit.
+    pub nonrefundable: i64,
+    /// Credits paid out even beyond the tax, such as the earned income credit
+    /// and the additional child tax credit.
+    pub refundable: i64,
+}
+
+/// The refund or balance due from [`estimate_refund`], with the lines that
+/// lead to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RefundEstimate {
+    /// The tax on taxable income, from [`compute_tax`] (Form 1040, line 16).
+    pub tax: i64,
+    /// The nonrefundable credits used, no more than
+    /// [`tax`](Self::tax) (line 21).
+    pub nonrefundable_credits: i64,
+    /// The tax less the nonrefundable credits (line 22).
+    pub tax_after_credits: i64,
+    /// Federal income tax withheld (line 25d).
+    pub withheld: i64,
+    /// Estimated tax payments (line 26).
+    pub estimated_payments: i64,
+    /// The refundable credits (line 32).
+    pub refundable_credits: i64,
+    /// Withholding, estimated payments, and refundable credits (line 33).
+    pub total_payments: i64,
+}
+
+impl RefundEstimate {
+    /// The amount overpaid and refunded (line 34), or zero.
+    pub fn refund(&self) -> i64 {
+        (self.total_payments - self.tax_after_credits).max(0)
+    }
+
+    /// The amount owed (line 37), or zero.
+    pub fn amount_owed(&self) -> i64 {
+        (self.tax_after_credits - self.total_payments).max(0)
+    }
+}
+
+/// Estimate the refund or balance due on `taxable_income` from the tax,
+/// `credits`, and what was already paid through `federal_withheld` and
+/// `estimated_payments`.
+///
+/// The tax comes from [`compute_tax`], so qualified dividends and capital
+/// gains are taxed at ordinary rates and other taxes, such as
+/// self-employment tax, are left out; [`TaxReturn::builder`] handles those.
+/// Nonrefundable credits beyond the tax are lost. Any penalty for
+/// underpaying during the year is not included.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if any amount is negative,
+/// [`TaxError::IncomeOutOfRange`] if the payments do not fit in an `i64`
+/// together, and the same errors as [`compute_tax`].
+///
+/// [`TaxReturn::builder`]: crate::TaxReturn::builder
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{estimate_refund, Credits, FilingStatus, TaxYear};
+///
+/// // $11,420 of tax, less a $2,000 child tax credit, against $10,000 withheld
+/// let credits = Credits { nonrefundable: 2_000, refundable: 0 };
+/// let estimate =
+///     estimate_refund(TaxYear::Y2025, FilingStatus::Single, 75_000, 10_000, 0, credits).unwrap();
+/// assert_eq!(estimate.tax_after_credits, 9_420);
+/// assert_eq!(estimate.refund(), 580);
+/// assert_eq!(estimate.amount_owed(), 0);
+/// # }
+/// ```
+pub fn estimate_refund(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+    federal_withheld: i64,
+    estimated_payments: i64,
+    credits: Credits,
+) -> Result<RefundEstimate, TaxError> {
+    let taxable_income = income_to_i64(taxable_income)?;
+    if taxable_income < 0
+        || federal_withheld < 0
+        || estimated_payments < 0
+        || credits.nonrefundable < 0
+        || credits.refundable < 0
+    {
+        return Err(TaxError::NegativeIncome);
+    }
+    let tax = compute_tax(year, status, taxable_income)?;
+    let nonrefundable_credits = credits.nonrefundable.min(tax);
+    let total_payments = federal_withheld
+        .checked_add(estimated_payments)
+        .and_then(|paid| paid.checked_add(credits.refundable))
+        .ok_or(TaxError::IncomeOutOfRange)?;
+    Ok(RefundEstimate {
+        tax,
+        nonrefundable_credits,
+        tax_after_credits: tax - nonrefundable_credits,
+        withheld: federal_withheld,
+        estimated_payments,
+        refundable_credits: credits.refundable,
+        total_payments,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn balance_due() {
+        let estimate = estimate_refund(
+            TaxYear::Y2024,
+            FilingStatus::MarriedFilingJointly,
+            150_000,
+            15_000,
+            4_000,
+            Credits::default(),
+        )
+        .unwrap();
+        assert_eq!(estimate.tax, 23_106);
+        assert_eq!(estimate.total_payments, 19_000);
+        assert_eq!(estimate.amount_owed(), 4_106);
+        assert_eq!(estimate.refund(), 0);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn credits() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::HeadOfHousehold);
+        // The nonrefundable credit is limited to the tax, but the refundable
+        // credit is paid in full.
+        let credits = Credits {
+            nonrefundable: 5_000,
+            refundable: 1_500,
+        };
+        let estimate = estimate_refund(year, status, 10_000, 300, 0, credits).unwrap();
+        assert_eq!(estimate.tax, 1_003);
+        assert_eq!(estimate.nonrefundable_credits, 1_003);
+        assert_eq!(estimate.tax_after_credits, 0);
+        assert_eq!(estimate.refund(), 1_800);
+
+        let zero = estimate_refund(year, status, 0, 0, 0, Credits::default()).unwrap();
+        assert_eq!((zero.refund(), zero.amount_owed()), (0, 0));
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn errors() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+        let negative = Credits {
+            refundable: -1,
+            ..Credits::default()
+        };
+        assert_eq!(
+            estimate_refund(year, status, 50_000, 0, 0, negative),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            estimate_refund(year, status, 50_000, -1, 0, Credits::default()),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            estimate_refund(year, status, 50_000, i64::MAX, 1, Credits::default()),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
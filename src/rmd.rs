@@ -0,0 +1,144 @@
+//! Required minimum distributions from IRAs and retirement plans
+//! (Publication 590-B).
+
+use crate::compute::income_to_i64;
+use crate::data;
+use crate::money::UsdAmount;
+use crate::types::TaxError;
+
+/// The distribution period for an IRA owner of `age` from the Uniform
+/// Lifetime Table, or [`None`] before age 72, where the table starts.
+///
+/// The tables are those in effect from 2022. Ages past 120 use the last row.
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::uniform_lifetime_period;
+///
+/// assert_eq!(uniform_lifetime_period(73), Some(26.5));
+/// assert_eq!(uniform_lifetime_period(120), Some(2.0));
+/// assert_eq!(uniform_lifetime_period(65), None);
+/// ```
+pub fn uniform_lifetime_period(age: u8) -> Option<f64> {
+    let (_, uniform_lifetime) = data::life_expectancy(age);
+    uniform_lifetime.map(|tenths| tenths as f64 / 10.0)
+}
+
+/// The life expectancy at `age` from the Single Life Table, which
+/// beneficiaries of an inherited IRA use for their distributions.
+///
+/// A beneficiary takes the life expectancy at their age in the year after
+/// the owner's death and subtracts one for each later year. Ages past 120
+/// use the last row.
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::single_life_expectancy;
+///
+/// assert_eq!(single_life_expectancy(50), 36.2);
+/// assert_eq!(single_life_expectancy(80), 11.2);
+/// ```
+pub fn single_life_expectancy(age: u8) -> f64 {
+    let (single_life, _) = data::life_expectancy(age);
+    single_life as f64 / 10.0
+}
+
+/// Compute the required minimum distribution for an IRA owner who reaches
+/// `age` this year, with `prior_year_balance` the account balance at the
+/// end of last year, rounded to the cent.
+///
+/// The balance is divided by the [`uniform_lifetime_period`] for `age`.
+/// Below 72 the table has no entry and nothing is required. Whether the
+/// distributions start at 72, 73, or later depends on the owner's birth
+/// year, and an owner whose spouse is the sole beneficiary and more than 10
+/// years younger uses the Joint and Last Survivor Table instead; both are
+/// left to the caller.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `prior_year_balance` is negative
+/// and [`TaxError::IncomeOutOfRange`] if it does not fit in an `i64` or the
+/// distribution does not fit in [`UsdAmount`].
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::required_minimum_distribution;
+///
+/// let rmd = required_minimum_distribution(75, 500_000).unwrap();
+/// assert_eq!(rmd.to_string(), "$20,325.20");
+/// assert_eq!(required_minimum_distribution(70, 500_000).unwrap().cents(), 0);
+/// ```
+pub fn required_minimum_distribution(
+    age: u8,
+    prior_year_balance: impl TryInto<i64>,
+) -> Result<UsdAmount, TaxError> {
+    let balance = income_to_i64(prior_year_balance)?;
+    if balance < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let (_, Some(tenths)) = data::life_expectancy(age) else {
+        return Ok(UsdAmount::ZERO);
+    };
+    // balance / (tenths / 10) dollars = balance × 1,000 / tenths cents,
+    // rounded half up
+    let tenths = i128::from(tenths);
+    let cents = (i128::from(balance) * 2_000 + tenths) / (2 * tenths);
+    i64::try_from(cents)
+        .map(UsdAmount::from_cents)
+        .map_err(|_| TaxError::IncomeOutOfRange)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables() {
+        assert_eq!(uniform_lifetime_period(72), Some(27.4));
+        assert_eq!(uniform_lifetime_period(71), None);
+        assert_eq!(uniform_lifetime_period(u8::MAX), Some(2.0));
+        assert_eq!(single_life_expectancy(0), 84.6);
+        assert_eq!(single_life_expectancy(u8::MAX), 1.0);
+        // Each table shortens with age and the Uniform Lifetime Table, which
+        // assumes a beneficiary 10 years younger, is always longer.
+        for age in 1..=120 {
+            assert!(single_life_expectancy(age) <= single_life_expectancy(age - 1));
+            if let Some(period) = uniform_lifetime_period(age) {
+                assert!(period > single_life_expectancy(age), "{age}");
+                if age > 72 {
+                    assert!(period < uniform_lifetime_period(age - 1).unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn distributions() {
+        let rmd = |age, balance| required_minimum_distribution(age, balance).unwrap();
+        assert_eq!(rmd(73, 265_000), UsdAmount::from_dollars(10_000));
+        // $100,000 / 26.5 = $3,773.584…
+        assert_eq!(rmd(73, 100_000), UsdAmount::from_cents(377_358));
+        assert_eq!(rmd(100, 64_000), UsdAmount::from_dollars(10_000));
+        assert_eq!(rmd(72, 0), UsdAmount::ZERO);
+        assert_eq!(rmd(50, 1_000_000), UsdAmount::ZERO);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            required_minimum_distribution(80, -1),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            required_minimum_distribution(80, u64::MAX),
+            Err(TaxError::IncomeOutOfRange)
+        );
+        assert_eq!(
+            required_minimum_distribution(120, i64::MAX),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! The retirement savings contributions credit (Form 8880).
+
+use crate::compute::income_to_i64;
+use crate::data;
+use crate::schedule::TaxBracket;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The contributions of each person that count toward the credit.
+const CONTRIBUTION_LIMIT: i64 = 2_000;
+
+/// The saver's credit rates of 50%, 20%, 10%, and 0% by adjusted gross
+/// income for `year` and `status`, in ascending order.
+///
+/// The tiers are inflation-adjusted each year. Heads of household get 3/4
+/// of the married filing jointly amounts and everyone else 1/2; qualifying
+/// surviving spouses use the single tiers.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{savers_credit_tiers, FilingStatus, TaxYear};
+///
+/// let tiers = savers_credit_tiers(TaxYear::Y2025, FilingStatus::MarriedFilingJointly);
+/// assert_eq!(tiers[0].income_max, Some(47_500));
+/// assert_eq!(tiers[0].rate, 0.50);
+/// assert_eq!(tiers[3].income_min, 79_000);
+/// assert_eq!(tiers[3].rate, 0.0);
+/// # }
+/// ```
+pub fn savers_credit_tiers(year: TaxYear, status: FilingStatus) -> &'static [TaxBracket] {
+    data::savers_credit_tiers(year, status)
+}
+
+/// Compute the saver's credit on Form 8880 for a return with adjusted gross
+/// income `agi`, rounded to whole dollars.
+///
+/// `contributions` has one amount for each person: the taxpayer and, on a
+/// joint return, the spouse. Each is the contributions to IRAs and
+/// workplace retirement plans less recent distributions (line 6), and only
+/// the first $2,000 of each counts. The total is multiplied by the rate of
+/// the [`savers_credit_tiers`] containing `agi`. The credit can only reduce
+/// the tax, which is left to the caller, as are the age, student, and
+/// dependent tests.
+///
+/// # Errors
+///
+/// Returns [`TaxError::IncomeOutOfRange`] if `agi` does not fit in an
+/// `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_savers_credit, FilingStatus, TaxYear};
+///
+/// let status = FilingStatus::MarriedFilingJointly;
+/// // 50% of $2,000 and $1,500
+/// let credit = compute_savers_credit(TaxYear::Y2025, status, 45_000, &[5_000, 1_500]);
+/// assert_eq!(credit, Ok(1_750));
+/// // 10% once income is over $51,000
+/// let credit = compute_savers_credit(TaxYear::Y2025, status, 60_000, &[5_000, 1_500]);
+/// assert_eq!(credit, Ok(350));
+/// # }
+/// ```
+pub fn compute_savers_credit(
+    year: TaxYear,
+    status: FilingStatus,
+    agi: impl TryInto<i64>,
+    contributions: &[i64],
+) -> Result<i64, TaxError> {
+    let agi = income_to_i64(agi)?.max(0);
+    let people = match status {
+        FilingStatus::MarriedFilingJointly => 2,
+        _ => 1,
+    };
+    let eligible: i64 = contributions
+        .iter()
+        .take(people)
+        .map(|&amount| amount.clamp(0, CONTRIBUTION_LIMIT))
+        .sum();
+    let percent = savers_credit_tiers(year, status)
+        .iter()
+        .find(|tier| tier.contains(agi))
+        .map_or(0, |tier| (tier.rate * 100.0).round() as i64);
+    Ok((eligible * percent + 50) / 100)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn tiers() {
+        let year = TaxYear::Y2024;
+        let single = FilingStatus::Single;
+        let credit = |agi| compute_savers_credit(year, single, agi, &[3_000]).unwrap();
+        assert_eq!(credit(-5_000), 1_000);
+        assert_eq!(credit(23_000), 1_000);
+        assert_eq!(credit(23_001), 400);
+        assert_eq!(credit(38_250), 200);
+        assert_eq!(credit(38_251), 0);
+
+        // Qualifying surviving spouses use the single tiers.
+        let qss = FilingStatus::QualifyingSurvivingSpouse;
+        assert_eq!(compute_savers_credit(year, qss, 30_000, &[2_000]), Ok(200));
+        assert_eq!(
+            savers_credit_tiers(year, qss),
+            savers_credit_tiers(year, FilingStatus::MarriedFilingSeparately)
+        );
+        let hoh = FilingStatus::HeadOfHousehold;
+        assert_eq!(
+            compute_savers_credit(TaxYear::Y2025, hoh, 36_000, &[2_000]),
+            Ok(400)
+        );
+    }
+
+    #[test]
+    fn head_of_household_and_other_tiers() {
+        for year in TaxYear::ALL {
+            let joint = savers_credit_tiers(year, FilingStatus::MarriedFilingJointly);
+            let hoh = savers_credit_tiers(year, FilingStatus::HeadOfHousehold);
+            let single = savers_credit_tiers(year, FilingStatus::Single);
+            for ((joint, hoh), single) in joint.iter().zip(hoh).zip(single) {
+                assert_eq!(hoh.income_min * 4, joint.income_min * 3, "{year:?}");
+                assert_eq!(single.income_min * 2, joint.income_min, "{year:?}");
+                assert_eq!((hoh.rate, single.rate), (joint.rate, joint.rate));
+            }
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn contribution_limits() {
+        let year = TaxYear::Y2024;
+        let joint = FilingStatus::MarriedFilingJointly;
+        assert_eq!(
+            compute_savers_credit(year, joint, 45_000, &[2_000, 1_000]),
+            Ok(1_500)
+        );
+        // Each spouse's contributions are limited separately.
+        assert_eq!(
+            compute_savers_credit(year, joint, 45_000, &[4_000]),
+            Ok(1_000)
+        );
+        assert_eq!(
+            compute_savers_credit(year, joint, 45_000, &[4_000, 4_000, 4_000]),
+            Ok(2_000)
+        );
+        // A single filer has no spouse's contributions.
+        let single = FilingStatus::Single;
+        assert_eq!(
+            compute_savers_credit(year, single, 20_000, &[2_000, 2_000]),
+            Ok(1_000)
+        );
+        assert_eq!(compute_savers_credit(year, single, 20_000, &[-500]), Ok(0));
+        assert_eq!(compute_savers_credit(year, single, 20_000, &[]), Ok(0));
+        // Rounded half up to the dollar
+        assert_eq!(
+            compute_savers_credit(year, single, 30_000, &[1_555]),
+            Ok(156)
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn errors() {
+        assert_eq!(
+            compute_savers_credit(TaxYear::Y2025, FilingStatus::Single, u64::MAX, &[2_000]),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
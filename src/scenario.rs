@@ -0,0 +1,224 @@
+//! Tax under alternative law (requires the `scenarios` feature).
+
+use crate::compute::{WORKSHEET_THRESHOLD, compute_tax, income_to_i64, lookup_tax_table};
+use crate::data;
+use crate::inflation::{Statute, projected_adjustment, projected_schedule, statutory_schedule};
+use crate::schedule::TaxSchedule;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+use crate::validate::midpoint_tax;
+
+/// The pre-2018 brackets, as Rev. Proc. 2017-58 adjusted them for 2018
+/// before the Tax Cuts and Jobs Act replaced them.
+const PRE_TCJA: Statute = Statute {
+    rates: [1_000, 1_500, 2_500, 2_800, 3_300, 3_500, 3_960],
+    thresholds: [
+        [9_525, 38_700, 93_700, 195_450, 424_950, 426_700],
+        [19_050, 77_400, 156_150, 237_950, 424_950, 480_050],
+        [9_525, 38_700, 78_075, 118_975, 212_475, 240_025],
+        [13_600, 51_850, 133_850, 216_700, 424_950, 453_350],
+    ],
+    rounding: [50, 50, 25, 50],
+};
+
+/// The law a tax is computed under.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum LawScenario {
+    /// The brackets in force: the embedded IRS data, and for later years the
+    /// same statute projected with [`projected_schedule`].
+    #[default]
+    CurrentLaw,
+    /// The pre-2018 rates of 10, 15, 25, 28, 33, 35, and 39.6% that the Tax
+    /// Cuts and Jobs Act scheduled to return in 2026, before the One Big
+    /// Beautiful Bill Act of 2025 made its brackets permanent.
+    ///
+    /// The thresholds are the pre-TCJA brackets for 2018 grown by the same
+    /// cost-of-living adjustment as current law, which approximates the
+    /// indexing the IRS would have applied.
+    TcjaSunset,
+}
+
+impl LawScenario {
+    /// Every scenario, in declaration order.
+    pub const ALL: [LawScenario; 2] = [LawScenario::CurrentLaw, LawScenario::TcjaSunset];
+
+    /// The Tax Rate Schedule for `year` and `status` under this scenario.
+    ///
+    /// Years after the embedded data are projected at `assumed_inflation`,
+    /// as [`projected_schedule`] does; it is ignored for embedded years.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`projected_schedule`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use us_tax_brackets::{FilingStatus, LawScenario};
+    ///
+    /// let schedule = LawScenario::TcjaSunset.schedule(2026, FilingStatus::Single, 0.025).unwrap();
+    /// assert_eq!(schedule.iter().last().unwrap().rate, 0.396);
+    /// ```
+    pub fn schedule(
+        self,
+        year: u16,
+        status: FilingStatus,
+        assumed_inflation: f64,
+    ) -> Result<TaxSchedule, TaxError> {
+        match self {
+            LawScenario::CurrentLaw => projected_schedule(year, status, assumed_inflation),
+            LawScenario::TcjaSunset => statutory_schedule(
+                &PRE_TCJA,
+                status,
+                projected_adjustment(year, assumed_inflation)?,
+            ),
+        }
+    }
+}
+
+/// Compute federal income tax for `year` under a [`LawScenario`].
+///
+/// Current law in an embedded year is exactly [`compute_tax`]. Otherwise the
+/// scenario's [`schedule`](LawScenario::schedule) is applied the way the IRS
+/// applies its own: below $100,000 at the midpoint of the Tax Table band
+/// containing the income, and above it exactly, rounded to whole dollars.
+/// Results for different scenarios are therefore directly comparable.
+///
+/// # Errors
+///
+/// Returns the same errors as [`compute_tax`] and
+/// [`LawScenario::schedule`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_tax_under_law, FilingStatus, LawScenario};
+///
+/// let tax = |law| {
+///     compute_tax_under_law(law, 2025, FilingStatus::MarriedFilingJointly, 250_000, 0.0)
+///         .unwrap()
+/// };
+/// assert_eq!(tax(LawScenario::CurrentLaw), 45_694);
+/// assert!(tax(LawScenario::TcjaSunset) > tax(LawScenario::CurrentLaw));
+/// # }
+/// ```
+pub fn compute_tax_under_law(
+    scenario: LawScenario,
+    year: u16,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+    assumed_inflation: f64,
+) -> Result<i64, TaxError> {
+    let taxable_income = income_to_i64(taxable_income)?;
+    if scenario == LawScenario::CurrentLaw
+        && let Ok(year) = TaxYear::try_from(year)
+    {
+        return compute_tax(year, status, taxable_income);
+    }
+    if taxable_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let schedule = scenario.schedule(year, status, assumed_inflation)?;
+    if taxable_income == 0 {
+        return Ok(0);
+    }
+
+    if taxable_income < WORKSHEET_THRESHOLD {
+        // The Tax Table's bands are the same every year.
+        let latest = TaxYear::ALL[TaxYear::ALL.len() - 1];
+        let row = lookup_tax_table(data::tax_table(latest), taxable_income).ok_or(
+            TaxError::NoBracketFound {
+                year: latest,
+                status,
+                taxable_income,
+            },
+        )?;
+        Ok(midpoint_tax(row.income_min, row.income_max, &schedule))
+    } else {
+        Ok(midpoint_tax(taxable_income, taxable_income, &schedule))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn current_law_matches_compute_tax() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for income in (0..600_000).step_by(7_919) {
+                    assert_eq!(
+                        compute_tax_under_law(
+                            LawScenario::CurrentLaw,
+                            year.into(),
+                            status,
+                            income,
+                            0.0
+                        ),
+                        compute_tax(year, status, income)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn projected_current_law_matches_table_method() {
+        // With no inflation, a projected year reproduces the latest year's
+        // Tax Table and worksheet.
+        let latest = TaxYear::ALL[TaxYear::ALL.len() - 1];
+        let next = u16::from(latest) + 1;
+        for status in FilingStatus::ALL {
+            for income in (0..400_000).step_by(3_217).chain([99_999, 100_000]) {
+                assert_eq!(
+                    compute_tax_under_law(LawScenario::CurrentLaw, next, status, income, 0.0),
+                    compute_tax(latest, status, income),
+                    "{status} {income}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sunset_brackets() {
+        // At a factor of 1, the 2018 pre-TCJA brackets come back unchanged.
+        let schedule = statutory_schedule(&PRE_TCJA, FilingStatus::HeadOfHousehold, 1.0).unwrap();
+        let rates: Vec<f64> = schedule.iter().map(|bracket| bracket.rate).collect();
+        assert_eq!(rates, [0.10, 0.15, 0.25, 0.28, 0.33, 0.35, 0.396]);
+        assert_eq!(schedule.thresholds().nth(1), Some(51_850));
+
+        for status in FilingStatus::ALL {
+            let sunset = LawScenario::TcjaSunset
+                .schedule(2027, status, 0.03)
+                .unwrap();
+            let current = LawScenario::CurrentLaw
+                .schedule(2027, status, 0.03)
+                .unwrap();
+            let tax = |schedule: &TaxSchedule| schedule.tax(300_000).unwrap();
+            assert!(tax(&sunset) > tax(&current), "{status}");
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let sunset = LawScenario::TcjaSunset;
+        assert_eq!(
+            compute_tax_under_law(sunset, 2025, FilingStatus::Single, -1, 0.0),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            compute_tax_under_law(sunset, 2017, FilingStatus::Single, 1, 0.0),
+            Err(TaxError::UnsupportedYear(2017))
+        );
+        assert_eq!(
+            compute_tax_under_law(sunset, 2025, FilingStatus::Single, 0, 0.0),
+            Ok(0)
+        );
+    }
+}
@@ -0,0 +1,847 @@
+//! Public view of the Tax Rate Schedule brackets.
+
+use crate::breakdown::BracketPortion;
+use crate::compute::income_to_i64;
+use crate::data;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// A single bracket of a Tax Rate Schedule.
+///
+/// Brackets follow the IRS "Over $X but not over $Y" wording: the lower
+/// bound is exclusive (except for the first bracket, which includes $0) and
+/// the upper bound is inclusive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaxBracket {
+    /// Lower bound of the bracket ("Over $X").
+    pub income_min: i64,
+    /// Upper bound of the bracket ("But not over $Y"), or [`None`] for the
+    /// highest (unbounded) bracket.
+    pub income_max: Option<i64>,
+    /// Marginal rate applied to income within the bracket (e.g., 0.22 for 22%).
+    pub rate: f64,
+}
+
+impl TaxBracket {
+    /// Return `true` if `taxable_income` falls within this bracket.
+    pub fn contains(&self, taxable_income: i64) -> bool {
+        let above_min =
+            taxable_income > self.income_min || (self.income_min == 0 && taxable_income == 0);
+        match self.income_max {
+            Some(max) => above_min && taxable_income <= max,
+            None => above_min,
+        }
+    }
+}
+
+/// The Tax Rate Schedule for one tax year and filing status, or a custom
+/// progressive schedule.
+///
+/// [`TaxSchedule::new`] loads the embedded `tax_rate_schedule.csv` data,
+/// which covers every income level from $0 up. Above $100,000 the thresholds
+/// and rates match the Tax Computation Worksheet. [`TaxSchedule::builder`]
+/// defines any other set of brackets, such as a proposed reform, and the
+/// [`breakdown`](Self::breakdown), [`tax`](Self::tax), and rate methods work
+/// the same on both.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{FilingStatus, TaxSchedule, TaxYear};
+///
+/// let schedule = TaxSchedule::new(TaxYear::Y2025, FilingStatus::Single).unwrap();
+/// let rates: Vec<f64> = schedule.iter().map(|bracket| bracket.rate).collect();
+/// assert_eq!(rates, [0.10, 0.12, 0.22, 0.24, 0.32, 0.35, 0.37]);
+///
+/// let thresholds: Vec<i64> = schedule.thresholds().collect();
+/// assert_eq!(thresholds[0], 11_925);
+/// # }
+/// ```
+///
+/// With the `serde` feature, a schedule serializes as its optional `year`
+/// and `status` and its list of `brackets`. Deserializing checks the
+/// brackets as [`TaxScheduleBuilder::build`] does, and also that each
+/// bracket ends where the next one starts and the last is unbounded.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ScheduleFile", into = "ScheduleFile")
+)]
+pub struct TaxSchedule {
+    year: Option<TaxYear>,
+    status: Option<FilingStatus>,
+    brackets: Vec<TaxBracket>,
+}
+
+impl TaxSchedule {
+    /// Load the embedded Tax Rate Schedule for the given year and status.
+    ///
+    /// # Errors
+    ///
+    /// Does not currently fail: the data is checked when the crate is built.
+    /// The [`Result`] is kept so callers need not change if a fallible data
+    /// source is added.
+    pub fn new(year: TaxYear, status: FilingStatus) -> Result<Self, TaxError> {
+        Ok(TaxSchedule {
+            year: Some(year),
+            status: Some(status),
+            brackets: data::rate_schedule(year, status).to_vec(),
+        })
+    }
+
+    /// Start defining a custom schedule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use us_tax_brackets::TaxSchedule;
+    ///
+    /// // A hypothetical three-bracket reform
+    /// let schedule = TaxSchedule::builder()
+    ///     .bracket(0, 0.10)
+    ///     .bracket(50_000, 0.20)
+    ///     .bracket(200_000, 0.30)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(schedule.tax(80_000).unwrap(), 5_000.0 + 6_000.0);
+    /// assert_eq!(schedule.marginal_rate(80_000).unwrap(), 0.20);
+    /// assert_eq!(schedule.breakdown(80_000).unwrap().len(), 2);
+    /// assert_eq!(schedule.source_year(), None);
+    /// ```
+    pub fn builder() -> TaxScheduleBuilder {
+        TaxScheduleBuilder::default()
+    }
+
+    /// Start a custom schedule from this one's brackets, to change a rate,
+    /// move a threshold, or add or remove a bracket.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "year-2025")] {
+    /// use us_tax_brackets::{FilingStatus, TaxSchedule, TaxYear};
+    ///
+    /// let current = TaxSchedule::new(TaxYear::Y2025, FilingStatus::Single).unwrap();
+    /// let reform = current
+    ///     .to_builder()
+    ///     .set_rate(626_350, 0.396)
+    ///     .move_threshold(11_925, 15_000)
+    ///     .insert_bracket(1_000_000, 0.45)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(reform.marginal_rate(2_000_000).unwrap(), 0.45);
+    /// assert_eq!(reform.marginal_rate(700_000).unwrap(), 0.396);
+    /// assert_eq!(reform.thresholds().next(), Some(15_000));
+    /// assert_eq!(reform.source_year(), None);
+    /// # }
+    /// ```
+    pub fn to_builder(&self) -> TaxScheduleBuilder {
+        TaxScheduleBuilder {
+            brackets: self
+                .brackets
+                .iter()
+                .map(|bracket| (bracket.income_min, bracket.rate))
+                .collect(),
+            unmatched: None,
+        }
+    }
+
+    /// The tax year this schedule applies to.
+    ///
+    /// A custom schedule has no year of its own and returns the latest
+    /// embedded year; use [`source_year`](Self::source_year) to tell the
+    /// two apart.
+    pub fn year(&self) -> TaxYear {
+        self.year.unwrap_or(TaxYear::ALL[TaxYear::ALL.len() - 1])
+    }
+
+    /// The filing status this schedule applies to.
+    ///
+    /// A custom schedule has no filing status of its own and returns
+    /// [`FilingStatus::Single`]; use [`source_status`](Self::source_status)
+    /// to tell the two apart.
+    pub fn status(&self) -> FilingStatus {
+        self.status.unwrap_or(FilingStatus::Single)
+    }
+
+    /// The tax year of the published schedule this one was loaded from, or
+    /// [`None`] for a custom schedule.
+    pub fn source_year(&self) -> Option<TaxYear> {
+        self.year
+    }
+
+    /// The filing status of the published schedule this one was loaded
+    /// from, or [`None`] for a custom schedule.
+    pub fn source_status(&self) -> Option<FilingStatus> {
+        self.status
+    }
+
+    /// The brackets in ascending order of income.
+    pub fn brackets(&self) -> &[TaxBracket] {
+        &self.brackets
+    }
+
+    /// Iterate over the brackets in ascending order of income.
+    pub fn iter(&self) -> std::slice::Iter<'_, TaxBracket> {
+        self.brackets.iter()
+    }
+
+    /// Iterate over the bracket thresholds — the income levels above which
+    /// the next, higher rate applies.
+    pub fn thresholds(&self) -> impl Iterator<Item = i64> + '_ {
+        self.brackets
+            .iter()
+            .filter_map(|bracket| bracket.income_max)
+    }
+
+    /// Return the bracket containing `taxable_income`, if any.
+    pub fn bracket_for(&self, taxable_income: i64) -> Option<&TaxBracket> {
+        self.brackets
+            .iter()
+            .find(|bracket| bracket.contains(taxable_income))
+    }
+
+    /// Split `taxable_income` across the brackets. See
+    /// [`compute_tax_breakdown`](crate::compute_tax_breakdown).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::NegativeIncome`] if `taxable_income` is negative,
+    /// and [`TaxError::IncomeOutOfRange`] if it does not fit in an `i64`.
+    pub fn breakdown(
+        &self,
+        taxable_income: impl TryInto<i64>,
+    ) -> Result<Vec<BracketPortion>, TaxError> {
+        let taxable_income = income_to_i64(taxable_income)?;
+        if taxable_income < 0 {
+            return Err(TaxError::NegativeIncome);
+        }
+
+        let mut portions = Vec::new();
+        let mut covered = 0;
+        for bracket in &self.brackets {
+            if taxable_income <= bracket.income_min {
+                break;
+            }
+            let top = match bracket.income_max {
+                Some(max) => taxable_income.min(max),
+                None => taxable_income,
+            };
+            let income = top - bracket.income_min;
+            portions.push(BracketPortion {
+                income_min: bracket.income_min,
+                income_max: bracket.income_max,
+                rate: bracket.rate,
+                income,
+                tax: income as f64 * bracket.rate,
+            });
+            covered = top;
+        }
+
+        if covered != taxable_income {
+            return Err(self.no_bracket(taxable_income));
+        }
+        Ok(portions)
+    }
+
+    /// The unrounded tax on `taxable_income`: the sum of the
+    /// [`breakdown`](Self::breakdown). See
+    /// [`compute_tax_exact`](crate::compute_tax_exact).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`breakdown`](Self::breakdown).
+    pub fn tax(&self, taxable_income: impl TryInto<i64>) -> Result<f64, TaxError> {
+        let portions = self.breakdown(taxable_income)?;
+        Ok(portions.iter().map(|portion| portion.tax).sum())
+    }
+
+    /// The rate of the bracket containing `taxable_income`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`breakdown`](Self::breakdown).
+    pub fn marginal_rate(&self, taxable_income: impl TryInto<i64>) -> Result<f64, TaxError> {
+        let taxable_income = income_to_i64(taxable_income)?;
+        if taxable_income < 0 {
+            return Err(TaxError::NegativeIncome);
+        }
+        self.bracket_for(taxable_income)
+            .map(|bracket| bracket.rate)
+            .ok_or_else(|| self.no_bracket(taxable_income))
+    }
+
+    /// [`tax`](Self::tax) divided by `taxable_income`, or `0.0` for zero
+    /// income.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`breakdown`](Self::breakdown).
+    pub fn effective_rate(&self, taxable_income: impl TryInto<i64>) -> Result<f64, TaxError> {
+        let taxable_income = income_to_i64(taxable_income)?;
+        let tax = self.tax(taxable_income)?;
+        if taxable_income == 0 {
+            return Ok(0.0);
+        }
+        Ok(tax / taxable_income as f64)
+    }
+
+    /// The error for an income no bracket covers, which only malformed
+    /// embedded data can cause.
+    fn no_bracket(&self, taxable_income: i64) -> TaxError {
+        match (self.year, self.status) {
+            (Some(year), Some(status)) => TaxError::NoBracketFound {
+                year,
+                status,
+                taxable_income,
+            },
+            _ => TaxError::InvalidSchedule(format!("no bracket covers {taxable_income}")),
+        }
+    }
+}
+
+/// Builds a custom [`TaxSchedule`]. Created by [`TaxSchedule::builder`], or
+/// by [`TaxSchedule::to_builder`] to modify an existing schedule.
+///
+/// Each [`bracket`](Self::bracket) call starts a new bracket at a threshold,
+/// and the bracket runs up to the next threshold. The top bracket is
+/// unbounded. Brackets are identified by the threshold they start at.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaxScheduleBuilder {
+    brackets: Vec<(i64, f64)>,
+    /// The first threshold a modification named that no bracket starts at.
+    unmatched: Option<i64>,
+}
+
+impl TaxScheduleBuilder {
+    /// Tax income over `threshold` at `rate` (e.g., 0.22 for 22%), up to
+    /// the next bracket's threshold.
+    ///
+    /// The first bracket must start at $0.
+    pub fn bracket(mut self, threshold: i64, rate: f64) -> Self {
+        self.brackets.push((threshold, rate));
+        self
+    }
+
+    /// Add a bracket starting at `threshold`, in order among the existing
+    /// ones. The bracket it splits keeps its rate below `threshold`.
+    pub fn insert_bracket(mut self, threshold: i64, rate: f64) -> Self {
+        let index = self
+            .brackets
+            .partition_point(|&(start, _)| start <= threshold);
+        self.brackets.insert(index, (threshold, rate));
+        self
+    }
+
+    /// Change the rate of the bracket starting at `threshold`.
+    pub fn set_rate(mut self, threshold: i64, rate: f64) -> Self {
+        if let Some(index) = self.position(threshold) {
+            self.brackets[index].1 = rate;
+        }
+        self
+    }
+
+    /// Move the threshold a bracket starts at from `from` to `to`, which
+    /// also moves the end of the bracket below it.
+    pub fn move_threshold(mut self, from: i64, to: i64) -> Self {
+        if let Some(index) = self.position(from) {
+            self.brackets[index].0 = to;
+        }
+        self
+    }
+
+    /// Remove the bracket starting at `threshold`; the bracket below it
+    /// extends to cover its income.
+    pub fn remove_bracket(mut self, threshold: i64) -> Self {
+        if let Some(index) = self.position(threshold) {
+            self.brackets.remove(index);
+        }
+        self
+    }
+
+    /// The index of the bracket starting at `threshold`, recording the
+    /// threshold for [`build`](Self::build) to report if there is none.
+    fn position(&mut self, threshold: i64) -> Option<usize> {
+        let index = self
+            .brackets
+            .iter()
+            .position(|&(start, _)| start == threshold);
+        if index.is_none() {
+            self.unmatched.get_or_insert(threshold);
+        }
+        index
+    }
+
+    /// Check the brackets and build the schedule.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::InvalidSchedule`] if there are no brackets, the
+    /// first does not start at $0, the thresholds are not strictly
+    /// increasing, a rate is not between 0 and 1, or a modification named a
+    /// threshold no bracket starts at.
+    pub fn build(self) -> Result<TaxSchedule, TaxError> {
+        let invalid = |message: String| Err(TaxError::InvalidSchedule(message));
+        if let Some(threshold) = self.unmatched {
+            return invalid(format!("no bracket starts at {threshold}"));
+        }
+        match self.brackets.first() {
+            None => return invalid("a schedule needs at least one bracket".to_string()),
+            Some(&(threshold, _)) if threshold != 0 => {
+                return invalid(format!("the first bracket starts at {threshold}, not 0"));
+            }
+            Some(_) => {}
+        }
+        for pair in self.brackets.windows(2) {
+            if pair[1].0 <= pair[0].0 {
+                return invalid(format!(
+                    "threshold {} does not follow {}",
+                    pair[1].0, pair[0].0
+                ));
+            }
+        }
+        if let Some(&(_, rate)) = self
+            .brackets
+            .iter()
+            .find(|(_, rate)| !(0.0..=1.0).contains(rate))
+        {
+            return invalid(format!("rate {rate} is not between 0 and 1"));
+        }
+
+        let brackets = self
+            .brackets
+            .iter()
+            .enumerate()
+            .map(|(index, &(income_min, rate))| TaxBracket {
+                income_min,
+                income_max: self.brackets.get(index + 1).map(|&(next, _)| next),
+                rate,
+            })
+            .collect();
+        Ok(TaxSchedule {
+            year: None,
+            status: None,
+            brackets,
+        })
+    }
+}
+
+/// The serialized form of [`TaxSchedule`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ScheduleFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    year: Option<TaxYear>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    status: Option<FilingStatus>,
+    brackets: Vec<TaxBracket>,
+}
+
+#[cfg(feature = "serde")]
+impl From<TaxSchedule> for ScheduleFile {
+    fn from(schedule: TaxSchedule) -> Self {
+        ScheduleFile {
+            year: schedule.year,
+            status: schedule.status,
+            brackets: schedule.brackets,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ScheduleFile> for TaxSchedule {
+    type Error = TaxError;
+
+    fn try_from(file: ScheduleFile) -> Result<Self, TaxError> {
+        for pair in file.brackets.windows(2) {
+            if pair[0].income_max != Some(pair[1].income_min) {
+                return Err(TaxError::InvalidSchedule(format!(
+                    "the bracket starting at {} does not end where the next one starts",
+                    pair[0].income_min
+                )));
+            }
+        }
+        if let Some(TaxBracket {
+            income_max: Some(max),
+            ..
+        }) = file.brackets.last()
+        {
+            return Err(TaxError::InvalidSchedule(format!(
+                "the top bracket ends at {max} instead of being unbounded"
+            )));
+        }
+
+        let builder = file
+            .brackets
+            .iter()
+            .fold(TaxSchedule::builder(), |builder, bracket| {
+                builder.bracket(bracket.income_min, bracket.rate)
+            });
+        Ok(TaxSchedule {
+            year: file.year,
+            status: file.status,
+            ..builder.build()?
+        })
+    }
+}
+
+impl<'a> IntoIterator for &'a TaxSchedule {
+    type Item = &'a TaxBracket;
+    type IntoIter = std::slice::Iter<'a, TaxBracket>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Return the Tax Rate Schedule bracket containing `taxable_income` for a
+/// given tax year and filing status.
+///
+/// The returned [`TaxBracket`] gives the bracket's bounds and rate, so callers
+/// can report which bracket a taxpayer is in and how far away the next one is.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `taxable_income` is negative.
+/// Returns [`TaxError::IncomeOutOfRange`] if `taxable_income` does not fit in
+/// an `i64`.
+/// Returns [`TaxError::NoBracketFound`] if no matching bracket exists (should
+/// not occur with valid embedded data).
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{bracket_for_income, FilingStatus, TaxYear};
+///
+/// let bracket = bracket_for_income(TaxYear::Y2024, FilingStatus::Single, 60_000).unwrap();
+/// assert_eq!(bracket.income_min, 47_150);
+/// assert_eq!(bracket.income_max, Some(100_525));
+/// assert_eq!(bracket.rate, 0.22);
+/// # }
+/// ```
+pub fn bracket_for_income(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+) -> Result<TaxBracket, TaxError> {
+    let taxable_income = income_to_i64(taxable_income)?;
+    if taxable_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    TaxSchedule::new(year, status)?
+        .bracket_for(taxable_income)
+        .copied()
+        .ok_or(TaxError::NoBracketFound {
+            year,
+            status,
+            taxable_income,
+        })
+}
+
+/// Return how many additional dollars of taxable income can be earned before
+/// the marginal rate increases.
+///
+/// This is the distance from `taxable_income` to the upper bound of its
+/// bracket. An income exactly at a threshold has zero headroom: the next
+/// dollar is taxed at the higher rate. Returns [`None`] in the top bracket,
+/// where no higher rate exists.
+///
+/// # Errors
+///
+/// Returns the same errors as [`bracket_for_income`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{headroom_in_current_bracket, FilingStatus, TaxYear};
+///
+/// // 2025 MFJ: the 12% bracket ends at $96,950
+/// let room = headroom_in_current_bracket(TaxYear::Y2025, FilingStatus::MarriedFilingJointly, 80_000);
+/// assert_eq!(room, Ok(Some(16_950)));
+///
+/// // No higher rate above the 37% bracket
+/// let room = headroom_in_current_bracket(TaxYear::Y2025, FilingStatus::Single, 1_000_000);
+/// assert_eq!(room, Ok(None));
+/// # }
+/// ```
+pub fn headroom_in_current_bracket(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+) -> Result<Option<i64>, TaxError> {
+    let taxable_income = income_to_i64(taxable_income)?;
+    let bracket = bracket_for_income(year, status, taxable_income)?;
+    Ok(bracket.income_max.map(|max| max - taxable_income))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn brackets_are_contiguous() {
+        for year in TaxYear::ALL {
+            let schedule = TaxSchedule::new(year, FilingStatus::HeadOfHousehold).unwrap();
+            assert_eq!(schedule.brackets().len(), 7);
+            assert_eq!(schedule.brackets()[0].income_min, 0);
+            for pair in schedule.brackets().windows(2) {
+                assert_eq!(pair[0].income_max, Some(pair[1].income_min));
+            }
+            assert_eq!(schedule.brackets().last().unwrap().income_max, None);
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn qualifying_surviving_spouse_uses_mfj_schedule() {
+        let mfj = TaxSchedule::new(TaxYear::Y2024, FilingStatus::MarriedFilingJointly).unwrap();
+        let qss =
+            TaxSchedule::new(TaxYear::Y2024, FilingStatus::QualifyingSurvivingSpouse).unwrap();
+        assert_eq!(mfj.brackets(), qss.brackets());
+        assert_eq!(qss.status(), FilingStatus::QualifyingSurvivingSpouse);
+    }
+
+    #[cfg(feature = "year-2023")]
+    #[test]
+    fn thresholds_match_worksheet() {
+        // 2023 MFS worksheet brackets start at $182,100, $231,250, $346,875
+        let schedule =
+            TaxSchedule::new(TaxYear::Y2023, FilingStatus::MarriedFilingSeparately).unwrap();
+        let thresholds: Vec<i64> = schedule.thresholds().collect();
+        assert_eq!(
+            thresholds,
+            [11_000, 44_725, 95_375, 182_100, 231_250, 346_875]
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn bracket_for_threshold() {
+        let schedule = TaxSchedule::new(TaxYear::Y2025, FilingStatus::Single).unwrap();
+        assert_eq!(schedule.bracket_for(0).unwrap().rate, 0.10);
+        assert_eq!(schedule.bracket_for(11_925).unwrap().rate, 0.10);
+        assert_eq!(schedule.bracket_for(11_926).unwrap().rate, 0.12);
+        assert!(schedule.bracket_for(-1).is_none());
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn bracket_for_income_negative() {
+        assert_eq!(
+            bracket_for_income(TaxYear::Y2025, FilingStatus::Single, -5),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[cfg(feature = "year-2023")]
+    #[test]
+    fn bracket_for_income_top_bracket_is_unbounded() {
+        let bracket = bracket_for_income(
+            TaxYear::Y2023,
+            FilingStatus::MarriedFilingJointly,
+            1_000_000,
+        )
+        .unwrap();
+        assert_eq!(bracket.income_min, 693_750);
+        assert_eq!(bracket.income_max, None);
+        assert_eq!(bracket.rate, 0.37);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn headroom_at_threshold_is_zero() {
+        assert_eq!(
+            headroom_in_current_bracket(TaxYear::Y2024, FilingStatus::HeadOfHousehold, 16_550),
+            Ok(Some(0))
+        );
+        // One dollar later, the whole 12% bracket lies ahead
+        assert_eq!(
+            headroom_in_current_bracket(TaxYear::Y2024, FilingStatus::HeadOfHousehold, 16_551),
+            Ok(Some(63_100 - 16_551))
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn embedded_schedule_methods_match_free_functions() {
+        let year = TaxYear::Y2025;
+        let status = FilingStatus::HeadOfHousehold;
+        let schedule = TaxSchedule::new(year, status).unwrap();
+        for income in [0, 17_000, 80_000, 400_000, 2_000_000] {
+            assert_eq!(
+                schedule.tax(income),
+                crate::compute_tax_exact(year, status, income)
+            );
+            assert_eq!(
+                schedule.marginal_rate(income),
+                crate::marginal_rate(year, status, income)
+            );
+        }
+        assert_eq!(schedule.year(), year);
+        assert_eq!(schedule.source_year(), Some(year));
+    }
+
+    #[test]
+    fn custom_schedule() {
+        let flat = TaxSchedule::builder().bracket(0, 0.15).build().unwrap();
+        assert_eq!(flat.brackets().len(), 1);
+        assert_eq!(flat.brackets()[0].income_max, None);
+        assert_eq!(flat.tax(1_000_000).unwrap(), 150_000.0);
+        assert_eq!(flat.effective_rate(1_000_000).unwrap(), 0.15);
+        assert_eq!(flat.effective_rate(0).unwrap(), 0.0);
+        assert_eq!(flat.marginal_rate(-1), Err(TaxError::NegativeIncome));
+        assert_eq!((flat.source_year(), flat.source_status()), (None, None));
+        assert_eq!(flat.status(), FilingStatus::Single);
+
+        let schedule = TaxSchedule::builder()
+            .bracket(0, 0.0)
+            .bracket(10_000, 0.25)
+            .build()
+            .unwrap();
+        assert_eq!(schedule.thresholds().collect::<Vec<_>>(), [10_000]);
+        assert_eq!(schedule.marginal_rate(10_000).unwrap(), 0.0);
+        assert_eq!(schedule.marginal_rate(10_001).unwrap(), 0.25);
+        let portions = schedule.breakdown(30_000).unwrap();
+        assert_eq!(portions[1].income, 20_000);
+        assert_eq!(portions[1].tax, 5_000.0);
+    }
+
+    #[test]
+    fn invalid_custom_schedules() {
+        let error = |builder: TaxScheduleBuilder| match builder.build() {
+            Err(TaxError::InvalidSchedule(message)) => message,
+            other => panic!("expected InvalidSchedule, got {other:?}"),
+        };
+        assert_eq!(
+            error(TaxSchedule::builder()),
+            "a schedule needs at least one bracket"
+        );
+        assert_eq!(
+            error(TaxSchedule::builder().bracket(100, 0.1)),
+            "the first bracket starts at 100, not 0"
+        );
+        assert_eq!(
+            error(TaxSchedule::builder().bracket(0, 0.1).bracket(0, 0.2)),
+            "threshold 0 does not follow 0"
+        );
+        assert_eq!(
+            error(TaxSchedule::builder().bracket(0, 1.5)),
+            "rate 1.5 is not between 0 and 1"
+        );
+        assert!(matches!(
+            TaxSchedule::builder().bracket(0, f64::NAN).build(),
+            Err(TaxError::InvalidSchedule(_))
+        ));
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn modified_schedules() {
+        let base = TaxSchedule::builder()
+            .bracket(0, 0.1)
+            .bracket(50_000, 0.2)
+            .bracket(200_000, 0.3)
+            .build()
+            .unwrap();
+        assert_eq!(base.to_builder().build(), Ok(base.clone()));
+        let irs = TaxSchedule::new(TaxYear::Y2024, FilingStatus::Single).unwrap();
+        assert_eq!(irs.to_builder().build().unwrap().brackets(), irs.brackets());
+
+        let reform = base
+            .to_builder()
+            .insert_bracket(100_000, 0.25)
+            .remove_bracket(200_000)
+            .move_threshold(50_000, 40_000)
+            .set_rate(0, 0.05)
+            .build()
+            .unwrap();
+        let brackets: Vec<_> = reform
+            .iter()
+            .map(|bracket| (bracket.income_min, bracket.income_max, bracket.rate))
+            .collect();
+        assert_eq!(
+            brackets,
+            [
+                (0, Some(40_000), 0.05),
+                (40_000, Some(100_000), 0.2),
+                (100_000, None, 0.25)
+            ]
+        );
+
+        let error = |builder: TaxScheduleBuilder| builder.build().unwrap_err().to_string();
+        assert_eq!(
+            error(base.to_builder().set_rate(60_000, 0.2).remove_bracket(1)),
+            "invalid tax schedule: no bracket starts at 60000"
+        );
+        assert_eq!(
+            error(base.to_builder().move_threshold(50_000, 250_000)),
+            "invalid tax schedule: threshold 200000 does not follow 250000"
+        );
+    }
+
+    #[cfg(all(feature = "year-2025", feature = "serde"))]
+    #[test]
+    fn serde_round_trip() {
+        let schedule = TaxSchedule::new(TaxYear::Y2025, FilingStatus::Single).unwrap();
+        let json = serde_json::to_string(&schedule).unwrap();
+        assert!(json.starts_with(r#"{"year":2025,"status":"single","brackets":[{"#));
+        assert_eq!(
+            serde_json::from_str::<TaxSchedule>(&json).unwrap(),
+            schedule
+        );
+
+        let custom = TaxSchedule::builder()
+            .bracket(0, 0.1)
+            .bracket(50_000, 0.2)
+            .build()
+            .unwrap();
+        let toml = toml::to_string(&custom).unwrap();
+        assert!(!toml.contains("year"), "{toml}");
+        assert_eq!(toml::from_str::<TaxSchedule>(&toml).unwrap(), custom);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_checks_brackets() {
+        let error = |json: &str| {
+            serde_json::from_str::<TaxSchedule>(json)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error(
+                r#"{"brackets":[
+                    {"income_min":0,"income_max":100,"rate":0.1},
+                    {"income_min":200,"income_max":null,"rate":0.2}]}"#
+            ),
+            "invalid tax schedule: the bracket starting at 0 does not end where the next one starts"
+        );
+        assert_eq!(
+            error(r#"{"brackets":[{"income_min":0,"income_max":100,"rate":0.1}]}"#),
+            "invalid tax schedule: the top bracket ends at 100 instead of being unbounded"
+        );
+        assert_eq!(
+            error(r#"{"brackets":[{"income_min":0,"rate":2.0}]}"#),
+            "invalid tax schedule: rate 2 is not between 0 and 1"
+        );
+        assert_eq!(
+            error(r#"{"brackets":[]}"#),
+            "invalid tax schedule: a schedule needs at least one bracket"
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn headroom_negative_income() {
+        assert_eq!(
+            headroom_in_current_bracket(TaxYear::Y2024, FilingStatus::Single, -1),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+}
@@ -0,0 +1,116 @@
+//! The taxable part of Social Security benefits (Publication 915).
+
+use crate::tax_return::percent_of;
+use crate::types::{FilingStatus, TaxError};
+
+/// The first and second base amounts of provisional income, set by statute
+/// and never adjusted for inflation.
+const fn base_amounts(status: FilingStatus) -> (i64, i64) {
+    match status {
+        FilingStatus::MarriedFilingJointly => (32_000, 44_000),
+        FilingStatus::MarriedFilingSeparately => (0, 0),
+        FilingStatus::Single
+        | FilingStatus::HeadOfHousehold
+        | FilingStatus::QualifyingSurvivingSpouse => (25_000, 34_000),
+    }
+}
+
+/// Compute how much of `benefits`, the year's Social Security and tier 1
+/// railroad retirement benefits, is taxable, with the Social Security
+/// Benefits Worksheet of the Form 1040 instructions.
+///
+/// `other_income` is modified adjusted gross income without the benefits:
+/// every other item of gross income less the adjustments, plus tax-exempt
+/// interest. Nothing is taxable until this plus half the benefits, the
+/// provisional income, passes $25,000 ($32,000 married filing jointly); up to
+/// half is taxable until it passes $34,000 ($44,000), and up to 85% beyond.
+/// The amounts are the same every year. Married people filing separately
+/// who lived with their spouse during the year have base amounts of zero,
+/// so 85% of the benefits is taxable from the first dollar; those who did
+/// not can use [`FilingStatus::Single`].
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `benefits` is negative. A negative
+/// `other_income`, from adjustments larger than the other income, lowers the
+/// provisional income, which like the worksheet's line 8 is floored at zero.
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::{taxable_social_security, FilingStatus};
+///
+/// // Provisional income of $32,000 is $7,000 over the base amount.
+/// assert_eq!(taxable_social_security(FilingStatus::Single, 24_000, 20_000), Ok(3_500));
+/// // Far enough over that the 85% limit applies
+/// let status = FilingStatus::MarriedFilingJointly;
+/// assert_eq!(taxable_social_security(status, 40_000, 60_000), Ok(34_000));
+/// ```
+pub fn taxable_social_security(
+    status: FilingStatus,
+    benefits: i64,
+    other_income: i64,
+) -> Result<i64, TaxError> {
+    if benefits < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    let half_of_benefits = percent_of(benefits, 500);
+    let provisional_income = other_income.saturating_add(half_of_benefits).max(0);
+    let (first_base, second_base) = base_amounts(status);
+    if provisional_income <= first_base {
+        return Ok(0);
+    }
+    let excess = provisional_income - first_base;
+    let second_tier = second_base - first_base;
+    let taxed_at_half = percent_of(excess.min(second_tier), 500).min(half_of_benefits);
+    let taxed_at_85 = percent_of(excess.saturating_sub(second_tier).max(0), 850);
+    Ok(taxed_at_half
+        .saturating_add(taxed_at_85)
+        .min(percent_of(benefits, 850)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tiers() {
+        let single =
+            |benefits, other| taxable_social_security(FilingStatus::Single, benefits, other);
+        assert_eq!(single(30_000, 0), Ok(0));
+        assert_eq!(single(30_000, 10_000), Ok(0));
+        // Half of the $5,000 over the first base amount
+        assert_eq!(single(30_000, 15_000), Ok(2_500));
+        // Half of the $9,000 between the base amounts, plus 85% of $1,000
+        assert_eq!(single(30_000, 20_000), Ok(4_500 + 850));
+        // Never more than 85% of the benefits
+        assert_eq!(single(30_000, 1_000_000), Ok(25_500));
+        assert_eq!(single(0, 100_000), Ok(0));
+
+        let separately = |benefits, other| {
+            taxable_social_security(FilingStatus::MarriedFilingSeparately, benefits, other)
+        };
+        assert_eq!(separately(20_000, 0), Ok(8_500));
+        assert_eq!(separately(20_000, 100_000), Ok(17_000));
+    }
+
+    #[test]
+    fn half_of_benefits_limit() {
+        // Provisional income of $40,000 is $8,000 over the joint base
+        // amount, but only half of the $6,000 of benefits can be taxed in
+        // the first tier.
+        let status = FilingStatus::MarriedFilingJointly;
+        assert_eq!(taxable_social_security(status, 6_000, 37_000), Ok(3_000));
+        assert_eq!(
+            taxable_social_security(status, -1, 0),
+            Err(TaxError::NegativeIncome)
+        );
+        // A net loss in other income lowers the provisional income, which
+        // stops at zero.
+        assert_eq!(taxable_social_security(status, 6_000, -50_000), Ok(0));
+        assert_eq!(
+            taxable_social_security(FilingStatus::MarriedFilingSeparately, 10_000, -1_000),
+            Ok(3_400)
+        );
+    }
+}
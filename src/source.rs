@@ -0,0 +1,265 @@
+//! Pluggable sources of Tax Table rows and worksheet brackets.
+
+use std::borrow::Cow;
+
+use crate::compute::{
+    WORKSHEET_THRESHOLD, income_to_i64, search_tax_table, search_worksheet, worksheet_tax,
+};
+use crate::data::{self, TaxTableRow, WorksheetBracket};
+use crate::types::{FilingStatus, Method, TaxError, TaxYear};
+
+/// Where the tax computation gets its Tax Table rows and Tax Computation
+/// Worksheet brackets.
+///
+/// [`compute_tax_from`] and [`compute_tax_with_method_from`] run the same
+/// math as [`compute_tax`](crate::compute_tax) over any source, so data can
+/// come from a database, an HTTP service, or a test fixture. The crate's
+/// embedded data is [`EmbeddedData`], and a [`TaxData`](crate::TaxData)
+/// loaded at run time is also a source.
+///
+/// Returning [`Cow::Borrowed`] avoids copying data the source already holds;
+/// a source that builds rows on each call can return [`Cow::Owned`].
+///
+/// # Requirements
+///
+/// The Tax Table must be sorted by income, with each row starting where the
+/// previous one ends, and cover $0 up to $100,000. The worksheet brackets
+/// must be in ascending order and cover $100,000 and up. An income that no
+/// row or bracket covers gives [`TaxError::NoBracketFound`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use std::borrow::Cow;
+/// use us_tax_brackets::{
+///     BracketSource, FilingStatus, TaxError, TaxTableRow, TaxYear, WorksheetBracket,
+///     compute_tax_from,
+/// };
+///
+/// /// A flat 10% tax, for tests.
+/// struct Flat;
+///
+/// impl BracketSource for Flat {
+///     fn tax_table(&self, _year: TaxYear) -> Result<Cow<'_, [TaxTableRow]>, TaxError> {
+///         let row = |min: i64| {
+///             let tax = (min + 25) / 10;
+///             TaxTableRow {
+///                 income_min: min,
+///                 income_max: min + 50,
+///                 single: tax,
+///                 married_filing_jointly: tax,
+///                 married_filing_separately: tax,
+///                 head_of_household: tax,
+///             }
+///         };
+///         Ok((0..100_000).step_by(50).map(row).collect())
+///     }
+///
+///     fn worksheet(
+///         &self,
+///         _year: TaxYear,
+///         _status: FilingStatus,
+///     ) -> Result<Cow<'_, [WorksheetBracket]>, TaxError> {
+///         Ok(Cow::Owned(vec![WorksheetBracket::new(100_000, None, 1_000, 0)]))
+///     }
+/// }
+///
+/// assert_eq!(compute_tax_from(&Flat, TaxYear::Y2025, FilingStatus::Single, 75_010), Ok(7_502));
+/// assert_eq!(compute_tax_from(&Flat, TaxYear::Y2025, FilingStatus::Single, 250_000), Ok(25_000));
+/// # }
+/// ```
+pub trait BracketSource {
+    /// The Tax Table rows for `year`, sorted by income.
+    ///
+    /// # Errors
+    ///
+    /// Implementations return an error, such as
+    /// [`TaxError::UnsupportedYear`], when they have no data for `year`.
+    fn tax_table(&self, year: TaxYear) -> Result<Cow<'_, [TaxTableRow]>, TaxError>;
+
+    /// The Tax Computation Worksheet brackets for `year` and `status`, in
+    /// ascending order.
+    ///
+    /// # Errors
+    ///
+    /// Implementations return an error, such as
+    /// [`TaxError::UnsupportedYear`], when they have no data for `year`.
+    fn worksheet(
+        &self,
+        year: TaxYear,
+        status: FilingStatus,
+    ) -> Result<Cow<'_, [WorksheetBracket]>, TaxError>;
+
+    /// Why the Tax Table row or worksheet bracket this source gives for
+    /// `taxable_income` differs from the IRS publication, if it does.
+    ///
+    /// [`compute_tax_detailed_from`](crate::compute_tax_detailed_from)
+    /// records the answer in the computation trace. Sources that correct the
+    /// published data, such as [`DataOverrides`](crate::DataOverrides), say
+    /// so here; the default reports no correction.
+    fn correction(
+        &self,
+        year: TaxYear,
+        status: FilingStatus,
+        taxable_income: i64,
+    ) -> Option<String> {
+        let _ = (year, status, taxable_income);
+        None
+    }
+}
+
+/// The IRS data embedded in the crate at build time.
+///
+/// This is the source the crate's free functions use.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EmbeddedData;
+
+impl BracketSource for EmbeddedData {
+    fn tax_table(&self, year: TaxYear) -> Result<Cow<'_, [TaxTableRow]>, TaxError> {
+        Ok(Cow::Borrowed(data::tax_table(year)))
+    }
+
+    fn worksheet(
+        &self,
+        year: TaxYear,
+        status: FilingStatus,
+    ) -> Result<Cow<'_, [WorksheetBracket]>, TaxError> {
+        Ok(Cow::Borrowed(data::worksheet(year, status)))
+    }
+}
+
+/// Compute federal income tax with data from `source`.
+///
+/// See [`compute_tax`](crate::compute_tax) for the method selection and
+/// rounding, which are the same for every source.
+///
+/// # Errors
+///
+/// Returns the same errors as [`compute_tax`](crate::compute_tax), and any
+/// error from `source`.
+pub fn compute_tax_from<S: BracketSource + ?Sized>(
+    source: &S,
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+) -> Result<i64, TaxError> {
+    compute_tax_with_method_from(source, year, status, taxable_income).map(|(tax, _)| tax)
+}
+
+/// Compute federal income tax with data from `source`, and report which
+/// [`Method`] was used.
+///
+/// # Errors
+///
+/// Returns the same errors as [`compute_tax_from`].
+pub fn compute_tax_with_method_from<S: BracketSource + ?Sized>(
+    source: &S,
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+) -> Result<(i64, Method), TaxError> {
+    let taxable_income = income_to_i64(taxable_income)?;
+    if taxable_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    if taxable_income == 0 {
+        return Ok((0, Method::TaxTable));
+    }
+
+    if taxable_income < WORKSHEET_THRESHOLD {
+        let table = source.tax_table(year)?;
+        let row = search_tax_table(&table, year, status, taxable_income)?;
+        Ok((row.tax_for(status), Method::TaxTable))
+    } else {
+        let brackets = source.worksheet(year, status)?;
+        let bracket = search_worksheet(&brackets, year, status, taxable_income)?;
+        Ok((worksheet_tax(&bracket, taxable_income)?, Method::Worksheet))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::compute_tax_const;
+    use crate::tax_data::TaxData;
+
+    /// A source with only a few Tax Table rows and no worksheet.
+    struct Sparse;
+
+    #[cfg(feature = "year-2025")]
+    impl BracketSource for Sparse {
+        fn tax_table(&self, year: TaxYear) -> Result<Cow<'_, [TaxTableRow]>, TaxError> {
+            if year != TaxYear::Y2025 {
+                return Err(TaxError::UnsupportedYear(year.into()));
+            }
+            Ok(Cow::Borrowed(&data::tax_table(year)[..10]))
+        }
+
+        fn worksheet(
+            &self,
+            _year: TaxYear,
+            _status: FilingStatus,
+        ) -> Result<Cow<'_, [WorksheetBracket]>, TaxError> {
+            Ok(Cow::Owned(Vec::new()))
+        }
+    }
+
+    #[test]
+    fn embedded_matches_const() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for income in (0..800_000).step_by(1_234).chain([99_999, 100_000]) {
+                    assert_eq!(
+                        compute_tax_from(&EmbeddedData, year, status, income).ok(),
+                        compute_tax_const(year, status, income)
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn tax_data_is_a_source() {
+        let dir = format!("{}/data/2024", env!("CARGO_MANIFEST_DIR"));
+        let data = TaxData::load_from_dir(dir).unwrap();
+        let source: &dyn BracketSource = &data;
+        assert_eq!(
+            compute_tax_from(
+                source,
+                TaxYear::Y2024,
+                FilingStatus::MarriedFilingJointly,
+                150_000
+            ),
+            Ok(23_106)
+        );
+    }
+
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    #[test]
+    fn source_errors_and_gaps() {
+        let status = FilingStatus::Single;
+        assert_eq!(
+            compute_tax_from(&Sparse, TaxYear::Y2025, status, 10),
+            compute_tax_from(&EmbeddedData, TaxYear::Y2025, status, 10)
+        );
+        assert_eq!(
+            compute_tax_from(&Sparse, TaxYear::Y2024, status, 200),
+            Err(TaxError::UnsupportedYear(2024))
+        );
+        assert_eq!(
+            compute_tax_from(&Sparse, TaxYear::Y2025, status, 50_000),
+            Err(TaxError::NoBracketFound {
+                year: TaxYear::Y2025,
+                status,
+                taxable_income: 50_000,
+            })
+        );
+        assert!(compute_tax_from(&Sparse, TaxYear::Y2025, status, 100_000).is_err());
+        assert_eq!(
+            compute_tax_from(&Sparse, TaxYear::Y2025, status, -1),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+}
@@ -0,0 +1,639 @@
+//! Tax data loaded from CSV files at run time.
+
+use std::borrow::Cow;
+use std::fs;
+use std::path::Path;
+
+use crate::compute::{
+    WORKSHEET_THRESHOLD, income_to_i64, lookup_tax_table, lookup_worksheet, worksheet_tax,
+};
+use crate::data::{self, CsvFile, TaxTableRow, WorksheetBracket};
+use crate::schedule::TaxBracket;
+use crate::source::BracketSource;
+use crate::types::{FilingStatus, Method, TaxError, TaxYear};
+use crate::validate::{CSV_STATUSES, DataIssue, validate_files};
+
+/// File names of the Tax Table, Tax Computation Worksheet, and Tax Rate
+/// Schedules in a `data/<year>/` directory.
+const FILE_NAMES: [&str; 3] = [
+    "tax_table.csv",
+    "tax_computation_worksheet.csv",
+    "tax_rate_schedule.csv",
+];
+
+/// Every lookup succeeds once [`TaxData::from_files`] has checked coverage.
+const COVERED: &str = "TaxData coverage is checked when it is loaded";
+
+/// One year of tax data read at run time instead of embedded in the crate.
+///
+/// The files use the same format as the repository's `data/<year>/`
+/// directories, so corrected or newly published IRS data can be dropped in
+/// without waiting for a crate release. The data goes through the same
+/// checks as [`validate_data`](crate::validate_data) when it is loaded, and
+/// the methods then compute exactly as the free functions do for embedded
+/// years.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{compute_tax, FilingStatus, TaxData, TaxYear};
+///
+/// let data = TaxData::load_from_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/data/2025")).unwrap();
+/// assert_eq!(data.compute(FilingStatus::Single, 75_000).unwrap(), 11_420);
+/// assert_eq!(
+///     data.compute(FilingStatus::Single, 150_000),
+///     compute_tax(TaxYear::Y2025, FilingStatus::Single, 150_000)
+/// );
+/// # }
+/// ```
+///
+/// With the `serde` feature, tax data also serializes to and from JSON,
+/// TOML, or any other serde format using the schema described in the
+/// README, and is checked the same way when it is deserialized.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "TaxDataFile", into = "TaxDataFile")
+)]
+pub struct TaxData {
+    table: Vec<TaxTableRow>,
+    worksheets: [Vec<WorksheetBracket>; 4],
+    schedules: [Vec<TaxBracket>; 4],
+}
+
+impl TaxData {
+    /// Read `tax_table.csv`, `tax_computation_worksheet.csv`, and
+    /// `tax_rate_schedule.csv` from `dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::UnreadableData`] if a file cannot be read, and
+    /// [`TaxError::InvalidData`] if the files are malformed or fail the
+    /// consistency checks.
+    pub fn load_from_dir(dir: impl AsRef<Path>) -> Result<Self, TaxError> {
+        let dir = dir.as_ref();
+        let paths = FILE_NAMES.map(|name| dir.join(name).display().to_string());
+        let mut contents = [String::new(), String::new(), String::new()];
+        for (path, contents) in paths.iter().zip(&mut contents) {
+            *contents = fs::read_to_string(path).map_err(|error| TaxError::UnreadableData {
+                file: path.clone(),
+                message: error.to_string(),
+            })?;
+        }
+        Self::from_files([0, 1, 2].map(|index| CsvFile {
+            path: &paths[index],
+            contents: &contents[index],
+        }))
+    }
+
+    /// Build tax data from the contents of the three CSV files.
+    ///
+    /// Issues are reported against the file names `tax_table.csv`,
+    /// `tax_computation_worksheet.csv`, and `tax_rate_schedule.csv`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::InvalidData`] if the files are malformed or fail
+    /// the consistency checks.
+    pub fn from_csv(
+        tax_table: &str,
+        worksheet: &str,
+        rate_schedule: &str,
+    ) -> Result<Self, TaxError> {
+        let contents = [tax_table, worksheet, rate_schedule];
+        Self::from_files([0, 1, 2].map(|index| CsvFile {
+            path: FILE_NAMES[index],
+            contents: contents[index],
+        }))
+    }
+
+    /// A copy of the data embedded in the crate for `year`, for example to
+    /// export it in another format.
+    pub fn embedded(year: TaxYear) -> Self {
+        TaxData {
+            table: data::tax_table(year).to_vec(),
+            worksheets: CSV_STATUSES.map(|status| data::worksheet(year, status).to_vec()),
+            schedules: CSV_STATUSES.map(|status| data::rate_schedule(year, status).to_vec()),
+        }
+    }
+
+    /// Validate and parse one year's files.
+    fn from_files([table, worksheet, schedule]: [CsvFile<'_>; 3]) -> Result<Self, TaxError> {
+        let issues = validate_files(&table, &worksheet, &schedule);
+        if !issues.is_empty() {
+            return Err(TaxError::InvalidData(issues));
+        }
+
+        // Validation has checked that every file parses.
+        let table_rows = data::parse_tax_table_lines(&table).expect("validated");
+        let worksheet_rows = CSV_STATUSES
+            .map(|status| data::parse_worksheet_lines(&worksheet, status).expect("validated"));
+        let schedule_rows = CSV_STATUSES
+            .map(|status| data::parse_rate_schedule_lines(&schedule, status).expect("validated"));
+        Self::from_rows(
+            &table_rows,
+            &worksheet_rows,
+            &schedule_rows,
+            [worksheet.path; 4],
+            issues,
+        )
+    }
+
+    /// Build tax data from rows that have passed the coverage checks, adding
+    /// any problem those checks cannot see to `issues`.
+    ///
+    /// `worksheet_files` names where each filing status's worksheet brackets
+    /// came from, for error reports.
+    fn from_rows(
+        table_rows: &[(usize, TaxTableRow)],
+        worksheet_rows: &[Vec<(usize, WorksheetBracket)>; 4],
+        schedule_rows: &[Vec<(usize, TaxBracket)>; 4],
+        worksheet_files: [&str; 4],
+        mut issues: Vec<DataIssue>,
+    ) -> Result<Self, TaxError> {
+        // The coverage checks count the top worksheet bracket's lower bound as
+        // covered, but a lone "Over $100,000" bracket leaves exactly $100,000
+        // without one.
+        let worksheets = worksheet_rows.each_ref().map(|rows| strip_lines(rows));
+        for ((brackets, rows), file) in worksheets.iter().zip(worksheet_rows).zip(worksheet_files) {
+            if !rows.is_empty() && lookup_worksheet(brackets, WORKSHEET_THRESHOLD).is_none() {
+                issues.push(DataIssue::Gap {
+                    file: file.to_string(),
+                    line: rows[0].0,
+                    from: WORKSHEET_THRESHOLD,
+                    to: Some(WORKSHEET_THRESHOLD + 1),
+                });
+            }
+        }
+        if !issues.is_empty() {
+            return Err(TaxError::InvalidData(issues));
+        }
+
+        Ok(TaxData {
+            table: strip_lines(table_rows),
+            worksheets,
+            schedules: schedule_rows.each_ref().map(|rows| strip_lines(rows)),
+        })
+    }
+
+    /// See [`compute_tax`](crate::compute_tax).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::NegativeIncome`] or [`TaxError::IncomeOutOfRange`]
+    /// for an income that cannot be taxed.
+    pub fn compute(
+        &self,
+        status: FilingStatus,
+        taxable_income: impl TryInto<i64>,
+    ) -> Result<i64, TaxError> {
+        self.compute_with_method(status, taxable_income)
+            .map(|(tax, _)| tax)
+    }
+
+    /// See [`compute_tax_with_method`](crate::compute_tax_with_method).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`compute`](Self::compute).
+    pub fn compute_with_method(
+        &self,
+        status: FilingStatus,
+        taxable_income: impl TryInto<i64>,
+    ) -> Result<(i64, Method), TaxError> {
+        let taxable_income = income_to_i64(taxable_income)?;
+        if taxable_income < 0 {
+            return Err(TaxError::NegativeIncome);
+        }
+        if taxable_income == 0 {
+            return Ok((0, Method::TaxTable));
+        }
+
+        if taxable_income < WORKSHEET_THRESHOLD {
+            let row = lookup_tax_table(&self.table, taxable_income).expect(COVERED);
+            Ok((row.tax_for(status), Method::TaxTable))
+        } else {
+            let brackets = &self.worksheets[data::csv_status_index(status)];
+            let bracket = lookup_worksheet(brackets, taxable_income).expect(COVERED);
+            Ok((worksheet_tax(&bracket, taxable_income)?, Method::Worksheet))
+        }
+    }
+
+    /// See [`marginal_rate`](crate::marginal_rate).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`compute`](Self::compute).
+    pub fn marginal_rate(
+        &self,
+        status: FilingStatus,
+        taxable_income: impl TryInto<i64>,
+    ) -> Result<f64, TaxError> {
+        let taxable_income = income_to_i64(taxable_income)?;
+        if taxable_income < 0 {
+            return Err(TaxError::NegativeIncome);
+        }
+        let bracket = self
+            .brackets(status)
+            .iter()
+            .find(|bracket| bracket.contains(taxable_income))
+            .expect(COVERED);
+        Ok(bracket.rate)
+    }
+
+    /// See [`effective_rate`](crate::effective_rate).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`compute`](Self::compute).
+    pub fn effective_rate(
+        &self,
+        status: FilingStatus,
+        taxable_income: impl TryInto<i64>,
+    ) -> Result<f64, TaxError> {
+        let taxable_income = income_to_i64(taxable_income)?;
+        let tax = self.compute(status, taxable_income)?;
+        if taxable_income == 0 {
+            return Ok(0.0);
+        }
+        Ok(tax as f64 / taxable_income as f64)
+    }
+
+    /// The Tax Rate Schedule brackets for a filing status, in ascending
+    /// order.
+    pub fn brackets(&self, status: FilingStatus) -> &[TaxBracket] {
+        &self.schedules[data::csv_status_index(status)]
+    }
+}
+
+/// A [`TaxData`] serves its files for whichever year is asked, so it can
+/// stand in for a year's embedded data.
+impl BracketSource for TaxData {
+    fn tax_table(&self, _year: TaxYear) -> Result<Cow<'_, [TaxTableRow]>, TaxError> {
+        Ok(Cow::Borrowed(&self.table))
+    }
+
+    fn worksheet(
+        &self,
+        _year: TaxYear,
+        status: FilingStatus,
+    ) -> Result<Cow<'_, [WorksheetBracket]>, TaxError> {
+        Ok(Cow::Borrowed(
+            &self.worksheets[data::csv_status_index(status)],
+        ))
+    }
+}
+
+/// The serialized form of [`TaxData`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TaxDataFile {
+    tax_table: Vec<TaxTableRow>,
+    worksheet: ByStatus<WorksheetEntry>,
+    rate_schedule: ByStatus<TaxBracket>,
+}
+
+/// One list per filing status column of the data files.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ByStatus<T> {
+    single: Vec<T>,
+    married_filing_jointly: Vec<T>,
+    married_filing_separately: Vec<T>,
+    head_of_household: Vec<T>,
+}
+
+/// The [`ByStatus`] field names, in [`CSV_STATUSES`] order.
+#[cfg(feature = "serde")]
+const STATUS_KEYS: [&str; 4] = [
+    "single",
+    "married_filing_jointly",
+    "married_filing_separately",
+    "head_of_household",
+];
+
+#[cfg(feature = "serde")]
+impl<T> ByStatus<T> {
+    fn from_array(
+        [
+            single,
+            married_filing_jointly,
+            married_filing_separately,
+            head_of_household,
+        ]: [Vec<T>; 4],
+    ) -> Self {
+        ByStatus {
+            single,
+            married_filing_jointly,
+            married_filing_separately,
+            head_of_household,
+        }
+    }
+
+    fn into_array(self) -> [Vec<T>; 4] {
+        [
+            self.single,
+            self.married_filing_jointly,
+            self.married_filing_separately,
+            self.head_of_household,
+        ]
+    }
+}
+
+/// A worksheet bracket with the rate and subtraction amount written as
+/// decimals, as in `tax_computation_worksheet.csv`.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct WorksheetEntry {
+    income_min: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    income_max: Option<i64>,
+    rate: f64,
+    subtraction_amount: f64,
+}
+
+#[cfg(feature = "serde")]
+impl WorksheetEntry {
+    /// The exact bracket, or [`None`] if the rate is not a whole number of
+    /// basis points or the amount a whole number of cents.
+    fn to_bracket(&self) -> Option<WorksheetBracket> {
+        let rate_basis_points = (self.rate * 10_000.0).round();
+        let subtraction_cents = (self.subtraction_amount * 100.0).round();
+        let exact = rate_basis_points / 10_000.0 == self.rate
+            && subtraction_cents / 100.0 == self.subtraction_amount
+            && subtraction_cents.abs() < i64::MAX as f64;
+        exact.then(|| {
+            WorksheetBracket::new(
+                self.income_min,
+                self.income_max,
+                rate_basis_points as i64,
+                subtraction_cents as i64,
+            )
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<TaxData> for TaxDataFile {
+    fn from(data: TaxData) -> Self {
+        let entry = |bracket: WorksheetBracket| WorksheetEntry {
+            income_min: bracket.income_min,
+            income_max: bracket.income_max,
+            rate: bracket.rate_basis_points as f64 / 10_000.0,
+            subtraction_amount: bracket.subtraction_cents as f64 / 100.0,
+        };
+        TaxDataFile {
+            tax_table: data.table,
+            worksheet: ByStatus::from_array(
+                data.worksheets
+                    .map(|brackets| brackets.into_iter().map(entry).collect()),
+            ),
+            rate_schedule: ByStatus::from_array(data.schedules),
+        }
+    }
+}
+
+/// Runs the same checks as [`TaxData::load_from_dir`]. Issues name the
+/// section, such as `worksheet.single`, with the 1-based position of the
+/// entry as the line.
+#[cfg(feature = "serde")]
+impl TryFrom<TaxDataFile> for TaxData {
+    type Error = TaxError;
+
+    fn try_from(file: TaxDataFile) -> Result<Self, TaxError> {
+        fn numbered<T>(entries: Vec<T>) -> Vec<(usize, T)> {
+            (1..).zip(entries).collect()
+        }
+        let section = |name: &str| STATUS_KEYS.map(|status| format!("{name}.{status}"));
+        let worksheet_files = section("worksheet");
+        let schedule_files = section("rate_schedule");
+
+        let mut issues = Vec::new();
+        let table_rows = numbered(file.tax_table);
+        crate::validate::check_table("tax_table", &table_rows, &mut issues);
+
+        let mut worksheet_rows: [Vec<(usize, WorksheetBracket)>; 4] = Default::default();
+        for (((rows, entries), status), name) in worksheet_rows
+            .iter_mut()
+            .zip(file.worksheet.into_array())
+            .zip(CSV_STATUSES)
+            .zip(&worksheet_files)
+        {
+            let issues_before = issues.len();
+            for (line, entry) in numbered(entries) {
+                match entry.to_bracket() {
+                    Some(bracket) => rows.push((line, bracket)),
+                    None => issues.push(DataIssue::Malformed {
+                        file: name.clone(),
+                        line,
+                    }),
+                }
+            }
+            if issues.len() == issues_before {
+                crate::validate::check_worksheet(name, status, rows, &table_rows, &mut issues);
+            }
+        }
+
+        let schedule_rows = file.rate_schedule.into_array().map(numbered);
+        for ((rows, name), status) in schedule_rows.iter().zip(&schedule_files).zip(CSV_STATUSES) {
+            crate::validate::check_schedule(name, rows, &mut issues);
+            crate::validate::check_table_amounts(
+                "tax_table",
+                status,
+                &table_rows,
+                rows,
+                &mut issues,
+            );
+        }
+
+        TaxData::from_rows(
+            &table_rows,
+            &worksheet_rows,
+            &schedule_rows,
+            worksheet_files.each_ref().map(String::as_str),
+            issues,
+        )
+    }
+}
+
+/// Drop the line numbers from parsed rows.
+fn strip_lines<T: Copy>(rows: &[(usize, T)]) -> Vec<T> {
+    rows.iter().map(|&(_, row)| row).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::compute_tax_with_method;
+    use crate::rates::marginal_rate;
+
+    fn data_dir(year: TaxYear) -> String {
+        format!("{}/data/{year}", env!("CARGO_MANIFEST_DIR"))
+    }
+
+    #[test]
+    fn matches_embedded_data() {
+        for year in TaxYear::ALL {
+            let data = TaxData::load_from_dir(data_dir(year)).unwrap();
+            for status in FilingStatus::ALL {
+                assert_eq!(
+                    data.brackets(status),
+                    crate::data::rate_schedule(year, status)
+                );
+                for income in (0..700_000).step_by(997).chain([99_999, 100_000, 731_200]) {
+                    assert_eq!(
+                        data.compute_with_method(status, income),
+                        compute_tax_with_method(year, status, income)
+                    );
+                    assert_eq!(
+                        data.marginal_rate(status, income),
+                        marginal_rate(year, status, income)
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn corrected_rows_take_effect() {
+        let dir = data_dir(TaxYear::Y2025);
+        let read = |name: &str| fs::read_to_string(format!("{dir}/{name}")).unwrap();
+        let worksheet = read("tax_computation_worksheet.csv").replace("0.37,", "0.38,");
+        let data = TaxData::from_csv(
+            &read("tax_table.csv"),
+            &worksheet,
+            &read("tax_rate_schedule.csv"),
+        )
+        .unwrap();
+        let embedded = compute_tax_with_method(TaxYear::Y2025, FilingStatus::Single, 1_000_000);
+        let (tax, _) = data
+            .compute_with_method(FilingStatus::Single, 1_000_000)
+            .unwrap();
+        assert!(tax > embedded.unwrap().0);
+    }
+
+    #[test]
+    fn missing_file() {
+        let error = TaxData::load_from_dir("/nonexistent").unwrap_err();
+        assert!(
+            matches!(&error, TaxError::UnreadableData { file, .. } if file.ends_with("tax_table.csv")),
+            "{error:?}"
+        );
+    }
+
+    #[test]
+    fn invalid_data() {
+        // A flat 10% tax up to $200,000 and 24% above, with a gap in the table
+        let table = "header\n\
+            0,50000,2500,2500,2500,2500\n\
+            60000,99950,7498,7498,7498,7498\n\
+            99950,100000,9998,9998,9998,9998\n";
+        let worksheet = "header\n\
+            single,100000,200000,0.10,0.0\n\
+            single,200000,,0.24,28000.0\n\
+            married_filing_jointly,100000,200000,0.10,0.0\n\
+            married_filing_jointly,200000,,0.24,28000.0\n\
+            married_filing_separately,100000,200000,0.10,0.0\n\
+            married_filing_separately,200000,,0.24,28000.0\n\
+            head_of_household,100000,200000,0.10,0.0\n\
+            head_of_household,200000,,0.24,28000.0\n";
+        let schedule = "header\n\
+            single,0,,0.10\n\
+            married_filing_jointly,0,,0.10\n\
+            married_filing_separately,0,,0.10\n\
+            head_of_household,0,,0.10\n";
+        let Err(TaxError::InvalidData(issues)) = TaxData::from_csv(table, worksheet, schedule)
+        else {
+            panic!("gap in the Tax Table was not reported");
+        };
+        assert_eq!(
+            issues[0],
+            DataIssue::Gap {
+                file: "tax_table.csv".to_string(),
+                line: 3,
+                from: 50_000,
+                to: Some(60_000),
+            }
+        );
+
+        let table = table.replace("60000,", "50000,");
+        assert!(TaxData::from_csv(&table, worksheet, schedule).is_ok());
+
+        // A lone "Over $100,000" bracket leaves $100,000 itself uncovered.
+        let worksheet = "header\n\
+            single,100000,,0.10,0.0\n\
+            married_filing_jointly,100000,,0.10,0.0\n\
+            married_filing_separately,100000,,0.10,0.0\n\
+            head_of_household,100000,,0.10,0.0\n";
+        assert_eq!(
+            TaxData::from_csv(&table, worksheet, schedule),
+            Err(TaxError::InvalidData(
+                (2..=5)
+                    .map(|line| DataIssue::Gap {
+                        file: "tax_computation_worksheet.csv".to_string(),
+                        line,
+                        from: 100_000,
+                        to: Some(100_001),
+                    })
+                    .collect()
+            ))
+        );
+    }
+
+    #[cfg(all(feature = "year-2025", feature = "serde"))]
+    #[test]
+    fn serde_round_trip() {
+        let data = TaxData::embedded(TaxYear::Y2025);
+        assert_eq!(
+            data,
+            TaxData::load_from_dir(data_dir(TaxYear::Y2025)).unwrap()
+        );
+
+        let json = serde_json::to_string(&data).unwrap();
+        assert_eq!(serde_json::from_str::<TaxData>(&json).unwrap(), data);
+
+        let toml = toml::to_string(&data).unwrap();
+        assert!(toml.contains("[[worksheet.single]]"), "{toml}");
+        assert_eq!(toml::from_str::<TaxData>(&toml).unwrap(), data);
+    }
+
+    #[cfg(all(feature = "year-2024", feature = "serde"))]
+    #[test]
+    fn deserialize_checks_data() {
+        let mut value = serde_json::to_value(TaxData::embedded(TaxYear::Y2024)).unwrap();
+        assert_eq!(value["worksheet"]["single"][0]["rate"], 0.22);
+        value["worksheet"]["single"][1]["rate"] = 0.24001.into();
+        value["rate_schedule"]["head_of_household"][2]["income_min"] = 1.into();
+        value["tax_table"][100]["married_filing_jointly"] = 0.into();
+        let file: TaxDataFile = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(
+            TaxData::try_from(file),
+            Err(TaxError::InvalidData(vec![
+                DataIssue::Malformed {
+                    file: "worksheet.single".to_string(),
+                    line: 2,
+                },
+                DataIssue::TableMismatch {
+                    file: "tax_table".to_string(),
+                    line: 101,
+                    status: FilingStatus::MarriedFilingJointly,
+                    table_tax: 0,
+                    expected_tax: 246,
+                },
+                DataIssue::Unsorted {
+                    file: "rate_schedule.head_of_household".to_string(),
+                    line: 3,
+                },
+            ]))
+        );
+        assert_eq!(
+            serde_json::from_value::<TaxData>(value)
+                .unwrap_err()
+                .to_string(),
+            "tax data failed validation: worksheet.single:2: malformed row (and 2 more)"
+        );
+    }
+}
@@ -0,0 +1,658 @@
+//! A whole Form 1040, from income to refund or amount owed.
+
+use crate::amt::compute_amt;
+use crate::capital_gains::{CapitalGains, ScheduleDWorksheet, schedule_d_tax_worksheet};
+use crate::ctc::compute_ctc;
+use crate::deduction::DeductionChoice;
+use crate::education::{StudentExpenses, compute_education_credits};
+use crate::eitc::{compute_eitc, eitc_investment_income_limit};
+use crate::fica::additional_medicare_threshold;
+use crate::itemized::{ItemizedDeductions, state_and_local_tax_cap};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// A Form 1040 computed from its income, deductions, dependents, and
+/// withholding, in whole dollars. Created by [`TaxReturn::builder`].
+///
+/// Each field is the amount on a line of the form or one of its schedules.
+/// Amounts the builder has no input for, such as business income, other
+/// credits, and estimated tax payments, are taken as zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TaxReturn {
+    /// The year of the return.
+    pub year: TaxYear,
+    /// The filing status.
+    pub status: FilingStatus,
+    /// Wages, interest, dividends, and capital gain or loss (line 9).
+    pub total_income: i64,
+    /// Adjustments to income from Schedule 1 (line 10).
+    pub adjustments: i64,
+    /// Adjusted gross income (line 11).
+    pub agi: i64,
+    /// The standard deduction or itemized deductions, whichever was used
+    /// (line 12).
+    pub deduction: i64,
+    /// Whether [`deduction`](Self::deduction) is the itemized deduction.
+    pub itemized: bool,
+    /// Taxable income (line 15).
+    pub taxable_income: i64,
+    /// The Qualified Dividends and Capital Gain Tax Worksheet, when there
+    /// are qualified dividends or a net capital gain to tax at the lower
+    /// rates. Otherwise the tax comes from the Tax Table or the Tax
+    /// Computation Worksheet.
+    pub capital_gain_worksheet: Option<ScheduleDWorksheet>,
+    /// The tax on taxable income (line 16).
+    pub tax: i64,
+    /// The alternative minimum tax from Form 6251 (Schedule 2, line 1).
+    pub alternative_minimum_tax: i64,
+    /// The child tax credit and credit for other dependents used against
+    /// the tax (line 19).
+    pub child_tax_credit: i64,
+    /// The nonrefundable education credits used against the tax (Schedule
+    /// 3, line 3).
+    pub education_credit: i64,
+    /// The 0.9% Additional Medicare Tax on wages above the threshold for the
+    /// filing status (Form 8959).
+    pub additional_medicare_tax: i64,
+    /// The 3.8% net investment income tax (Form 8960).
+    pub net_investment_income_tax: i64,
+    /// The total tax (line 24).
+    pub total_tax: i64,
+    /// Federal income tax withheld (line 25d).
+    pub withholding: i64,
+    /// The earned income credit (line 27).
+    pub earned_income_credit: i64,
+    /// The refundable additional child tax credit (line 28).
+    pub additional_child_tax_credit: i64,
+    /// The refundable part of the American opportunity credit (line 29).
+    pub refundable_education_credit: i64,
+    /// Total payments and refundable credits (line 33).
+    pub total_payments: i64,
+}
+
+impl TaxReturn {
+    /// Start a return for `year` and `status`, with every amount zero and
+    /// the basic standard deduction.
+    pub fn builder(year: TaxYear, status: FilingStatus) -> TaxReturnBuilder {
+        TaxReturnBuilder {
+            year,
+            status,
+            wages: 0,
+            taxable_interest: 0,
+            ordinary_dividends: 0,
+            qualified_dividends: 0,
+            short_term_gain: 0,
+            long_term_gain: 0,
+            adjustments: 0,
+            deduction: DeductionChoice::Standard,
+            itemized: None,
+            qualifying_children: 0,
+            other_dependents: 0,
+            education_expenses: Vec::new(),
+            withholding: 0,
+            earned_income_credit: true,
+        }
+    }
+
+    /// The refundable credits: the earned income credit, the additional
+    /// child tax credit, and the refundable American opportunity credit.
+    pub fn refundable_credits(&self) -> i64 {
+        self.earned_income_credit
+            + self.additional_child_tax_credit
+            + self.refundable_education_credit
+    }
+
+    /// The amount overpaid and refunded (line 34), or zero.
+    pub fn refund(&self) -> i64 {
+        (self.total_payments - self.total_tax).max(0)
+    }
+
+    /// The amount owed (line 37), or zero.
+    pub fn amount_owed(&self) -> i64 {
+        (self.total_tax - self.total_payments).max(0)
+    }
+}
+
+/// Builds a [`TaxReturn`]. Created by [`TaxReturn::builder`].
+///
+/// Each method sets one input, replacing any earlier value, and
+/// [`build`](Self::build) computes the return.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaxReturnBuilder {
+    year: TaxYear,
+    status: FilingStatus,
+    wages: i64,
+    taxable_interest: i64,
+    ordinary_dividends: i64,
+    qualified_dividends: i64,
+    short_term_gain: i64,
+    long_term_gain: i64,
+    adjustments: i64,
+    deduction: DeductionChoice,
+    itemized: Option<ItemizedDeductions>,
+    qualifying_children: u8,
+    other_dependents: u8,
+    education_expenses: Vec<StudentExpenses>,
+    withholding: i64,
+    earned_income_credit: bool,
+}
+
+impl TaxReturnBuilder {
+    /// Wages, salaries, and tips (line 1z). They are also the earned income
+    /// for the earned income credit and the additional child tax credit.
+    pub fn wages(mut self, wages: i64) -> Self {
+        self.wages = wages;
+        self
+    }
+
+    /// Taxable interest (line 2b).
+    pub fn interest(mut self, taxable_interest: i64) -> Self {
+        self.taxable_interest = taxable_interest;
+        self
+    }
+
+    /// Ordinary dividends (line 3b), of which `qualified` are qualified
+    /// dividends (line 3a). Qualified dividends above the ordinary dividends
+    /// are ignored.
+    pub fn dividends(mut self, ordinary: i64, qualified: i64) -> Self {
+        self.ordinary_dividends = ordinary;
+        self.qualified_dividends = qualified;
+        self
+    }
+
+    /// The net short-term and net long-term capital gain or loss from
+    /// Schedule D, lines 7 and 15. A net loss is deducted up to $3,000
+    /// ($1,500 married filing separately).
+    pub fn capital_gains(mut self, short_term: i64, long_term: i64) -> Self {
+        self.short_term_gain = short_term;
+        self.long_term_gain = long_term;
+        self
+    }
+
+    /// The total adjustments to income from Schedule 1 (line 10), such as
+    /// deductible IRA and HSA contributions and student loan interest.
+    pub fn adjustments(mut self, adjustments: i64) -> Self {
+        self.adjustments = adjustments;
+        self
+    }
+
+    /// The standard deduction to claim, or a fixed itemized amount. The
+    /// default is [`DeductionChoice::Standard`].
+    pub fn deduction(mut self, deduction: DeductionChoice) -> Self {
+        self.deduction = deduction;
+        self
+    }
+
+    /// Itemized deductions from Schedule A, claimed instead of the
+    /// [`deduction`](Self::deduction) when their
+    /// [`total`](ItemizedDeductions::total) is larger.
+    pub fn itemized_deductions(mut self, itemized: ItemizedDeductions) -> Self {
+        self.itemized = Some(itemized);
+        self
+    }
+
+    /// The dependents claimed: `qualifying_children` under 17, who also
+    /// count for the earned income credit, and `other_dependents`.
+    pub fn dependents(mut self, qualifying_children: u8, other_dependents: u8) -> Self {
+        self.qualifying_children = qualifying_children;
+        self.other_dependents = other_dependents;
+        self
+    }
+
+    /// Each student's qualified education expenses, for the education
+    /// credits of Form 8863.
+    pub fn education_expenses(mut self, students: &[StudentExpenses]) -> Self {
+        self.education_expenses = students.to_vec();
+        self
+    }
+
+    /// Federal income tax withheld on Forms W-2 and 1099 (line 25d),
+    /// including any Additional Medicare Tax withheld.
+    pub fn withholding(mut self, withholding: i64) -> Self {
+        self.withholding = withholding;
+        self
+    }
+
+    /// Leave out the earned income credit, as for a married person filing
+    /// separately who lived with their spouse.
+    pub(crate) fn without_earned_income_credit(mut self) -> Self {
+        self.earned_income_credit = false;
+        self
+    }
+
+    /// Compute the return.
+    ///
+    /// The tax on taxable income uses [`schedule_d_tax_worksheet`] when there
+    /// are qualified dividends or a net capital gain, and
+    /// [`compute_tax`](crate::compute_tax) otherwise. The alternative minimum
+    /// tax adds back the standard deduction, or the state and local tax
+    /// deduction when itemizing with
+    /// [`itemized_deductions`](Self::itemized_deductions). The education
+    /// credits ([`compute_education_credits`]) are used against the tax
+    /// before the child tax credit ([`compute_ctc`]). These credits and the
+    /// earned income credit ([`compute_eitc`]) use adjusted gross income as
+    /// modified adjusted gross income, and the earned income credit is not
+    /// allowed when investment income exceeds the
+    /// [`eitc_investment_income_limit`]. The net investment income tax
+    /// applies to interest, dividends, and capital gain.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaxError::NegativeIncome`] if the wages, interest,
+    /// dividends, adjustments, or withholding are negative, and the errors
+    /// of [`DeductionChoice::amount`] and [`schedule_d_tax_worksheet`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "year-2024")] {
+    /// use us_tax_brackets::{FilingStatus, TaxReturn, TaxYear};
+    ///
+    /// let tax_return = TaxReturn::builder(TaxYear::Y2024, FilingStatus::Single)
+    ///     .wages(80_000)
+    ///     .interest(500)
+    ///     .dividends(2_000, 1_500)
+    ///     .capital_gains(-1_000, 6_000)
+    ///     .withholding(11_000)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(tax_return.agi, 87_500);
+    /// assert_eq!(tax_return.taxable_income, 72_900);
+    /// assert!(tax_return.capital_gain_worksheet.is_some());
+    /// assert_eq!(tax_return.total_tax, 10_642);
+    /// assert_eq!(tax_return.refund(), 358);
+    /// # }
+    /// ```
+    pub fn build(&self) -> Result<TaxReturn, TaxError> {
+        let (year, status) = (self.year, self.status);
+        if [
+            self.wages,
+            self.taxable_interest,
+            self.ordinary_dividends,
+            self.qualified_dividends,
+            self.adjustments,
+            self.withholding,
+        ]
+        .iter()
+        .any(|&amount| amount < 0)
+        {
+            return Err(TaxError::NegativeIncome);
+        }
+        let qualified_dividends = self.qualified_dividends.min(self.ordinary_dividends);
+
+        // Schedule D, lines 16 and 21
+        let net_gain = self.short_term_gain.saturating_add(self.long_term_gain);
+        let loss_limit = match status {
+            FilingStatus::MarriedFilingSeparately => 1_500,
+            _ => 3_000,
+        };
+        let capital_gain = net_gain.max(-loss_limit);
+        let net_capital_gain = self.long_term_gain.min(net_gain).max(0);
+
+        let total_income = [
+            self.wages,
+            self.taxable_interest,
+            self.ordinary_dividends,
+            capital_gain,
+        ]
+        .into_iter()
+        .fold(0_i64, i64::saturating_add);
+        let agi = total_income.saturating_sub(self.adjustments);
+
+        let mut deduction = self.deduction.amount(year, status)?;
+        let mut itemized = matches!(self.deduction, DeductionChoice::Itemized(_));
+        if let Some(deductions) = &self.itemized {
+            let total = deductions.total(year, status, agi);
+            if total > deduction {
+                deduction = total;
+                itemized = true;
+            }
+        }
+        let taxable_income = agi.saturating_sub(deduction).max(0);
+
+        let gains = CapitalGains {
+            qualified_dividends,
+            net_capital_gain,
+            ..CapitalGains::default()
+        };
+        let capital_gain_worksheet = if qualified_dividends > 0 || net_capital_gain > 0 {
+            Some(schedule_d_tax_worksheet(
+                year,
+                status,
+                taxable_income,
+                &gains,
+            )?)
+        } else {
+            None
+        };
+        let amt_adjustments = match (&self.itemized, itemized) {
+            (Some(deductions), true) => deductions
+                .state_and_local_taxes
+                .clamp(0, state_and_local_tax_cap(year, status, agi)),
+            (None, true) => 0,
+            (_, false) => deduction,
+        };
+        let amt = compute_amt(year, status, taxable_income, amt_adjustments, &gains)?;
+        let tax = amt.regular_tax;
+        let tax_before_credits = amt.total_tax();
+
+        let ctc = compute_ctc(
+            year,
+            status,
+            agi,
+            self.qualifying_children,
+            self.other_dependents,
+        )?;
+        let education = compute_education_credits(year, status, agi, &self.education_expenses)?;
+        let education_credit = education.nonrefundable().min(tax_before_credits);
+        let tax_after_education = tax_before_credits - education_credit;
+        let child_tax_credit = ctc.nonrefundable(tax_after_education);
+
+        let additional_medicare_tax = percent_of(
+            self.wages
+                .saturating_sub(additional_medicare_threshold(status))
+                .max(0),
+            9,
+        );
+        let net_investment_income = [
+            self.taxable_interest,
+            self.ordinary_dividends,
+            capital_gain.max(0),
+        ]
+        .into_iter()
+        .fold(0_i64, i64::saturating_add);
+        let excess_agi = agi.saturating_sub(niit_threshold(status)).max(0);
+        let net_investment_income_tax = percent_of(net_investment_income.min(excess_agi), 38);
+        let total_tax = [
+            tax_after_education - child_tax_credit,
+            additional_medicare_tax,
+            net_investment_income_tax,
+        ]
+        .into_iter()
+        .fold(0_i64, i64::saturating_add);
+
+        let earned_income_credit = if !self.earned_income_credit
+            || net_investment_income > eitc_investment_income_limit(year)
+        {
+            0
+        } else {
+            compute_eitc(year, status, self.wages, agi, self.qualifying_children)?
+        };
+        let additional_child_tax_credit =
+            ctc.additional_child_tax_credit(tax_after_education, self.wages);
+        let refundable_education_credit = education.refundable_american_opportunity;
+        let total_payments = [
+            self.withholding,
+            earned_income_credit,
+            additional_child_tax_credit,
+            refundable_education_credit,
+        ]
+        .into_iter()
+        .fold(0_i64, i64::saturating_add);
+
+        Ok(TaxReturn {
+            year,
+            status,
+            total_income,
+            adjustments: self.adjustments,
+            agi,
+            deduction,
+            itemized,
+            taxable_income,
+            capital_gain_worksheet,
+            tax,
+            alternative_minimum_tax: amt.amt,
+            child_tax_credit,
+            education_credit,
+            additional_medicare_tax,
+            net_investment_income_tax,
+            total_tax,
+            withholding: self.withholding,
+            earned_income_credit,
+            additional_child_tax_credit,
+            refundable_education_credit,
+            total_payments,
+        })
+    }
+}
+
+/// The modified adjusted gross income above which the net investment income
+/// tax applies. Like the Additional Medicare Tax thresholds, these are set
+/// by statute.
+pub(crate) const fn niit_threshold(status: FilingStatus) -> i64 {
+    match status {
+        FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse => 250_000,
+        FilingStatus::MarriedFilingSeparately => 125_000,
+        FilingStatus::Single | FilingStatus::HeadOfHousehold => 200_000,
+    }
+}
+
+/// `tenths_of_percent`/1,000 of `amount`, rounded half up to whole dollars.
+pub(crate) fn percent_of(amount: i64, tenths_of_percent: i64) -> i64 {
+    ((i128::from(amount) * i128::from(tenths_of_percent) + 500) / 1_000) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::compute_tax;
+    use crate::deduction::standard_deduction;
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn wages_only() {
+        let (year, status) = (TaxYear::Y2025, FilingStatus::Single);
+        let tax_return = TaxReturn::builder(year, status)
+            .wages(60_000)
+            .withholding(6_000)
+            .build()
+            .unwrap();
+        assert_eq!(tax_return.agi, 60_000);
+        assert_eq!(tax_return.deduction, standard_deduction(year, status));
+        assert!(!tax_return.itemized);
+        assert_eq!(tax_return.taxable_income, 44_250);
+        assert_eq!(tax_return.capital_gain_worksheet, None);
+        assert_eq!(tax_return.tax, compute_tax(year, status, 44_250).unwrap());
+        assert_eq!(tax_return.total_tax, tax_return.tax);
+        assert_eq!(tax_return.refund(), 6_000 - tax_return.tax);
+        assert_eq!(tax_return.amount_owed(), 0);
+
+        // Nothing at all gives an empty return.
+        let empty = TaxReturn::builder(year, status).build().unwrap();
+        assert_eq!(
+            (empty.total_tax, empty.refund(), empty.amount_owed()),
+            (0, 0, 0)
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn capital_gains_and_losses() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::MarriedFilingSeparately);
+        let builder = TaxReturn::builder(year, status).wages(50_000);
+        // A net loss is limited to $1,500 married filing separately.
+        let loss = builder
+            .clone()
+            .capital_gains(-10_000, 2_000)
+            .build()
+            .unwrap();
+        assert_eq!(loss.total_income, 48_500);
+        assert_eq!(loss.capital_gain_worksheet, None);
+        // A short-term loss offsets the long-term gain taxed at lower rates.
+        let gain = builder.capital_gains(-2_000, 12_000).build().unwrap();
+        let worksheet = gain.capital_gain_worksheet.unwrap();
+        assert_eq!(gain.agi, 60_000);
+        assert_eq!(worksheet.taxed_at_0 + worksheet.taxed_at_15, 10_000);
+        assert!(gain.tax < compute_tax(year, status, gain.taxable_income).unwrap());
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn itemized_deductions() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::MarriedFilingJointly);
+        let itemized = ItemizedDeductions {
+            state_and_local_taxes: 15_000,
+            mortgage_interest: 20_000,
+            ..ItemizedDeductions::default()
+        };
+        let tax_return = TaxReturn::builder(year, status)
+            .wages(200_000)
+            .adjustments(10_000)
+            .itemized_deductions(itemized)
+            .build()
+            .unwrap();
+        assert!(tax_return.itemized);
+        assert_eq!(tax_return.deduction, 30_000);
+        assert_eq!(tax_return.taxable_income, 160_000);
+
+        // Smaller itemized deductions leave the standard deduction.
+        let small = ItemizedDeductions {
+            mortgage_interest: 5_000,
+            ..ItemizedDeductions::default()
+        };
+        let tax_return = TaxReturn::builder(year, status)
+            .wages(200_000)
+            .itemized_deductions(small)
+            .build()
+            .unwrap();
+        assert!(!tax_return.itemized);
+        assert_eq!(tax_return.deduction, 29_200);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn credits() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::HeadOfHousehold);
+        let tax_return = TaxReturn::builder(year, status)
+            .wages(30_000)
+            .dependents(2, 0)
+            .withholding(1_000)
+            .build()
+            .unwrap();
+        // $30,000 less the $21,900 standard deduction
+        assert_eq!(tax_return.tax, compute_tax(year, status, 8_100).unwrap());
+        assert_eq!(tax_return.child_tax_credit, tax_return.tax);
+        assert_eq!(tax_return.total_tax, 0);
+        assert_eq!(
+            tax_return.additional_child_tax_credit,
+            4_000 - tax_return.tax
+        );
+        assert_eq!(
+            tax_return.earned_income_credit,
+            compute_eitc(year, status, 30_000, 30_000, 2).unwrap()
+        );
+        assert_eq!(
+            tax_return.refund(),
+            1_000 + tax_return.earned_income_credit + tax_return.additional_child_tax_credit
+        );
+
+        // Too much investment income rules out the earned income credit.
+        let tax_return = TaxReturn::builder(year, status)
+            .wages(30_000)
+            .interest(12_000)
+            .dependents(2, 0)
+            .build()
+            .unwrap();
+        assert_eq!(tax_return.earned_income_credit, 0);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn education_credits() {
+        let (year, status) = (TaxYear::Y2024, FilingStatus::MarriedFilingJointly);
+        let students = [StudentExpenses {
+            qualified_expenses: 4_000,
+            ..StudentExpenses::default()
+        }];
+        let builder = TaxReturn::builder(year, status)
+            .wages(100_000)
+            .dependents(1, 0)
+            .education_expenses(&students);
+        let tax_return = builder.build().unwrap();
+        // $2,500 of American opportunity credit, 40% of it refundable
+        assert_eq!(tax_return.education_credit, 1_500);
+        assert_eq!(tax_return.refundable_education_credit, 1_000);
+        assert_eq!(tax_return.child_tax_credit, 2_000);
+        assert_eq!(tax_return.total_tax, tax_return.tax - 3_500);
+        assert_eq!(tax_return.refundable_credits(), 1_000);
+
+        let separate = TaxReturn::builder(year, FilingStatus::MarriedFilingSeparately)
+            .wages(100_000)
+            .education_expenses(&students)
+            .build()
+            .unwrap();
+        assert_eq!(separate.education_credit, 0);
+        assert_eq!(separate.refundable_credits(), 0);
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn other_taxes() {
+        let (year, status) = (TaxYear::Y2025, FilingStatus::Single);
+        let tax_return = TaxReturn::builder(year, status)
+            .wages(250_000)
+            .interest(20_000)
+            .dividends(40_000, 40_000)
+            .withholding(60_000)
+            .build()
+            .unwrap();
+        // 0.9% of $50,000
+        assert_eq!(tax_return.additional_medicare_tax, 450);
+        // 3.8% of the $60,000 of investment income, less than the $110,000
+        // of AGI above $200,000
+        assert_eq!(tax_return.net_investment_income_tax, 2_280);
+        assert_eq!(
+            tax_return.total_tax,
+            tax_return.tax + tax_return.alternative_minimum_tax + 450 + 2_280
+        );
+        assert_eq!(
+            tax_return.amount_owed(),
+            (tax_return.total_tax - 60_000).max(0)
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn alternative_minimum_tax() {
+        // Itemized state and local taxes are added back for the AMT, so a
+        // large itemized return can owe it.
+        let (year, status) = (TaxYear::Y2025, FilingStatus::MarriedFilingJointly);
+        let itemized = ItemizedDeductions {
+            state_and_local_taxes: 40_000,
+            mortgage_interest: 30_000,
+            ..ItemizedDeductions::default()
+        };
+        let tax_return = TaxReturn::builder(year, status)
+            .wages(400_000)
+            .itemized_deductions(itemized)
+            .build()
+            .unwrap();
+        let amt = compute_amt(
+            year,
+            status,
+            tax_return.taxable_income,
+            40_000,
+            &CapitalGains::default(),
+        )
+        .unwrap();
+        assert_eq!(tax_return.alternative_minimum_tax, amt.amt);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn errors() {
+        let builder = TaxReturn::builder(TaxYear::Y2024, FilingStatus::Single);
+        assert_eq!(
+            builder.clone().wages(-1).build(),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            builder.clone().withholding(-1).build(),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            builder.deduction(DeductionChoice::Itemized(-1)).build(),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+}
@@ -0,0 +1,439 @@
+//! Auditable computation traces.
+
+use std::fmt;
+
+use crate::compute::{
+    WORKSHEET_THRESHOLD, income_to_i64, search_tax_table, search_worksheet, worksheet_tax,
+};
+use crate::money::group_thousands;
+use crate::source::{BracketSource, EmbeddedData};
+use crate::types::{FilingStatus, Method, TaxError, TaxYear};
+
+/// A full record of how a tax amount was computed.
+///
+/// Returned by [`compute_tax_detailed`]. The [`tax`](Self::tax) field always
+/// equals what [`compute_tax`](crate::compute_tax) returns for the same
+/// inputs.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaxComputation {
+    /// The tax year used.
+    pub year: TaxYear,
+    /// The filing status used.
+    pub status: FilingStatus,
+    /// The taxable income the tax was computed on.
+    pub taxable_income: i64,
+    /// The Tax Table row or worksheet bracket that was applied.
+    pub calculation: Calculation,
+    /// The final tax, rounded to whole dollars.
+    pub tax: i64,
+    /// Why the row or bracket applied differs from the IRS publication, when
+    /// the source corrected it (see [`BracketSource::correction`]).
+    pub correction: Option<String>,
+}
+
+impl TaxComputation {
+    /// The IRS method that produced the tax.
+    pub fn method(&self) -> Method {
+        match self.calculation {
+            Calculation::TaxTable(_) => Method::TaxTable,
+            Calculation::Worksheet(_) => Method::Worksheet,
+        }
+    }
+
+    /// Describe the computation as steps that mirror the Form 1040
+    /// instructions.
+    ///
+    /// Worksheet computations are labeled with the Tax Computation Worksheet
+    /// section and columns (a)–(d). Tax Table lookups use the table's "At
+    /// least / But less than" row wording and the filing status column.
+    ///
+    /// The [`Display`](fmt::Display) impl prints these steps one per line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "year-2024")] {
+    /// use us_tax_brackets::{compute_tax_detailed, FilingStatus, TaxYear};
+    ///
+    /// let detail = compute_tax_detailed(TaxYear::Y2024, FilingStatus::Single, 150_000).unwrap();
+    /// let steps = detail.explain();
+    /// assert_eq!(steps[1].label, "(a)");
+    /// assert_eq!(steps[1].text, "Taxable income. Enter the amount from line 15: 150,000");
+    /// assert_eq!(steps[3].text, "Multiply (a) by (b): 36,000.00");
+    /// # }
+    /// ```
+    pub fn explain(&self) -> Vec<ExplanationStep> {
+        let step = |label: &'static str, text: String| ExplanationStep { label, text };
+        let correction = self
+            .correction
+            .iter()
+            .map(|reason| step("Correction", reason.clone()));
+        let steps = match self.calculation {
+            Calculation::TaxTable(lookup) => vec![
+                step(
+                    "Line 15",
+                    format!("Taxable income: {}", format_dollars(self.taxable_income)),
+                ),
+                step(
+                    "Tax Table",
+                    format!(
+                        "At least {} but less than {}",
+                        format_dollars(lookup.income_min),
+                        format_dollars(lookup.income_max)
+                    ),
+                ),
+                step(
+                    "Tax",
+                    format!(
+                        "{} column: {}",
+                        tax_table_column(self.status),
+                        format_dollars(lookup.tax)
+                    ),
+                ),
+            ],
+            Calculation::Worksheet(calc) => vec![
+                step(
+                    worksheet_section(self.status),
+                    format!(
+                        "Taxable income over {}{}",
+                        format_dollars(calc.income_min),
+                        match calc.income_max {
+                            Some(max) => format!(" but not over {}", format_dollars(max)),
+                            None => String::new(),
+                        }
+                    ),
+                ),
+                step(
+                    "(a)",
+                    format!(
+                        "Taxable income. Enter the amount from line 15: {}",
+                        format_dollars(self.taxable_income)
+                    ),
+                ),
+                step(
+                    "(b)",
+                    format!("Multiplication amount: {}", format_rate(calc.rate)),
+                ),
+                step(
+                    "(c)",
+                    format!("Multiply (a) by (b): {}", format_cents(calc.product)),
+                ),
+                step(
+                    "(d)",
+                    format!(
+                        "Subtraction amount: {}",
+                        format_cents(calc.subtraction_amount)
+                    ),
+                ),
+                step(
+                    "Tax",
+                    format!(
+                        "Subtract (d) from (c): {}, rounded to {}",
+                        format_cents(calc.unrounded_tax),
+                        format_dollars(self.tax)
+                    ),
+                ),
+            ],
+        };
+        correction.chain(steps).collect()
+    }
+}
+
+impl fmt::Display for TaxComputation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in self.explain() {
+            writeln!(f, "{step}")?;
+        }
+        Ok(())
+    }
+}
+
+/// One labeled step of a [`TaxComputation::explain`] listing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ExplanationStep {
+    /// The line, column, or section label from the instructions, e.g. `"(c)"`.
+    pub label: &'static str,
+    /// Human-readable description of the step, including its amount.
+    pub text: String,
+}
+
+impl fmt::Display for ExplanationStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<10} {}", self.label, self.text)
+    }
+}
+
+/// The intermediate values of a tax computation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Calculation {
+    /// The tax was looked up in the Tax Table.
+    TaxTable(TableLookup),
+    /// The tax was computed with the Tax Computation Worksheet.
+    Worksheet(WorksheetCalculation),
+}
+
+/// The Tax Table row matched for an income under $100,000.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableLookup {
+    /// Lower bound of the row ("At least"), inclusive.
+    pub income_min: i64,
+    /// Upper bound of the row ("But less than"), exclusive.
+    pub income_max: i64,
+    /// The tax listed in the row for the filing status.
+    pub tax: i64,
+}
+
+/// The Tax Computation Worksheet line applied to an income of $100,000 or
+/// more.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorksheetCalculation {
+    /// Lower bound of the worksheet bracket.
+    pub income_min: i64,
+    /// Upper bound of the worksheet bracket (inclusive), or [`None`] for the
+    /// highest bracket.
+    pub income_max: Option<i64>,
+    /// Multiplication rate (e.g., 0.24 for 24%).
+    pub rate: f64,
+    /// `taxable_income × rate`.
+    pub product: f64,
+    /// Subtraction amount for the bracket.
+    pub subtraction_amount: f64,
+    /// `product − subtraction_amount`, before rounding.
+    ///
+    /// The `f64` fields are for display. The rounded
+    /// [`TaxComputation::tax`] is computed exactly in integer arithmetic, as
+    /// [`compute_tax`](crate::compute_tax) does.
+    pub unrounded_tax: f64,
+}
+
+/// Compute federal income tax and return every intermediate value.
+///
+/// This is [`compute_tax`](crate::compute_tax) with an audit trail: the
+/// inputs, the matched Tax Table row or worksheet bracket, the worksheet
+/// multiplication and subtraction, and the final rounded tax. Zero income is
+/// reported as a lookup in the first Tax Table row.
+///
+/// # Errors
+///
+/// Returns the same errors as [`compute_tax`](crate::compute_tax).
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{compute_tax_detailed, Calculation, FilingStatus, TaxYear};
+///
+/// let detail = compute_tax_detailed(TaxYear::Y2024, FilingStatus::Single, 150_000).unwrap();
+/// assert_eq!(detail.tax, 29_043);
+///
+/// let Calculation::Worksheet(step) = detail.calculation else { panic!() };
+/// assert_eq!(step.rate, 0.24);
+/// assert_eq!(step.product, 36_000.0);
+/// assert_eq!(step.subtraction_amount, 6_957.5);
+/// assert_eq!(step.unrounded_tax, 29_042.5);
+/// # }
+/// ```
+pub fn compute_tax_detailed(
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+) -> Result<TaxComputation, TaxError> {
+    compute_tax_detailed_from(&EmbeddedData, year, status, taxable_income)
+}
+
+/// Compute federal income tax with data from `source` and return every
+/// intermediate value.
+///
+/// This is [`compute_tax_detailed`] over any [`BracketSource`]. If the source
+/// reports a [`correction`](BracketSource::correction) for the row or
+/// bracket applied, it is recorded in [`TaxComputation::correction`] and
+/// listed first by [`TaxComputation::explain`].
+///
+/// # Errors
+///
+/// Returns the same errors as
+/// [`compute_tax_from`](crate::compute_tax_from).
+pub fn compute_tax_detailed_from<S: BracketSource + ?Sized>(
+    source: &S,
+    year: TaxYear,
+    status: FilingStatus,
+    taxable_income: impl TryInto<i64>,
+) -> Result<TaxComputation, TaxError> {
+    let taxable_income = income_to_i64(taxable_income)?;
+    if taxable_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+
+    let (calculation, tax) = if taxable_income < WORKSHEET_THRESHOLD {
+        let table = source.tax_table(year)?;
+        let row = search_tax_table(&table, year, status, taxable_income)?;
+        let tax = row.tax_for(status);
+        let lookup = TableLookup {
+            income_min: row.income_min,
+            income_max: row.income_max,
+            tax,
+        };
+        (Calculation::TaxTable(lookup), tax)
+    } else {
+        let brackets = source.worksheet(year, status)?;
+        let bracket = search_worksheet(&brackets, year, status, taxable_income)?;
+        let product = taxable_income as f64 * bracket.rate;
+        let unrounded_tax = product - bracket.subtraction_amount;
+        let step = WorksheetCalculation {
+            income_min: bracket.income_min,
+            income_max: bracket.income_max,
+            rate: bracket.rate,
+            product,
+            subtraction_amount: bracket.subtraction_amount,
+            unrounded_tax,
+        };
+        (
+            Calculation::Worksheet(step),
+            worksheet_tax(&bracket, taxable_income)?,
+        )
+    };
+
+    Ok(TaxComputation {
+        year,
+        status,
+        taxable_income,
+        calculation,
+        tax,
+        correction: source.correction(year, status, taxable_income),
+    })
+}
+
+/// The Tax Computation Worksheet section used for a filing status.
+fn worksheet_section(status: FilingStatus) -> &'static str {
+    match status {
+        FilingStatus::Single => "Section A",
+        FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse => "Section B",
+        FilingStatus::MarriedFilingSeparately => "Section C",
+        FilingStatus::HeadOfHousehold => "Section D",
+    }
+}
+
+/// The Tax Table column heading used for a filing status.
+fn tax_table_column(status: FilingStatus) -> &'static str {
+    match status {
+        FilingStatus::Single => "Single",
+        FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse => {
+            "Married filing jointly"
+        }
+        FilingStatus::MarriedFilingSeparately => "Married filing separately",
+        FilingStatus::HeadOfHousehold => "Head of a household",
+    }
+}
+
+/// Format whole dollars with thousands separators, e.g. `75,050`.
+fn format_dollars(amount: i64) -> String {
+    let sign = if amount < 0 { "-" } else { "" };
+    format!("{sign}{}", group_thousands(amount.unsigned_abs()))
+}
+
+/// Format an amount with cents and thousands separators, e.g. `6,957.50`.
+fn format_cents(amount: f64) -> String {
+    let cents = (amount * 100.0).round() as i64;
+    let sign = if cents < 0 { "-" } else { "" };
+    let cents = cents.abs();
+    format!("{sign}{}.{:02}", format_dollars(cents / 100), cents % 100)
+}
+
+/// Format a worksheet rate the way the instructions print it, e.g. `24% (0.24)`.
+fn format_rate(rate: f64) -> String {
+    format!("{}% ({rate:.2})", (rate * 100.0).round())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::compute_tax;
+
+    #[cfg(feature = "year-2023")]
+    #[test]
+    fn table_lookup_records_row() {
+        let detail =
+            compute_tax_detailed(TaxYear::Y2023, FilingStatus::HeadOfHousehold, 75_000).unwrap();
+        assert_eq!(detail.method(), Method::TaxTable);
+        assert_eq!(
+            detail.calculation,
+            Calculation::TaxTable(TableLookup {
+                income_min: 75_000,
+                income_max: 75_050,
+                tax: 10_207,
+            })
+        );
+        assert_eq!(detail.tax, 10_207);
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn zero_income_uses_first_row() {
+        let detail = compute_tax_detailed(TaxYear::Y2025, FilingStatus::Single, 0).unwrap();
+        assert_eq!(detail.tax, 0);
+        assert_eq!(detail.method(), Method::TaxTable);
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn negative_income() {
+        assert_eq!(
+            compute_tax_detailed(TaxYear::Y2025, FilingStatus::Single, -1),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn explain_worksheet_columns() {
+        let detail =
+            compute_tax_detailed(TaxYear::Y2025, FilingStatus::HeadOfHousehold, 300_000).unwrap();
+        let labels: Vec<&str> = detail.explain().iter().map(|step| step.label).collect();
+        assert_eq!(labels, ["Section D", "(a)", "(b)", "(c)", "(d)", "Tax"]);
+        let text = detail.to_string();
+        assert!(text.contains("Taxable income over 250,500 but not over 626,350"));
+        assert!(text.contains("Multiplication amount: 35% (0.35)"));
+        assert!(text.contains("Subtraction amount: 32,191.00"));
+        assert!(text.contains("Subtract (d) from (c): 72,809.00, rounded to 72,809"));
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn explain_tax_table_row() {
+        let detail =
+            compute_tax_detailed(TaxYear::Y2024, FilingStatus::MarriedFilingJointly, 75_000)
+                .unwrap();
+        let steps = detail.explain();
+        assert_eq!(steps[1].text, "At least 75,000 but less than 75,050");
+        assert_eq!(steps[2].text, "Married filing jointly column: 8,539");
+    }
+
+    #[test]
+    fn format_helpers() {
+        assert_eq!(format_dollars(0), "0");
+        assert_eq!(format_dollars(999), "999");
+        assert_eq!(format_dollars(1_000), "1,000");
+        assert_eq!(format_dollars(1_234_567), "1,234,567");
+        assert_eq!(format_dollars(-50_000), "-50,000");
+        assert_eq!(format_cents(327_020.25), "327,020.25");
+        assert_eq!(format_rate(0.37), "37% (0.37)");
+    }
+
+    #[test]
+    fn agrees_with_compute_tax() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for income in [1, 49_999, 99_999, 100_000, 250_000, 731_200, 5_000_000] {
+                    let detail = compute_tax_detailed(year, status, income).unwrap();
+                    assert_eq!(detail.tax, compute_tax(year, status, income).unwrap());
+                    assert_eq!(detail.taxable_income, income);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Income tax on estates and trusts.
+
+use crate::compute::income_to_i64;
+use crate::data;
+use crate::schedule::TaxBracket;
+use crate::types::{TaxError, TaxYear};
+
+/// The rate schedule for estates and non-grantor trusts in `year`, from the
+/// Form 1041 instructions, in ascending order.
+///
+/// The brackets are the same rates as for individuals but compressed, so
+/// the 37% rate applies above about $15,000 of taxable income. The
+/// thresholds are inflation-adjusted each year along with the individual
+/// brackets. Grantor trusts are taxed to their grantor instead.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{trust_brackets, TaxYear};
+///
+/// let brackets = trust_brackets(TaxYear::Y2025);
+/// assert_eq!(brackets.len(), 4);
+/// assert_eq!(brackets[3].income_min, 15_650);
+/// assert_eq!(brackets[3].rate, 0.37);
+/// # }
+/// ```
+pub fn trust_brackets(year: TaxYear) -> &'static [TaxBracket] {
+    data::trust_rate_schedule(year)
+}
+
+/// Compute the income tax on an estate or trust with `taxable_income` in
+/// `year` (Form 1041, Schedule G, line 1a), rounded to whole dollars.
+///
+/// There is no Tax Table for estates and trusts, so the tax is the
+/// [`trust_brackets`] applied exactly, with 50 cents rounding up. Capital
+/// gain rates and the net investment income tax are not applied.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `taxable_income` is negative and
+/// [`TaxError::IncomeOutOfRange`] if it does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{compute_trust_tax, TaxYear};
+///
+/// // $310 + $1,932 + $1,417.50 + $1,776
+/// assert_eq!(compute_trust_tax(TaxYear::Y2024, 20_000), Ok(5_436));
+/// # }
+/// ```
+pub fn compute_trust_tax(
+    year: TaxYear,
+    taxable_income: impl TryInto<i64>,
+) -> Result<i64, TaxError> {
+    let taxable_income = income_to_i64(taxable_income)?;
+    if taxable_income < 0 {
+        return Err(TaxError::NegativeIncome);
+    }
+    // Whole dollars times whole percentages are whole cents.
+    let cents: i128 = trust_brackets(year)
+        .iter()
+        .filter(|bracket| bracket.income_min < taxable_income)
+        .map(|bracket| {
+            let top = bracket
+                .income_max
+                .map_or(taxable_income, |max| max.min(taxable_income));
+            i128::from(top - bracket.income_min) * (bracket.rate * 100.0).round() as i128
+        })
+        .sum();
+    i64::try_from((cents + 50) / 100).map_err(|_| TaxError::IncomeOutOfRange)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_bracket_thresholds() {
+        let thresholds = TaxYear::ALL.map(|year| trust_brackets(year)[3].income_min);
+        assert_eq!(
+            thresholds,
+            TaxYear::select([
+                12_500, 12_750, 12_950, 13_050, 13_450, 14_450, 15_200, 15_650
+            ])
+        );
+        for year in TaxYear::ALL {
+            let rates: Vec<f64> = trust_brackets(year).iter().map(|b| b.rate).collect();
+            assert_eq!(rates, [0.10, 0.24, 0.35, 0.37], "{year:?}");
+        }
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn tax_at_thresholds() {
+        let year = TaxYear::Y2025;
+        assert_eq!(compute_trust_tax(year, 0), Ok(0));
+        assert_eq!(compute_trust_tax(year, 3_150), Ok(315));
+        assert_eq!(compute_trust_tax(year, 11_450), Ok(315 + 1_992));
+        assert_eq!(compute_trust_tax(year, 15_650), Ok(315 + 1_992 + 1_470));
+        assert_eq!(
+            compute_trust_tax(year, 115_650),
+            Ok(315 + 1_992 + 1_470 + 37_000)
+        );
+        // Half dollars round up.
+        assert_eq!(compute_trust_tax(year, 5), Ok(1));
+        assert_eq!(compute_trust_tax(year, 4), Ok(0));
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn errors() {
+        assert_eq!(
+            compute_trust_tax(TaxYear::Y2025, -1),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            compute_trust_tax(TaxYear::Y2025, u64::MAX),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
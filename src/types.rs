@@ -1,39 +1,181 @@
 //! Public types: tax year, filing status, and error definitions.
 
 use std::fmt;
+use std::str::FromStr;
+
+use crate::validate::DataIssue;
 
 /// A tax year supported by this crate.
 ///
 /// Each variant corresponds to a set of IRS tax tables and computation
 /// worksheet brackets embedded in the crate. New variants are added as
 /// the IRS publishes updated instructions each year.
+///
+/// Each variant exists only when its `year-<year>` Cargo feature is enabled.
+/// All years are enabled by default; disable default features and pick
+/// years to leave the other years' data out of the binary.
+///
+/// With the `serde` feature, a tax year serializes as its four-digit number
+/// (e.g. `2024`).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u16", into = "u16")
+)]
 pub enum TaxYear {
+    /// Tax year 2018 (filed in 2019).
+    #[cfg(feature = "year-2018")]
+    Y2018,
+    /// Tax year 2019 (filed in 2020).
+    #[cfg(feature = "year-2019")]
+    Y2019,
+    /// Tax year 2020 (filed in 2021).
+    #[cfg(feature = "year-2020")]
+    Y2020,
+    /// Tax year 2021 (filed in 2022).
+    #[cfg(feature = "year-2021")]
+    Y2021,
+    /// Tax year 2022 (filed in 2023).
+    #[cfg(feature = "year-2022")]
+    Y2022,
     /// Tax year 2023 (filed in 2024).
+    #[cfg(feature = "year-2023")]
     Y2023,
     /// Tax year 2024 (filed in 2025).
+    #[cfg(feature = "year-2024")]
     Y2024,
     /// Tax year 2025 (filed in 2026).
+    #[cfg(feature = "year-2025")]
     Y2025,
 }
 
-impl fmt::Display for TaxYear {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// The number of tax years enabled by Cargo features.
+const YEAR_COUNT: usize = cfg!(feature = "year-2018") as usize
+    + cfg!(feature = "year-2019") as usize
+    + cfg!(feature = "year-2020") as usize
+    + cfg!(feature = "year-2021") as usize
+    + cfg!(feature = "year-2022") as usize
+    + cfg!(feature = "year-2023") as usize
+    + cfg!(feature = "year-2024") as usize
+    + cfg!(feature = "year-2025") as usize;
+
+impl TaxYear {
+    /// Every supported tax year, in ascending order.
+    pub const ALL: [TaxYear; YEAR_COUNT] = [
+        #[cfg(feature = "year-2018")]
+        TaxYear::Y2018,
+        #[cfg(feature = "year-2019")]
+        TaxYear::Y2019,
+        #[cfg(feature = "year-2020")]
+        TaxYear::Y2020,
+        #[cfg(feature = "year-2021")]
+        TaxYear::Y2021,
+        #[cfg(feature = "year-2022")]
+        TaxYear::Y2022,
+        #[cfg(feature = "year-2023")]
+        TaxYear::Y2023,
+        #[cfg(feature = "year-2024")]
+        TaxYear::Y2024,
+        #[cfg(feature = "year-2025")]
+        TaxYear::Y2025,
+    ];
+
+    /// The calendar year, usable in `const` contexts (unlike `u16::from`).
+    pub(crate) const fn calendar_year(self) -> u16 {
         match self {
-            TaxYear::Y2023 => write!(f, "2023"),
-            TaxYear::Y2024 => write!(f, "2024"),
-            TaxYear::Y2025 => write!(f, "2025"),
+            #[cfg(feature = "year-2018")]
+            TaxYear::Y2018 => 2018,
+            #[cfg(feature = "year-2019")]
+            TaxYear::Y2019 => 2019,
+            #[cfg(feature = "year-2020")]
+            TaxYear::Y2020 => 2020,
+            #[cfg(feature = "year-2021")]
+            TaxYear::Y2021 => 2021,
+            #[cfg(feature = "year-2022")]
+            TaxYear::Y2022 => 2022,
+            #[cfg(feature = "year-2023")]
+            TaxYear::Y2023 => 2023,
+            #[cfg(feature = "year-2024")]
+            TaxYear::Y2024 => 2024,
+            #[cfg(feature = "year-2025")]
+            TaxYear::Y2025 => 2025,
         }
     }
 }
 
+#[cfg(test)]
+impl TaxYear {
+    /// The entries of `per_year`, which has one for each year from 2018
+    /// through 2025, for the years enabled by Cargo features.
+    pub(crate) fn select<T: Copy>(per_year: [T; 8]) -> [T; YEAR_COUNT] {
+        TaxYear::ALL.map(|year| per_year[usize::from(u16::from(year) - 2018)])
+    }
+}
+
+impl fmt::Display for TaxYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", u16::from(*self))
+    }
+}
+
+impl From<TaxYear> for u16 {
+    fn from(year: TaxYear) -> u16 {
+        year.calendar_year()
+    }
+}
+
+impl TryFrom<u16> for TaxYear {
+    type Error = TaxError;
+
+    /// Convert a calendar year such as `2024` into a [`TaxYear`].
+    ///
+    /// Returns [`TaxError::UnsupportedYear`] if the crate has no data for it.
+    fn try_from(year: u16) -> Result<Self, Self::Error> {
+        TaxYear::ALL
+            .into_iter()
+            .find(|&y| u16::from(y) == year)
+            .ok_or(TaxError::UnsupportedYear(year))
+    }
+}
+
+impl FromStr for TaxYear {
+    type Err = TaxError;
+
+    /// Parse a tax year from its four-digit form, e.g. `"2024"`.
+    ///
+    /// This is the inverse of the [`Display`](fmt::Display) impl. Returns
+    /// [`TaxError::InvalidYear`] if the input is not a number and
+    /// [`TaxError::UnsupportedYear`] if the year has no embedded data.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year: u16 = s
+            .trim()
+            .parse()
+            .map_err(|_| TaxError::InvalidYear(s.to_string()))?;
+        TaxYear::try_from(year)
+    }
+}
+
 /// IRS filing status for Form 1040.
 ///
 /// Filing status determines tax rates, standard deduction amounts, and eligibility
 /// for certain credits and deductions.
 ///
 /// See: <https://www.irs.gov/publications/p501#en_US_2024_publink1000220721>
+///
+/// With the `serde` feature, a filing status serializes in `snake_case`
+/// (e.g. `"married_filing_jointly"`), matching the embedded CSV column names.
+/// With the `python` feature, it is exposed to Python as an enum class.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, frozen, hash, from_py_object, module = "us_tax_brackets")
+)]
 pub enum FilingStatus {
     /// Unmarried or legally separated/divorced on the last day of the tax year,
     /// and not qualifying for another filing status.
@@ -56,6 +198,17 @@ pub enum FilingStatus {
     QualifyingSurvivingSpouse,
 }
 
+impl FilingStatus {
+    /// Every filing status, in the order they appear on Form 1040.
+    pub const ALL: [FilingStatus; 5] = [
+        FilingStatus::Single,
+        FilingStatus::MarriedFilingJointly,
+        FilingStatus::MarriedFilingSeparately,
+        FilingStatus::HeadOfHousehold,
+        FilingStatus::QualifyingSurvivingSpouse,
+    ];
+}
+
 impl fmt::Display for FilingStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -70,25 +223,265 @@ impl fmt::Display for FilingStatus {
     }
 }
 
+impl FromStr for FilingStatus {
+    type Err = TaxError;
+
+    /// Parse a filing status from its full name or a common abbreviation.
+    ///
+    /// Matching is case-insensitive and treats spaces, hyphens, and
+    /// underscores alike, so `"Married Filing Jointly"`, `"married_filing_jointly"`,
+    /// and `"MFJ"` all parse. Accepted abbreviations are `s`, `mfj`, `mfs`,
+    /// `hoh`, and `qss` (or the former `qw` for qualifying widow(er)).
+    ///
+    /// Returns [`TaxError::InvalidFilingStatus`] for unrecognized input.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s
+            .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        match normalized.as_str() {
+            "single" | "s" => Ok(FilingStatus::Single),
+            "married filing jointly" | "married jointly" | "joint" | "mfj" => {
+                Ok(FilingStatus::MarriedFilingJointly)
+            }
+            "married filing separately" | "married separately" | "separate" | "mfs" => {
+                Ok(FilingStatus::MarriedFilingSeparately)
+            }
+            "head of household" | "hoh" => Ok(FilingStatus::HeadOfHousehold),
+            "qualifying surviving spouse"
+            | "qualifying widow"
+            | "qualifying widower"
+            | "qualifying widow(er)"
+            | "qss"
+            | "qw" => Ok(FilingStatus::QualifyingSurvivingSpouse),
+            _ => Err(TaxError::InvalidFilingStatus(s.to_string())),
+        }
+    }
+}
+
+/// The IRS method used to compute a tax amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Method {
+    /// Looked up in the Tax Table (taxable income under $100,000).
+    TaxTable,
+    /// Computed with the Tax Computation Worksheet (taxable income of
+    /// $100,000 or more).
+    Worksheet,
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Method::TaxTable => write!(f, "Tax Table"),
+            Method::Worksheet => write!(f, "Tax Computation Worksheet"),
+        }
+    }
+}
+
 /// Errors that can occur during tax computation.
+///
+/// New variants may be added in minor releases, so a `match` on this type
+/// needs a wildcard arm.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum TaxError {
     /// The provided taxable income was negative.
     NegativeIncome,
+    /// The provided taxable income does not fit in an `i64`.
+    IncomeOutOfRange,
     /// No matching tax bracket was found for the given income.
     ///
     /// This should not occur under normal usage and may indicate corrupted
     /// embedded data.
-    NoBracketFound,
+    NoBracketFound {
+        /// The tax year whose data was searched.
+        year: TaxYear,
+        /// The filing status whose brackets were searched.
+        status: FilingStatus,
+        /// The taxable income (in whole dollars) that matched no bracket.
+        taxable_income: i64,
+    },
+    /// A row of a tax data CSV file could not be parsed.
+    MalformedData {
+        /// Path of the offending file, e.g. `data/2024/tax_table.csv`.
+        file: String,
+        /// 1-based line number of the offending row (the header is line 1).
+        line: usize,
+    },
+    /// The requested tax year is not embedded in this crate.
+    UnsupportedYear(u16),
+    /// The input could not be parsed as a tax year.
+    InvalidYear(String),
+    /// The input could not be parsed as a filing status.
+    InvalidFilingStatus(String),
+    /// The input could not be parsed as a dollar amount.
+    InvalidAmount(String),
+    /// The tax data parsed but failed the consistency checks of
+    /// [`validate_data`](crate::validate_data).
+    InvalidData(Vec<DataIssue>),
+    /// A custom [`TaxSchedule`](crate::TaxSchedule) has invalid brackets.
+    InvalidSchedule(String),
+    /// A tax data file could not be read from disk.
+    UnreadableData {
+        /// Path of the file that could not be read.
+        file: String,
+        /// The underlying I/O error message.
+        message: String,
+    },
 }
 
 impl fmt::Display for TaxError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             TaxError::NegativeIncome => write!(f, "taxable income cannot be negative"),
-            TaxError::NoBracketFound => write!(f, "no matching tax bracket found"),
+            TaxError::IncomeOutOfRange => write!(f, "taxable income is out of range"),
+            TaxError::NoBracketFound {
+                year,
+                status,
+                taxable_income,
+            } => write!(
+                f,
+                "no matching tax bracket found for taxable income {taxable_income} \
+                 ({year}, {status})"
+            ),
+            TaxError::MalformedData { file, line } => {
+                write!(f, "malformed tax data in {file} at line {line}")
+            }
+            TaxError::UnsupportedYear(year) => write!(f, "tax year {year} is not supported"),
+            TaxError::InvalidYear(input) => write!(f, "invalid tax year: {input:?}"),
+            TaxError::InvalidFilingStatus(input) => write!(f, "invalid filing status: {input:?}"),
+            TaxError::InvalidAmount(input) => write!(f, "invalid dollar amount: {input:?}"),
+            TaxError::InvalidData(issues) => match issues.as_slice() {
+                [] => write!(f, "tax data failed validation"),
+                [issue] => write!(f, "tax data failed validation: {issue}"),
+                [issue, rest @ ..] => write!(
+                    f,
+                    "tax data failed validation: {issue} (and {} more)",
+                    rest.len()
+                ),
+            },
+            TaxError::InvalidSchedule(message) => write!(f, "invalid tax schedule: {message}"),
+            TaxError::UnreadableData { file, message } => {
+                write!(f, "cannot read tax data file {file}: {message}")
+            }
         }
     }
 }
 
 impl std::error::Error for TaxError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tax_year_display_round_trip() {
+        for year in TaxYear::ALL {
+            assert_eq!(year.to_string().parse::<TaxYear>(), Ok(year));
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn tax_year_try_from_u16() {
+        assert_eq!(TaxYear::try_from(2024), Ok(TaxYear::Y2024));
+        assert_eq!(
+            TaxYear::try_from(2017),
+            Err(TaxError::UnsupportedYear(2017))
+        );
+    }
+
+    #[cfg(feature = "year-2023")]
+    #[test]
+    fn tax_year_from_str_errors() {
+        assert_eq!(" 2023 ".parse::<TaxYear>(), Ok(TaxYear::Y2023));
+        assert_eq!(
+            "20x4".parse::<TaxYear>(),
+            Err(TaxError::InvalidYear("20x4".to_string()))
+        );
+        assert_eq!(
+            "2099".parse::<TaxYear>(),
+            Err(TaxError::UnsupportedYear(2099))
+        );
+        assert_eq!(
+            TaxError::UnsupportedYear(2099).to_string(),
+            "tax year 2099 is not supported"
+        );
+    }
+
+    #[test]
+    fn filing_status_display_round_trip() {
+        for status in FilingStatus::ALL {
+            assert_eq!(status.to_string().parse::<FilingStatus>(), Ok(status));
+        }
+    }
+
+    #[test]
+    fn filing_status_aliases() {
+        assert_eq!("mfj".parse(), Ok(FilingStatus::MarriedFilingJointly));
+        assert_eq!("MFS".parse(), Ok(FilingStatus::MarriedFilingSeparately));
+        assert_eq!(
+            "  Head-of-Household ".parse(),
+            Ok(FilingStatus::HeadOfHousehold)
+        );
+        assert_eq!(
+            "married_filing_jointly".parse(),
+            Ok(FilingStatus::MarriedFilingJointly)
+        );
+        assert_eq!("QW".parse(), Ok(FilingStatus::QualifyingSurvivingSpouse));
+        assert_eq!("single".parse(), Ok(FilingStatus::Single));
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn error_messages_carry_context() {
+        let error = TaxError::NoBracketFound {
+            year: TaxYear::Y2024,
+            status: FilingStatus::HeadOfHousehold,
+            taxable_income: 123_456,
+        };
+        assert_eq!(
+            error.to_string(),
+            "no matching tax bracket found for taxable income 123456 (2024, Head of Household)"
+        );
+        let error = TaxError::MalformedData {
+            file: "data/2024/tax_table.csv".to_string(),
+            line: 17,
+        };
+        assert_eq!(
+            error.to_string(),
+            "malformed tax data in data/2024/tax_table.csv at line 17"
+        );
+    }
+
+    #[test]
+    fn filing_status_invalid() {
+        assert_eq!(
+            "married".parse::<FilingStatus>(),
+            Err(TaxError::InvalidFilingStatus("married".to_string()))
+        );
+    }
+
+    #[cfg(all(feature = "year-2024", feature = "serde"))]
+    #[test]
+    fn serde_round_trip() {
+        let json = serde_json::to_string(&(TaxYear::Y2024, FilingStatus::HeadOfHousehold)).unwrap();
+        assert_eq!(json, r#"[2024,"head_of_household"]"#);
+        let parsed: (TaxYear, FilingStatus) = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, (TaxYear::Y2024, FilingStatus::HeadOfHousehold));
+
+        assert!(serde_json::from_str::<TaxYear>("2017").is_err());
+
+        let error = TaxError::UnsupportedYear(2017);
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(serde_json::from_str::<TaxError>(&json).unwrap(), error);
+    }
+}
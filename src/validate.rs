@@ -0,0 +1,616 @@
+//! Consistency checks for tax data files.
+
+use std::fmt;
+
+use crate::compute::{WORKSHEET_THRESHOLD, worksheet_tax};
+use crate::data::{self, CsvFile};
+use crate::schedule::TaxBracket;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// A problem found in a tax data file by [`validate_data`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataIssue {
+    /// A row could not be parsed. Further checks on the file are skipped.
+    Malformed {
+        /// Path of the file, e.g. `data/2024/tax_table.csv`.
+        file: String,
+        /// 1-based line number of the row.
+        line: usize,
+    },
+    /// A row's upper bound is not above its lower bound.
+    InvalidRange {
+        /// Path of the file.
+        file: String,
+        /// 1-based line number of the row.
+        line: usize,
+    },
+    /// A row starts below the row before it.
+    Unsorted {
+        /// Path of the file.
+        file: String,
+        /// 1-based line number of the out-of-order row.
+        line: usize,
+    },
+    /// A row starts before the row above it ends.
+    Overlap {
+        /// Path of the file.
+        file: String,
+        /// 1-based line number of the overlapping row.
+        line: usize,
+    },
+    /// No row covers incomes from `from` up to `to` ([`None`] meaning no upper
+    /// limit).
+    Gap {
+        /// Path of the file.
+        file: String,
+        /// 1-based line number of the row next to the gap.
+        line: usize,
+        /// First uncovered income.
+        from: i64,
+        /// End of the uncovered range, or [`None`] if it is unbounded.
+        to: Option<i64>,
+    },
+    /// The last Tax Table row and the worksheet at $100,000 disagree by more
+    /// than the width of one $50 table band allows.
+    BoundaryMismatch {
+        /// The filing status whose columns disagree.
+        status: FilingStatus,
+        /// Tax from the last Tax Table row ($99,950–$100,000).
+        table_tax: i64,
+        /// Tax from the worksheet at exactly $100,000.
+        worksheet_tax: i64,
+    },
+    /// A Tax Table amount differs from the rate schedule applied to the
+    /// midpoint of the row's band.
+    TableMismatch {
+        /// Path of the file.
+        file: String,
+        /// 1-based line number of the row.
+        line: usize,
+        /// The filing status whose column is wrong.
+        status: FilingStatus,
+        /// Tax in the row.
+        table_tax: i64,
+        /// Tax the rate schedule gives at the band's midpoint.
+        expected_tax: i64,
+    },
+    /// A Tax Rate Schedule threshold differs from the statutory bracket
+    /// adjusted by the year's cost-of-living factor. See
+    /// [`check_inflation_adjustment`](crate::check_inflation_adjustment).
+    InflationMismatch {
+        /// The filing status whose schedule is wrong.
+        status: FilingStatus,
+        /// Threshold in the rate schedule.
+        threshold: i64,
+        /// Threshold the adjusted statutory bracket gives.
+        expected: i64,
+    },
+}
+
+impl fmt::Display for DataIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataIssue::Malformed { file, line } => write!(f, "{file}:{line}: malformed row"),
+            DataIssue::InvalidRange { file, line } => {
+                write!(f, "{file}:{line}: upper bound is not above lower bound")
+            }
+            DataIssue::Unsorted { file, line } => {
+                write!(f, "{file}:{line}: row starts below the previous row")
+            }
+            DataIssue::Overlap { file, line } => {
+                write!(f, "{file}:{line}: row overlaps the previous row")
+            }
+            DataIssue::Gap {
+                file,
+                line,
+                from,
+                to: Some(to),
+            } => write!(f, "{file}:{line}: no row covers {from} to {to}"),
+            DataIssue::Gap {
+                file,
+                line,
+                from,
+                to: None,
+            } => write!(f, "{file}:{line}: no row covers {from} and above"),
+            DataIssue::BoundaryMismatch {
+                status,
+                table_tax,
+                worksheet_tax,
+            } => write!(
+                f,
+                "{status}: Tax Table ends at {table_tax} but the worksheet gives \
+                 {worksheet_tax} at {WORKSHEET_THRESHOLD}"
+            ),
+            DataIssue::TableMismatch {
+                file,
+                line,
+                status,
+                table_tax,
+                expected_tax,
+            } => write!(
+                f,
+                "{file}:{line}: {status} tax is {table_tax} but the rate schedule gives \
+                 {expected_tax} at the band midpoint"
+            ),
+            DataIssue::InflationMismatch {
+                status,
+                threshold,
+                expected,
+            } => write!(
+                f,
+                "{status}: rate schedule threshold {threshold} should be {expected} from the \
+                 inflation-adjusted statutory brackets"
+            ),
+        }
+    }
+}
+
+/// Check the embedded data for a tax year and report every problem found.
+///
+/// The Tax Table, Tax Computation Worksheet, and Tax Rate Schedules are each
+/// checked for malformed rows, inverted ranges, unsorted rows, overlaps, and
+/// gaps in coverage. The table must cover $0 up to $100,000, the worksheet
+/// $100,000 and up, and the rate schedules every income from $0.
+///
+/// The last Tax Table row is also compared with the worksheet at $100,000.
+/// The table evaluates the rate schedule at each $50 band's midpoint, so the
+/// worksheet amount may exceed the table amount by at most the bracket rate
+/// times $50. Any other difference is reported as a
+/// [`DataIssue::BoundaryMismatch`]. Every Tax Table amount must also equal
+/// the rate schedule applied to the midpoint of its band, rounded to the
+/// nearest dollar, or it is reported as a [`DataIssue::TableMismatch`].
+/// Together these catch transcription errors in the table.
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::{validate_data, TaxYear};
+///
+/// for year in TaxYear::ALL {
+///     assert_eq!(validate_data(year), Ok(()));
+/// }
+/// ```
+pub fn validate_data(year: TaxYear) -> Result<(), Vec<DataIssue>> {
+    let (table, worksheet) = data::csv_for_year(year);
+    let schedule = data::rate_schedule_csv_for_year(year);
+    let issues = validate_files(&table, &worksheet, &schedule);
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(issues)
+    }
+}
+
+/// The filing statuses with their own columns or sections in the data files.
+pub(crate) const CSV_STATUSES: [FilingStatus; 4] = [
+    FilingStatus::Single,
+    FilingStatus::MarriedFilingJointly,
+    FilingStatus::MarriedFilingSeparately,
+    FilingStatus::HeadOfHousehold,
+];
+
+/// Run every check against one year's three data files.
+pub(crate) fn validate_files(
+    table: &CsvFile<'_>,
+    worksheet: &CsvFile<'_>,
+    schedule: &CsvFile<'_>,
+) -> Vec<DataIssue> {
+    let mut issues = Vec::new();
+
+    let table_rows = match data::parse_tax_table_lines(table) {
+        Ok(rows) => {
+            check_table(table.path, &rows, &mut issues);
+            rows
+        }
+        Err(error) => {
+            issues.push(malformed(error));
+            Vec::new()
+        }
+    };
+
+    for status in CSV_STATUSES {
+        match data::parse_worksheet_lines(worksheet, status) {
+            Ok(brackets) => {
+                check_worksheet(worksheet.path, status, &brackets, &table_rows, &mut issues);
+            }
+            Err(error) => {
+                issues.push(malformed(error));
+                break;
+            }
+        }
+    }
+
+    for status in CSV_STATUSES {
+        match data::parse_rate_schedule_lines(schedule, status) {
+            Ok(brackets) => {
+                check_schedule(schedule.path, &brackets, &mut issues);
+                check_table_amounts(table.path, status, &table_rows, &brackets, &mut issues);
+            }
+            Err(error) => {
+                issues.push(malformed(error));
+                break;
+            }
+        }
+    }
+
+    issues
+}
+
+/// Check that Tax Table rows, paired with their 1-based line numbers in
+/// `file`, cover $0 up to $100,000.
+pub(crate) fn check_table(
+    file: &str,
+    rows: &[(usize, data::TaxTableRow)],
+    issues: &mut Vec<DataIssue>,
+) {
+    let ranges: Vec<_> = rows
+        .iter()
+        .map(|(line, row)| (*line, row.income_min, Some(row.income_max)))
+        .collect();
+    check_coverage(file, &ranges, 0, Some(WORKSHEET_THRESHOLD), issues);
+}
+
+/// Check that one filing status's worksheet brackets cover $100,000 and up
+/// and agree with the last Tax Table row.
+pub(crate) fn check_worksheet(
+    file: &str,
+    status: FilingStatus,
+    brackets: &[(usize, data::WorksheetBracket)],
+    table_rows: &[(usize, data::TaxTableRow)],
+    issues: &mut Vec<DataIssue>,
+) {
+    let ranges: Vec<_> = brackets
+        .iter()
+        .map(|(line, bracket)| (*line, bracket.income_min, bracket.income_max))
+        .collect();
+    check_coverage(file, &ranges, WORKSHEET_THRESHOLD, None, issues);
+    check_boundary(status, table_rows, brackets, issues);
+}
+
+/// Check that one filing status's rate schedule brackets cover every income
+/// from $0.
+pub(crate) fn check_schedule(
+    file: &str,
+    brackets: &[(usize, TaxBracket)],
+    issues: &mut Vec<DataIssue>,
+) {
+    let ranges: Vec<_> = brackets
+        .iter()
+        .map(|(line, bracket)| (*line, bracket.income_min, bracket.income_max))
+        .collect();
+    check_coverage(file, &ranges, 0, None, issues);
+}
+
+/// Check that each Tax Table row in `file` holds the tax the rate schedule
+/// gives at the midpoint of the row's band, rounded to the nearest dollar
+/// with 50 cents rounding up.
+///
+/// The check is skipped when the brackets do not cover every income, since
+/// their coverage issues are already reported.
+pub(crate) fn check_table_amounts(
+    file: &str,
+    status: FilingStatus,
+    table_rows: &[(usize, data::TaxTableRow)],
+    brackets: &[(usize, TaxBracket)],
+    issues: &mut Vec<DataIssue>,
+) {
+    let mut coverage = Vec::new();
+    check_schedule("", brackets, &mut coverage);
+    if !coverage.is_empty() {
+        return;
+    }
+
+    for (line, row) in table_rows {
+        let expected_tax = midpoint_tax(
+            row.income_min,
+            row.income_max,
+            brackets.iter().map(|(_, bracket)| bracket),
+        );
+        let table_tax = row.tax_for(status);
+        if table_tax != expected_tax {
+            issues.push(DataIssue::TableMismatch {
+                file: file.to_string(),
+                line: *line,
+                status,
+                table_tax,
+                expected_tax,
+            });
+        }
+    }
+}
+
+/// The tax `brackets` charge at the midpoint of `income_min` to
+/// `income_max`, rounded to whole dollars with 50 cents rounding up, as the
+/// IRS computes each Tax Table amount.
+pub(crate) fn midpoint_tax<'a>(
+    income_min: i64,
+    income_max: i64,
+    brackets: impl IntoIterator<Item = &'a TaxBracket>,
+) -> i64 {
+    // Twice the midpoint, to stay in whole numbers for odd-width bands.
+    let doubled = income_min + income_max;
+    // The tax in units of 1/20,000 dollar: half-dollars times basis points.
+    let mut scaled = 0;
+    for bracket in brackets {
+        let bottom = 2 * bracket.income_min;
+        if doubled <= bottom {
+            break;
+        }
+        let top = bracket
+            .income_max
+            .map_or(doubled, |max| doubled.min(2 * max));
+        scaled += (top - bottom) * (bracket.rate * 10_000.0).round() as i64;
+    }
+    (scaled + 10_000) / 20_000
+}
+
+/// Convert a parse error into a [`DataIssue::Malformed`].
+fn malformed(error: TaxError) -> DataIssue {
+    match error {
+        TaxError::MalformedData { file, line } => DataIssue::Malformed { file, line },
+        other => unreachable!("CSV parsing only fails with MalformedData, got {other:?}"),
+    }
+}
+
+/// Check that `(line, min, max)` ranges are valid, sorted, and contiguous,
+/// covering `start` up to `end` ([`None`] meaning unbounded).
+fn check_coverage(
+    file: &str,
+    ranges: &[(usize, i64, Option<i64>)],
+    start: i64,
+    end: Option<i64>,
+    issues: &mut Vec<DataIssue>,
+) {
+    let file = || file.to_string();
+    let Some(&(first_line, first_min, _)) = ranges.first() else {
+        issues.push(DataIssue::Gap {
+            file: file(),
+            line: 1,
+            from: start,
+            to: end,
+        });
+        return;
+    };
+
+    if first_min > start {
+        issues.push(DataIssue::Gap {
+            file: file(),
+            line: first_line,
+            from: start,
+            to: Some(first_min),
+        });
+    }
+
+    for (index, &(line, min, max)) in ranges.iter().enumerate() {
+        if max.is_some_and(|max| max <= min) {
+            issues.push(DataIssue::InvalidRange { file: file(), line });
+        }
+        let Some(&(_, prev_min, prev_max)) = index.checked_sub(1).map(|i| &ranges[i]) else {
+            continue;
+        };
+        if min < prev_min {
+            issues.push(DataIssue::Unsorted { file: file(), line });
+        } else if prev_max.is_none_or(|prev_max| min < prev_max) {
+            issues.push(DataIssue::Overlap { file: file(), line });
+        } else if let Some(prev_max) = prev_max.filter(|&prev_max| min > prev_max) {
+            issues.push(DataIssue::Gap {
+                file: file(),
+                line,
+                from: prev_max,
+                to: Some(min),
+            });
+        }
+    }
+
+    let &(last_line, _, last_max) = ranges.last().expect("ranges is non-empty");
+    if let Some(last_max) = last_max
+        && end.is_none_or(|end| last_max < end)
+    {
+        issues.push(DataIssue::Gap {
+            file: file(),
+            line: last_line,
+            from: last_max,
+            to: end,
+        });
+    }
+}
+
+/// Compare the last Tax Table row with the worksheet at $100,000.
+fn check_boundary(
+    status: FilingStatus,
+    table_rows: &[(usize, data::TaxTableRow)],
+    brackets: &[(usize, data::WorksheetBracket)],
+    issues: &mut Vec<DataIssue>,
+) {
+    let income = WORKSHEET_THRESHOLD;
+    let Some((_, last_row)) = table_rows
+        .iter()
+        .find(|(_, row)| row.income_min < income && income <= row.income_max)
+    else {
+        return;
+    };
+    let Some((_, bracket)) = brackets.iter().find(|(_, bracket)| {
+        bracket.income_min <= income && bracket.income_max.is_none_or(|max| income <= max)
+    }) else {
+        return;
+    };
+
+    let table_tax = last_row.tax_for(status);
+    let Ok(worksheet_tax) = worksheet_tax(bracket, income) else {
+        return;
+    };
+    let tolerance = (50 * bracket.rate_basis_points + 9_999) / 10_000;
+    let difference = worksheet_tax - table_tax;
+    if !(0..=tolerance).contains(&difference) {
+        issues.push(DataIssue::BoundaryMismatch {
+            status,
+            table_tax,
+            worksheet_tax,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn csv(path: &'static str, contents: &'static str) -> CsvFile<'static> {
+        CsvFile { path, contents }
+    }
+
+    // A flat 10% tax, with each table row at its band's midpoint
+    const TABLE: &str = "header\n0,99950,4998,4998,4998,4998\n99950,100000,9998,9998,9998,9998\n";
+    const WORKSHEET: &str = "header\n\
+        single,100000,,0.10,0.0\n\
+        married_filing_jointly,100000,,0.10,0.0\n\
+        married_filing_separately,100000,,0.10,0.0\n\
+        head_of_household,100000,,0.10,0.0\n";
+    const SCHEDULE: &str = "header\n\
+        single,0,,0.10\n\
+        married_filing_jointly,0,,0.10\n\
+        married_filing_separately,0,,0.10\n\
+        head_of_household,0,,0.10\n";
+
+    #[test]
+    fn embedded_data_is_valid() {
+        for year in TaxYear::ALL {
+            assert_eq!(validate_data(year), Ok(()));
+        }
+    }
+
+    #[test]
+    fn minimal_files_are_valid() {
+        let issues = validate_files(
+            &csv("t.csv", TABLE),
+            &csv("w.csv", WORKSHEET),
+            &csv("s.csv", SCHEDULE),
+        );
+        assert_eq!(issues, []);
+    }
+
+    #[test]
+    fn table_gap_overlap_and_unsorted() {
+        let table = csv(
+            "t.csv",
+            "header\n0,50,3,3,3,3\n60,100,8,8,8,8\n90,200,15,15,15,15\n80,99950,5002,5002,5002,5002\n",
+        );
+        let issues = validate_files(&table, &csv("w.csv", WORKSHEET), &csv("s.csv", SCHEDULE));
+        assert_eq!(
+            issues,
+            [
+                DataIssue::Gap {
+                    file: "t.csv".to_string(),
+                    line: 3,
+                    from: 50,
+                    to: Some(60),
+                },
+                DataIssue::Overlap {
+                    file: "t.csv".to_string(),
+                    line: 4,
+                },
+                DataIssue::Unsorted {
+                    file: "t.csv".to_string(),
+                    line: 5,
+                },
+                DataIssue::Gap {
+                    file: "t.csv".to_string(),
+                    line: 5,
+                    from: 99_950,
+                    to: Some(100_000),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn bounded_top_bracket_is_a_gap() {
+        let schedule = csv(
+            "s.csv",
+            "header\n\
+             single,0,1000,0.10\n\
+             married_filing_jointly,0,,0.10\n\
+             married_filing_separately,0,,0.10\n\
+             head_of_household,0,,0.10\n",
+        );
+        let issues = validate_files(&csv("t.csv", TABLE), &csv("w.csv", WORKSHEET), &schedule);
+        assert_eq!(
+            issues,
+            [DataIssue::Gap {
+                file: "s.csv".to_string(),
+                line: 2,
+                from: 1000,
+                to: None,
+            }]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "s.csv:2: no row covers 1000 and above"
+        );
+    }
+
+    #[test]
+    fn boundary_mismatch() {
+        // A transcription error: single's last table amount is off by $100
+        let table = csv(
+            "t.csv",
+            "header\n0,99950,4998,4998,4998,4998\n99950,100000,9898,9998,9998,9998\n",
+        );
+        let issues = validate_files(&table, &csv("w.csv", WORKSHEET), &csv("s.csv", SCHEDULE));
+        assert_eq!(
+            issues,
+            [
+                DataIssue::BoundaryMismatch {
+                    status: FilingStatus::Single,
+                    table_tax: 9_898,
+                    worksheet_tax: 10_000,
+                },
+                DataIssue::TableMismatch {
+                    file: "t.csv".to_string(),
+                    line: 3,
+                    status: FilingStatus::Single,
+                    table_tax: 9_898,
+                    expected_tax: 9_998,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn table_amount_mismatch() {
+        // Off by a dollar mid-table, where the boundary check cannot see it
+        let table = csv(
+            "t.csv",
+            "header\n0,99950,4998,4998,4997,4998\n99950,100000,9998,9998,9998,9998\n",
+        );
+        let issues = validate_files(&table, &csv("w.csv", WORKSHEET), &csv("s.csv", SCHEDULE));
+        assert_eq!(
+            issues,
+            [DataIssue::TableMismatch {
+                file: "t.csv".to_string(),
+                line: 2,
+                status: FilingStatus::MarriedFilingSeparately,
+                table_tax: 4_997,
+                expected_tax: 4_998,
+            }]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "t.csv:2: Married Filing Separately tax is 4997 but the rate schedule gives 4998 \
+             at the band midpoint"
+        );
+    }
+
+    #[test]
+    fn malformed_row() {
+        let table = csv("t.csv", "header\n0,99950,4998,4998,4998\n");
+        let issues = validate_files(&table, &csv("w.csv", WORKSHEET), &csv("s.csv", SCHEDULE));
+        assert_eq!(
+            issues,
+            [DataIssue::Malformed {
+                file: "t.csv".to_string(),
+                line: 2,
+            }]
+        );
+    }
+}
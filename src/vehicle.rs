@@ -0,0 +1,153 @@
+//! Income limits for the clean vehicle credits.
+
+use crate::compute::income_to_i64;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The kind of clean vehicle bought.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum CleanVehicle {
+    /// A new clean vehicle (section 30D), with a credit of up to $7,500.
+    New,
+    /// A previously-owned clean vehicle (section 25E), with a credit of up
+    /// to $4,000.
+    Used,
+}
+
+/// The most modified adjusted gross income allowing the credit for a
+/// `vehicle` bought by someone with filing status `status`.
+///
+/// The limits are set by statute and not adjusted for inflation. Limits for
+/// a used vehicle are half those for a new one.
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::{clean_vehicle_income_limit, CleanVehicle, FilingStatus};
+///
+/// let status = FilingStatus::HeadOfHousehold;
+/// assert_eq!(clean_vehicle_income_limit(CleanVehicle::New, status), 225_000);
+/// assert_eq!(clean_vehicle_income_limit(CleanVehicle::Used, status), 112_500);
+/// ```
+pub const fn clean_vehicle_income_limit(vehicle: CleanVehicle, status: FilingStatus) -> i64 {
+    let new = match status {
+        FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse => 300_000,
+        FilingStatus::HeadOfHousehold => 225_000,
+        FilingStatus::Single | FilingStatus::MarriedFilingSeparately => 150_000,
+    };
+    match vehicle {
+        CleanVehicle::New => new,
+        CleanVehicle::Used => new / 2,
+    }
+}
+
+/// Whether someone who takes delivery of a `vehicle` in `year` meets the
+/// income test for the clean vehicle credit.
+///
+/// The test is met if either `magi` for `year` or `prior_year_magi` for the
+/// year before is at most the [`clean_vehicle_income_limit`] for `status`.
+/// The limits apply from 2023; before then the new vehicle credit had no
+/// income test and there was no credit for used vehicles. The credits end
+/// for vehicles acquired after September 30, 2025. The vehicle's price and
+/// other requirements are left to the caller.
+///
+/// # Errors
+///
+/// Returns [`TaxError::IncomeOutOfRange`] if `magi` or `prior_year_magi`
+/// does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{clean_vehicle_income_test, CleanVehicle, FilingStatus, TaxYear};
+///
+/// let (year, status) = (TaxYear::Y2024, FilingStatus::Single);
+/// let vehicle = CleanVehicle::New;
+/// // Income above the limit this year, but not last year
+/// assert_eq!(clean_vehicle_income_test(year, status, vehicle, 180_000, 140_000), Ok(true));
+/// assert_eq!(clean_vehicle_income_test(year, status, vehicle, 180_000, 160_000), Ok(false));
+/// # }
+/// ```
+pub fn clean_vehicle_income_test(
+    year: TaxYear,
+    status: FilingStatus,
+    vehicle: CleanVehicle,
+    magi: impl TryInto<i64>,
+    prior_year_magi: impl TryInto<i64>,
+) -> Result<bool, TaxError> {
+    let magi = income_to_i64(magi)?;
+    let prior_year_magi = income_to_i64(prior_year_magi)?;
+    if u16::from(year) < 2023 {
+        return Ok(vehicle == CleanVehicle::New);
+    }
+    Ok(magi.min(prior_year_magi) <= clean_vehicle_income_limit(vehicle, status))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits() {
+        let limits = FilingStatus::ALL.map(|status| {
+            (
+                clean_vehicle_income_limit(CleanVehicle::New, status),
+                clean_vehicle_income_limit(CleanVehicle::Used, status),
+            )
+        });
+        assert!(limits.contains(&(300_000, 150_000)));
+        assert!(limits.contains(&(225_000, 112_500)));
+        assert!(limits.contains(&(150_000, 75_000)));
+        assert_eq!(
+            clean_vehicle_income_limit(CleanVehicle::New, FilingStatus::QualifyingSurvivingSpouse),
+            300_000
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn lesser_of_two_years() {
+        let year = TaxYear::Y2025;
+        let joint = FilingStatus::MarriedFilingJointly;
+        let test = |vehicle, magi, prior| {
+            clean_vehicle_income_test(year, joint, vehicle, magi, prior).unwrap()
+        };
+        assert!(test(CleanVehicle::New, 300_000, 300_000));
+        assert!(!test(CleanVehicle::New, 300_001, 300_001));
+        assert!(test(CleanVehicle::New, 300_001, 250_000));
+        assert!(test(CleanVehicle::New, 250_000, 400_000));
+        assert!(!test(CleanVehicle::Used, 200_000, 160_000));
+        assert!(test(CleanVehicle::Used, 200_000, 150_000));
+    }
+
+    #[cfg(feature = "year-2022")]
+    #[test]
+    fn before_2023() {
+        let single = FilingStatus::Single;
+        let test = |vehicle| {
+            clean_vehicle_income_test(TaxYear::Y2022, single, vehicle, 1_000_000, 1_000_000)
+        };
+        assert_eq!(test(CleanVehicle::New), Ok(true));
+        assert_eq!(test(CleanVehicle::Used), Ok(false));
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn errors() {
+        assert_eq!(
+            clean_vehicle_income_test(
+                TaxYear::Y2024,
+                FilingStatus::Single,
+                CleanVehicle::New,
+                0,
+                u64::MAX
+            ),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}
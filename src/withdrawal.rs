@@ -0,0 +1,617 @@
+//! Ordering retirement withdrawals across taxable, traditional, and Roth
+//! accounts.
+
+use crate::capital_gains::{CapitalGains, compute_tax_with_gains};
+use crate::conversion::irmaa_tiers;
+use crate::data;
+use crate::deduction::standard_deduction_for;
+use crate::social_security::taxable_social_security;
+use crate::tax_return::{niit_threshold, percent_of};
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// The balances a retiree can draw on, in whole dollars, for
+/// [`plan_withdrawals`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RetirementAccounts {
+    /// The value of a taxable brokerage account.
+    pub taxable: i64,
+    /// The cost basis of the taxable account. Selling part of it realizes
+    /// the same share of the unrealized gain, taxed as long-term gain.
+    pub taxable_basis: i64,
+    /// Traditional IRA and 401(k) balances, taxed as ordinary income when
+    /// withdrawn.
+    pub traditional: i64,
+    /// Roth IRA and Roth 401(k) balances, withdrawn tax-free.
+    pub roth: i64,
+}
+
+/// An order to draw on the accounts in, for [`plan_withdrawals`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum WithdrawalOrder {
+    /// The taxable account, then traditional, then Roth: the conventional
+    /// order, which leaves the tax-advantaged accounts to grow.
+    TaxableFirst,
+    /// Traditional, then taxable, then Roth.
+    TraditionalFirst,
+    /// Roth, then taxable, then traditional.
+    RothFirst,
+    /// Each account in proportion to its balance.
+    Proportional,
+    /// Traditional until ordinary taxable income reaches the top of the
+    /// bracket taxed at this rate, such as 0.12 (matched to the nearest
+    /// basis point), then taxable, then Roth, then the rest of traditional.
+    FillBracket(f64),
+}
+
+/// One year of retirement income to draw from [`RetirementAccounts`], for
+/// [`plan_withdrawals`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithdrawalPlan {
+    /// The tax year.
+    pub year: TaxYear,
+    /// The filing status.
+    pub status: FilingStatus,
+    /// The number of people on the return who are 65 or older, for the
+    /// standard deduction.
+    pub age65: u8,
+    /// The balances to draw on.
+    pub accounts: RetirementAccounts,
+    /// The amount to withdraw from the accounts for the year.
+    pub spending: i64,
+    /// Social Security benefits for the year.
+    pub social_security: i64,
+    /// Other ordinary income, such as pensions and interest.
+    pub other_income: i64,
+    /// The required minimum distribution from the traditional accounts,
+    /// such as from [`required_minimum_distribution`], taken under every
+    /// order even beyond the spending.
+    ///
+    /// [`required_minimum_distribution`]: crate::required_minimum_distribution
+    pub required_distribution: i64,
+    /// The number of people on the return enrolled in Medicare, who pay
+    /// premium surcharges from [`irmaa_tiers`] on high incomes.
+    pub medicare_enrollees: u8,
+}
+
+impl WithdrawalPlan {
+    /// A plan to withdraw `spending` from `accounts`, with no other income,
+    /// no required distribution, and no one 65 or older.
+    pub fn new(
+        year: TaxYear,
+        status: FilingStatus,
+        accounts: RetirementAccounts,
+        spending: i64,
+    ) -> Self {
+        WithdrawalPlan {
+            year,
+            status,
+            age65: 0,
+            accounts,
+            spending,
+            social_security: 0,
+            other_income: 0,
+            required_distribution: 0,
+            medicare_enrollees: 0,
+        }
+    }
+}
+
+/// The withdrawals and taxes for one [`WithdrawalOrder`], returned by
+/// [`plan_withdrawals`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct WithdrawalOutcome {
+    /// The order evaluated.
+    pub order: WithdrawalOrder,
+    /// The amount withdrawn from the taxable account.
+    pub from_taxable: i64,
+    /// The amount withdrawn from the traditional accounts.
+    pub from_traditional: i64,
+    /// The amount withdrawn from the Roth accounts.
+    pub from_roth: i64,
+    /// The spending the accounts could not cover.
+    pub shortfall: i64,
+    /// The long-term gain realized by the taxable withdrawal.
+    pub capital_gains: i64,
+    /// The taxable part of the Social Security benefits.
+    pub taxable_social_security: i64,
+    /// Adjusted gross income, which is also the modified adjusted gross
+    /// income for the Medicare premiums.
+    pub adjusted_gross_income: i64,
+    /// Taxable income after the standard deduction.
+    pub taxable_income: i64,
+    /// The income tax, with the capital gain rates.
+    pub income_tax: i64,
+    /// The 3.8% net investment income tax on the capital gains.
+    pub net_investment_income_tax: i64,
+    /// The yearly Medicare premium surcharges for every enrollee, above the
+    /// standard premium, which are charged two years later.
+    pub irmaa_surcharge: i64,
+    /// The sum of the income tax, net investment income tax, and premium
+    /// surcharges.
+    pub total_tax: i64,
+}
+
+/// Evaluate each of `orders` for drawing the `plan`'s spending from its
+/// accounts, and return the withdrawals and total tax of each, in the same
+/// order.
+///
+/// Every order first takes the required distribution, then draws the rest
+/// of the spending from the accounts in turn. The year's return then
+/// stacks the traditional withdrawals, other income, and taxable Social
+/// Security benefits ([`taxable_social_security`]) under the standard
+/// deduction, taxes the realized gains at the capital gain rates, and adds
+/// the net investment income tax and any Medicare premium surcharges from
+/// the resulting income. Drawing on traditional accounts raises the share
+/// of the benefits that is taxed, which is often what sets the orders
+/// apart.
+///
+/// The plan covers one year: the taxes come out of the accounts the next
+/// year, and the balances left over set what later years can do. Plan each
+/// year in turn and compare the streams with
+/// [`present_value_of_taxes`](crate::present_value_of_taxes) to see a whole
+/// retirement. Losses in the taxable account, state taxes, and tax-exempt
+/// interest are left out.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if any amount in the plan is
+/// negative, [`TaxError::InvalidAmount`] for a
+/// [`FillBracket`](WithdrawalOrder::FillBracket) rate that no bracket has,
+/// [`TaxError::IncomeOutOfRange`] if the income does not fit in an `i64`,
+/// and the same errors as [`compute_tax_with_gains`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2025")] {
+/// use us_tax_brackets::{
+///     plan_withdrawals, FilingStatus, RetirementAccounts, TaxYear, WithdrawalOrder,
+///     WithdrawalPlan,
+/// };
+///
+/// let accounts = RetirementAccounts {
+///     taxable: 200_000,
+///     taxable_basis: 150_000,
+///     traditional: 800_000,
+///     roth: 100_000,
+/// };
+/// let plan = WithdrawalPlan {
+///     age65: 2,
+///     social_security: 50_000,
+///     ..WithdrawalPlan::new(
+///         TaxYear::Y2025,
+///         FilingStatus::MarriedFilingJointly,
+///         accounts,
+///         70_000,
+///     )
+/// };
+/// let orders = [
+///     WithdrawalOrder::TaxableFirst,
+///     WithdrawalOrder::TraditionalFirst,
+///     WithdrawalOrder::FillBracket(0.12),
+/// ];
+/// let outcomes = plan_withdrawals(&plan, &orders).unwrap();
+/// // Selling $70,000 of the taxable account realizes $17,500 of gain and
+/// // makes little of the benefits taxable, all under the deduction.
+/// assert_eq!(outcomes[0].capital_gains, 17_500);
+/// assert_eq!(outcomes[0].taxable_social_security, 5_250);
+/// assert_eq!(outcomes[0].total_tax, 0);
+/// // $70,000 from traditional makes most of the benefits taxable.
+/// assert_eq!(outcomes[1].taxable_social_security, 42_500);
+/// assert!(outcomes[1].total_tax > 5_000);
+/// # }
+/// ```
+pub fn plan_withdrawals(
+    plan: &WithdrawalPlan,
+    orders: &[WithdrawalOrder],
+) -> Result<Vec<WithdrawalOutcome>, TaxError> {
+    let RetirementAccounts {
+        taxable,
+        taxable_basis,
+        traditional,
+        roth,
+    } = plan.accounts;
+    if [
+        taxable,
+        taxable_basis,
+        traditional,
+        roth,
+        plan.spending,
+        plan.social_security,
+        plan.other_income,
+        plan.required_distribution,
+    ]
+    .iter()
+    .any(|&amount| amount < 0)
+    {
+        return Err(TaxError::NegativeIncome);
+    }
+    orders
+        .iter()
+        .map(|&order| {
+            let draws = match order {
+                WithdrawalOrder::TaxableFirst => draw(plan, [TAXABLE, TRADITIONAL, ROTH], 0),
+                WithdrawalOrder::TraditionalFirst => draw(plan, [TRADITIONAL, TAXABLE, ROTH], 0),
+                WithdrawalOrder::RothFirst => draw(plan, [ROTH, TAXABLE, TRADITIONAL], 0),
+                WithdrawalOrder::Proportional => proportional(plan),
+                WithdrawalOrder::FillBracket(rate) => fill_bracket(plan, rate)?,
+            };
+            evaluate(plan, order, draws)
+        })
+        .collect()
+}
+
+const TAXABLE: usize = 0;
+const TRADITIONAL: usize = 1;
+const ROTH: usize = 2;
+
+/// The balances of the accounts, indexed by [`TAXABLE`], [`TRADITIONAL`],
+/// and [`ROTH`].
+fn balances(plan: &WithdrawalPlan) -> [i64; 3] {
+    let accounts = plan.accounts;
+    [accounts.taxable, accounts.traditional, accounts.roth]
+}
+
+/// The required distribution, then the rest of the spending from each
+/// account in `order`, after `traditional_first` more from traditional.
+fn draw(plan: &WithdrawalPlan, order: [usize; 3], traditional_first: i64) -> [i64; 3] {
+    let mut available = balances(plan);
+    let mut draws = [0; 3];
+    let first = plan
+        .required_distribution
+        .max(traditional_first)
+        .min(available[TRADITIONAL]);
+    draws[TRADITIONAL] = first;
+    available[TRADITIONAL] -= first;
+    let mut need = plan.spending.saturating_sub(first).max(0);
+    for account in order {
+        let amount = need.min(available[account]);
+        draws[account] += amount;
+        need -= amount;
+    }
+    draws
+}
+
+/// The required distribution, then the rest of the spending from each
+/// account in proportion to what is left in it.
+fn proportional(plan: &WithdrawalPlan) -> [i64; 3] {
+    let mut available = balances(plan);
+    let required = plan.required_distribution.min(available[TRADITIONAL]);
+    available[TRADITIONAL] -= required;
+    let total: i128 = available.iter().map(|&balance| i128::from(balance)).sum();
+    let need = i128::from(plan.spending.saturating_sub(required).max(0)).min(total);
+    let mut draws = [0; 3];
+    if total > 0 {
+        draws[TAXABLE] = (need * i128::from(available[TAXABLE]) / total) as i64;
+        draws[TRADITIONAL] = (need * i128::from(available[TRADITIONAL]) / total) as i64;
+        draws[ROTH] = (need as i64 - draws[TAXABLE] - draws[TRADITIONAL]).min(available[ROTH]);
+    }
+    draws[TRADITIONAL] += required;
+    draws
+}
+
+/// The largest traditional withdrawal that keeps ordinary taxable income
+/// within the bracket taxed at `rate`, then the taxable account and Roth.
+fn fill_bracket(plan: &WithdrawalPlan, rate: f64) -> Result<[i64; 3], TaxError> {
+    let order = [TAXABLE, ROTH, TRADITIONAL];
+    // Match whole basis points, so a rate computed as 0.1 + 0.02 still
+    // finds the 12% bracket.
+    let basis_points = |rate: f64| (rate * 10_000.0).round();
+    let bracket = data::rate_schedule(plan.year, plan.status)
+        .iter()
+        .find(|bracket| basis_points(bracket.rate) == basis_points(rate))
+        .ok_or_else(|| TaxError::InvalidAmount(format!("no bracket is taxed at {rate}")))?;
+    let Some(top) = bracket.income_max else {
+        return Ok(draw(plan, order, plan.spending));
+    };
+    let ordinary_taxable_income = |traditional: i64| {
+        let draws = draw(plan, order, traditional);
+        let income = income(plan, draws)?;
+        Ok::<_, TaxError>(income.taxable_income - income.taxable_income.min(income.capital_gains))
+    };
+    // Ordinary taxable income grows with the traditional withdrawal, so
+    // search for the last amount within the bracket.
+    let (mut within, mut beyond) = (0, plan.spending.min(plan.accounts.traditional) + 1);
+    while beyond - within > 1 {
+        let middle = within + (beyond - within) / 2;
+        if ordinary_taxable_income(middle)? <= top {
+            within = middle;
+        } else {
+            beyond = middle;
+        }
+    }
+    Ok(draw(plan, order, within))
+}
+
+/// The income on a return with withdrawals of `draws`.
+struct Income {
+    capital_gains: i64,
+    taxable_social_security: i64,
+    adjusted_gross_income: i64,
+    taxable_income: i64,
+}
+
+fn income(plan: &WithdrawalPlan, draws: [i64; 3]) -> Result<Income, TaxError> {
+    let RetirementAccounts {
+        taxable,
+        taxable_basis,
+        ..
+    } = plan.accounts;
+    let capital_gains = if taxable == 0 {
+        0
+    } else {
+        let gain = i128::from(taxable.saturating_sub(taxable_basis).max(0));
+        (i128::from(draws[TAXABLE]) * gain / i128::from(taxable)) as i64
+    };
+    let add = |amount: i64, more: i64| amount.checked_add(more).ok_or(TaxError::IncomeOutOfRange);
+    let other_income = add(add(plan.other_income, draws[TRADITIONAL])?, capital_gains)?;
+    let taxable_social_security =
+        taxable_social_security(plan.status, plan.social_security, other_income)?;
+    let adjusted_gross_income = add(other_income, taxable_social_security)?;
+    let deduction = standard_deduction_for(plan.year, plan.status, plan.age65, 0);
+    Ok(Income {
+        capital_gains,
+        taxable_social_security,
+        adjusted_gross_income,
+        taxable_income: adjusted_gross_income.saturating_sub(deduction).max(0),
+    })
+}
+
+/// The outcome of withdrawing `draws` under `order`.
+fn evaluate(
+    plan: &WithdrawalPlan,
+    order: WithdrawalOrder,
+    draws: [i64; 3],
+) -> Result<WithdrawalOutcome, TaxError> {
+    let (year, status) = (plan.year, plan.status);
+    let Income {
+        capital_gains,
+        taxable_social_security,
+        adjusted_gross_income,
+        taxable_income,
+    } = income(plan, draws)?;
+    let gains = CapitalGains {
+        net_capital_gain: capital_gains,
+        ..CapitalGains::default()
+    };
+    let income_tax = compute_tax_with_gains(year, status, taxable_income, &gains)?;
+    let excess_agi = adjusted_gross_income
+        .saturating_sub(niit_threshold(status))
+        .max(0);
+    let net_investment_income_tax = percent_of(capital_gains.min(excess_agi), 38);
+
+    let tiers = irmaa_tiers(year, status);
+    let irmaa_surcharge = match (tiers.first(), plan.medicare_enrollees) {
+        (Some(standard), enrollees) if enrollees > 0 => {
+            let tier = tiers
+                .iter()
+                .rev()
+                .find(|tier| adjusted_gross_income > tier.magi_over)
+                .unwrap_or(standard);
+            let increase = tier.annual_cost().cents() - standard.annual_cost().cents();
+            (increase * i64::from(enrollees) + 50) / 100
+        }
+        _ => 0,
+    };
+
+    let withdrawn = draws.iter().sum::<i64>();
+    Ok(WithdrawalOutcome {
+        order,
+        from_taxable: draws[TAXABLE],
+        from_traditional: draws[TRADITIONAL],
+        from_roth: draws[ROTH],
+        shortfall: plan.spending.saturating_sub(withdrawn).max(0),
+        capital_gains,
+        taxable_social_security,
+        adjusted_gross_income,
+        taxable_income,
+        income_tax,
+        net_investment_income_tax,
+        irmaa_surcharge,
+        total_tax: income_tax + net_investment_income_tax + irmaa_surcharge,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::compute_tax;
+
+    #[cfg(feature = "year-2024")]
+    fn plan(accounts: RetirementAccounts, spending: i64) -> WithdrawalPlan {
+        WithdrawalPlan::new(
+            TaxYear::Y2024,
+            FilingStatus::MarriedFilingJointly,
+            accounts,
+            spending,
+        )
+    }
+
+    const ACCOUNTS: RetirementAccounts = RetirementAccounts {
+        taxable: 100_000,
+        taxable_basis: 60_000,
+        traditional: 500_000,
+        roth: 200_000,
+    };
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn orders() {
+        let orders = [
+            WithdrawalOrder::TaxableFirst,
+            WithdrawalOrder::TraditionalFirst,
+            WithdrawalOrder::RothFirst,
+            WithdrawalOrder::Proportional,
+        ];
+        let outcomes = plan_withdrawals(&plan(ACCOUNTS, 150_000), &orders).unwrap();
+        let draws = |outcome: &WithdrawalOutcome| {
+            (
+                outcome.from_taxable,
+                outcome.from_traditional,
+                outcome.from_roth,
+            )
+        };
+        assert_eq!(draws(&outcomes[0]), (100_000, 50_000, 0));
+        assert_eq!(draws(&outcomes[1]), (0, 150_000, 0));
+        assert_eq!(draws(&outcomes[2]), (0, 0, 150_000));
+        assert_eq!(draws(&outcomes[3]), (18_750, 93_750, 37_500));
+        for (outcome, order) in outcomes.iter().zip(orders) {
+            assert_eq!(outcome.order, order);
+            assert_eq!(outcome.shortfall, 0);
+        }
+
+        // Selling the whole taxable account realizes all $40,000 of gain.
+        let taxable_first = &outcomes[0];
+        assert_eq!(taxable_first.capital_gains, 40_000);
+        assert_eq!(taxable_first.adjusted_gross_income, 90_000);
+        assert_eq!(taxable_first.taxable_income, 90_000 - 29_200);
+        // Roth withdrawals are not taxed at all.
+        assert_eq!(outcomes[2].total_tax, 0);
+        let traditional_first = &outcomes[1];
+        assert_eq!(
+            traditional_first.income_tax,
+            compute_tax(
+                TaxYear::Y2024,
+                FilingStatus::MarriedFilingJointly,
+                150_000 - 29_200
+            )
+            .unwrap()
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn required_distribution_and_shortfall() {
+        let accounts = RetirementAccounts {
+            traditional: 30_000,
+            ..ACCOUNTS
+        };
+        let plan = WithdrawalPlan {
+            required_distribution: 20_000,
+            ..plan(accounts, 10_000)
+        };
+        let outcomes = plan_withdrawals(
+            &plan,
+            &[WithdrawalOrder::RothFirst, WithdrawalOrder::Proportional],
+        )
+        .unwrap();
+        // The required distribution is taken even beyond the spending.
+        for outcome in &outcomes {
+            assert_eq!(outcome.from_traditional, 20_000);
+            assert_eq!(outcome.from_taxable + outcome.from_roth, 0);
+        }
+
+        let plan = WithdrawalPlan {
+            spending: 400_000,
+            ..plan
+        };
+        let outcomes = plan_withdrawals(&plan, &[WithdrawalOrder::TaxableFirst]).unwrap();
+        assert_eq!(outcomes[0].shortfall, 70_000);
+        assert_eq!(outcomes[0].from_traditional, 30_000);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn social_security_and_premiums() {
+        let plan = WithdrawalPlan {
+            social_security: 60_000,
+            medicare_enrollees: 2,
+            age65: 2,
+            ..plan(ACCOUNTS, 200_000)
+        };
+        let outcomes = plan_withdrawals(
+            &plan,
+            &[
+                WithdrawalOrder::RothFirst,
+                WithdrawalOrder::TraditionalFirst,
+            ],
+        )
+        .unwrap();
+        let roth_first = &outcomes[0];
+        assert_eq!(roth_first.taxable_social_security, 0);
+        assert_eq!(roth_first.irmaa_surcharge, 0);
+        // $200,000 of traditional withdrawals taxes 85% of the benefits and
+        // puts both spouses in a higher premium tier.
+        let traditional_first = &outcomes[1];
+        assert_eq!(traditional_first.taxable_social_security, 51_000);
+        assert_eq!(traditional_first.adjusted_gross_income, 251_000);
+        let tiers = irmaa_tiers(TaxYear::Y2024, FilingStatus::MarriedFilingJointly);
+        let tier = tiers
+            .iter()
+            .rev()
+            .find(|tier| tier.magi_over < 251_000)
+            .unwrap();
+        let increase = tier.annual_cost().cents() - tiers[0].annual_cost().cents();
+        assert_eq!(traditional_first.irmaa_surcharge, increase * 2 / 100);
+        assert_eq!(
+            traditional_first.total_tax,
+            traditional_first.income_tax + traditional_first.irmaa_surcharge
+        );
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn fill_bracket() {
+        let plan = WithdrawalPlan {
+            social_security: 40_000,
+            ..plan(ACCOUNTS, 200_000)
+        };
+        let outcome = plan_withdrawals(&plan, &[WithdrawalOrder::FillBracket(0.12)]).unwrap()[0];
+        // The 12% bracket ends at $94,300 of taxable income.
+        let ordinary = outcome.taxable_income - outcome.capital_gains;
+        assert!(ordinary <= 94_300 && ordinary > 94_000, "{outcome:?}");
+        assert_eq!(
+            outcome.from_taxable + outcome.from_traditional + outcome.from_roth,
+            200_000
+        );
+        assert!(outcome.from_roth > 0);
+
+        // The top bracket has no end, so everything comes from traditional.
+        let top = plan_withdrawals(&plan, &[WithdrawalOrder::FillBracket(0.37)]).unwrap()[0];
+        assert_eq!(top.from_traditional, 200_000);
+
+        // A rate from arithmetic fills the same bracket as the literal.
+        let computed =
+            plan_withdrawals(&plan, &[WithdrawalOrder::FillBracket(0.1 + 0.02)]).unwrap()[0];
+        assert_eq!(
+            (
+                computed.from_taxable,
+                computed.from_traditional,
+                computed.from_roth
+            ),
+            (
+                outcome.from_taxable,
+                outcome.from_traditional,
+                outcome.from_roth
+            )
+        );
+        for rate in [0.13, 0.1201, f64::NAN] {
+            assert!(matches!(
+                plan_withdrawals(&plan, &[WithdrawalOrder::FillBracket(rate)]),
+                Err(TaxError::InvalidAmount(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn errors() {
+        let accounts = RetirementAccounts {
+            roth: -1,
+            ..ACCOUNTS
+        };
+        assert_eq!(
+            plan_withdrawals(&plan(accounts, 0), &[WithdrawalOrder::TaxableFirst]),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(plan_withdrawals(&plan(ACCOUNTS, 0), &[]), Ok(Vec::new()));
+    }
+}
@@ -0,0 +1,515 @@
+//! Federal income tax withholding with the percentage method of Publication
+//! 15-T.
+
+use crate::data;
+use crate::money::UsdAmount;
+use crate::types::{FilingStatus, TaxError, TaxYear};
+
+/// A row of a Publication 15-T percentage method table, returned by
+/// [`withholding_table`].
+///
+/// Annual wages of at least [`wage_over`](Self::wage_over), after the Form
+/// W-4 adjustments, have [`tentative_withholding`](Self::tentative_withholding)
+/// plus [`rate`](Self::rate) times the excess withheld.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct WithholdingBracket {
+    /// Whether the row belongs to the table for a Form W-4 with the Step 2
+    /// box checked.
+    pub step_2_checkbox: bool,
+    /// The adjusted annual wage the row starts at, in whole dollars.
+    pub wage_over: i64,
+    /// The annual withholding at [`wage_over`](Self::wage_over).
+    pub tentative_withholding: UsdAmount,
+    /// The rate on adjusted annual wages above
+    /// [`wage_over`](Self::wage_over).
+    pub rate: f64,
+}
+
+/// How often wages are paid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PayFrequency {
+    /// 260 pay periods a year.
+    Daily,
+    /// 52 pay periods a year.
+    Weekly,
+    /// 26 pay periods a year.
+    Biweekly,
+    /// 24 pay periods a year.
+    Semimonthly,
+    /// 12 pay periods a year.
+    Monthly,
+    /// 4 pay periods a year.
+    Quarterly,
+    /// 2 pay periods a year.
+    Semiannually,
+    /// 1 pay period a year.
+    Annually,
+}
+
+impl PayFrequency {
+    /// Every pay frequency, from the most frequent.
+    pub const ALL: [PayFrequency; 8] = [
+        PayFrequency::Daily,
+        PayFrequency::Weekly,
+        PayFrequency::Biweekly,
+        PayFrequency::Semimonthly,
+        PayFrequency::Monthly,
+        PayFrequency::Quarterly,
+        PayFrequency::Semiannually,
+        PayFrequency::Annually,
+    ];
+
+    /// The number of pay periods in a year, from the table in Worksheet 1A.
+    pub const fn periods_per_year(self) -> i64 {
+        match self {
+            PayFrequency::Daily => 260,
+            PayFrequency::Weekly => 52,
+            PayFrequency::Biweekly => 26,
+            PayFrequency::Semimonthly => 24,
+            PayFrequency::Monthly => 12,
+            PayFrequency::Quarterly => 4,
+            PayFrequency::Semiannually => 2,
+            PayFrequency::Annually => 1,
+        }
+    }
+}
+
+/// An employee's Form W-4, in the format used since 2020.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormW4 {
+    /// The filing status checked in Step 1(c). Married filing separately
+    /// means "Single or Married filing separately", and qualifying
+    /// surviving spouse goes with married filing jointly.
+    pub status: FilingStatus,
+    /// Whether the Step 2 box for multiple jobs or a working spouse is
+    /// checked.
+    pub step_2_checkbox: bool,
+    /// The credits for dependents and other credits in Step 3, in whole
+    /// dollars a year.
+    pub credits: i64,
+    /// Other income in Step 4(a), in whole dollars a year.
+    pub other_income: i64,
+    /// Deductions in Step 4(b), in whole dollars a year.
+    pub deductions: i64,
+    /// Extra withholding each pay period in Step 4(c).
+    pub extra_withholding: UsdAmount,
+}
+
+impl FormW4 {
+    /// A Form W-4 with only the filing status filled in.
+    pub const fn new(status: FilingStatus) -> Self {
+        FormW4 {
+            status,
+            step_2_checkbox: false,
+            credits: 0,
+            other_income: 0,
+            deductions: 0,
+            extra_withholding: UsdAmount::ZERO,
+        }
+    }
+}
+
+/// The Publication 15-T annual percentage method table for `year` and
+/// `status`, for a Form W-4 with or without the Step 2 box checked, in
+/// ascending order.
+///
+/// Married people filing separately use the single table, and qualifying
+/// surviving spouses the married filing jointly table. The 2025 tables were
+/// published before the 2025 increase in the standard deduction and were
+/// not revised for it.
+///
+/// # Errors
+///
+/// Returns [`TaxError::UnsupportedYear`] before 2020, when withholding used
+/// the earlier Form W-4 and its allowances.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{withholding_table, FilingStatus, TaxYear};
+///
+/// let table = withholding_table(TaxYear::Y2024, FilingStatus::Single, false).unwrap();
+/// assert_eq!(table[1].wage_over, 6_000);
+/// assert_eq!(table[2].wage_over, 17_600);
+/// assert_eq!(table[2].tentative_withholding.to_string(), "$1,160");
+/// assert_eq!(table[2].rate, 0.12);
+/// # }
+/// ```
+pub fn withholding_table(
+    year: TaxYear,
+    status: FilingStatus,
+    step_2_checkbox: bool,
+) -> Result<&'static [WithholdingBracket], TaxError> {
+    let table = data::withholding_table(year, status, step_2_checkbox);
+    if table.is_empty() {
+        return Err(TaxError::UnsupportedYear(u16::from(year)));
+    }
+    Ok(table)
+}
+
+/// Compute the federal income tax to withhold from one pay period's
+/// `wages` with the percentage method for automated payroll systems
+/// (Publication 15-T, Worksheet 1A).
+///
+/// The wages are annualized, adjusted by Step 4(a) and Step 4(b) of the
+/// Form W-4 and, without the Step 2 box checked, reduced by $12,900 for
+/// married filing jointly or $8,600 otherwise, then looked up in
+/// [`withholding_table`]. The annual amount is divided back over the pay
+/// periods, reduced by a share of the Step 3 credits, but not below zero,
+/// and the Step 4(c) extra withholding is added. Amounts are rounded to the
+/// cent; employers may also round to whole dollars.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if `wages` or any Form W-4 amount is
+/// negative, [`TaxError::IncomeOutOfRange`] if the annual wages do not fit
+/// in an `i64` of cents, and the same errors as [`withholding_table`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "year-2024")] {
+/// use us_tax_brackets::{
+///     federal_withholding, FilingStatus, FormW4, PayFrequency, TaxYear, UsdAmount,
+/// };
+///
+/// let w4 = FormW4::new(FilingStatus::Single);
+/// let wages = UsdAmount::from_dollars(2_000);
+/// let withheld =
+///     federal_withholding(TaxYear::Y2024, &w4, PayFrequency::Biweekly, wages).unwrap();
+/// // $52,000 a year less $8,600: $1,160 plus 12% over $17,600, over 26 periods
+/// assert_eq!(withheld.to_string(), "$163.69");
+///
+/// let w4 = FormW4 { credits: 2_000, ..w4 };
+/// let withheld =
+///     federal_withholding(TaxYear::Y2024, &w4, PayFrequency::Biweekly, wages).unwrap();
+/// assert_eq!(withheld.to_string(), "$86.77");
+/// # }
+/// ```
+pub fn federal_withholding(
+    year: TaxYear,
+    w4: &FormW4,
+    frequency: PayFrequency,
+    wages: UsdAmount,
+) -> Result<UsdAmount, TaxError> {
+    if wages.cents() < 0
+        || w4.credits < 0
+        || w4.other_income < 0
+        || w4.deductions < 0
+        || w4.extra_withholding.cents() < 0
+    {
+        return Err(TaxError::NegativeIncome);
+    }
+    let table = withholding_table(year, w4.status, w4.step_2_checkbox)?;
+    let periods = i128::from(frequency.periods_per_year());
+
+    // Step 1: the adjusted annual wage, in cents
+    let line_1g = match (w4.step_2_checkbox, w4.status) {
+        (true, _) => 0,
+        (false, FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse) => {
+            12_900
+        }
+        (false, _) => 8_600,
+    };
+    let annual_wages = i128::from(wages.cents()) * periods + i128::from(w4.other_income) * 100;
+    if annual_wages > i128::from(i64::MAX) {
+        return Err(TaxError::IncomeOutOfRange);
+    }
+    let adjusted = (annual_wages - (i128::from(w4.deductions) + line_1g) * 100).max(0);
+
+    // Step 2: the tentative withholding
+    let row = table
+        .iter()
+        .rev()
+        .find(|row| i128::from(row.wage_over) * 100 <= adjusted)
+        .unwrap_or(&table[0]);
+    let rate_basis_points = (row.rate * 10_000.0).round() as i128;
+    let annual = i128::from(row.tentative_withholding.cents())
+        + div_round(
+            (adjusted - i128::from(row.wage_over) * 100) * rate_basis_points,
+            10_000,
+        );
+    let tentative = div_round(annual, periods);
+
+    // Steps 3 and 4: credits and extra withholding
+    let credits = div_round(i128::from(w4.credits) * 100, periods);
+    let withholding = (tentative - credits).max(0) + i128::from(w4.extra_withholding.cents());
+    i64::try_from(withholding)
+        .map(UsdAmount::from_cents)
+        .map_err(|_| TaxError::IncomeOutOfRange)
+}
+
+/// The Form W-4 change suggested by [`recommend_w4`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct W4Recommendation {
+    /// The withholding for the year at the current rate: what was withheld
+    /// so far plus the current amount for each remaining pay period.
+    pub projected_withholding: UsdAmount,
+    /// The liability less [`projected_withholding`](Self::projected_withholding):
+    /// the balance due, or a refund if negative.
+    pub projected_balance: UsdAmount,
+    /// Credits to add to Step 3, in whole dollars a year, when too much is
+    /// being withheld.
+    pub additional_credits: i64,
+    /// Extra withholding to add to Step 4(c) each pay period, in whole
+    /// dollars, when too little is being withheld.
+    pub additional_withholding: UsdAmount,
+}
+
+/// Suggest the Form W-4 change that brings the year's withholding close to
+/// `projected_liability`, such as the total tax from [`compute_tax`] or
+/// [`TaxReturn::builder`].
+///
+/// The year's withholding is projected from `withheld_to_date` and
+/// `withholding_per_period`, the amount [`federal_withholding`] gives now,
+/// for the `periods_remaining` paychecks left. A shortfall is spread over
+/// those paychecks as Step 4(c) extra withholding, rounded up to whole
+/// dollars, so the balance due ends at or just below zero. An excess becomes
+/// Step 3 credits, rounded down; because the employer spreads Step 3 over
+/// every pay period of the year, the credits are scaled up by the periods in
+/// a year at `frequency` and cannot take withholding below zero, so a large
+/// excess late in the year may not be fully undone. Changes are on top of
+/// what the current Form W-4 already claims, and nothing changes with no
+/// paychecks left.
+///
+/// # Errors
+///
+/// Returns [`TaxError::NegativeIncome`] if any amount or
+/// `periods_remaining` is negative, and [`TaxError::IncomeOutOfRange`] if
+/// the projection does not fit in an `i64` of cents.
+///
+/// [`compute_tax`]: crate::compute_tax
+/// [`TaxReturn::builder`]: crate::TaxReturn::builder
+///
+/// # Examples
+///
+/// ```
+/// use us_tax_brackets::{recommend_w4, PayFrequency, UsdAmount};
+///
+/// // $10,000 of tax, and $300 withheld from each of 26 paychecks
+/// let frequency = PayFrequency::Biweekly;
+/// let per_period = UsdAmount::from_dollars(300);
+/// let change = recommend_w4(10_000, UsdAmount::ZERO, per_period, frequency, 26).unwrap();
+/// assert_eq!(change.projected_balance.to_string(), "$2,200");
+/// // $2,200 over 26 paychecks, rounded up
+/// assert_eq!(change.additional_withholding.to_string(), "$85");
+///
+/// // $450 a paycheck withholds $1,700 too much.
+/// let per_period = UsdAmount::from_dollars(450);
+/// let change = recommend_w4(10_000, UsdAmount::ZERO, per_period, frequency, 26).unwrap();
+/// assert_eq!(change.projected_balance.to_string(), "-$1,700");
+/// assert_eq!(change.additional_credits, 1_699);
+/// ```
+pub fn recommend_w4(
+    projected_liability: i64,
+    withheld_to_date: UsdAmount,
+    withholding_per_period: UsdAmount,
+    frequency: PayFrequency,
+    periods_remaining: i64,
+) -> Result<W4Recommendation, TaxError> {
+    if projected_liability < 0
+        || withheld_to_date.cents() < 0
+        || withholding_per_period.cents() < 0
+        || periods_remaining < 0
+    {
+        return Err(TaxError::NegativeIncome);
+    }
+    let remaining = i128::from(periods_remaining);
+    let projected = i128::from(withheld_to_date.cents())
+        + i128::from(withholding_per_period.cents()) * remaining;
+    let balance = i128::from(projected_liability) * 100 - projected;
+    let cents = |amount: i128| {
+        i64::try_from(amount)
+            .map(UsdAmount::from_cents)
+            .map_err(|_| TaxError::IncomeOutOfRange)
+    };
+
+    let (mut additional_credits, mut additional_withholding) = (0, 0);
+    if remaining > 0 && balance > 0 {
+        // Whole dollars per paycheck, rounded up
+        additional_withholding = (balance + remaining * 100 - 1) / (remaining * 100) * 100;
+    } else if remaining > 0 && balance < 0 {
+        let reduction = (-balance / remaining).min(i128::from(withholding_per_period.cents()));
+        additional_credits = reduction * i128::from(frequency.periods_per_year()) / 100;
+    }
+    Ok(W4Recommendation {
+        projected_withholding: cents(projected)?,
+        projected_balance: cents(balance)?,
+        additional_credits: i64::try_from(additional_credits)
+            .map_err(|_| TaxError::IncomeOutOfRange)?,
+        additional_withholding: cents(additional_withholding)?,
+    })
+}
+
+/// `numerator / denominator` for a non-negative numerator, rounded half up.
+fn div_round(numerator: i128, denominator: i128) -> i128 {
+    (numerator * 2 + denominator) / (denominator * 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "year-2019", feature = "year-2023"))]
+    #[test]
+    fn tables() {
+        for year in TaxYear::ALL {
+            for status in FilingStatus::ALL {
+                for checkbox in [false, true] {
+                    let Ok(table) = withholding_table(year, status, checkbox) else {
+                        assert!(u16::from(year) < 2020);
+                        continue;
+                    };
+                    assert_eq!(table.len(), 8, "{year:?} {status:?} {checkbox}");
+                    assert_eq!(table[0].wage_over, 0);
+                    assert!(table.iter().all(|row| row.step_2_checkbox == checkbox));
+                    // Each row's tentative amount is the last one's plus its
+                    // rate over the row, to within rounding.
+                    for pair in table.windows(2) {
+                        let width = (pair[1].wage_over - pair[0].wage_over) as f64;
+                        let expected = pair[0].tentative_withholding.cents() as f64
+                            + width * pair[0].rate * 100.0;
+                        let cents = pair[1].tentative_withholding.cents() as f64;
+                        assert!((cents - expected).abs() <= 60.0, "{year:?} {status:?}");
+                    }
+                }
+            }
+        }
+        assert_eq!(
+            withholding_table(TaxYear::Y2019, FilingStatus::Single, false),
+            Err(TaxError::UnsupportedYear(2019))
+        );
+        assert_eq!(
+            withholding_table(TaxYear::Y2023, FilingStatus::MarriedFilingSeparately, true),
+            withholding_table(TaxYear::Y2023, FilingStatus::Single, true)
+        );
+    }
+
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn withholding() {
+        let year = TaxYear::Y2025;
+        let joint = FormW4::new(FilingStatus::MarriedFilingJointly);
+        let withhold = |w4: &FormW4, frequency, dollars| {
+            federal_withholding(year, w4, frequency, UsdAmount::from_dollars(dollars))
+                .unwrap()
+                .cents()
+        };
+        // $60,000 a year less $12,900 is $47,100: $2,385 plus 12% over
+        // $40,950, or $3,123.
+        assert_eq!(withhold(&joint, PayFrequency::Monthly, 5_000), 26_025);
+        assert_eq!(withhold(&joint, PayFrequency::Annually, 60_000), 312_300);
+        assert_eq!(withhold(&joint, PayFrequency::Monthly, 0), 0);
+
+        // The Step 2 table withholds more from each of two jobs.
+        let checked = FormW4 {
+            step_2_checkbox: true,
+            ..joint
+        };
+        assert!(withhold(&checked, PayFrequency::Monthly, 5_000) > 26_025);
+
+        // Other income raises withholding and deductions lower it.
+        let adjusted = FormW4 {
+            other_income: 12_000,
+            ..joint
+        };
+        assert_eq!(withhold(&adjusted, PayFrequency::Annually, 60_000), 456_300);
+        let adjusted = FormW4 {
+            deductions: 1_000_000,
+            extra_withholding: UsdAmount::from_dollars(50),
+            ..joint
+        };
+        assert_eq!(withhold(&adjusted, PayFrequency::Weekly, 2_000), 5_000);
+    }
+
+    #[cfg(feature = "year-2024")]
+    #[test]
+    fn recommendations() {
+        let year = TaxYear::Y2024;
+        let w4 = FormW4::new(FilingStatus::MarriedFilingJointly);
+        let frequency = PayFrequency::Semimonthly;
+        let wages = UsdAmount::from_dollars(6_000);
+        let per_period = federal_withholding(year, &w4, frequency, wages).unwrap();
+
+        // Halfway through the year, with a liability well above what the
+        // W-4 withholds
+        let withheld = UsdAmount::from_cents(per_period.cents() * 12);
+        let change = recommend_w4(20_000, withheld, per_period, frequency, 12).unwrap();
+        assert_eq!(
+            change.projected_withholding.cents(),
+            per_period.cents() * 24
+        );
+        assert_eq!(change.additional_credits, 0);
+        let extra = change.additional_withholding;
+        assert!(extra.is_whole_dollars());
+        let w4 = FormW4 {
+            extra_withholding: extra,
+            ..w4
+        };
+        let raised = federal_withholding(year, &w4, frequency, wages).unwrap();
+        let total = withheld.cents() + raised.cents() * 12;
+        assert!((0..1_200).contains(&(total - 2_000_000)), "{total}");
+
+        // Too much withheld: Step 3 credits bring it back near zero.
+        let w4 = FormW4::new(FilingStatus::MarriedFilingJointly);
+        let change = recommend_w4(5_000, UsdAmount::ZERO, per_period, frequency, 24).unwrap();
+        assert!(change.projected_balance.cents() < 0);
+        let w4 = FormW4 {
+            credits: change.additional_credits,
+            ..w4
+        };
+        let lowered = federal_withholding(year, &w4, frequency, wages).unwrap();
+        let total = lowered.cents() * 24;
+        assert!((500_000..500_000 + 2_400).contains(&total), "{total}");
+
+        // A refund beyond the withholding left cannot be undone.
+        let change = recommend_w4(0, UsdAmount::from_dollars(9_000), per_period, frequency, 2);
+        assert_eq!(
+            change.unwrap().additional_credits,
+            per_period.cents() * 24 / 100
+        );
+        // No paychecks left
+        let change = recommend_w4(50_000, UsdAmount::ZERO, per_period, frequency, 0).unwrap();
+        assert_eq!(change.projected_balance.to_string(), "$50,000");
+        assert_eq!(change.additional_withholding, UsdAmount::ZERO);
+        assert_eq!(
+            recommend_w4(1_000, UsdAmount::ZERO, per_period, frequency, -1),
+            Err(TaxError::NegativeIncome)
+        );
+    }
+
+    #[cfg(all(feature = "year-2018", feature = "year-2024"))]
+    #[test]
+    fn errors() {
+        let w4 = FormW4::new(FilingStatus::Single);
+        let withhold = |year, w4: &FormW4, cents| {
+            federal_withholding(year, w4, PayFrequency::Weekly, UsdAmount::from_cents(cents))
+        };
+        assert_eq!(
+            withhold(TaxYear::Y2024, &w4, -1),
+            Err(TaxError::NegativeIncome)
+        );
+        let negative = FormW4 { credits: -1, ..w4 };
+        assert_eq!(
+            withhold(TaxYear::Y2024, &negative, 100_000),
+            Err(TaxError::NegativeIncome)
+        );
+        assert_eq!(
+            withhold(TaxYear::Y2018, &w4, 100_000),
+            Err(TaxError::UnsupportedYear(2018))
+        );
+        assert_eq!(
+            withhold(TaxYear::Y2024, &w4, i64::MAX),
+            Err(TaxError::IncomeOutOfRange)
+        );
+    }
+}